@@ -19,6 +19,7 @@ import {
   MsfsMiscPublisher,
   MsfsVersionPopupMonitor,
   NotificationManager,
+  PersistentLocalVarSync,
   PilotSeatManager,
   PilotSeatPublisher,
   TelexCheck,
@@ -95,6 +96,16 @@ class ExtrasHost extends BaseInstrument {
 
   private readonly pilotSeatManager = new PilotSeatManager(ExtrasHost.flightDeckBounds);
 
+  private readonly persistentLocalVarSync = new PersistentLocalVarSync([
+    { configKey: 'APU_START_COUNT', localVarName: 'L:A32NX_APU_START_COUNT' },
+    { configKey: 'APU_OPERATING_HOURS', localVarName: 'L:A32NX_APU_OPERATING_HOURS' },
+    { configKey: 'APU_OIL_CONSUMED_QUANTITY', localVarName: 'L:A32NX_APU_OIL_CONSUMED_QUANTITY' },
+    ...Array.from({ length: 4 }, (_, i) => ({
+      configKey: `BRAKE_WEAR_${i + 1}`,
+      localVarName: `L:A32NX_BRAKE_WEAR_${i + 1}`,
+    })),
+  ]);
+
   private readonly telexCheck = new TelexCheck();
 
   private readonly flightPlanAsoboSync: FlightPlanAsoboSync | undefined;
@@ -157,6 +168,7 @@ class ExtrasHost extends BaseInstrument {
     this.backplane.addPublisher('A32NXEcpBusPublisher', new A32NXEcpBusPublisher(this.bus));
     this.backplane.addPublisher('PilotSeatPublisher', new PilotSeatPublisher(this.bus));
     this.backplane.addInstrument('PilotSeatManager', this.pilotSeatManager);
+    this.backplane.addInstrument('PersistentLocalVarSync', this.persistentLocalVarSync);
     this.backplane.addInstrument('GPUManagement', this.gpuManagement);
     this.backplane.addInstrument('Clock', this.clock);
     this.backplane.addInstrument('LightSync', this.lightSync);
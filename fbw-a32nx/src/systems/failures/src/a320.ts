@@ -54,6 +54,33 @@ export const A320Failure = Object.freeze({
   DirectCurrentHot1: 24111,
   DirectCurrentHot2: 24112,
   DirectCurrentGndFltService: 24113,
+  CircuitBreakerAcEssShed: 24200,
+  CircuitBreakerDcEssShed: 24201,
+
+  Engine1Fire: 26001,
+  Engine2Fire: 26002,
+  APUFire: 26003,
+  MLGFire: 26004,
+  Engine1LoopA: 26005,
+  Engine1LoopB: 26006,
+  Engine2LoopA: 26007,
+  Engine2LoopB: 26008,
+  APULoopA: 26009,
+  APULoopB: 26010,
+  MLGLoopA: 26011,
+  MLGLoopB: 26012,
+  FwdCargoSmoke: 26013,
+  AftCargoSmoke: 26014,
+  LavatorySmoke: 26015,
+  AvionicsSmoke: 26016,
+  FwdCargoSmokeDetectorA: 26017,
+  FwdCargoSmokeDetectorB: 26018,
+  AftCargoSmokeDetectorA: 26019,
+  AftCargoSmokeDetectorB: 26020,
+  LavatorySmokeDetectorA: 26021,
+  LavatorySmokeDetectorB: 26022,
+  AvionicsSmokeDetectorA: 26023,
+  AvionicsSmokeDetectorB: 26024,
 
   Elac1Failure: 27000,
   Elac2Failure: 27001,
@@ -62,6 +89,77 @@ export const A320Failure = Object.freeze({
   Sec3Failure: 27004,
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
+  LeftAileronBlueActuatorJam: 27100,
+  LeftAileronGreenActuatorJam: 27101,
+  LeftAileronBlueActuatorRunaway: 27102,
+  LeftAileronGreenActuatorRunaway: 27103,
+  LeftAileronDisconnect: 27104,
+  RightAileronBlueActuatorJam: 27105,
+  RightAileronGreenActuatorJam: 27106,
+  RightAileronBlueActuatorRunaway: 27107,
+  RightAileronGreenActuatorRunaway: 27108,
+  RightAileronDisconnect: 27109,
+  LeftElevatorBlueActuatorJam: 27110,
+  LeftElevatorGreenActuatorJam: 27111,
+  LeftElevatorBlueActuatorRunaway: 27112,
+  LeftElevatorGreenActuatorRunaway: 27113,
+  LeftElevatorDisconnect: 27114,
+  RightElevatorBlueActuatorJam: 27115,
+  RightElevatorYellowActuatorJam: 27116,
+  RightElevatorBlueActuatorRunaway: 27117,
+  RightElevatorYellowActuatorRunaway: 27118,
+  RightElevatorDisconnect: 27119,
+  RudderGreenActuatorJam: 27120,
+  RudderBlueActuatorJam: 27121,
+  RudderYellowActuatorJam: 27122,
+  RudderGreenActuatorRunaway: 27123,
+  RudderBlueActuatorRunaway: 27124,
+  RudderYellowActuatorRunaway: 27125,
+  RudderDisconnect: 27126,
+  LeftSpoiler1ActuatorJam: 27127,
+  LeftSpoiler1ActuatorRunaway: 27128,
+  LeftSpoiler1Disconnect: 27129,
+  LeftSpoiler2ActuatorJam: 27130,
+  LeftSpoiler2ActuatorRunaway: 27131,
+  LeftSpoiler2Disconnect: 27132,
+  LeftSpoiler3ActuatorJam: 27133,
+  LeftSpoiler3ActuatorRunaway: 27134,
+  LeftSpoiler3Disconnect: 27135,
+  LeftSpoiler4ActuatorJam: 27136,
+  LeftSpoiler4ActuatorRunaway: 27137,
+  LeftSpoiler4Disconnect: 27138,
+  LeftSpoiler5ActuatorJam: 27139,
+  LeftSpoiler5ActuatorRunaway: 27140,
+  LeftSpoiler5Disconnect: 27141,
+  RightSpoiler1ActuatorJam: 27142,
+  RightSpoiler1ActuatorRunaway: 27143,
+  RightSpoiler1Disconnect: 27144,
+  RightSpoiler2ActuatorJam: 27145,
+  RightSpoiler2ActuatorRunaway: 27146,
+  RightSpoiler2Disconnect: 27147,
+  RightSpoiler3ActuatorJam: 27148,
+  RightSpoiler3ActuatorRunaway: 27149,
+  RightSpoiler3Disconnect: 27150,
+  RightSpoiler4ActuatorJam: 27151,
+  RightSpoiler4ActuatorRunaway: 27152,
+  RightSpoiler4Disconnect: 27153,
+  RightSpoiler5ActuatorJam: 27154,
+  RightSpoiler5ActuatorRunaway: 27155,
+  RightSpoiler5Disconnect: 27156,
+  ThsJam: 27157,
+  ThsElectricMotor1Runaway: 27158,
+  ThsElectricMotor2Runaway: 27159,
+  ThsElectricMotor3Runaway: 27160,
+  ThsGreenHydraulicMotor: 27161,
+  ThsYellowHydraulicMotor: 27162,
+  LeftFlapsTransmissionShaftBreak: 27163,
+  RightFlapsTransmissionShaftBreak: 27164,
+  LeftSlatsTransmissionShaftBreak: 27165,
+  RightSlatsTransmissionShaftBreak: 27166,
+  LeftFlapsSkew: 27167,
+  RightFlapsSkew: 27168,
+  LeftSlatsSkew: 27169,
+  RightSlatsSkew: 27170,
 
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
@@ -76,6 +174,25 @@ export const A320Failure = Object.freeze({
   BlueEpumpOverheat: 29010,
   YellowEdpOverheat: 29011,
   YellowEpumpOverheat: 29012,
+  GreenPumpCaseDrainLeak: 29013,
+  BluePumpCaseDrainLeak: 29014,
+  YellowPumpCaseDrainLeak: 29015,
+  GreenPumpSectionLeak: 29016,
+  BluePumpSectionLeak: 29017,
+  YellowPumpSectionLeak: 29018,
+  GreenSystemSectionLeak: 29019,
+  BlueSystemSectionLeak: 29020,
+  YellowSystemSectionLeak: 29021,
+  GreenPtuLeak: 29022,
+  YellowPtuLeak: 29023,
+  LeftAileronBlueActuatorLeak: 29024,
+  LeftAileronGreenActuatorLeak: 29025,
+  RightAileronBlueActuatorLeak: 29026,
+  RightAileronGreenActuatorLeak: 29027,
+  LeftElevatorBlueActuatorLeak: 29028,
+  LeftElevatorGreenActuatorLeak: 29029,
+  RightElevatorBlueActuatorLeak: 29030,
+  RightElevatorYellowActuatorLeak: 29031,
 
   LeftPfdDisplay: 31000,
   RightPfdDisplay: 31001,
@@ -111,10 +228,43 @@ export const A320Failure = Object.freeze({
   GearActuatorJammedGearDoorLeft: 32024,
   GearActuatorJammedGearDoorRight: 32025,
 
+  GearDoorActuatorFaultGearDoorNose: 32030,
+  GearDoorActuatorFaultGearDoorLeft: 32031,
+  GearDoorActuatorFaultGearDoorRight: 32032,
+
+  GearUplockJammedGearNose: 32040,
+  GearUplockJammedGearLeft: 32041,
+  GearUplockJammedGearRight: 32042,
+  GearUplockJammedGearDoorNose: 32043,
+  GearUplockJammedGearDoorLeft: 32044,
+  GearUplockJammedGearDoorRight: 32045,
+
+  GearDownlockFailureGearNose: 32050,
+  GearDownlockFailureGearLeft: 32051,
+  GearDownlockFailureGearRight: 32052,
+
   GreenBrakeHydraulicLeak: 32100,
   YellowBrakeHydraulicLeak: 32101,
   YellowBrakeAccumulatorGasLeak: 32150,
 
+  FlatTyre1: 32200,
+  FlatTyre2: 32201,
+  FlatTyre3: 32202,
+  FlatTyre4: 32203,
+  BrakeSeized1: 32210,
+  BrakeSeized2: 32211,
+  BrakeSeized3: 32212,
+  BrakeSeized4: 32213,
+  BrakeReleased1: 32220,
+  BrakeReleased2: 32221,
+  BrakeReleased3: 32222,
+  BrakeReleased4: 32223,
+  BrakeTemperatureProbe1: 32230,
+  BrakeTemperatureProbe2: 32231,
+  BrakeTemperatureProbe3: 32232,
+  BrakeTemperatureProbe4: 32233,
+  AntiSkid: 32240,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAntennaInterrupted1: 34010,
@@ -122,6 +272,59 @@ export const A320Failure = Object.freeze({
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
   Egpwc: 34030,
+
+  Eng1BleedPylonLeak: 36000,
+  Eng2BleedPylonLeak: 36001,
+  LeftWingBleedLeak: 36002,
+  RightWingBleedLeak: 36003,
+  ApuBleedDuctLeak: 36004,
+  Pack1BayBleedLeak: 36005,
+  Pack2BayBleedLeak: 36006,
+  Eng1PylonLeakDetectionLoop: 36010,
+  Eng2PylonLeakDetectionLoop: 36011,
+  LeftWingLeakDetectionLoopA: 36012,
+  LeftWingLeakDetectionLoopB: 36013,
+  RightWingLeakDetectionLoopA: 36014,
+  RightWingLeakDetectionLoopB: 36015,
+  ApuDuctLeakDetectionLoop: 36016,
+  Eng1HpValveStuckOpen: 36020,
+  Eng2HpValveStuckOpen: 36021,
+  Eng1PrValveStuckOpen: 36022,
+  Eng2PrValveStuckOpen: 36023,
+  CrossBleedValveStuckOpen: 36024,
+  Pack1FlowControlValveStuckOpen: 36025,
+  Pack2FlowControlValveStuckOpen: 36026,
+  ApuBleedValveStuckOpen: 36027,
+  Eng1HpValveStuckClosed: 36040,
+  Eng2HpValveStuckClosed: 36041,
+  Eng1PrValveStuckClosed: 36042,
+  Eng2PrValveStuckClosed: 36043,
+  CrossBleedValveStuckClosed: 36044,
+  Pack1FlowControlValveStuckClosed: 36045,
+  Pack2FlowControlValveStuckClosed: 36046,
+  ApuBleedValveStuckClosed: 36047,
+  Eng1HpValveStuckInPosition: 36060,
+  Eng2HpValveStuckInPosition: 36061,
+  Eng1PrValveStuckInPosition: 36062,
+  Eng2PrValveStuckInPosition: 36063,
+  CrossBleedValveStuckInPosition: 36064,
+  Pack1FlowControlValveStuckInPosition: 36065,
+  Pack2FlowControlValveStuckInPosition: 36066,
+  ApuBleedValveStuckInPosition: 36067,
+  Eng1HpValveInternalLeak: 36080,
+  Eng2HpValveInternalLeak: 36081,
+  Eng1PrValveInternalLeak: 36082,
+  Eng2PrValveInternalLeak: 36083,
+  CrossBleedValveInternalLeak: 36084,
+  Pack1FlowControlValveInternalLeak: 36085,
+  Pack2FlowControlValveInternalLeak: 36086,
+  ApuBleedValveInternalLeak: 36087,
+
+  ApuHungStart: 49000,
+  ApuEgtOvertemperature: 49001,
+  ApuLowOilPressure: 49002,
+  ApuAirIntakeFlapStuck: 49003,
+  ApuElectronicControlBox: 49004,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -174,6 +377,33 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.DirectCurrentHot1, 'DC HOT 1'],
   [24, A320Failure.DirectCurrentHot2, 'DC HOT 2'],
   [24, A320Failure.DirectCurrentGndFltService, 'DC GND FLT SRV'],
+  [24, A320Failure.CircuitBreakerAcEssShed, 'AC ESS SHED CB'],
+  [24, A320Failure.CircuitBreakerDcEssShed, 'DC ESS SHED CB'],
+
+  [26, A320Failure.Engine1Fire, 'Fire - Engine 1'],
+  [26, A320Failure.Engine2Fire, 'Fire - Engine 2'],
+  [26, A320Failure.APUFire, 'Fire - APU'],
+  [26, A320Failure.MLGFire, 'Fire - Main Landing Gear Bay'],
+  [26, A320Failure.Engine1LoopA, 'Engine 1 Loop A'],
+  [26, A320Failure.Engine1LoopB, 'Engine 1 Loop B'],
+  [26, A320Failure.Engine2LoopA, 'Engine 2 Loop A'],
+  [26, A320Failure.Engine2LoopB, 'Engine 2 Loop B'],
+  [26, A320Failure.APULoopA, 'APU Loop A'],
+  [26, A320Failure.APULoopB, 'APU Loop B'],
+  [26, A320Failure.MLGLoopA, 'Main Landing Gear Bay Loop A'],
+  [26, A320Failure.MLGLoopB, 'Main Landing Gear Bay Loop B'],
+  [26, A320Failure.FwdCargoSmoke, 'Smoke - Forward Cargo'],
+  [26, A320Failure.AftCargoSmoke, 'Smoke - Aft Cargo'],
+  [26, A320Failure.LavatorySmoke, 'Smoke - Lavatory'],
+  [26, A320Failure.AvionicsSmoke, 'Smoke - Avionics Bay'],
+  [26, A320Failure.FwdCargoSmokeDetectorA, 'Forward Cargo Smoke Detector A'],
+  [26, A320Failure.FwdCargoSmokeDetectorB, 'Forward Cargo Smoke Detector B'],
+  [26, A320Failure.AftCargoSmokeDetectorA, 'Aft Cargo Smoke Detector A'],
+  [26, A320Failure.AftCargoSmokeDetectorB, 'Aft Cargo Smoke Detector B'],
+  [26, A320Failure.LavatorySmokeDetectorA, 'Lavatory Smoke Detector A'],
+  [26, A320Failure.LavatorySmokeDetectorB, 'Lavatory Smoke Detector B'],
+  [26, A320Failure.AvionicsSmokeDetectorA, 'Avionics Bay Smoke Detector A'],
+  [26, A320Failure.AvionicsSmokeDetectorB, 'Avionics Bay Smoke Detector B'],
 
   [27, A320Failure.Elac1Failure, 'ELAC 1'],
   [27, A320Failure.Elac2Failure, 'ELAC 2'],
@@ -182,6 +412,77 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Sec3Failure, 'SEC 3'],
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
+  [27, A320Failure.LeftAileronBlueActuatorJam, 'Left aileron blue actuator jam'],
+  [27, A320Failure.LeftAileronGreenActuatorJam, 'Left aileron green actuator jam'],
+  [27, A320Failure.LeftAileronBlueActuatorRunaway, 'Left aileron blue actuator runaway'],
+  [27, A320Failure.LeftAileronGreenActuatorRunaway, 'Left aileron green actuator runaway'],
+  [27, A320Failure.LeftAileronDisconnect, 'Left aileron disconnect'],
+  [27, A320Failure.RightAileronBlueActuatorJam, 'Right aileron blue actuator jam'],
+  [27, A320Failure.RightAileronGreenActuatorJam, 'Right aileron green actuator jam'],
+  [27, A320Failure.RightAileronBlueActuatorRunaway, 'Right aileron blue actuator runaway'],
+  [27, A320Failure.RightAileronGreenActuatorRunaway, 'Right aileron green actuator runaway'],
+  [27, A320Failure.RightAileronDisconnect, 'Right aileron disconnect'],
+  [27, A320Failure.LeftElevatorBlueActuatorJam, 'Left elevator blue actuator jam'],
+  [27, A320Failure.LeftElevatorGreenActuatorJam, 'Left elevator green actuator jam'],
+  [27, A320Failure.LeftElevatorBlueActuatorRunaway, 'Left elevator blue actuator runaway'],
+  [27, A320Failure.LeftElevatorGreenActuatorRunaway, 'Left elevator green actuator runaway'],
+  [27, A320Failure.LeftElevatorDisconnect, 'Left elevator disconnect'],
+  [27, A320Failure.RightElevatorBlueActuatorJam, 'Right elevator blue actuator jam'],
+  [27, A320Failure.RightElevatorYellowActuatorJam, 'Right elevator yellow actuator jam'],
+  [27, A320Failure.RightElevatorBlueActuatorRunaway, 'Right elevator blue actuator runaway'],
+  [27, A320Failure.RightElevatorYellowActuatorRunaway, 'Right elevator yellow actuator runaway'],
+  [27, A320Failure.RightElevatorDisconnect, 'Right elevator disconnect'],
+  [27, A320Failure.RudderGreenActuatorJam, 'Rudder green actuator jam'],
+  [27, A320Failure.RudderBlueActuatorJam, 'Rudder blue actuator jam'],
+  [27, A320Failure.RudderYellowActuatorJam, 'Rudder yellow actuator jam'],
+  [27, A320Failure.RudderGreenActuatorRunaway, 'Rudder green actuator runaway'],
+  [27, A320Failure.RudderBlueActuatorRunaway, 'Rudder blue actuator runaway'],
+  [27, A320Failure.RudderYellowActuatorRunaway, 'Rudder yellow actuator runaway'],
+  [27, A320Failure.RudderDisconnect, 'Rudder disconnect'],
+  [27, A320Failure.LeftSpoiler1ActuatorJam, 'Left spoiler 1 actuator jam'],
+  [27, A320Failure.LeftSpoiler1ActuatorRunaway, 'Left spoiler 1 actuator runaway'],
+  [27, A320Failure.LeftSpoiler1Disconnect, 'Left spoiler 1 disconnect'],
+  [27, A320Failure.LeftSpoiler2ActuatorJam, 'Left spoiler 2 actuator jam'],
+  [27, A320Failure.LeftSpoiler2ActuatorRunaway, 'Left spoiler 2 actuator runaway'],
+  [27, A320Failure.LeftSpoiler2Disconnect, 'Left spoiler 2 disconnect'],
+  [27, A320Failure.LeftSpoiler3ActuatorJam, 'Left spoiler 3 actuator jam'],
+  [27, A320Failure.LeftSpoiler3ActuatorRunaway, 'Left spoiler 3 actuator runaway'],
+  [27, A320Failure.LeftSpoiler3Disconnect, 'Left spoiler 3 disconnect'],
+  [27, A320Failure.LeftSpoiler4ActuatorJam, 'Left spoiler 4 actuator jam'],
+  [27, A320Failure.LeftSpoiler4ActuatorRunaway, 'Left spoiler 4 actuator runaway'],
+  [27, A320Failure.LeftSpoiler4Disconnect, 'Left spoiler 4 disconnect'],
+  [27, A320Failure.LeftSpoiler5ActuatorJam, 'Left spoiler 5 actuator jam'],
+  [27, A320Failure.LeftSpoiler5ActuatorRunaway, 'Left spoiler 5 actuator runaway'],
+  [27, A320Failure.LeftSpoiler5Disconnect, 'Left spoiler 5 disconnect'],
+  [27, A320Failure.RightSpoiler1ActuatorJam, 'Right spoiler 1 actuator jam'],
+  [27, A320Failure.RightSpoiler1ActuatorRunaway, 'Right spoiler 1 actuator runaway'],
+  [27, A320Failure.RightSpoiler1Disconnect, 'Right spoiler 1 disconnect'],
+  [27, A320Failure.RightSpoiler2ActuatorJam, 'Right spoiler 2 actuator jam'],
+  [27, A320Failure.RightSpoiler2ActuatorRunaway, 'Right spoiler 2 actuator runaway'],
+  [27, A320Failure.RightSpoiler2Disconnect, 'Right spoiler 2 disconnect'],
+  [27, A320Failure.RightSpoiler3ActuatorJam, 'Right spoiler 3 actuator jam'],
+  [27, A320Failure.RightSpoiler3ActuatorRunaway, 'Right spoiler 3 actuator runaway'],
+  [27, A320Failure.RightSpoiler3Disconnect, 'Right spoiler 3 disconnect'],
+  [27, A320Failure.RightSpoiler4ActuatorJam, 'Right spoiler 4 actuator jam'],
+  [27, A320Failure.RightSpoiler4ActuatorRunaway, 'Right spoiler 4 actuator runaway'],
+  [27, A320Failure.RightSpoiler4Disconnect, 'Right spoiler 4 disconnect'],
+  [27, A320Failure.RightSpoiler5ActuatorJam, 'Right spoiler 5 actuator jam'],
+  [27, A320Failure.RightSpoiler5ActuatorRunaway, 'Right spoiler 5 actuator runaway'],
+  [27, A320Failure.RightSpoiler5Disconnect, 'Right spoiler 5 disconnect'],
+  [27, A320Failure.ThsJam, 'THS jam'],
+  [27, A320Failure.ThsElectricMotor1Runaway, 'THS electric motor 1 runaway'],
+  [27, A320Failure.ThsElectricMotor2Runaway, 'THS electric motor 2 runaway'],
+  [27, A320Failure.ThsElectricMotor3Runaway, 'THS electric motor 3 runaway'],
+  [27, A320Failure.ThsGreenHydraulicMotor, 'THS green hydraulic motor'],
+  [27, A320Failure.ThsYellowHydraulicMotor, 'THS yellow hydraulic motor'],
+  [27, A320Failure.LeftFlapsTransmissionShaftBreak, 'Left flaps transmission shaft break'],
+  [27, A320Failure.RightFlapsTransmissionShaftBreak, 'Right flaps transmission shaft break'],
+  [27, A320Failure.LeftSlatsTransmissionShaftBreak, 'Left slats transmission shaft break'],
+  [27, A320Failure.RightSlatsTransmissionShaftBreak, 'Right slats transmission shaft break'],
+  [27, A320Failure.LeftFlapsSkew, 'Left flaps skew'],
+  [27, A320Failure.RightFlapsSkew, 'Right flaps skew'],
+  [27, A320Failure.LeftSlatsSkew, 'Left slats skew'],
+  [27, A320Failure.RightSlatsSkew, 'Right slats skew'],
 
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
@@ -196,6 +497,25 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [29, A320Failure.BlueEpumpOverheat, 'Blue electric pump overheat'],
   [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
   [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
+  [29, A320Failure.GreenPumpCaseDrainLeak, 'Green pump case drain leak'],
+  [29, A320Failure.BluePumpCaseDrainLeak, 'Blue pump case drain leak'],
+  [29, A320Failure.YellowPumpCaseDrainLeak, 'Yellow pump case drain leak'],
+  [29, A320Failure.GreenPumpSectionLeak, 'Green pump section leak'],
+  [29, A320Failure.BluePumpSectionLeak, 'Blue pump section leak'],
+  [29, A320Failure.YellowPumpSectionLeak, 'Yellow pump section leak'],
+  [29, A320Failure.GreenSystemSectionLeak, 'Green system section leak'],
+  [29, A320Failure.BlueSystemSectionLeak, 'Blue system section leak'],
+  [29, A320Failure.YellowSystemSectionLeak, 'Yellow system section leak'],
+  [29, A320Failure.GreenPtuLeak, 'PTU leak on green side'],
+  [29, A320Failure.YellowPtuLeak, 'PTU leak on yellow side'],
+  [29, A320Failure.LeftAileronBlueActuatorLeak, 'Left aileron blue actuator leak'],
+  [29, A320Failure.LeftAileronGreenActuatorLeak, 'Left aileron green actuator leak'],
+  [29, A320Failure.RightAileronBlueActuatorLeak, 'Right aileron blue actuator leak'],
+  [29, A320Failure.RightAileronGreenActuatorLeak, 'Right aileron green actuator leak'],
+  [29, A320Failure.LeftElevatorBlueActuatorLeak, 'Left elevator blue actuator leak'],
+  [29, A320Failure.LeftElevatorGreenActuatorLeak, 'Left elevator green actuator leak'],
+  [29, A320Failure.RightElevatorBlueActuatorLeak, 'Right elevator blue actuator leak'],
+  [29, A320Failure.RightElevatorYellowActuatorLeak, 'Right elevator yellow actuator leak'],
 
   [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
   [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -226,10 +546,43 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [32, A320Failure.GearActuatorJammedGearDoorLeft, 'Main left gear door jammed actuator'],
   [32, A320Failure.GearActuatorJammedGearDoorRight, 'Main right gear door jammed actuator'],
 
+  [32, A320Failure.GearDoorActuatorFaultGearDoorNose, 'Nose gear door actuator fault'],
+  [32, A320Failure.GearDoorActuatorFaultGearDoorLeft, 'Main left gear door actuator fault'],
+  [32, A320Failure.GearDoorActuatorFaultGearDoorRight, 'Main right gear door actuator fault'],
+
+  [32, A320Failure.GearUplockJammedGearNose, 'Nose gear uplock jammed'],
+  [32, A320Failure.GearUplockJammedGearLeft, 'Main left gear uplock jammed'],
+  [32, A320Failure.GearUplockJammedGearRight, 'Main right gear uplock jammed'],
+  [32, A320Failure.GearUplockJammedGearDoorNose, 'Nose gear door uplock jammed'],
+  [32, A320Failure.GearUplockJammedGearDoorLeft, 'Main left gear door uplock jammed'],
+  [32, A320Failure.GearUplockJammedGearDoorRight, 'Main right gear door uplock jammed'],
+
+  [32, A320Failure.GearDownlockFailureGearNose, 'Nose gear downlock failure'],
+  [32, A320Failure.GearDownlockFailureGearLeft, 'Main left gear downlock failure'],
+  [32, A320Failure.GearDownlockFailureGearRight, 'Main right gear downlock failure'],
+
   [32, A320Failure.GreenBrakeHydraulicLeak, 'Green brakes circuit leak'],
   [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
   [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
 
+  [32, A320Failure.FlatTyre1, 'Flat tyre 1'],
+  [32, A320Failure.FlatTyre2, 'Flat tyre 2'],
+  [32, A320Failure.FlatTyre3, 'Flat tyre 3'],
+  [32, A320Failure.FlatTyre4, 'Flat tyre 4'],
+  [32, A320Failure.BrakeSeized1, 'Brake 1 seized'],
+  [32, A320Failure.BrakeSeized2, 'Brake 2 seized'],
+  [32, A320Failure.BrakeSeized3, 'Brake 3 seized'],
+  [32, A320Failure.BrakeSeized4, 'Brake 4 seized'],
+  [32, A320Failure.BrakeReleased1, 'Brake 1 released'],
+  [32, A320Failure.BrakeReleased2, 'Brake 2 released'],
+  [32, A320Failure.BrakeReleased3, 'Brake 3 released'],
+  [32, A320Failure.BrakeReleased4, 'Brake 4 released'],
+  [32, A320Failure.BrakeTemperatureProbe1, 'Brake 1 temperature probe'],
+  [32, A320Failure.BrakeTemperatureProbe2, 'Brake 2 temperature probe'],
+  [32, A320Failure.BrakeTemperatureProbe3, 'Brake 3 temperature probe'],
+  [32, A320Failure.BrakeTemperatureProbe4, 'Brake 4 temperature probe'],
+  [32, A320Failure.AntiSkid, 'Anti-skid inoperative'],
+
   [34, A320Failure.RadioAltimeter1, 'RA 1'],
   [34, A320Failure.RadioAltimeter2, 'RA 2'],
   [34, A320Failure.RadioAntennaInterrupted1, 'RA 1 Interrupted'],
@@ -238,4 +591,57 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [34, A320Failure.RadioAntennaDirectCoupling2, 'RA 2 Direct Coupling'],
 
   [34, A320Failure.Egpwc, 'EGPWC'],
+
+  [36, A320Failure.Eng1BleedPylonLeak, 'Engine 1 Pylon Bleed Leak'],
+  [36, A320Failure.Eng2BleedPylonLeak, 'Engine 2 Pylon Bleed Leak'],
+  [36, A320Failure.LeftWingBleedLeak, 'Left Wing Bleed Leak'],
+  [36, A320Failure.RightWingBleedLeak, 'Right Wing Bleed Leak'],
+  [36, A320Failure.ApuBleedDuctLeak, 'APU Bleed Duct Leak'],
+  [36, A320Failure.Pack1BayBleedLeak, 'Pack 1 Bay Bleed Leak'],
+  [36, A320Failure.Pack2BayBleedLeak, 'Pack 2 Bay Bleed Leak'],
+  [36, A320Failure.Eng1PylonLeakDetectionLoop, 'Engine 1 Pylon Leak Detection Loop'],
+  [36, A320Failure.Eng2PylonLeakDetectionLoop, 'Engine 2 Pylon Leak Detection Loop'],
+  [36, A320Failure.LeftWingLeakDetectionLoopA, 'Left Wing Leak Detection Loop A'],
+  [36, A320Failure.LeftWingLeakDetectionLoopB, 'Left Wing Leak Detection Loop B'],
+  [36, A320Failure.RightWingLeakDetectionLoopA, 'Right Wing Leak Detection Loop A'],
+  [36, A320Failure.RightWingLeakDetectionLoopB, 'Right Wing Leak Detection Loop B'],
+  [36, A320Failure.ApuDuctLeakDetectionLoop, 'APU Duct Leak Detection Loop'],
+  [36, A320Failure.Eng1HpValveStuckOpen, 'Engine 1 HP Valve Stuck Open'],
+  [36, A320Failure.Eng2HpValveStuckOpen, 'Engine 2 HP Valve Stuck Open'],
+  [36, A320Failure.Eng1PrValveStuckOpen, 'Engine 1 Pressure Regulating Valve Stuck Open'],
+  [36, A320Failure.Eng2PrValveStuckOpen, 'Engine 2 Pressure Regulating Valve Stuck Open'],
+  [36, A320Failure.CrossBleedValveStuckOpen, 'Cross Bleed Valve Stuck Open'],
+  [36, A320Failure.Pack1FlowControlValveStuckOpen, 'Pack 1 Flow Control Valve Stuck Open'],
+  [36, A320Failure.Pack2FlowControlValveStuckOpen, 'Pack 2 Flow Control Valve Stuck Open'],
+  [36, A320Failure.ApuBleedValveStuckOpen, 'APU Bleed Valve Stuck Open'],
+  [36, A320Failure.Eng1HpValveStuckClosed, 'Engine 1 HP Valve Stuck Closed'],
+  [36, A320Failure.Eng2HpValveStuckClosed, 'Engine 2 HP Valve Stuck Closed'],
+  [36, A320Failure.Eng1PrValveStuckClosed, 'Engine 1 Pressure Regulating Valve Stuck Closed'],
+  [36, A320Failure.Eng2PrValveStuckClosed, 'Engine 2 Pressure Regulating Valve Stuck Closed'],
+  [36, A320Failure.CrossBleedValveStuckClosed, 'Cross Bleed Valve Stuck Closed'],
+  [36, A320Failure.Pack1FlowControlValveStuckClosed, 'Pack 1 Flow Control Valve Stuck Closed'],
+  [36, A320Failure.Pack2FlowControlValveStuckClosed, 'Pack 2 Flow Control Valve Stuck Closed'],
+  [36, A320Failure.ApuBleedValveStuckClosed, 'APU Bleed Valve Stuck Closed'],
+  [36, A320Failure.Eng1HpValveStuckInPosition, 'Engine 1 HP Valve Stuck In Position'],
+  [36, A320Failure.Eng2HpValveStuckInPosition, 'Engine 2 HP Valve Stuck In Position'],
+  [36, A320Failure.Eng1PrValveStuckInPosition, 'Engine 1 Pressure Regulating Valve Stuck In Position'],
+  [36, A320Failure.Eng2PrValveStuckInPosition, 'Engine 2 Pressure Regulating Valve Stuck In Position'],
+  [36, A320Failure.CrossBleedValveStuckInPosition, 'Cross Bleed Valve Stuck In Position'],
+  [36, A320Failure.Pack1FlowControlValveStuckInPosition, 'Pack 1 Flow Control Valve Stuck In Position'],
+  [36, A320Failure.Pack2FlowControlValveStuckInPosition, 'Pack 2 Flow Control Valve Stuck In Position'],
+  [36, A320Failure.ApuBleedValveStuckInPosition, 'APU Bleed Valve Stuck In Position'],
+  [36, A320Failure.Eng1HpValveInternalLeak, 'Engine 1 HP Valve Internal Leak'],
+  [36, A320Failure.Eng2HpValveInternalLeak, 'Engine 2 HP Valve Internal Leak'],
+  [36, A320Failure.Eng1PrValveInternalLeak, 'Engine 1 Pressure Regulating Valve Internal Leak'],
+  [36, A320Failure.Eng2PrValveInternalLeak, 'Engine 2 Pressure Regulating Valve Internal Leak'],
+  [36, A320Failure.CrossBleedValveInternalLeak, 'Cross Bleed Valve Internal Leak'],
+  [36, A320Failure.Pack1FlowControlValveInternalLeak, 'Pack 1 Flow Control Valve Internal Leak'],
+  [36, A320Failure.Pack2FlowControlValveInternalLeak, 'Pack 2 Flow Control Valve Internal Leak'],
+  [36, A320Failure.ApuBleedValveInternalLeak, 'APU Bleed Valve Internal Leak'],
+
+  [49, A320Failure.ApuHungStart, 'APU Hung Start'],
+  [49, A320Failure.ApuEgtOvertemperature, 'APU EGT Overtemperature'],
+  [49, A320Failure.ApuLowOilPressure, 'APU Low Oil Pressure'],
+  [49, A320Failure.ApuAirIntakeFlapStuck, 'APU Air Intake Flap Stuck'],
+  [49, A320Failure.ApuElectronicControlBox, 'APU ECB'],
 ];
@@ -475,6 +475,7 @@ const EgtGauge = ({ x, y }: ComponentPositionProps) => {
 
 const ApuMemos = ({ x, y }: ComponentPositionProps) => {
   const lowFuelPressure = useArinc429Var('L:A32NX_APU_LOW_FUEL_PRESSURE_FAULT', 1000);
+  const lowOilLevel = useArinc429Var('L:A32NX_APU_LOW_OIL_LEVEL', 1000);
 
   const [apuFlapOpenPercentage] = useSimVar('L:A32NX_APU_FLAP_OPEN_PERCENTAGE', 'Percent', 1000);
   const [isIntakeIndicationFlashing, setIsIntakeIndicationFlashing] = useState(false);
@@ -506,6 +507,12 @@ const ApuMemos = ({ x, y }: ComponentPositionProps) => {
           </text>
         )}
 
+        {lowOilLevel.value && (
+          <text className="Green FontNormal FillPulse" x={0} y={30}>
+            LOW OIL LEVEL
+          </text>
+        )}
+
         {apuFlapOpenPercentage === 100 && (
           <text className={`Green FontNormal ${isIntakeIndicationFlashing && 'FillPulse'}`} x={0} y={60}>
             FLAP OPEN
@@ -283,6 +283,10 @@ const EwdMessages = new Map<string, { group?: string; text: string }>([
   ['270023002', { text: '\x1b<5m -SEC 3......OFF THEN ON' }],
   ['270023003', { text: '\x1b<7m   .IF UNSUCCESSFUL :' }],
   ['270023004', { text: '\x1b<5m -SEC 3..............OFF' }],
+  ['270015001', { group: 'F/CTL$4', text: ' FLAPS LOCKED' }],
+  ['270015002', { text: '\x1b<5m LDG DIST PROC.....APPLY' }],
+  ['270016001', { group: 'F/CTL$4', text: ' SLATS FAULT' }],
+  ['270016002', { text: '\x1b<5m LDG DIST PROC.....APPLY' }],
   ['270087001', { group: 'F/CTL$4', text: ' GND SPLR NOT ARMED' }],
   ['270036501', { group: 'F/CTL$5', text: ' DIRECT LAW' }],
   ['270036502', { text: '\x1b<4m      (PROT LOST)' }],
@@ -473,6 +477,23 @@ const EwdMessages = new Map<string, { group?: string; text: string }>([
   ['216330503', { text: '\x1b<4m -FWD CAB TRIM VALVE' }],
   ['216330504', { text: '\x1b<4m -AFT CAB TRIM VALVE' }],
   ['216330505', { text: '\x1b<4m -TRIM AIR HI PR' }],
+  ['360001001', { group: 'AIR$4', text: ' ENG 1 BLEED LEAK' }],
+  ['360001002', { text: '\x1b<5m -ENG 1 BLEED........OFF' }],
+  ['360001003', { text: '\x1b<5m -X BLEED...........SHUT' }],
+  ['360002001', { group: 'AIR$4', text: ' ENG 2 BLEED LEAK' }],
+  ['360002002', { text: '\x1b<5m -ENG 2 BLEED........OFF' }],
+  ['360002003', { text: '\x1b<5m -X BLEED...........SHUT' }],
+  ['360003001', { group: 'AIR$4', text: ' L WING LEAK' }],
+  ['360003002', { text: '\x1b<5m -ENG 1 BLEED........OFF' }],
+  ['360003003', { text: '\x1b<5m -X BLEED...........SHUT' }],
+  ['360003004', { text: '\x1b<5m -APU BLEED..........OFF' }],
+  ['360003005', { text: '\x1b<5m AVOID ICING CONDITIONS' }],
+  ['360004001', { group: 'AIR$4', text: ' R WING LEAK' }],
+  ['360004002', { text: '\x1b<5m -ENG 2 BLEED........OFF' }],
+  ['360004003', { text: '\x1b<5m -X BLEED...........SHUT' }],
+  ['360004004', { text: '\x1b<5m AVOID ICING CONDITIONS' }],
+  ['360005001', { group: 'AIR$4', text: ' APU BLEED LEAK' }],
+  ['360005002', { text: '\x1b<5m -APU BLEED..........OFF' }],
   ['213122201', { group: 'CAB PR$4', text: ' SYS 1 FAULT' }],
   ['213122301', { group: 'CAB PR$4', text: ' SYS 2 FAULT' }],
   ['000000001', { text: '              \x1b<3mNORMAL' }],
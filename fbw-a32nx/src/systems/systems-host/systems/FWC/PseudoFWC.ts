@@ -407,6 +407,24 @@ export class PseudoFWC {
 
   private readonly packOffNotFailed2Status = Subject.create(false);
 
+  private readonly eng1BleedLeak = Subject.create(false);
+
+  private readonly eng2BleedLeak = Subject.create(false);
+
+  private readonly leftWingLeak = Subject.create(false);
+
+  private readonly rightWingLeak = Subject.create(false);
+
+  private readonly apuBleedLeak = Subject.create(false);
+
+  private readonly eng1BleedPbAuto = Subject.create(false);
+
+  private readonly eng2BleedPbAuto = Subject.create(false);
+
+  private readonly apuBleedPbOn = Subject.create(false);
+
+  private readonly crossbleedSelectorShut = Subject.create(false);
+
   private readonly cpc1Fault = Subject.create(false);
 
   private readonly cpc2Fault = Subject.create(false);
@@ -920,6 +938,10 @@ export class PseudoFWC {
 
   private readonly flapsMcduDisagree = Subject.create(false);
 
+  private readonly flapsLocked = Subject.create(false);
+
+  private readonly slatsFault = Subject.create(false);
+
   private readonly flapsAndPitchMcduDisagreeEnable = Subject.create(false);
 
   private readonly pitchConfigInPhase3or4Sr = new NXLogicMemoryNode(true);
@@ -3166,6 +3188,16 @@ export class PseudoFWC {
     this.pack1On.set(SimVar.GetSimVarValue('L:A32NX_OVHD_COND_PACK_1_PB_IS_ON', 'bool'));
     this.pack2On.set(SimVar.GetSimVarValue('L:A32NX_OVHD_COND_PACK_2_PB_IS_ON', 'bool'));
 
+    this.eng1BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_PYLON_1_LEAK_DETECTED', 'bool'));
+    this.eng2BleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_PYLON_2_LEAK_DETECTED', 'bool'));
+    this.leftWingLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_WING_1_LEAK_DETECTED', 'bool'));
+    this.rightWingLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_WING_2_LEAK_DETECTED', 'bool'));
+    this.apuBleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_APU_DUCT_LEAK_DETECTED', 'bool'));
+    this.eng1BleedPbAuto.set(eng1Bleed === 1);
+    this.eng2BleedPbAuto.set(SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_2_BLEED_PB_IS_AUTO', 'bool'));
+    this.apuBleedPbOn.set(SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_APU_BLEED_PB_IS_ON', 'bool'));
+    this.crossbleedSelectorShut.set(SimVar.GetSimVarValue('L:A32NX_KNOB_OVHD_AIRCOND_XBLEED_Position', 'number') === 0);
+
     this.cpc1DiscreteWord.setFromSimVar('L:A32NX_PRESS_CPC_1_DISCRETE_WORD');
     this.cpc2DiscreteWord.setFromSimVar('L:A32NX_PRESS_CPC_2_DISCRETE_WORD');
 
@@ -3495,6 +3527,12 @@ export class PseudoFWC {
     // TODO: add switching between SFCC_1 and SFCC_2
     const flapsPos = Arinc429Word.fromSimVarValue('L:A32NX_SFCC_1_FLAP_ACTUAL_POSITION_WORD');
     const slatsPos = Arinc429Word.fromSimVarValue('L:A32NX_SFCC_1_SLAT_ACTUAL_POSITION_WORD');
+    const sfcc1SlatFlapPos = Arinc429Word.fromSimVarValue('L:A32NX_SFCC_1_SLAT_FLAP_ACTUAL_POSITION_WORD');
+    const sfcc2SlatFlapPos = Arinc429Word.fromSimVarValue('L:A32NX_SFCC_2_SLAT_FLAP_ACTUAL_POSITION_WORD');
+
+    // wing tip brakes engaged and slat channel fault
+    this.flapsLocked.set(sfcc1SlatFlapPos.bitValueOr(24, false) || sfcc2SlatFlapPos.bitValueOr(24, false));
+    this.slatsFault.set(sfcc1SlatFlapPos.bitValueOr(17, false) || sfcc2SlatFlapPos.bitValueOr(17, false));
 
     // WARNING these vary for other variants... A320 CFM LEAP values here
     // flap/slat internal signals
@@ -3957,11 +3995,11 @@ export class PseudoFWC {
     this.eng1FireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_ENG1', 'bool'));
     this.eng2FireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_ENG2', 'bool'));
     this.apuFireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_APU', 'bool'));
-    this.eng1Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG1_AGENT1_Discharge', 'bool'));
-    this.eng1Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG1_AGENT2_Discharge', 'bool'));
-    this.eng2Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG2_AGENT1_Discharge', 'bool'));
-    this.eng2Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_ENG2_AGENT2_Discharge', 'bool'));
-    this.apuAgentPB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_APU_AGENT1_Discharge', 'bool'));
+    this.eng1Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_1_ENG_1_IS_DISCHARGED', 'bool'));
+    this.eng1Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_2_ENG_1_IS_DISCHARGED', 'bool'));
+    this.eng2Agent1PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_1_ENG_2_IS_DISCHARGED', 'bool'));
+    this.eng2Agent2PB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_2_ENG_2_IS_DISCHARGED', 'bool'));
+    this.apuAgentPB.set(SimVar.GetSimVarValue('L:A32NX_FIRE_SQUIB_1_APU_1_IS_DISCHARGED', 'bool'));
     this.cargoFireTest.set(SimVar.GetSimVarValue('L:A32NX_FIRE_TEST_CARGO', 'bool'));
     this.cargoFireAgentDisch.set(SimVar.GetSimVarValue('L:A32NX_CARGOSMOKE_FWD_DISCHARGED', 'bool'));
 
@@ -5801,6 +5839,28 @@ export class PseudoFWC {
       sysPage: EcamSysPage.FCTL,
       side: 'LEFT',
     },
+    2700150: {
+      // FLAPS LOCKED
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.flapsLocked,
+      whichCodeToReturn: () => [0, 1],
+      codesToReturn: ['270015001', '270015002'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.FCTL,
+      side: 'LEFT',
+    },
+    2700160: {
+      // SLATS FAULT
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.slatsFault,
+      whichCodeToReturn: () => [0, 1],
+      codesToReturn: ['270016001', '270016002'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.FCTL,
+      side: 'LEFT',
+    },
     2700240: {
       // PITCH TRIM CONFIG
       flightPhaseInhib: [5, 6, 7, 8],
@@ -6394,6 +6454,80 @@ export class PseudoFWC {
       sysPage: EcamSysPage.NONE,
       side: 'LEFT',
     },
+    3600010: {
+      // ENG 1 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.eng1BleedLeak,
+      whichCodeToReturn: () => [
+        0,
+        this.eng1BleedPbAuto.get() ? 1 : null,
+        !this.crossbleedSelectorShut.get() ? 2 : null,
+      ],
+      codesToReturn: ['360001001', '360001002', '360001003'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3600020: {
+      // ENG 2 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.eng2BleedLeak,
+      whichCodeToReturn: () => [
+        0,
+        this.eng2BleedPbAuto.get() ? 1 : null,
+        !this.crossbleedSelectorShut.get() ? 2 : null,
+      ],
+      codesToReturn: ['360002001', '360002002', '360002003'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3600030: {
+      // L WING LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.leftWingLeak,
+      whichCodeToReturn: () => [
+        0,
+        this.eng1BleedPbAuto.get() ? 1 : null,
+        !this.crossbleedSelectorShut.get() ? 2 : null,
+        this.apuBleedPbOn.get() ? 3 : null,
+        4,
+      ],
+      codesToReturn: ['360003001', '360003002', '360003003', '360003004', '360003005'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3600040: {
+      // R WING LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.rightWingLeak,
+      whichCodeToReturn: () => [
+        0,
+        this.eng2BleedPbAuto.get() ? 1 : null,
+        !this.crossbleedSelectorShut.get() ? 2 : null,
+        3,
+      ],
+      codesToReturn: ['360004001', '360004002', '360004003', '360004004'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
+    3600050: {
+      // APU BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8],
+      simVarIsActive: this.apuBleedLeak,
+      whichCodeToReturn: () => [0, this.apuBleedPbOn.get() ? 1 : null],
+      codesToReturn: ['360005001', '360005002'],
+      memoInhibit: () => false,
+      failure: 2,
+      sysPage: EcamSysPage.BLEED,
+      side: 'LEFT',
+    },
     2600150: {
       // SMOKE FWD CARGO SMOKE
       flightPhaseInhib: [4, 5, 7, 8],
@@ -6,9 +6,9 @@ use systems::{
         cabin_pressure_controller::{CabinPressureController, CpcId},
         pressure_valve::{OutflowValve, SafetyValve, SafetyValveSignal},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack, CabinFan,
-        Channel, DuctTemperature, MixerUnit, OutflowValveSignal, OutletAir, OverheadFlowSelector,
-        PackFlowControllers, PressurizationConstants, PressurizationOverheadShared, TrimAirSystem,
-        VcmShared, ZoneType,
+        Channel, DuctTemperature, GroundAirConditioningUnit, MixerUnit, OutflowValveSignal,
+        OutletAir, OverheadFlowSelector, PackFlowControllers, PressurizationConstants,
+        PressurizationOverheadShared, TrimAirSystem, VcmShared, ZoneType,
     },
     overhead::{
         AutoManFaultPushButton, NormalOnPushButton, OnOffFaultPushButton, OnOffPushButton,
@@ -33,6 +33,7 @@ use systems::{
 use std::time::Duration;
 use uom::si::{
     f64::*,
+    mass_rate::kilogram_per_second,
     pressure::{hectopascal, psi},
     ratio::percent,
     thermodynamic_temperature::degree_celsius,
@@ -250,12 +251,14 @@ pub struct A320AirConditioningSystem {
     // Temporary structure until packs are simulated
     packs: [AirConditioningPack; 2],
     trim_air_system: TrimAirSystem<3, 2>,
+    ground_air_conditioning_unit: GroundAirConditioningUnit,
 
     air_conditioning_overhead: A320AirConditioningSystemOverhead<3>,
 }
 
 impl A320AirConditioningSystem {
     const CAB_FAN_DESIGN_FLOW_RATE_L_S: f64 = 325.; // litres/sec
+    const LP_GROUND_CONNECTION_RATED_FLOW_KG_S: f64 = 1.2; // kg/s
 
     pub(crate) fn new(context: &mut InitContext, cabin_zones: &[ZoneType; 3]) -> Self {
         Self {
@@ -327,6 +330,10 @@ impl A320AirConditioningSystem {
                 Volume::new::<cubic_meter>(4.),
                 Volume::new::<cubic_meter>(0.03),
             ),
+            ground_air_conditioning_unit: GroundAirConditioningUnit::new(
+                context,
+                MassRate::new::<kilogram_per_second>(Self::LP_GROUND_CONNECTION_RATED_FLOW_KG_S),
+            ),
 
             air_conditioning_overhead: A320AirConditioningSystemOverhead::new(context, cabin_zones),
         }
@@ -360,6 +367,8 @@ impl A320AirConditioningSystem {
 
         self.update_packs(context);
 
+        self.ground_air_conditioning_unit.update(context);
+
         self.update_mixer_unit();
 
         self.update_trim_air_system(context);
@@ -432,7 +441,11 @@ impl A320AirConditioningSystem {
     }
 
     fn update_mixer_unit(&mut self) {
-        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![&self.packs[0], &self.packs[1]];
+        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![
+            &self.packs[0],
+            &self.packs[1],
+            &self.ground_air_conditioning_unit,
+        ];
         for fan in self.cabin_fans.iter() {
             mixer_intakes.push(fan)
         }
@@ -494,7 +507,9 @@ impl OutletAir for A320AirConditioningSystem {
     fn outlet_air(&self) -> Air {
         let mut outlet_air = Air::new();
         outlet_air.set_flow_rate(
-            self.acsc[0].individual_pack_flow() + self.acsc[1].individual_pack_flow(),
+            self.acsc[0].individual_pack_flow()
+                + self.acsc[1].individual_pack_flow()
+                + self.ground_air_conditioning_unit.outlet_air().flow_rate(),
         );
         outlet_air.set_pressure(self.trim_air_system.trim_air_outlet_pressure());
         outlet_air.set_temperature(self.duct_temperature().iter().average());
@@ -515,6 +530,7 @@ impl SimulationElement for A320AirConditioningSystem {
         self.trim_air_system.accept(visitor);
         accept_iterable!(self.cabin_fans, visitor);
         accept_iterable!(self.packs, visitor);
+        self.ground_air_conditioning_unit.accept(visitor);
 
         self.air_conditioning_overhead.accept(visitor);
 
@@ -1898,6 +1914,19 @@ mod tests {
             self
         }
 
+        fn command_ground_air_conditioning_unit(
+            mut self,
+            on_off: bool,
+            supply_temperature: ThermodynamicTemperature,
+        ) -> Self {
+            self.write_by_name("GND_AIR_COND_TURNED_ON", on_off);
+            self.write_by_name(
+                "GND_AIR_COND_SUPPLY_TEMPERATURE",
+                supply_temperature.get::<degree_celsius>(),
+            );
+            self
+        }
+
         fn command_man_vs_switch_position(mut self, position: usize) -> Self {
             if position == 0 {
                 self.write_by_name("OVHD_PRESS_MAN_VS_CTL_SWITCH", 0);
@@ -3039,4 +3068,81 @@ mod tests {
 
         assert_eq!(test_bed.cabin_vs(), Velocity::new::<foot_per_minute>(6400.));
     }
+
+    mod ground_air_conditioning_unit_tests {
+        use super::*;
+
+        #[test]
+        fn ground_air_conditioning_unit_supplies_air_to_cabin_with_packs_off() {
+            let test_bed = test_bed()
+                .on_ground()
+                .command_packs_on_off(false)
+                .command_ground_air_conditioning_unit(
+                    true,
+                    ThermodynamicTemperature::new::<degree_celsius>(5.),
+                )
+                .iterate(20);
+
+            assert!(test_bed.cabin_air_in() > MassRate::new::<kilogram_per_second>(1.));
+        }
+
+        #[test]
+        fn ground_air_conditioning_unit_cools_cabin_on_hot_day() {
+            let test_bed = test_bed()
+                .on_ground()
+                .command_packs_on_off(false)
+                .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(40.))
+                .iterate(10)
+                .then()
+                .command_ground_air_conditioning_unit(
+                    true,
+                    ThermodynamicTemperature::new::<degree_celsius>(5.),
+                )
+                .iterate(1000);
+
+            assert!(
+                test_bed.cabin_temperature() < ThermodynamicTemperature::new::<degree_celsius>(30.)
+            );
+        }
+
+        #[test]
+        fn ground_air_conditioning_unit_heats_cabin_on_cold_day() {
+            let test_bed = test_bed()
+                .on_ground()
+                .command_packs_on_off(false)
+                .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-10.))
+                .iterate(10)
+                .then()
+                .command_ground_air_conditioning_unit(
+                    true,
+                    ThermodynamicTemperature::new::<degree_celsius>(50.),
+                )
+                .iterate(1000);
+
+            assert!(
+                test_bed.cabin_temperature() > ThermodynamicTemperature::new::<degree_celsius>(10.)
+            );
+        }
+
+        #[test]
+        fn no_air_supplied_when_ground_air_conditioning_unit_turned_off() {
+            let test_bed = test_bed()
+                .on_ground()
+                .command_packs_on_off(false)
+                .cab_fans_pb_on(false)
+                .command_ground_air_conditioning_unit(
+                    true,
+                    ThermodynamicTemperature::new::<degree_celsius>(5.),
+                )
+                .iterate(20)
+                .then()
+                .command_ground_air_conditioning_unit(
+                    false,
+                    ThermodynamicTemperature::new::<degree_celsius>(5.),
+                )
+                .iterate(20);
+
+            assert!(test_bed.cabin_air_in() < MassRate::new::<kilogram_per_second>(0.1));
+        }
+    }
 }
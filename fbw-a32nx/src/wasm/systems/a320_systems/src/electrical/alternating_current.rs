@@ -6,9 +6,9 @@ use std::time::Duration;
 use systems::{
     apu::ApuGenerator,
     electrical::{
-        AlternatingCurrentElectricalSystem, Contactor, ElectricalBus, ElectricalElement,
-        Electricity, EmergencyGenerator, ExternalPowerSource, IntegratedDriveGenerator,
-        TransformerRectifier,
+        AlternatingCurrentElectricalSystem, CircuitBreaker, Contactor, ElectricalBus,
+        ElectricalElement, Electricity, EmergencyGenerator, ExternalPowerSource,
+        IntegratedDriveGenerator, TransformerRectifier,
     },
     engine::Engine,
     shared::{
@@ -17,7 +17,7 @@ use systems::{
     },
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
-use uom::si::{f64::*, power::kilowatt};
+use uom::si::{electric_current::ampere, f64::*, power::kilowatt};
 
 pub(super) struct A320AlternatingCurrentElectrical {
     main_power_sources: A320MainPowerSources,
@@ -27,6 +27,7 @@ pub(super) struct A320AlternatingCurrentElectrical {
     ac_ess_bus: ElectricalBus,
     ac_ess_shed_bus: ElectricalBus,
     ac_ess_shed_contactor: Contactor,
+    ac_ess_shed_circuit_breaker: CircuitBreaker,
     tr_1: TransformerRectifier,
     tr_2: TransformerRectifier,
     ac_bus_2_to_tr_2_contactor: Contactor,
@@ -52,6 +53,12 @@ impl A320AlternatingCurrentElectrical {
                 ElectricalBusType::AlternatingCurrentEssentialShed,
             ),
             ac_ess_shed_contactor: Contactor::new(context, "8XH"),
+            ac_ess_shed_circuit_breaker: CircuitBreaker::new(
+                context,
+                "AC_ESS_SHED",
+                ElectricCurrent::new::<ampere>(15.),
+            )
+            .protecting(ElectricalBusType::AlternatingCurrentEssentialShed),
             tr_1: TransformerRectifier::new(context, 1),
             tr_2: TransformerRectifier::new(context, 2),
             ac_bus_2_to_tr_2_contactor: Contactor::new(context, "14PU"),
@@ -237,7 +244,11 @@ impl A320AlternatingCurrentElectrical {
         self.ac_ess_shed_contactor
             .close_when(ac_bus_or_emergency_gen_provides_power);
 
-        electricity.flow(&self.ac_ess_shed_contactor, &self.ac_ess_shed_bus);
+        electricity.flow(
+            &self.ac_ess_shed_contactor,
+            &self.ac_ess_shed_circuit_breaker,
+        );
+        electricity.flow(&self.ac_ess_shed_circuit_breaker, &self.ac_ess_shed_bus);
     }
 
     /// Whether or not AC BUS 1 and AC BUS 2 are powered by a single engine
@@ -297,6 +308,10 @@ impl A320AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn any_engine_generator_overloaded(&self) -> bool {
+        self.main_power_sources.any_engine_generator_overloaded()
+    }
+
     pub fn apu_gen_contactor_open(&self) -> bool {
         self.main_power_sources.apu_gen_contactor_open()
     }
@@ -353,6 +368,7 @@ impl SimulationElement for A320AlternatingCurrentElectrical {
         self.tr_ess.accept(visitor);
 
         self.ac_ess_shed_contactor.accept(visitor);
+        self.ac_ess_shed_circuit_breaker.accept(visitor);
         self.ac_ess_to_tr_ess_contactor.accept(visitor);
         self.emergency_gen_contactor.accept(visitor);
         self.static_inv_to_ac_ess_bus_contactor.accept(visitor);
@@ -373,7 +389,7 @@ impl SimulationElement for A320AlternatingCurrentElectrical {
     }
 }
 
-struct A320MainPowerSources {
+pub(super) struct A320MainPowerSources {
     engine_1_gen: IntegratedDriveGenerator,
     engine_2_gen: IntegratedDriveGenerator,
     engine_generator_contactors: [Contactor; 2],
@@ -383,6 +399,10 @@ struct A320MainPowerSources {
     ext_pwr_contactor: Contactor,
 }
 impl A320MainPowerSources {
+    /// The A320 has no no-break power transfer. When the source powering an AC bus changes,
+    /// the bus is unpowered for the time it takes the new source's contactor to close.
+    pub const CONTACTOR_CLOSING_TIME: Duration = Duration::from_millis(50);
+
     fn new(context: &mut InitContext) -> Self {
         A320MainPowerSources {
             engine_1_gen: IntegratedDriveGenerator::new(
@@ -398,13 +418,17 @@ impl A320MainPowerSources {
                 390.0..=410.0,
             ),
             engine_generator_contactors: [
-                Contactor::new(context, "9XU1"),
-                Contactor::new(context, "9XU2"),
+                Contactor::new(context, "9XU1").with_closing_time(Self::CONTACTOR_CLOSING_TIME),
+                Contactor::new(context, "9XU2").with_closing_time(Self::CONTACTOR_CLOSING_TIME),
             ],
-            bus_tie_1_contactor: Contactor::new(context, "11XU1"),
-            bus_tie_2_contactor: Contactor::new(context, "11XU2"),
-            apu_gen_contactor: Contactor::new(context, "3XS"),
-            ext_pwr_contactor: Contactor::new(context, "3XG"),
+            bus_tie_1_contactor: Contactor::new(context, "11XU1")
+                .with_closing_time(Self::CONTACTOR_CLOSING_TIME),
+            bus_tie_2_contactor: Contactor::new(context, "11XU2")
+                .with_closing_time(Self::CONTACTOR_CLOSING_TIME),
+            apu_gen_contactor: Contactor::new(context, "3XS")
+                .with_closing_time(Self::CONTACTOR_CLOSING_TIME),
+            ext_pwr_contactor: Contactor::new(context, "3XG")
+                .with_closing_time(Self::CONTACTOR_CLOSING_TIME),
         }
     }
 
@@ -448,18 +472,24 @@ impl A320MainPowerSources {
             && !ext_pwr_provides_power
             && !both_engine_gens_provide_power;
 
-        self.engine_generator_contactors[0].close_when(gen_1_provides_power);
-        self.engine_generator_contactors[1].close_when(gen_2_provides_power);
-        self.apu_gen_contactor.close_when(apu_gen_provides_power);
-        self.ext_pwr_contactor.close_when(ext_pwr_provides_power);
+        self.engine_generator_contactors[0]
+            .close_after_closing_time_when(context, gen_1_provides_power);
+        self.engine_generator_contactors[1]
+            .close_after_closing_time_when(context, gen_2_provides_power);
+        self.apu_gen_contactor
+            .close_after_closing_time_when(context, apu_gen_provides_power);
+        self.ext_pwr_contactor
+            .close_after_closing_time_when(context, ext_pwr_provides_power);
 
         let apu_or_ext_pwr_provides_power = ext_pwr_provides_power || apu_gen_provides_power;
-        self.bus_tie_1_contactor.close_when(
+        self.bus_tie_1_contactor.close_after_closing_time_when(
+            context,
             overhead.bus_tie_is_auto()
                 && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
                     || (apu_or_ext_pwr_provides_power && !gen_1_provides_power)),
         );
-        self.bus_tie_2_contactor.close_when(
+        self.bus_tie_2_contactor.close_after_closing_time_when(
+            context,
             overhead.bus_tie_is_auto()
                 && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
                     || (apu_or_ext_pwr_provides_power && !gen_2_provides_power)),
@@ -522,6 +552,10 @@ impl A320MainPowerSources {
             .iter()
             .all(Contactor::is_closed)
     }
+
+    pub fn any_engine_generator_overloaded(&self) -> bool {
+        self.engine_1_gen.is_overloaded() || self.engine_2_gen.is_overloaded()
+    }
 }
 impl SimulationElement for A320MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
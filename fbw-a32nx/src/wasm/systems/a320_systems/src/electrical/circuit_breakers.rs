@@ -0,0 +1,43 @@
+use systems::{electrical::CircuitBreakerDefinition, shared::ElectricalBusType};
+
+/// LGCIU 1 SYS 1.
+pub(crate) const LGCIU_1: CircuitBreakerDefinition =
+    CircuitBreakerDefinition::new("1GA", "49VU", ElectricalBusType::DirectCurrentEssential, 5.);
+
+/// LGCIU 2 SYS 2.
+pub(crate) const LGCIU_2: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "2GA",
+    "121VU",
+    ElectricalBusType::DirectCurrentGndFltService,
+    5.,
+);
+
+/// RA 1 AC.
+pub(crate) const RADIO_ALTIMETER_1: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "1SA1",
+    "121VU",
+    ElectricalBusType::AlternatingCurrent(1),
+    3.,
+);
+
+/// RA 2 AC.
+pub(crate) const RADIO_ALTIMETER_2: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "1SA2",
+    "121VU",
+    ElectricalBusType::AlternatingCurrent(2),
+    3.,
+);
+
+/// EGPWS AC.
+pub(crate) const EGPWS: CircuitBreakerDefinition =
+    CircuitBreakerDefinition::new("1WZ", "121VU", ElectricalBusType::AlternatingCurrent(1), 3.);
+
+/// The circuit breakers which are simulated, such that pulling them in the cockpit
+/// de-powers the computer they protect.
+pub(super) const A320_CIRCUIT_BREAKERS: [CircuitBreakerDefinition; 5] = [
+    LGCIU_1,
+    LGCIU_2,
+    RADIO_ALTIMETER_1,
+    RADIO_ALTIMETER_2,
+    EGPWS,
+];
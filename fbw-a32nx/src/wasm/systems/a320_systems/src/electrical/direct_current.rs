@@ -6,8 +6,8 @@ use systems::shared::AdirsDiscreteOutputs;
 use systems::simulation::InitContext;
 use systems::{
     electrical::{
-        Battery, BatteryChargeLimiter, Contactor, ElectricalBus, Electricity, EmergencyElectrical,
-        EmergencyGenerator, StaticInverter,
+        Battery, BatteryChargeLimiter, CircuitBreaker, Contactor, ElectricalBus, Electricity,
+        EmergencyElectrical, EmergencyGenerator, StaticInverter,
     },
     shared::{
         ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical, ContactorSignal, ElectricalBusType,
@@ -15,6 +15,7 @@ use systems::{
     },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
+use uom::si::{electric_current::ampere, f64::*};
 
 pub(crate) const APU_START_MOTOR_BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
 
@@ -28,6 +29,7 @@ pub(super) struct A320DirectCurrentElectrical {
     dc_bat_bus_to_dc_ess_bus_contactor: Contactor,
     dc_ess_shed_bus: ElectricalBus,
     dc_ess_shed_contactor: Contactor,
+    dc_ess_shed_circuit_breaker: CircuitBreaker,
     battery_1: Battery,
     battery_1_contactor: Contactor,
     battery_1_charge_limiter: BatteryChargeLimiter,
@@ -63,6 +65,12 @@ impl A320DirectCurrentElectrical {
                 ElectricalBusType::DirectCurrentEssentialShed,
             ),
             dc_ess_shed_contactor: Contactor::new(context, "8PH"),
+            dc_ess_shed_circuit_breaker: CircuitBreaker::new(
+                context,
+                "DC_ESS_SHED",
+                ElectricCurrent::new::<ampere>(20.),
+            )
+            .protecting(ElectricalBusType::DirectCurrentEssentialShed),
             battery_1: Battery::full(context, 1),
             battery_1_contactor: Contactor::new(context, "6PB1"),
             battery_1_charge_limiter: BatteryChargeLimiter::new(context, 1, "6PB1"),
@@ -238,7 +246,11 @@ impl A320DirectCurrentElectrical {
             .close_when(self.hot_bus_2_to_dc_ess_bus_contactor.is_open());
         electricity.flow(&self.dc_ess_bus, &self.dc_ess_shed_contactor);
 
-        electricity.flow(&self.dc_ess_shed_contactor, &self.dc_ess_shed_bus);
+        electricity.flow(
+            &self.dc_ess_shed_contactor,
+            &self.dc_ess_shed_circuit_breaker,
+        );
+        electricity.flow(&self.dc_ess_shed_circuit_breaker, &self.dc_ess_shed_bus);
     }
 
     /// Determines if the 2XB contactors should be closed. 2XB are the two contactors
@@ -325,6 +337,7 @@ impl SimulationElement for A320DirectCurrentElectrical {
         self.dc_bus_2_tie_contactor.accept(visitor);
         self.dc_bat_bus_to_dc_ess_bus_contactor.accept(visitor);
         self.dc_ess_shed_contactor.accept(visitor);
+        self.dc_ess_shed_circuit_breaker.accept(visitor);
         self.battery_1_contactor.accept(visitor);
         self.battery_2_contactor.accept(visitor);
         self.hot_bus_2_to_dc_ess_bus_contactor.accept(visitor);
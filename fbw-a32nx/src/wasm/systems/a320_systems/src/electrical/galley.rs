@@ -6,10 +6,14 @@ use systems::{
 
 pub(super) struct MainGalley {
     is_shed: bool,
+    is_shed_by_overload: bool,
 }
 impl MainGalley {
     pub fn new() -> Self {
-        Self { is_shed: false }
+        Self {
+            is_shed: false,
+            is_shed_by_overload: false,
+        }
     }
 
     pub fn is_shed(&self) -> bool {
@@ -23,11 +27,20 @@ impl MainGalley {
         alternating_current: &A320AlternatingCurrentElectrical,
         overhead: &A320ElectricalOverheadPanel,
     ) {
+        // Shedding due to a generator overload is latched, as the overload disappears
+        // once the galley is shed. It is reset by switching the GALY & CAB pb off.
+        if alternating_current.any_engine_generator_overloaded() {
+            self.is_shed_by_overload = true;
+        } else if overhead.galy_and_cab_is_off() {
+            self.is_shed_by_overload = false;
+        }
+
         self.is_shed = !alternating_current.any_non_essential_bus_powered(electricity)
             || alternating_current
                 .main_ac_buses_powered_by_single_engine_generator_only(electricity)
             || (alternating_current.main_ac_buses_powered_by_apu_generator_only(electricity)
                 && context.is_in_flight())
+            || self.is_shed_by_overload
             || overhead.commercial_is_off()
             || overhead.galy_and_cab_is_off();
     }
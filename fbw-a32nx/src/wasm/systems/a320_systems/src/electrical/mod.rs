@@ -1,12 +1,15 @@
 mod alternating_current;
+mod circuit_breakers;
 mod direct_current;
 mod galley;
 
 use self::{
     alternating_current::A320AlternatingCurrentElectrical,
+    circuit_breakers::A320_CIRCUIT_BREAKERS,
     direct_current::A320DirectCurrentElectrical,
     galley::{MainGalley, SecondaryGalley},
 };
+pub(super) use circuit_breakers::{EGPWS, LGCIU_1, LGCIU_2, RADIO_ALTIMETER_1, RADIO_ALTIMETER_2};
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
 use uom::si::{angular_velocity::revolution_per_minute, f64::*};
@@ -17,9 +20,9 @@ use systems::electrical::Battery;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, Electricity, EmergencyElectrical,
-        EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource, StaticInverter,
-        TransformerRectifier,
+        AlternatingCurrentElectricalSystem, BatteryPushButtons, CircuitBreakerPanel, Electricity,
+        EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource,
+        StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -47,6 +50,7 @@ pub(super) struct A320Electrical {
     secondary_galley: SecondaryGalley,
     emergency_elec: EmergencyElectrical,
     emergency_gen: EmergencyGenerator,
+    circuit_breakers: CircuitBreakerPanel,
 }
 impl A320Electrical {
     const MIN_EMERGENCY_GENERATOR_RPM_TO_ALLOW_CURRENT_SUPPLY: f64 = 10000.;
@@ -64,6 +68,7 @@ impl A320Electrical {
                     Self::MIN_EMERGENCY_GENERATOR_RPM_TO_ALLOW_CURRENT_SUPPLY,
                 ),
             ),
+            circuit_breakers: CircuitBreakerPanel::new(context, &A320_CIRCUIT_BREAKERS),
         }
     }
 
@@ -140,6 +145,8 @@ impl A320Electrical {
         self.secondary_galley
             .update(electricity, &self.alternating_current, overhead);
 
+        self.circuit_breakers.update(electricity);
+
         self.debug_assert_invariants();
     }
 
@@ -221,6 +228,7 @@ impl SimulationElement for A320Electrical {
         self.alternating_current.accept(visitor);
         self.direct_current.accept(visitor);
         self.emergency_gen.accept(visitor);
+        self.circuit_breakers.accept(visitor);
 
         visitor.visit(self);
     }
@@ -451,15 +459,19 @@ mod a320_electrical {
 
 #[cfg(test)]
 mod a320_electrical_circuit_tests {
-    use super::{alternating_current::A320AcEssFeedContactors, *};
+    use super::{
+        alternating_current::{A320AcEssFeedContactors, A320MainPowerSources},
+        *,
+    };
     use rstest::rstest;
     use std::{cell::Ref, time::Duration};
     use systems::{
         apu::ApuGenerator,
         electrical::{
-            ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-            Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            consumption::PowerConsumer, CircuitBreakerDefinition, ElectricalElement,
+            ElectricalElementIdentifier, ElectricalElementIdentifierProvider, Electricity,
+            ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency, ProvidePotential,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
         },
         failures::FailureType,
         shared::{
@@ -477,7 +489,7 @@ mod a320_electrical_circuit_tests {
         electric_potential::volt,
         frequency::hertz,
         length::foot,
-        power::watt,
+        power::{kilowatt, watt},
         ratio::{percent, ratio},
         velocity::knot,
     };
@@ -1359,6 +1371,50 @@ mod a320_electrical_circuit_tests {
             .is_single(PotentialOrigin::EngineGenerator(2)));
     }
 
+    #[test]
+    fn when_engine_generator_takes_over_from_apu_ac_bus_is_briefly_unpowered() {
+        let mut test_bed = test_bed_with().running_apu().run();
+        test_bed.command(|a| a.running_engine(1));
+        test_bed.run_with_delta(INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME);
+
+        test_bed.run_with_delta(Duration::from_millis(20));
+        assert!(test_bed.ac_bus_output(1).is_unpowered());
+        assert!(test_bed
+            .ac_bus_output(2)
+            .is_single(PotentialOrigin::ApuGenerator(1)));
+
+        test_bed.run_with_delta(Duration::from_millis(40));
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::EngineGenerator(1)));
+    }
+
+    #[test]
+    fn when_apu_takes_over_from_external_power_ac_buses_are_briefly_unpowered() {
+        let mut test_bed = test_bed_with()
+            .connected_external_power()
+            .ext_pwr_on()
+            .and()
+            .running_apu()
+            .run();
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::External));
+
+        test_bed = test_bed.ext_pwr_off();
+        test_bed.run_with_delta(Duration::from_millis(20));
+        assert!(test_bed.ac_bus_output(1).is_unpowered());
+        assert!(test_bed.ac_bus_output(2).is_unpowered());
+
+        test_bed.run_with_delta(Duration::from_millis(40));
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::ApuGenerator(1)));
+        assert!(test_bed
+            .ac_bus_output(2)
+            .is_single(PotentialOrigin::ApuGenerator(1)));
+    }
+
     #[test]
     fn ac_bus_1_powers_ac_ess_bus_whenever_it_is_powered() {
         let test_bed = test_bed_with().running_engines().run();
@@ -1740,6 +1796,129 @@ mod a320_electrical_circuit_tests {
         assert!(test_bed.galley_is_shed());
     }
 
+    #[rstest]
+    #[case(LGCIU_1)]
+    #[case(RADIO_ALTIMETER_2)]
+    #[case(EGPWS)]
+    fn pulling_a_circuit_breaker_depowers_only_its_computer(
+        #[case] circuit_breaker: CircuitBreakerDefinition,
+    ) {
+        let test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .pulled_circuit_breaker(circuit_breaker)
+            .run();
+
+        for definition in A320_CIRCUIT_BREAKERS {
+            assert_eq!(
+                test_bed.circuit_breaker_bus_output(definition).is_powered(),
+                definition.id() != circuit_breaker.id()
+            );
+        }
+    }
+
+    #[test]
+    fn ac_ess_shed_bus_overcurrent_trips_its_circuit_breaker() {
+        let mut test_bed = test_bed_with().running_engines().run();
+        test_bed.command(|a| {
+            a.power_demand(
+                ElectricalBusType::AlternatingCurrentEssentialShed,
+                Power::new::<kilowatt>(10.),
+            )
+        });
+        test_bed = test_bed.run();
+        test_bed = test_bed.run();
+
+        assert!(test_bed.ac_ess_shed_circuit_breaker_is_tripped());
+        assert!(test_bed.ac_ess_shed_bus_output().is_unpowered());
+        assert!(test_bed.ac_ess_bus_output().is_powered());
+    }
+
+    #[test]
+    fn tripped_ac_ess_shed_circuit_breaker_powers_bus_again_once_reset() {
+        let mut test_bed = test_bed_with().running_engines().run();
+        test_bed.command(|a| {
+            a.power_demand(
+                ElectricalBusType::AlternatingCurrentEssentialShed,
+                Power::new::<kilowatt>(10.),
+            )
+        });
+        test_bed = test_bed.run();
+        test_bed = test_bed.run();
+
+        test_bed.command(|a| a.remove_power_demands());
+        test_bed.write_by_name("ELEC_CB_AC_ESS_SHED_RESET", true);
+        test_bed = test_bed.run();
+        test_bed = test_bed.run();
+
+        assert!(!test_bed.ac_ess_shed_circuit_breaker_is_tripped());
+        assert!(test_bed.ac_ess_shed_bus_output().is_powered());
+    }
+
+    #[test]
+    fn failed_dc_ess_shed_circuit_breaker_unpowers_dc_ess_shed_bus() {
+        let mut test_bed = test_bed_with().running_engines().run();
+        test_bed.fail(FailureType::CircuitBreaker("DC_ESS_SHED"));
+        test_bed = test_bed.run();
+        test_bed = test_bed.run();
+
+        assert!(test_bed.dc_ess_shed_bus_output().is_unpowered());
+        assert!(test_bed.dc_ess_bus_output().is_powered());
+    }
+
+    #[test]
+    fn when_engine_generator_overloaded_galley_is_shed() {
+        let mut test_bed = test_bed_with().running_engines().run();
+        test_bed.command(|a| {
+            a.power_demand(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<kilowatt>(200.),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed = test_bed.run();
+
+        assert!(test_bed.galley_is_shed());
+    }
+
+    #[test]
+    fn when_engine_generator_no_longer_overloaded_galley_remains_shed() {
+        let mut test_bed = test_bed_with().running_engines().run();
+        test_bed.command(|a| {
+            a.power_demand(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<kilowatt>(200.),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed = test_bed.run();
+
+        test_bed.command(|a| a.remove_power_demands());
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed = test_bed.run();
+
+        assert!(test_bed.galley_is_shed());
+    }
+
+    #[test]
+    fn galley_shed_due_to_overload_is_reset_by_cycling_galy_and_cab_pb() {
+        let mut test_bed = test_bed_with().running_engines().run();
+        test_bed.command(|a| {
+            a.power_demand(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<kilowatt>(200.),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed = test_bed.run();
+
+        test_bed.command(|a| a.remove_power_demands());
+        test_bed = test_bed.galy_and_cab_off().run();
+        test_bed = test_bed.galy_and_cab_auto().run();
+
+        assert!(!test_bed.galley_is_shed());
+    }
+
     #[test]
     fn when_on_ground_and_apu_gen_only_galley_is_not_shed() {
         let mut test_bed = test_bed_with().running_apu().and().on_the_ground().run();
@@ -2533,6 +2712,7 @@ mod a320_electrical_circuit_tests {
         engine_fire_push_buttons: TestEngineFirePushButtons,
         hydraulics: TestHydraulicSystem,
         force_run_emergency_gen: bool,
+        consumers: Vec<PowerConsumer>,
     }
     impl A320ElectricalTestAircraft {
         fn new(context: &mut InitContext) -> Self {
@@ -2547,9 +2727,20 @@ mod a320_electrical_circuit_tests {
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 hydraulics: TestHydraulicSystem::new(),
                 force_run_emergency_gen: false,
+                consumers: Vec::new(),
             }
         }
 
+        fn power_demand(&mut self, bus_type: ElectricalBusType, power: Power) {
+            let mut consumer = PowerConsumer::from(bus_type);
+            consumer.demand(power);
+            self.consumers.push(consumer);
+        }
+
+        fn remove_power_demands(&mut self) {
+            self.consumers.clear();
+        }
+
         fn running_engine(&mut self, number: usize) {
             self.engines[number - 1].run();
         }
@@ -2662,6 +2853,7 @@ mod a320_electrical_circuit_tests {
             self.overhead.accept(visitor);
             self.emergency_overhead.accept(visitor);
             self.apu.accept(visitor);
+            accept_iterable!(self.consumers, visitor);
 
             visitor.visit(self);
         }
@@ -2682,6 +2874,7 @@ mod a320_electrical_circuit_tests {
 
             self = self.without_triggering_emergency_elec(|x| {
                 x.run_waiting_for(INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME)
+                    .run_waiting_for_contactors_to_close()
             });
 
             self
@@ -2696,6 +2889,11 @@ mod a320_electrical_circuit_tests {
             self.running_engine(1).and().running_engine(2)
         }
 
+        fn pulled_circuit_breaker(mut self, definition: CircuitBreakerDefinition) -> Self {
+            self.write_by_name(&format!("ELEC_CB_{}_IS_PULLED", definition.id()), true);
+            self
+        }
+
         fn running_apu(mut self) -> Self {
             self.command(|a| a.running_apu());
             self
@@ -2836,6 +3034,11 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn galy_and_cab_auto(mut self) -> Self {
+            self.write_by_name("OVHD_ELEC_GALY_AND_CAB_PB_IS_AUTO", true);
+            self
+        }
+
         fn apu_master_sw_pb_on(mut self) -> Self {
             self.command(|a| a.set_apu_master_sw_pb_on());
             self
@@ -2870,6 +3073,13 @@ mod a320_electrical_circuit_tests {
             })
         }
 
+        fn circuit_breaker_bus_output(
+            &'_ self,
+            definition: CircuitBreakerDefinition,
+        ) -> Ref<'_, Potential> {
+            self.query_elec_ref(|_, elec| elec.potential_of(definition.bus_type()))
+        }
+
         fn ac_ess_bus_output(&'_ self) -> Ref<'_, Potential> {
             self.query_elec_ref(|_, elec| {
                 elec.potential_of(ElectricalBusType::AlternatingCurrentEssential)
@@ -2990,6 +3200,10 @@ mod a320_electrical_circuit_tests {
             self.read_by_name("ELEC_GALLEY_IS_SHED")
         }
 
+        fn ac_ess_shed_circuit_breaker_is_tripped(&mut self) -> bool {
+            self.read_by_name("ELEC_CB_AC_ESS_SHED_IS_TRIPPED")
+        }
+
         fn both_ac_ess_feed_contactors_open(&mut self) -> bool {
             !ReadByName::<A320ElectricalTestBed, bool>::read_by_name(
                 self,
@@ -3052,6 +3266,14 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        /// Source contactors only close once they have been commanded closed for their closing
+        /// time. Power sources which only become available within a zero delta tick thus need
+        /// an additional tick before they power their buses.
+        fn run_waiting_for_contactors_to_close(mut self) -> Self {
+            self.run_with_delta(A320MainPowerSources::CONTACTOR_CLOSING_TIME);
+            self
+        }
+
         fn run_waiting_for_ac_ess_feed_transition(self) -> Self {
             self.run_waiting_for(A320AcEssFeedContactors::AC_ESS_FEED_TO_AC_BUS_2_DELAY_IN_SECONDS)
         }
@@ -0,0 +1,731 @@
+use systems::{
+    accept_iterable,
+    fire_protection::{
+        cargo_fire_extinguishing::CargoFireExtinguishingSystem,
+        smoke_detection::{SmokeDetectionControlUnit, SmokeGenerationModule},
+        ExtinguishingAgentBottle, FireDetectionUnit, SetOnFireModule,
+    },
+    overhead::FirePushButton,
+    shared::{
+        ElectricalBusType, EngineFirePushButtons, FireDetectionLoopID, FireDetectionZone,
+        LgciuWeightOnWheels,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+const FIRE_DETECTION_ZONES: [FireDetectionZone; 4] = [
+    FireDetectionZone::Engine(1),
+    FireDetectionZone::Engine(2),
+    FireDetectionZone::Apu,
+    FireDetectionZone::Mlg,
+];
+
+const SMOKE_DETECTION_ZONES: [FireDetectionZone; 4] = [
+    FireDetectionZone::ForwardCargo,
+    FireDetectionZone::AftCargo,
+    FireDetectionZone::Lavatory,
+    FireDetectionZone::Avionics,
+];
+
+const CARGO_COMPARTMENTS: [FireDetectionZone; 2] =
+    [FireDetectionZone::ForwardCargo, FireDetectionZone::AftCargo];
+
+pub(super) struct A320FireProtection {
+    fire_detection_unit: FireDetectionUnit<4>,
+    fire_extinguishing_system: A320FireExtinguishingSystem,
+    loop_fault_indications: [[LoopFaultIndication; 2]; 4],
+
+    // Each engine and the APU have their own fire test pushbutton on the overhead panel
+    fire_test_pushbutton_id: [VariableIdentifier; 3],
+    fire_test_pushbutton_is_pressed: [bool; 3],
+
+    set_zone_on_fire: SetOnFireModule<4>,
+
+    smoke_detection_control_unit: SmokeDetectionControlUnit<4>,
+    cargo_fire_extinguishing_system: CargoFireExtinguishingSystem<2>,
+    cargo_smoke_test_pushbutton_id: VariableIdentifier,
+    cargo_smoke_test_pushbutton_is_pressed: bool,
+
+    generate_smoke: SmokeGenerationModule<4>,
+}
+impl A320FireProtection {
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            fire_detection_unit: FireDetectionUnit::new(
+                context,
+                FIRE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            fire_extinguishing_system: A320FireExtinguishingSystem::new(context),
+            loop_fault_indications: FIRE_DETECTION_ZONES.map(|zone| {
+                [
+                    LoopFaultIndication::new(context, zone, FireDetectionLoopID::A),
+                    LoopFaultIndication::new(context, zone, FireDetectionLoopID::B),
+                ]
+            }),
+
+            fire_test_pushbutton_id: [
+                context.get_identifier("FIRE_TEST_ENG1".to_owned()),
+                context.get_identifier("FIRE_TEST_ENG2".to_owned()),
+                context.get_identifier("FIRE_TEST_APU".to_owned()),
+            ],
+            fire_test_pushbutton_is_pressed: [false; 3],
+
+            set_zone_on_fire: SetOnFireModule::new(context, FIRE_DETECTION_ZONES),
+
+            smoke_detection_control_unit: SmokeDetectionControlUnit::new(
+                context,
+                SMOKE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            cargo_fire_extinguishing_system: CargoFireExtinguishingSystem::new(
+                context,
+                CARGO_COMPARTMENTS,
+                [
+                    ElectricalBusType::DirectCurrentBattery,
+                    ElectricalBusType::DirectCurrentEssential,
+                ],
+            ),
+            cargo_smoke_test_pushbutton_id: context.get_identifier("FIRE_TEST_CARGO".to_owned()),
+            cargo_smoke_test_pushbutton_is_pressed: false,
+
+            generate_smoke: SmokeGenerationModule::new(context, SMOKE_DETECTION_ZONES),
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        lgciu: [&impl LgciuWeightOnWheels; 2],
+    ) {
+        let [eng_1_test, eng_2_test, apu_test] = self.fire_test_pushbutton_is_pressed;
+
+        // The MLG bay has no test pushbutton on the overhead panel
+        self.fire_detection_unit
+            .update(context, [eng_1_test, eng_2_test, apu_test, false], lgciu);
+        self.fire_extinguishing_system.update(
+            context,
+            engine_fire_push_buttons,
+            self.fire_test_pushbutton_is_pressed,
+            self.fire_detection_unit.should_extinguish_apu_fire(),
+        );
+
+        for (&zone, indications) in FIRE_DETECTION_ZONES
+            .iter()
+            .zip(self.loop_fault_indications.iter_mut())
+        {
+            for indication in indications.iter_mut() {
+                indication.update(&self.fire_detection_unit, zone);
+            }
+        }
+
+        self.set_zone_on_fire
+            .update(self.fire_extinguishing_system.bottles_discharged());
+
+        // The CARGO SMOKE TEST pushbutton only tests the cargo compartment detectors
+        let cargo_test = self.cargo_smoke_test_pushbutton_is_pressed;
+        self.smoke_detection_control_unit
+            .update([cargo_test, cargo_test, false, false]);
+        self.cargo_fire_extinguishing_system.update(context);
+
+        let [fwd_cargo_agent, aft_cargo_agent] =
+            self.cargo_fire_extinguishing_system.agent_is_present();
+        self.generate_smoke
+            .update(context, [fwd_cargo_agent, aft_cargo_agent, false, false]);
+    }
+
+    pub(super) fn apu_fire_on_ground(&self) -> bool {
+        self.fire_detection_unit.apu_fire_on_ground()
+    }
+}
+impl SimulationElement for A320FireProtection {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (id, is_pressed) in self
+            .fire_test_pushbutton_id
+            .iter()
+            .zip(self.fire_test_pushbutton_is_pressed.iter_mut())
+        {
+            *is_pressed = reader.read(id);
+        }
+        self.cargo_smoke_test_pushbutton_is_pressed =
+            reader.read(&self.cargo_smoke_test_pushbutton_id);
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fire_detection_unit.accept(visitor);
+        self.fire_extinguishing_system.accept(visitor);
+        for indications in self.loop_fault_indications.iter_mut() {
+            accept_iterable!(indications, visitor);
+        }
+        self.set_zone_on_fire.accept(visitor);
+        self.smoke_detection_control_unit.accept(visitor);
+        self.cargo_fire_extinguishing_system.accept(visitor);
+        self.generate_smoke.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Loop fault signal sent by the FDU to the FWS, for the ENG / APU / MLG LOOP A(B) FAULT warnings
+struct LoopFaultIndication {
+    loop_id: FireDetectionLoopID,
+    has_fault_id: VariableIdentifier,
+    has_fault: bool,
+}
+impl LoopFaultIndication {
+    fn new(
+        context: &mut InitContext,
+        zone: FireDetectionZone,
+        loop_id: FireDetectionLoopID,
+    ) -> Self {
+        let loop_name = match loop_id {
+            FireDetectionLoopID::A => "A",
+            FireDetectionLoopID::B => "B",
+        };
+        let zone_name = if matches!(zone, FireDetectionZone::Engine(_)) {
+            format!("ENG{}", zone)
+        } else {
+            format!("{}", zone)
+        };
+
+        Self {
+            loop_id,
+            has_fault_id: context
+                .get_identifier(format!("FIRE_{}_LOOP_{}_FAULT", zone_name, loop_name)),
+            has_fault: false,
+        }
+    }
+
+    fn update(&mut self, fire_detection_unit: &FireDetectionUnit<4>, zone: FireDetectionZone) {
+        self.has_fault = fire_detection_unit.loop_has_failed(self.loop_id, zone);
+    }
+}
+impl SimulationElement for LoopFaultIndication {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.has_fault_id, self.has_fault);
+    }
+}
+
+struct A320FireExtinguishingSystem {
+    // Two bottles per engine, and one for the APU
+    engine_bottles: [[ExtinguishingAgentBottle; 2]; 2],
+    apu_bottle: ExtinguishingAgentBottle,
+
+    apu_fire_push_button: FirePushButton,
+}
+impl A320FireExtinguishingSystem {
+    fn new(context: &mut InitContext) -> Self {
+        let agent_1_powered_by = [
+            ElectricalBusType::DirectCurrentHot(1),
+            ElectricalBusType::DirectCurrentEssential,
+        ];
+        let agent_2_powered_by = [
+            ElectricalBusType::DirectCurrentHot(2),
+            ElectricalBusType::DirectCurrent(2),
+        ];
+
+        Self {
+            engine_bottles: [1, 2].map(|engine_number| {
+                [
+                    ExtinguishingAgentBottle::new(
+                        context,
+                        &format!("1_ENG_{}", engine_number),
+                        agent_1_powered_by,
+                    ),
+                    ExtinguishingAgentBottle::new(
+                        context,
+                        &format!("2_ENG_{}", engine_number),
+                        agent_2_powered_by,
+                    ),
+                ]
+            }),
+            apu_bottle: ExtinguishingAgentBottle::new(
+                context,
+                "1_APU_1",
+                [
+                    ElectricalBusType::DirectCurrentHot(1),
+                    ElectricalBusType::DirectCurrentBattery,
+                ],
+            ),
+
+            apu_fire_push_button: FirePushButton::new(context, "APU"),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        fire_test_pushbutton_is_pressed: [bool; 3],
+        should_extinguish_apu_fire: bool,
+    ) {
+        for ((engine_number, bottles), &fire_test_pushbutton_is_pressed) in (1..)
+            .zip(self.engine_bottles.iter_mut())
+            .zip(fire_test_pushbutton_is_pressed.iter())
+        {
+            bottles.iter_mut().for_each(|bottle| {
+                bottle.update(
+                    context,
+                    engine_fire_push_buttons.is_released(engine_number),
+                    fire_test_pushbutton_is_pressed,
+                    None,
+                )
+            });
+        }
+        self.apu_bottle.update(
+            context,
+            self.apu_fire_push_button.is_released(),
+            fire_test_pushbutton_is_pressed[2],
+            Some(should_extinguish_apu_fire),
+        );
+    }
+
+    /// Number of bottles discharged into each of the ENG1, ENG2, APU and MLG zones.
+    /// The MLG bay does not have a fire extinguishing system
+    fn bottles_discharged(&self) -> [usize; 4] {
+        let [eng_1, eng_2] = self
+            .engine_bottles
+            .each_ref()
+            .map(|bottles| bottles.iter().filter(|b| b.bottle_discharge()).count());
+
+        [
+            eng_1,
+            eng_2,
+            usize::from(self.apu_bottle.bottle_discharge()),
+            0,
+        ]
+    }
+}
+impl SimulationElement for A320FireExtinguishingSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.apu_fire_push_button.accept(visitor);
+        for bottles in self.engine_bottles.iter_mut() {
+            accept_iterable!(bottles, visitor);
+        }
+        self.apu_bottle.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::EngineFireOverheadPanel,
+        failures::FailureType,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    struct TestAircraft {
+        fire_protection: A320FireProtection,
+        engine_fire_overhead: EngineFireOverheadPanel<2>,
+        lgciu: TestLgciu,
+
+        powered_source_ess: TestElectricitySource,
+        powered_source: TestElectricitySource,
+        dc_ess_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        dc_hot_1_bus: ElectricalBus,
+        dc_hot_2_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                fire_protection: A320FireProtection::new(context),
+                engine_fire_overhead: EngineFireOverheadPanel::new(context),
+                lgciu: TestLgciu { compressed: true },
+
+                powered_source_ess: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(2),
+                ),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                dc_hot_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(1)),
+                dc_hot_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(2)),
+            }
+        }
+
+        fn set_on_ground(&mut self, on_ground: bool) {
+            self.lgciu.compressed = on_ground;
+        }
+
+        fn unpower_dc_ess_bus(&mut self) {
+            self.powered_source_ess.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source_ess);
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source_ess, &self.dc_ess_bus);
+            electricity.flow(&self.powered_source, &self.dc_2_bus);
+            electricity.flow(&self.powered_source, &self.dc_hot_1_bus);
+            electricity.flow(&self.powered_source, &self.dc_hot_2_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fire_protection.update(
+                context,
+                &self.engine_fire_overhead,
+                [&self.lgciu, &self.lgciu],
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.fire_protection.accept(visitor);
+            self.engine_fire_overhead.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct A320FireProtectionTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl A320FireProtectionTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.run();
+            test_bed
+        }
+
+        fn set_on_ground(mut self, on_ground: bool) -> Self {
+            self.command(|a| a.set_on_ground(on_ground));
+            self
+        }
+
+        fn set_engine_on_fire(mut self, engine_number: usize) -> Self {
+            self.write_by_name(&format!("ENG ON FIRE:{}", engine_number), true);
+            self
+        }
+
+        fn set_apu_on_fire(mut self) -> Self {
+            self.write_by_name("APU_ON_FIRE", true);
+            self
+        }
+
+        fn set_engine_fire_pb_released(mut self, engine_number: usize) -> Self {
+            self.write_by_name(&format!("FIRE_BUTTON_ENG{}", engine_number), true);
+            self
+        }
+
+        fn press_agent_pb(mut self, agent: usize, engine_number: usize, pressed: bool) -> Self {
+            self.write_by_name(
+                &format!("OVHD_FIRE_AGENT_{}_ENG_{}_IS_PRESSED", agent, engine_number),
+                pressed,
+            );
+            self
+        }
+
+        fn set_fire_test_pb(mut self, name: &str, pressed: bool) -> Self {
+            self.write_by_name(&format!("FIRE_TEST_{}", name), pressed);
+            self
+        }
+
+        fn unpowered_dc_ess_bus(mut self) -> Self {
+            self.command(|a| a.unpower_dc_ess_bus());
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.run_with_delta(duration);
+            self
+        }
+
+        fn engine_fire_detected(&mut self, engine_number: usize) -> bool {
+            self.read_by_name(&format!("FIRE_DETECTED_ENG{}", engine_number))
+        }
+
+        fn apu_fire_detected(&mut self) -> bool {
+            self.read_by_name("FIRE_DETECTED_APU")
+        }
+
+        fn loop_fault(&mut self, zone: &str, loop_name: &str) -> bool {
+            self.read_by_name(&format!("FIRE_{}_LOOP_{}_FAULT", zone, loop_name))
+        }
+
+        fn engine_bottle_discharged(&mut self, agent: usize, engine_number: usize) -> bool {
+            self.read_by_name(&format!(
+                "FIRE_SQUIB_{}_ENG_{}_IS_DISCHARGED",
+                agent, engine_number
+            ))
+        }
+
+        fn engine_squib_armed(&mut self, agent: usize, engine_number: usize) -> bool {
+            self.read_by_name(&format!(
+                "FIRE_SQUIB_{}_ENG_{}_IS_ARMED",
+                agent, engine_number
+            ))
+        }
+
+        fn engine_bottle_pressure_psi(&mut self, agent: usize, engine_number: usize) -> f64 {
+            self.read_by_name(&format!(
+                "FIRE_BOTTLE_{}_ENG_{}_PRESSURE",
+                agent, engine_number
+            ))
+        }
+
+        fn apu_bottle_discharged(&mut self) -> bool {
+            self.read_by_name("FIRE_SQUIB_1_APU_1_IS_DISCHARGED")
+        }
+
+        fn apu_fire_on_ground(&self) -> bool {
+            self.query(|a| a.fire_protection.apu_fire_on_ground())
+        }
+
+        fn press_cargo_smoke_test_pb(mut self, pressed: bool) -> Self {
+            self.write_by_name("FIRE_TEST_CARGO", pressed);
+            self
+        }
+
+        fn press_cargo_agent_pb(mut self, zone: &str, pressed: bool) -> Self {
+            self.write_by_name(
+                &format!("OVHD_CARGO_FIRE_AGENT_{}_IS_PRESSED", zone),
+                pressed,
+            );
+            self
+        }
+
+        fn smoke_detected(&mut self, zone: &str) -> bool {
+            self.read_by_name(&format!("SMOKE_DETECTED_{}", zone))
+        }
+
+        fn cargo_bottle_discharged(&mut self) -> bool {
+            self.read_by_name("CARGO_FIRE_BOTTLE_1_IS_DISCHARGED")
+        }
+    }
+    impl TestBed for A320FireProtectionTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> A320FireProtectionTestBed {
+        A320FireProtectionTestBed::new()
+    }
+
+    #[test]
+    fn engine_fire_is_detected() {
+        let mut test_bed = test_bed()
+            .set_engine_on_fire(2)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.engine_fire_detected(2));
+        assert!(!test_bed.engine_fire_detected(1));
+        assert!(!test_bed.apu_fire_detected());
+    }
+
+    #[test]
+    fn engine_fire_test_detects_fire_in_tested_zone_only() {
+        let mut test_bed = test_bed()
+            .set_fire_test_pb("ENG1", true)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.engine_fire_detected(1));
+        assert!(!test_bed.engine_fire_detected(2));
+        assert!(test_bed.engine_squib_armed(1, 1));
+        assert!(test_bed.engine_bottle_discharged(2, 1));
+        assert!(!test_bed.engine_squib_armed(1, 2));
+    }
+
+    #[test]
+    fn engine_fire_is_detected_with_one_loop_unpowered() {
+        let mut test_bed = test_bed()
+            .unpowered_dc_ess_bus()
+            .set_engine_on_fire(1)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.engine_fire_detected(1));
+    }
+
+    #[test]
+    fn failed_loop_is_reported_as_faulty() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::FireDetectionLoop(
+            FireDetectionLoopID::B,
+            FireDetectionZone::Mlg,
+        ));
+        test_bed.run();
+
+        assert!(test_bed.loop_fault("MLG", "B"));
+        assert!(!test_bed.loop_fault("MLG", "A"));
+        assert!(!test_bed.loop_fault("ENG1", "B"));
+    }
+
+    #[test]
+    fn engine_agent_discharges_only_the_selected_bottle() {
+        let mut test_bed = test_bed()
+            .set_engine_on_fire(1)
+            .set_engine_fire_pb_released(1)
+            .press_agent_pb(1, 1, true)
+            .run_for(Duration::from_millis(600))
+            .press_agent_pb(1, 1, false)
+            .run_for(Duration::from_millis(600))
+            .run_for(Duration::from_millis(600));
+
+        assert!(test_bed.engine_bottle_discharged(1, 1));
+        assert!(!test_bed.engine_bottle_discharged(2, 1));
+
+        let mut test_bed = test_bed.run_for(Duration::from_secs(3));
+
+        assert!(test_bed.engine_bottle_pressure_psi(1, 1) < 10.);
+        assert!(test_bed.engine_bottle_pressure_psi(2, 1) > 500.);
+    }
+
+    #[test]
+    fn agent_does_not_discharge_without_fire_pb_released() {
+        let mut test_bed = test_bed()
+            .set_engine_on_fire(1)
+            .press_agent_pb(1, 1, true)
+            .run_for(Duration::from_secs(2));
+
+        assert!(!test_bed.engine_bottle_discharged(1, 1));
+    }
+
+    #[test]
+    fn apu_fire_on_ground_is_automatically_extinguished() {
+        let mut test_bed = test_bed()
+            .set_on_ground(true)
+            .set_apu_on_fire()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.apu_fire_on_ground());
+        assert!(!test_bed.apu_bottle_discharged());
+
+        for _ in 0..12 {
+            test_bed = test_bed.run_for(Duration::from_secs(1));
+        }
+
+        assert!(test_bed.apu_bottle_discharged());
+    }
+
+    #[test]
+    fn apu_fire_in_flight_is_not_automatically_extinguished() {
+        let mut test_bed = test_bed()
+            .set_on_ground(false)
+            .set_apu_on_fire()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.apu_fire_detected());
+        assert!(!test_bed.apu_fire_on_ground());
+
+        for _ in 0..12 {
+            test_bed = test_bed.run_for(Duration::from_secs(1));
+        }
+
+        assert!(!test_bed.apu_bottle_discharged());
+    }
+
+    #[test]
+    fn cargo_smoke_test_detects_smoke_in_cargo_compartments_only() {
+        let mut test_bed = test_bed()
+            .press_cargo_smoke_test_pb(true)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.smoke_detected("FWD_CARGO"));
+        assert!(test_bed.smoke_detected("AFT_CARGO"));
+        assert!(!test_bed.smoke_detected("LAVATORY"));
+        assert!(!test_bed.smoke_detected("AVIONICS"));
+    }
+
+    #[test]
+    fn lavatory_smoke_is_detected() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::Smoke(FireDetectionZone::Lavatory));
+        let mut test_bed = test_bed
+            .run_for(Duration::from_secs(1))
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.smoke_detected("LAVATORY"));
+        assert!(!test_bed.smoke_detected("FWD_CARGO"));
+    }
+
+    #[test]
+    fn cargo_smoke_clears_after_agent_discharge() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::Smoke(FireDetectionZone::AftCargo));
+        let mut test_bed = test_bed
+            .run_for(Duration::from_secs(1))
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.smoke_detected("AFT_CARGO"));
+
+        let mut test_bed = test_bed
+            .press_cargo_agent_pb("AFT_CARGO", true)
+            .run_for(Duration::from_secs(1))
+            .press_cargo_agent_pb("AFT_CARGO", false);
+
+        assert!(test_bed.cargo_bottle_discharged());
+
+        for _ in 0..35 {
+            test_bed = test_bed.run_for(Duration::from_secs(1));
+        }
+
+        assert!(!test_bed.smoke_detected("AFT_CARGO"));
+    }
+}
@@ -3,8 +3,12 @@
 use nalgebra::Vector3;
 use systems::{
     fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
+    ground_services::GroundServiceProcess,
     shared::ElectricalBusType,
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, VariableIdentifier, Write,
+    },
 };
 use uom::si::f64::*;
 
@@ -46,8 +50,33 @@ impl From<usize> for A320FuelTankType {
     }
 }
 
+/// The refuel itself is still performed by the JavaScript refuel logic, which clears the
+/// request once the desired fuel quantity has been reached.
+struct A320RefuelRequest {
+    refuel_started_by_user_id: VariableIdentifier,
+    refuel_started_by_user: bool,
+}
+impl A320RefuelRequest {
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            refuel_started_by_user_id: context.get_identifier("REFUEL_STARTED_BY_USR".to_owned()),
+            refuel_started_by_user: false,
+        }
+    }
+}
+impl SimulationElement for A320RefuelRequest {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.refuel_started_by_user = reader.read(&self.refuel_started_by_user_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.refuel_started_by_user_id, self.refuel_started_by_user);
+    }
+}
+
 pub struct A320Fuel {
     fuel_system: FuelSystem<5, 5>,
+    refuel_request: A320RefuelRequest,
 }
 impl A320Fuel {
     pub const A320_FUEL: [FuelInfo<'static>; 5] = [
@@ -128,6 +157,7 @@ impl A320Fuel {
             Self::FUEL_PUMPS.map(|(id, properties)| FuelPump::new(context, id, properties));
         A320Fuel {
             fuel_system: FuelSystem::new(context, fuel_tanks, fuel_pumps),
+            refuel_request: A320RefuelRequest::new(context),
         }
     }
 
@@ -208,9 +238,23 @@ impl FuelCG for A320Fuel {
         self.center_of_gravity()
     }
 }
+impl GroundServiceProcess for A320Fuel {
+    fn start(&mut self) {
+        self.refuel_request.refuel_started_by_user = true;
+    }
+
+    fn stop(&mut self) {
+        self.refuel_request.refuel_started_by_user = false;
+    }
+
+    fn is_running(&self) -> bool {
+        self.refuel_request.refuel_started_by_user
+    }
+}
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        self.refuel_request.accept(visitor);
         visitor.visit(self);
     }
 }
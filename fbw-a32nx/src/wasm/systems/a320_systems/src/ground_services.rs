@@ -0,0 +1,277 @@
+use std::time::Duration;
+
+use systems::{
+    ground_services::{
+        GroundServiceDoor, GroundServiceId, GroundServiceInfo, GroundServicePrerequisite,
+        GroundServiceProcess, GroundServices,
+    },
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+pub(super) struct A320GroundServices {
+    ground_services: GroundServices<3, 7>,
+}
+impl A320GroundServices {
+    const PASSENGER_DOOR: usize = 0;
+    const SERVICE_DOOR: usize = 1;
+    const CARGO_DOOR: usize = 2;
+
+    const A320_DOORS: [&'static str; 3] = [
+        "INTERACTIVE POINT OPEN:0",
+        "INTERACTIVE POINT OPEN:3",
+        "FWD_DOOR_CARGO_POSITION",
+    ];
+
+    const A320_SERVICES: [GroundServiceInfo<'static>; 7] = [
+        GroundServiceInfo {
+            id: GroundServiceId::Stairs,
+            connection_time: Duration::from_secs(30),
+            service_time: None,
+            disconnection_time: Duration::from_secs(30),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Jetway,
+            connection_time: Duration::from_secs(60),
+            service_time: None,
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Catering,
+            connection_time: Duration::from_secs(120),
+            service_time: Some(Duration::from_secs(600)),
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[GroundServicePrerequisite::DoorOpen(
+                A320GroundServices::SERVICE_DOOR,
+            )],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Cleaning,
+            connection_time: Duration::from_secs(60),
+            service_time: Some(Duration::from_secs(900)),
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[
+                GroundServicePrerequisite::PassengerAccess,
+                GroundServicePrerequisite::DoorOpen(A320GroundServices::PASSENGER_DOOR),
+            ],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::FuelTruck,
+            connection_time: Duration::from_secs(90),
+            service_time: None,
+            disconnection_time: Duration::from_secs(90),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::PotableWater,
+            connection_time: Duration::from_secs(60),
+            service_time: Some(Duration::from_secs(300)),
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Boarding,
+            connection_time: Duration::from_secs(10),
+            service_time: None,
+            disconnection_time: Duration::from_secs(10),
+            prerequisites: &[
+                GroundServicePrerequisite::PassengerAccess,
+                GroundServicePrerequisite::DoorOpen(A320GroundServices::PASSENGER_DOOR),
+                GroundServicePrerequisite::DoorOpen(A320GroundServices::CARGO_DOOR),
+            ],
+        },
+    ];
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        let doors = Self::A320_DOORS.map(|door| GroundServiceDoor::new(context, door));
+
+        Self {
+            ground_services: GroundServices::new(context, doors, Self::A320_SERVICES),
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        boarding: &mut impl GroundServiceProcess,
+        refuel: &mut impl GroundServiceProcess,
+    ) {
+        self.ground_services.update(context, boarding, refuel);
+    }
+}
+impl SimulationElement for A320GroundServices {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.ground_services.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fuel::A320Fuel, payload::A320Payload};
+    use systems::{
+        electrical::Electricity,
+        ground_services::GroundServiceState,
+        payload::BoardingRate,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use uom::si::{f64::Ratio, ratio::percent};
+
+    struct GroundServicesTestAircraft {
+        ground_services: A320GroundServices,
+        payload: A320Payload,
+        fuel: A320Fuel,
+    }
+    impl GroundServicesTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                ground_services: A320GroundServices::new(context),
+                payload: A320Payload::new(context),
+                fuel: A320Fuel::new(context),
+            }
+        }
+    }
+    impl Aircraft for GroundServicesTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _electricity: &mut Electricity,
+        ) {
+            self.ground_services
+                .update(context, &mut self.payload, &mut self.fuel);
+            self.payload.update(context);
+        }
+    }
+    impl SimulationElement for GroundServicesTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ground_services.accept(visitor);
+            self.payload.accept(visitor);
+            self.fuel.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<GroundServicesTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(GroundServicesTestAircraft::new);
+        test_bed.set_on_ground(true);
+        test_bed.write_by_name("BOARDING_RATE", BoardingRate::Real);
+        test_bed.write_by_name("WB_PER_PAX_WEIGHT", A320Payload::DEFAULT_PER_PAX_WEIGHT_KG);
+
+        test_bed
+    }
+
+    fn prepare_for_boarding(test_bed: &mut SimulationTestBed<GroundServicesTestAircraft>) {
+        for door in A320GroundServices::A320_DOORS {
+            test_bed.write_by_name(door, Ratio::new::<percent>(100.));
+        }
+        test_bed.write_by_name("GND_SVC_JETWAY_REQUESTED", true);
+        test_bed.write_by_name("PAX_A_DESIRED", 0b11_u64);
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+    }
+
+    fn state(
+        test_bed: &mut SimulationTestBed<GroundServicesTestAircraft>,
+        name: &str,
+    ) -> GroundServiceState {
+        test_bed.read_by_name(&format!("GND_SVC_{}_STATE", name))
+    }
+
+    #[test]
+    fn boarding_service_starts_boarding() {
+        let mut test_bed = test_bed();
+        prepare_for_boarding(&mut test_bed);
+
+        test_bed.write_by_name("GND_SVC_BOARDING_REQUESTED", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        let is_boarding: bool = test_bed.read_by_name("BOARDING_STARTED_BY_USR");
+        assert_eq!(
+            state(&mut test_bed, "BOARDING"),
+            GroundServiceState::Performing
+        );
+        assert!(is_boarding);
+        assert!(test_bed.query(|a| a.payload.is_running()));
+    }
+
+    #[test]
+    fn boarding_service_completes_once_boarding_has_finished() {
+        let mut test_bed = test_bed();
+        prepare_for_boarding(&mut test_bed);
+
+        test_bed.write_by_name("GND_SVC_BOARDING_REQUESTED", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed.run_multiple_frames(Duration::from_secs(120));
+
+        let pax_a: u64 = test_bed.read_by_name("PAX_A");
+        assert_eq!(pax_a, 0b11);
+        assert!(!test_bed.query(|a| a.payload.is_running()));
+
+        test_bed.run_with_delta(Duration::from_secs(10));
+        assert_eq!(
+            state(&mut test_bed, "BOARDING"),
+            GroundServiceState::Completed
+        );
+    }
+
+    #[test]
+    fn cancelling_boarding_service_stops_boarding() {
+        let mut test_bed = test_bed();
+        prepare_for_boarding(&mut test_bed);
+
+        test_bed.write_by_name("GND_SVC_BOARDING_REQUESTED", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(10));
+        assert!(test_bed.query(|a| a.payload.is_running()));
+
+        test_bed.write_by_name("GND_SVC_BOARDING_REQUESTED", false);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let is_boarding: bool = test_bed.read_by_name("BOARDING_STARTED_BY_USR");
+        assert_eq!(
+            state(&mut test_bed, "BOARDING"),
+            GroundServiceState::Disconnecting
+        );
+        assert!(!is_boarding);
+    }
+
+    #[test]
+    fn fuel_truck_requests_refuel_until_it_has_finished() {
+        let mut test_bed = test_bed();
+
+        test_bed.write_by_name("GND_SVC_FUEL_TRUCK_REQUESTED", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(90));
+
+        let is_refuelling: bool = test_bed.read_by_name("REFUEL_STARTED_BY_USR");
+        assert_eq!(
+            state(&mut test_bed, "FUEL_TRUCK"),
+            GroundServiceState::Performing
+        );
+        assert!(is_refuelling);
+
+        // The refuel logic clears the request once the desired fuel quantity has been reached
+        test_bed.write_by_name("REFUEL_STARTED_BY_USR", false);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            state(&mut test_bed, "FUEL_TRUCK"),
+            GroundServiceState::Disconnecting
+        );
+    }
+}
@@ -8,6 +8,7 @@ use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     electric_current::ampere,
     f64::*,
+    force::newton,
     length::{inch, meter},
     mass::kilogram,
     pressure::psi,
@@ -23,11 +24,13 @@ use systems::{
     engine::Engine,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
-        brake::{BrakeAssembly, BrakeFanPanel, BrakeProperties},
+        anti_skid::{AntiSkid, AntiSkidSide},
+        brake::{BrakeAssembly, BrakeFanPanel, BrakeProperties, WheelsCondition},
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
             BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
         },
+        brake_to_vacate::{AutobrakeRunwayOverrunProtection, BrakingDistanceCalculator},
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
         electrical_generator::{GeneratorControlUnit, HydraulicGeneratorMotor},
@@ -36,9 +39,10 @@ use systems::{
         },
         landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
         linear_actuator::{
-            Actuator, BoundedLinearLength, ElectroHydrostaticPowered, HydraulicAssemblyController,
-            HydraulicLinearActuatorAssembly, HydraulicLocking, LinearActuatedRigidBodyOnHingeAxis,
-            LinearActuator, LinearActuatorCharacteristics, LinearActuatorMode,
+            Actuator, BoundedLinearLength, ElectroHydrostaticPowered, FlightControlSurfaceFailures,
+            HydraulicAssemblyController, HydraulicLinearActuatorAssembly, HydraulicLocking,
+            LinearActuatedRigidBodyOnHingeAxis, LinearActuator, LinearActuatorCharacteristics,
+            LinearActuatorMode,
         },
         nose_steering::{
             SteeringActuator, SteeringAngleLimiter, SteeringController, SteeringRatioToAngle,
@@ -58,7 +62,7 @@ use systems::{
         PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, RamAirTurbine, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet},
+    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TyreProperties},
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
@@ -68,9 +72,10 @@ use systems::{
         AirbusElectricPumpId, AirbusEngineDrivenPumpId, ControllerSignal, DelayedFalseLogicGate,
         DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
         EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-        EmergencyGeneratorPower, EngineFirePushButtons, GearWheel, HydraulicColor,
-        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController,
-        ReservoirAirPressure, ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
+        EmergencyGeneratorPower, EngineFirePushButtons, FlightControlSurfaceId, GearWheel,
+        HydraulicColor, LandingGearHandle, LgciuInterface, LgciuWeightOnWheels,
+        RamAirTurbineController, ReservoirAirPressure, ReverserPosition, SectionPressure,
+        TrimmableHorizontalStabilizer,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -187,6 +192,7 @@ impl A320HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            Some("FUEL TANK LEFT MAIN QUANTITY"),
         )
     }
 
@@ -213,6 +219,7 @@ impl A320HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            None,
         )
     }
 
@@ -245,6 +252,7 @@ impl A320HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            Some("FUEL TANK RIGHT MAIN QUANTITY"),
         )
     }
 }
@@ -1551,6 +1559,7 @@ pub(super) struct A320Hydraulic {
     gear_system_hydraulic_controller: A320GearHydraulicController,
     gear_system: HydraulicGearSystem,
     brake_properties: BrakeProperties,
+    tyre_properties: TyreProperties,
     left_brake_assembly: BrakeAssembly<2>,
     right_brake_assembly: BrakeAssembly<2>,
     // The relays for enabling the brake fans are powered by DC2
@@ -1838,6 +1847,14 @@ impl A320Hydraulic {
                 Length::new::<inch>(15.75), // 40cm
                 Mass::new::<kilogram>(66.),
             ),
+            tyre_properties: TyreProperties::new(
+                Pressure::new::<psi>(200.),
+                Force::new::<newton>(204000.),
+                Velocity::new::<knot>(195.),
+                Length::new::<inch>(46.) / 2.,
+                // The main gear carries about 92% of the aircraft weight on four tyres
+                Ratio::new::<ratio>(0.23),
+            ),
             left_brake_assembly: BrakeAssembly::new(
                 context,
                 "WHEEL RPM:1".to_owned(),
@@ -2179,6 +2196,10 @@ impl A320Hydraulic {
             engine2,
             self.left_brake_assembly.brake_temperature_sensors(),
             self.right_brake_assembly.brake_temperature_sensors(),
+            [
+                self.left_brake_assembly.tyre_speeds(),
+                self.right_brake_assembly.tyre_speeds(),
+            ],
         );
 
         // Updating rat stowed pos on all frames in case it's used for graphics
@@ -2192,7 +2213,8 @@ impl A320Hydraulic {
             emergency_elec_state,
         );
 
-        self.pushback_tug.update(context);
+        self.pushback_tug
+            .update(context, self.nose_steering.position_feedback());
         self.bypass_pin.update(&self.pushback_tug);
 
         // The relay turning on the brake fans is grounded via LGCIU 2 signal and powered by DC2 (206PP via 3GS)
@@ -2216,12 +2238,20 @@ impl A320Hydraulic {
             brake_assembly.update(
                 context,
                 &self.brake_properties,
+                &self.tyre_properties,
                 braking_pressure_norm.max(braking_pressure_altn),
                 brake_fan_turned_on,
                 gear_position.get::<ratio>() > 0.25,
             );
         }
 
+        let anti_skid = self.brake_steer_computer.anti_skid();
+        self.braking_force.update_wheels_condition(
+            WheelsCondition::from_assemblies(&[&self.left_brake_assembly])
+                .with_braking_force_limit(anti_skid.braking_force_limit(AntiSkidSide::Left)),
+            WheelsCondition::from_assemblies(&[&self.right_brake_assembly])
+                .with_braking_force_limit(anti_skid.braking_force_limit(AntiSkidSide::Right)),
+        );
         self.braking_force.update_forces(
             context,
             &self.braking_circuit_norm,
@@ -3700,6 +3730,7 @@ struct A320HydraulicBrakeSteerComputerUnit {
     normal_brakes_available: bool,
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
+    anti_skid: AntiSkid<2>,
 
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
@@ -3780,6 +3811,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 Duration::from_secs_f64(Self::AUTOBRAKE_GEAR_RETRACTION_DURATION_S),
             ),
             anti_skid_activated: true,
+            anti_skid: AntiSkid::new(),
 
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
@@ -3809,7 +3841,15 @@ impl A320HydraulicBrakeSteerComputerUnit {
     }
 
     fn allow_autobrake_arming(&self) -> bool {
-        self.anti_skid_activated && self.normal_brakes_available
+        self.anti_skid_available() && self.normal_brakes_available
+    }
+
+    fn anti_skid_available(&self) -> bool {
+        self.anti_skid_activated && self.anti_skid.is_operative()
+    }
+
+    fn anti_skid(&self) -> &AntiSkid<2> {
+        &self.anti_skid
     }
 
     fn update_normal_braking_availability(&mut self, normal_braking_circuit_pressure: Pressure) {
@@ -3844,7 +3884,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 // Else manual action limited to a higher max nominal pressure
                 2538.
             }
-        } else if !self.anti_skid_activated {
+        } else if !self.anti_skid_available() {
             1160.
         } else {
             // Else if any manual braking we use standard limit
@@ -3869,6 +3909,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
         engine2: &impl Engine,
         left_brake_temperature_sensors: &[TemperatureSensor; 2],
         right_brake_temperature_sensors: &[TemperatureSensor; 2],
+        wheel_speeds: [[Velocity; 2]; 2],
     ) {
         for (brake_temperature, sensor) in self.brake_temperatures.iter_mut().zip(
             left_brake_temperature_sensors
@@ -3914,7 +3955,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
             self.alternate_brake_outputs.set_no_demands();
         } else {
             let green_used_for_brakes = self.normal_brakes_available
-                && self.anti_skid_activated
+                && self.anti_skid_available()
                 && !self.parking_brake_demand;
 
             if green_used_for_brakes {
@@ -3954,6 +3995,55 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 }
             }
         }
+
+        // Unpowered LGCIUs report neither compressed nor extended gears
+        let main_gear_extended = lgciu1.left_and_right_gear_extended(false)
+            && lgciu2.left_and_right_gear_extended(false);
+        self.update_anti_skid(
+            context,
+            !main_gear_extended,
+            wheel_speeds,
+            !is_in_flight_gear_lever_up && !self.parking_brake_demand,
+        );
+    }
+
+    /// Anti-skid modulates normal and alternate brake demands, but not the parking brake nor
+    /// the braking of the wheels during gear retraction.
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        main_gear_compressed: bool,
+        wheel_speeds: [[Velocity; 2]; 2],
+        brakes_are_modulated: bool,
+    ) {
+        self.anti_skid.update(
+            context,
+            self.anti_skid_activated,
+            main_gear_compressed,
+            wheel_speeds,
+            [
+                self.norm_brake_outputs
+                    .left_demand()
+                    .max(self.alternate_brake_outputs.left_demand()),
+                self.norm_brake_outputs
+                    .right_demand()
+                    .max(self.alternate_brake_outputs.right_demand()),
+            ],
+        );
+
+        if brakes_are_modulated {
+            for outputs in [
+                &mut self.norm_brake_outputs,
+                &mut self.alternate_brake_outputs,
+            ] {
+                outputs.set_brake_demands(
+                    self.anti_skid
+                        .modulated_demand(AntiSkidSide::Left, outputs.left_demand()),
+                    self.anti_skid
+                        .modulated_demand(AntiSkidSide::Right, outputs.right_demand()),
+                );
+            }
+        }
     }
 
     fn update_steering_demands(
@@ -4028,6 +4118,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.anti_skid.accept(visitor);
         visitor.visit(self);
     }
 
@@ -4091,6 +4182,9 @@ struct A320BrakingForce {
     left_braking_force: f64,
     right_braking_force: f64,
 
+    left_wheels_condition: WheelsCondition,
+    right_wheels_condition: WheelsCondition,
+
     flap_position: f64,
 
     is_chocks_enabled: bool,
@@ -4119,6 +4213,9 @@ impl A320BrakingForce {
             left_braking_force: 0.,
             right_braking_force: 0.,
 
+            left_wheels_condition: WheelsCondition::default(),
+            right_wheels_condition: WheelsCondition::default(),
+
             flap_position: 0.,
 
             is_chocks_enabled: false,
@@ -4154,9 +4251,25 @@ impl A320BrakingForce {
 
         self.correct_with_flaps_state(context);
 
+        self.left_braking_force = self
+            .left_wheels_condition
+            .correct_braking_force(self.left_braking_force);
+        self.right_braking_force = self
+            .right_wheels_condition
+            .correct_braking_force(self.right_braking_force);
+
         self.update_chocks_braking(context, engine1, engine2);
     }
 
+    pub fn update_wheels_condition(
+        &mut self,
+        left_wheels_condition: WheelsCondition,
+        right_wheels_condition: WheelsCondition,
+    ) {
+        self.left_wheels_condition = left_wheels_condition;
+        self.right_wheels_condition = right_wheels_condition;
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -4242,6 +4355,9 @@ pub struct A320AutobrakeController {
     should_reject_max_mode_after_time_in_flight: DelayedTrueLogicGate,
 
     external_disarm_event: bool,
+
+    braking_distance_calculator: BrakingDistanceCalculator,
+    autobrake_runway_overrun_protection: AutobrakeRunwayOverrunProtection<2>,
 }
 impl A320AutobrakeController {
     const DURATION_OF_FLIGHT_TO_DISARM_AUTOBRAKE_SECS: f64 = 10.;
@@ -4259,6 +4375,16 @@ impl A320AutobrakeController {
     const MARGIN_PERCENT_TO_TARGET_TO_SHOW_DECEL_IN_LO_MED: f64 = 80.;
     const TARGET_TO_SHOW_DECEL_IN_MAX_MS2: f64 = -2.7;
 
+    // Maximum braking decelerations of the braking distance predictions. They are fitted to the
+    // QRH landing distances with MAX autobrake in CONF FULL at the 68 t reference weight and its
+    // VLS of 137 kt (1060 m dry, 1320 m wet), the predictions assuming 5 s of rolling before the
+    // brakes are applied.
+    const MAX_DECEL_DRY_MS2: f64 = -3.5;
+    const MAX_DECEL_WET_MS2: f64 = -2.6;
+    // The reference datum is 8.4 m behind the nose gear (flight_model.cfg), which itself is 5.1 m
+    // behind the nose.
+    const OFFSET_PLANE_REF_POINT_TO_FRONT_METERS: f64 = 13.5;
+
     fn new(context: &mut InitContext) -> A320AutobrakeController {
         A320AutobrakeController {
             armed_mode_id: context.get_identifier("AUTOBRAKES_ARMED_MODE".to_owned()),
@@ -4290,6 +4416,14 @@ impl A320AutobrakeController {
             )
             .starting_as(context.is_in_flight()),
             external_disarm_event: false,
+
+            braking_distance_calculator: BrakingDistanceCalculator::new(
+                context,
+                Acceleration::new::<meter_per_second_squared>(Self::MAX_DECEL_DRY_MS2),
+                Acceleration::new::<meter_per_second_squared>(Self::MAX_DECEL_WET_MS2),
+                Length::new::<meter>(Self::OFFSET_PLANE_REF_POINT_TO_FRONT_METERS),
+            ),
+            autobrake_runway_overrun_protection: AutobrakeRunwayOverrunProtection::new(context),
         }
     }
 
@@ -4298,7 +4432,14 @@ impl A320AutobrakeController {
     }
 
     fn brake_output(&self) -> Ratio {
-        Ratio::new::<ratio>(self.deceleration_governor.output())
+        if self
+            .autobrake_runway_overrun_protection
+            .rop_max_braking_requested()
+        {
+            Ratio::new::<ratio>(1.)
+        } else {
+            Ratio::new::<ratio>(self.deceleration_governor.output())
+        }
     }
 
     fn determine_mode(
@@ -4446,6 +4587,16 @@ impl A320AutobrakeController {
             lgciu1,
             lgciu2,
         );
+
+        self.braking_distance_calculator.update_braking_estimations(
+            context,
+            if self.mode != AutobrakeMode::NONE {
+                context.long_accel()
+            } else {
+                Acceleration::default()
+            },
+        );
+
         self.mode = self.determine_mode(context, autobrake_panel);
 
         self.deceleration_governor
@@ -4453,9 +4604,24 @@ impl A320AutobrakeController {
 
         self.target = self.calculate_target();
         self.deceleration_governor.update(context, self.target);
+
+        self.autobrake_runway_overrun_protection.update(
+            context,
+            self.deceleration_governor.is_engaged(),
+            &self.braking_distance_calculator,
+            lgciu1,
+            lgciu2,
+        );
     }
 }
 impl SimulationElement for A320AutobrakeController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.braking_distance_calculator.accept(visitor);
+        self.autobrake_runway_overrun_protection.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.armed_mode_id, self.mode as u8 as f64);
         writer.write(&self.armed_mode_id_set, -1.);
@@ -5391,6 +5557,8 @@ struct AileronAssembly {
     position: Ratio,
 
     aerodynamic_model: AerodynamicModel,
+
+    failures: FlightControlSurfaceFailures<2>,
 }
 impl AileronAssembly {
     fn new(
@@ -5409,6 +5577,11 @@ impl AileronAssembly {
             },
             position: Ratio::new::<ratio>(0.),
             aerodynamic_model,
+
+            failures: FlightControlSurfaceFailures::new(match id {
+                ActuatorSide::Left => FlightControlSurfaceId::LeftAileron(1),
+                ActuatorSide::Right => FlightControlSurfaceId::RightAileron(1),
+            }),
         }
     }
 
@@ -5425,8 +5598,14 @@ impl AileronAssembly {
         current_pressure_outward: &impl SectionPressure,
         current_pressure_inward: &impl SectionPressure,
     ) {
+        self.failures.apply_to(&mut self.hydraulic_assembly);
+
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly.set_fluid_viscosity_flow_ratios([
+            current_pressure_outward.fluid_viscosity_flow_ratio(),
+            current_pressure_inward.fluid_viscosity_flow_ratio(),
+        ]);
         self.hydraulic_assembly.update(
             context,
             aileron_controllers,
@@ -5440,6 +5619,12 @@ impl AileronAssembly {
     }
 }
 impl SimulationElement for AileronAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.position_id, self.position.get::<ratio>());
     }
@@ -5453,6 +5638,8 @@ struct ElevatorAssembly {
     position: Ratio,
 
     aerodynamic_model: AerodynamicModel,
+
+    failures: FlightControlSurfaceFailures<2>,
 }
 impl ElevatorAssembly {
     fn new(
@@ -5471,6 +5658,11 @@ impl ElevatorAssembly {
             },
             position: Ratio::new::<ratio>(0.),
             aerodynamic_model,
+
+            failures: FlightControlSurfaceFailures::new(match id {
+                ActuatorSide::Left => FlightControlSurfaceId::LeftElevator(1),
+                ActuatorSide::Right => FlightControlSurfaceId::RightElevator(1),
+            }),
         }
     }
 
@@ -5490,8 +5682,14 @@ impl ElevatorAssembly {
     ) {
         self.hydraulic_assembly.set_trim_offset(ths.trim_angle());
 
+        self.failures.apply_to(&mut self.hydraulic_assembly);
+
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly.set_fluid_viscosity_flow_ratios([
+            current_pressure_outward.fluid_viscosity_flow_ratio(),
+            current_pressure_inward.fluid_viscosity_flow_ratio(),
+        ]);
         self.hydraulic_assembly.update(
             context,
             elevator_controllers,
@@ -5505,6 +5703,12 @@ impl ElevatorAssembly {
     }
 }
 impl SimulationElement for ElevatorAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.position_id, self.position.get::<ratio>());
     }
@@ -5517,6 +5721,8 @@ struct RudderAssembly {
     position: Ratio,
 
     aerodynamic_model: AerodynamicModel,
+
+    failures: FlightControlSurfaceFailures<3>,
 }
 impl RudderAssembly {
     fn new(
@@ -5532,6 +5738,8 @@ impl RudderAssembly {
             position: Ratio::new::<ratio>(0.5),
 
             aerodynamic_model,
+
+            failures: FlightControlSurfaceFailures::new(FlightControlSurfaceId::Rudder(1)),
         }
     }
 
@@ -5549,9 +5757,16 @@ impl RudderAssembly {
         current_pressure_blue: &impl SectionPressure,
         current_pressure_yellow: &impl SectionPressure,
     ) {
+        self.failures.apply_to(&mut self.hydraulic_assembly);
+
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
 
+        self.hydraulic_assembly.set_fluid_viscosity_flow_ratios([
+            current_pressure_green.fluid_viscosity_flow_ratio(),
+            current_pressure_blue.fluid_viscosity_flow_ratio(),
+            current_pressure_yellow.fluid_viscosity_flow_ratio(),
+        ]);
         self.hydraulic_assembly.update(
             context,
             rudder_controllers,
@@ -5566,6 +5781,12 @@ impl RudderAssembly {
     }
 }
 impl SimulationElement for RudderAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.name_id, self.position.get::<ratio>());
     }
@@ -5579,6 +5800,8 @@ struct SpoilerElement {
     position: Ratio,
 
     aerodynamic_model: AerodynamicModel,
+
+    failures: FlightControlSurfaceFailures<1>,
 }
 impl SpoilerElement {
     fn new(
@@ -5600,6 +5823,10 @@ impl SpoilerElement {
             },
             position: Ratio::new::<ratio>(0.),
             aerodynamic_model,
+            failures: FlightControlSurfaceFailures::new(match id {
+                ActuatorSide::Left => FlightControlSurfaceId::LeftSpoiler(id_num),
+                ActuatorSide::Right => FlightControlSurfaceId::RightSpoiler(id_num),
+            }),
         }
     }
 
@@ -5613,20 +5840,30 @@ impl SpoilerElement {
         spoiler_controller: &(impl HydraulicAssemblyController
               + HydraulicLocking
               + ElectroHydrostaticPowered),
-        current_pressure: Pressure,
+        section: &impl SectionPressure,
     ) {
+        self.failures.apply_to(&mut self.hydraulic_assembly);
+
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly
+            .set_fluid_viscosity_flow_ratios([section.fluid_viscosity_flow_ratio()]);
         self.hydraulic_assembly.update(
             context,
             std::slice::from_ref(spoiler_controller),
-            [current_pressure],
+            [section.pressure_downstream_leak_valve()],
         );
 
         self.position = self.hydraulic_assembly.position_normalized();
     }
 }
 impl SimulationElement for SpoilerElement {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.position_id, self.position.get::<ratio>());
     }
@@ -5657,31 +5894,11 @@ impl SpoilerGroup {
         blue_section: &impl SectionPressure,
         yellow_section: &impl SectionPressure,
     ) {
-        self.spoilers[0].update(
-            context,
-            &self.hydraulic_controllers[0],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[1].update(
-            context,
-            &self.hydraulic_controllers[1],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[2].update(
-            context,
-            &self.hydraulic_controllers[2],
-            blue_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[3].update(
-            context,
-            &self.hydraulic_controllers[3],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[4].update(
-            context,
-            &self.hydraulic_controllers[4],
-            green_section.pressure_downstream_leak_valve(),
-        );
+        self.spoilers[0].update(context, &self.hydraulic_controllers[0], green_section);
+        self.spoilers[1].update(context, &self.hydraulic_controllers[1], yellow_section);
+        self.spoilers[2].update(context, &self.hydraulic_controllers[2], blue_section);
+        self.spoilers[3].update(context, &self.hydraulic_controllers[3], yellow_section);
+        self.spoilers[4].update(context, &self.hydraulic_controllers[4], green_section);
     }
 
     fn actuator(&mut self, spoiler_id: usize) -> &mut impl Actuator {
@@ -6175,11 +6392,12 @@ mod tests {
             hydraulic::{
                 cargo_doors::{DoorControlState, HydraulicDoorController},
                 electrical_generator::TestGenerator,
+                pushback::TugAttachment,
             },
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{
                 arinc429::Arinc429Word, EmergencyElectricalState, EmergencyGeneratorControlUnit,
-                LgciuId, PotentialOrigin,
+                HydraulicSectionId, LgciuId, PotentialOrigin,
             },
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -6796,6 +7014,19 @@ mod tests {
                 self.read_by_name("HYD_BLUE_SYSTEM_1_SECTION_PRESSURE")
             }
 
+            fn green_fluid_temperature(&mut self) -> ThermodynamicTemperature {
+                self.read_by_name("HYD_GREEN_FLUID_TEMPERATURE")
+            }
+
+            fn yellow_fluid_temperature(&mut self) -> ThermodynamicTemperature {
+                self.read_by_name("HYD_YELLOW_FLUID_TEMPERATURE")
+            }
+
+            fn with_ambient_temperature(mut self, temperature: ThermodynamicTemperature) -> Self {
+                self.set_ambient_temperature(temperature);
+                self
+            }
+
             fn yellow_pressure(&mut self) -> Pressure {
                 self.read_by_name("HYD_YELLOW_SYSTEM_1_SECTION_PRESSURE")
             }
@@ -7134,6 +7365,21 @@ mod tests {
                 self
             }
 
+            fn attach_native_tug(mut self, nws_pin_inserted: bool) -> Self {
+                self.write_by_name("PUSHBACK_TUG_ATTACHMENT", TugAttachment::Towbarless);
+                self.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", nws_pin_inserted);
+                self
+            }
+
+            fn set_native_tug_turn_command(mut self, turn_command: f64) -> Self {
+                self.write_by_name("PUSHBACK_TUG_TURN_COMMAND", turn_command);
+                self
+            }
+
+            fn is_nose_gear_torque_link_overloaded(&mut self) -> bool {
+                self.read_by_name("PUSHBACK_TUG_TORQUE_LINK_OVERLOAD")
+            }
+
             fn is_nw_disc_memo_shown(&mut self) -> bool {
                 self.read_by_name("HYD_NW_STRG_DISC_ECAM_MEMO")
             }
@@ -7481,6 +7727,19 @@ mod tests {
                 self
             }
 
+            fn set_ground_speed(mut self, ground_speed: Velocity) -> Self {
+                self.write_by_name("GPS GROUND SPEED", ground_speed);
+                self
+            }
+
+            fn dry_landing_distance(&mut self) -> Length {
+                Length::new::<meter>(self.read_by_name("OANS_BTV_DRY_DISTANCE_ESTIMATED"))
+            }
+
+            fn wet_landing_distance(&mut self) -> Length {
+                Length::new::<meter>(self.read_by_name("OANS_BTV_WET_DISTANCE_ESTIMATED"))
+            }
+
             fn set_deploy_ground_spoilers(mut self) -> Self {
                 self.write_by_name("SEC_1_GROUND_SPOILER_OUT", true);
                 self.write_by_name("SEC_2_GROUND_SPOILER_OUT", true);
@@ -7598,6 +7857,26 @@ mod tests {
                 self.query(|a| a.lgcius.active_lgciu().gear_system_state())
             }
 
+            fn is_gear_downlocked_by_lgciu(&self, wheel_id: GearWheel) -> bool {
+                let downlocked_bit = match wheel_id {
+                    GearWheel::LEFT => 23,
+                    GearWheel::RIGHT => 24,
+                    GearWheel::NOSE => 25,
+                    GearWheel::WINGLEFT | GearWheel::WINGRIGHT => panic!("No wing bogey on 32NX"),
+                };
+
+                self.query(|a| {
+                    a.lgcius
+                        .active_lgciu()
+                        .discrete_word_1()
+                        .get_bit(downlocked_bit)
+                })
+            }
+
+            fn is_lgciu_control_fault(&self) -> bool {
+                self.query(|a| a.lgcius.active_lgciu().discrete_word_2().get_bit(29))
+            }
+
             fn set_elevator_full_up(mut self) -> Self {
                 self.write_by_name("LEFT_ELEV_BLUE_COMMANDED_POSITION", -30.);
                 self.write_by_name("RIGHT_ELEV_BLUE_COMMANDED_POSITION", -30.);
@@ -9529,6 +9808,17 @@ mod tests {
             assert!(test_bed.autobrake_mode() == AutobrakeMode::MED);
         }
 
+        #[test]
+        fn landing_distance_predictions_match_the_qrh_at_reference_weight() {
+            let mut test_bed = test_bed_on_ground_with()
+                .on_the_ground_after_touchdown()
+                .set_ground_speed(Velocity::new::<knot>(137.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!((test_bed.dry_landing_distance().get::<meter>() - 1060.).abs() < 30.);
+            assert!((test_bed.wet_landing_distance().get::<meter>() - 1320.).abs() < 30.);
+        }
+
         #[test]
         fn autobrakes_arming_according_to_set_variable() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10408,6 +10698,120 @@ mod tests {
             assert!(test_bed.is_slats_moving());
         }
 
+        #[test]
+        fn hydraulic_fluid_is_cold_soaked_on_cold_and_dark_start() {
+            let mut test_bed = test_bed_on_ground_with()
+                .with_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.))
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            assert!((test_bed.green_fluid_temperature().get::<degree_celsius>() + 20.).abs() < 1.);
+            assert!((test_bed.yellow_fluid_temperature().get::<degree_celsius>() + 20.).abs() < 1.);
+        }
+
+        #[test]
+        fn hydraulic_fluid_warms_up_with_engine_driven_pump_running() {
+            let mut test_bed = test_bed_on_ground_with()
+                .with_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.))
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(60 * 10));
+
+            assert!(
+                test_bed.green_fluid_temperature().get::<degree_celsius>()
+                    > test_bed.yellow_fluid_temperature().get::<degree_celsius>() + 5.
+            );
+        }
+
+        #[test]
+        fn ptu_running_heats_hydraulic_fluid_of_unpowered_circuit() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            let initial_temperature = test_bed.green_fluid_temperature();
+
+            test_bed = test_bed
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(60 * 5));
+
+            assert!(test_bed.green_fluid_temperature() > initial_temperature);
+        }
+
+        #[test]
+        fn cold_soaked_fluid_slows_down_flaps_deployment() {
+            let mut warm_test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .run_one_tick();
+
+            let mut cold_test_bed = test_bed_on_ground_with()
+                .with_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-40.))
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .run_one_tick();
+
+            warm_test_bed = warm_test_bed
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs(20))
+                .set_flaps_handle_position(4)
+                .run_waiting_for(Duration::from_secs(10));
+
+            cold_test_bed = cold_test_bed
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs(20))
+                .set_flaps_handle_position(4)
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(
+                cold_test_bed.get_flaps_left_position_percent()
+                    < 0.9 * warm_test_bed.get_flaps_left_position_percent()
+            );
+        }
+
+        #[test]
+        fn cold_soaked_fluid_slows_down_cargo_door_opening() {
+            let mut warm_test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            let mut cold_test_bed = test_bed_on_ground_with()
+                .with_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-40.))
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            warm_test_bed = warm_test_bed.open_fwd_cargo_door().run_waiting_for(
+                HydraulicDoorController::DELAY_UNLOCK_TO_HYDRAULIC_CONTROL + Duration::from_secs(5),
+            );
+
+            cold_test_bed = cold_test_bed.open_fwd_cargo_door().run_waiting_for(
+                HydraulicDoorController::DELAY_UNLOCK_TO_HYDRAULIC_CONTROL + Duration::from_secs(5),
+            );
+
+            assert!(
+                cold_test_bed.cargo_fwd_door_position()
+                    < 0.9 * warm_test_bed.cargo_fwd_door_position()
+            );
+        }
+
         #[test]
         fn yellow_epump_can_deploy_flaps_and_slats_on_worst_case_ptu() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10527,6 +10931,38 @@ mod tests {
             assert!(test_bed.get_slats_right_position_percent() > 99.);
         }
 
+        #[test]
+        fn flaps_shaft_break_locks_flaps_with_wing_tip_brakes() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_yellow_e_pump(false)
+                .set_blue_e_pump_ovrd_pressed(true)
+                .set_flaps_handle_position(1)
+                .run_waiting_for(Duration::from_secs(20));
+
+            test_bed.fail(FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Flaps,
+                SecondarySurfaceSide::Left,
+            ));
+            test_bed = test_bed
+                .set_flaps_handle_position(4)
+                .run_waiting_for(Duration::from_secs(30));
+
+            let left_flaps_position = test_bed.get_flaps_left_position_percent();
+            let right_flaps_position = test_bed.get_flaps_right_position_percent();
+            assert!(right_flaps_position < 99.);
+            assert!((right_flaps_position - left_flaps_position).abs() < 5.);
+            assert!(!test_bed.is_flaps_moving());
+
+            assert!(test_bed.get_slats_left_position_percent() > 99.);
+            assert!(test_bed.get_slats_right_position_percent() > 99.);
+        }
+
         #[test]
         fn no_pressure_no_flap_slats() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11501,7 +11937,7 @@ mod tests {
         }
 
         #[test]
-        fn elevators_centers_with_pressure_but_no_computer_command() {
+        fn jammed_elevator_actuator_holds_elevator_in_place() {
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
@@ -11513,17 +11949,94 @@ mod tests {
                 .set_elevator_full_up()
                 .run_waiting_for(Duration::from_secs_f64(5.));
 
-            assert!(test_bed.is_blue_pressure_switch_pressurised());
-
             assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
-            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
+
+            test_bed.fail(FailureType::FlightControlActuatorJam(
+                FlightControlSurfaceId::LeftElevator(1),
+                LeftElevatorActuatorCircuit::Blue as usize,
+            ));
 
             test_bed = test_bed
-                .set_elac_actuators_de_energized()
+                .set_elevator_full_down()
                 .run_waiting_for(Duration::from_secs_f64(2.));
 
-            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.4);
-            assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.4);
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.1);
+        }
+
+        #[test]
+        fn runaway_elevator_actuator_drives_elevator_to_full_deflection() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_elevator_neutral()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::FlightControlActuatorRunaway(
+                FlightControlSurfaceId::RightElevator(1),
+                RightElevatorActuatorCircuit::Blue as usize,
+            ));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(3.));
+
+            // Full actuator extension is the full down elevator position
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.99);
+            assert!((0.3..=0.7).contains(&test_bed.get_left_elevator_position().get::<ratio>()));
+        }
+
+        #[test]
+        fn disconnected_elevator_floats_freely_with_pressure_available() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::FlightControlSurfaceDisconnect(
+                FlightControlSurfaceId::LeftElevator(1),
+            ));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.is_blue_pressure_switch_pressurised());
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.1);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn elevators_centers_with_pressure_but_no_computer_command() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_blue_e_pump_ovrd_pressed(true)
+                .run_one_tick();
+
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.is_blue_pressure_switch_pressurised());
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
+
+            test_bed = test_bed
+                .set_elac_actuators_de_energized()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.4);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.4);
 
             assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.3);
             assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.3);
@@ -11603,6 +12116,101 @@ mod tests {
             assert!(!test_bed.is_green_leak_meas_valve_commanded_open());
         }
 
+        #[test]
+        fn aileron_actuator_leak_is_isolated_by_leak_measurement_valve() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .press_blue_epump_override_button_once()
+                .run_waiting_for(Duration::from_secs(20));
+            assert!(test_bed.is_blue_pressure_switch_pressurised());
+
+            test_bed.fail(FailureType::FlightControlActuatorLeak(
+                FlightControlSurfaceId::LeftAileron(1),
+                AileronActuatorPosition::Blue as usize,
+            ));
+
+            let level_before_leak = test_bed.get_blue_reservoir_volume();
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(20));
+            let level_after_leak_with_valve_open = test_bed.get_blue_reservoir_volume();
+
+            assert!(
+                level_before_leak - level_after_leak_with_valve_open > Volume::new::<gallon>(0.3)
+            );
+
+            test_bed = test_bed
+                .blue_leak_meas_valve_closed()
+                .run_waiting_for(Duration::from_secs(5));
+            let level_after_valve_closing = test_bed.get_blue_reservoir_volume();
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(20));
+
+            assert!(
+                level_after_valve_closing - test_bed.get_blue_reservoir_volume()
+                    < Volume::new::<gallon>(0.05)
+            );
+        }
+
+        #[test]
+        fn system_section_leak_is_not_isolated_by_leak_measurement_valve() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .press_blue_epump_override_button_once()
+                .blue_leak_meas_valve_closed()
+                .run_waiting_for(Duration::from_secs(20));
+
+            test_bed.fail(FailureType::HydraulicSectionLeak(
+                HydraulicColor::Blue,
+                HydraulicSectionId::System,
+            ));
+
+            let level_before_leak = test_bed.get_blue_reservoir_volume();
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(20));
+
+            assert!(
+                level_before_leak - test_bed.get_blue_reservoir_volume()
+                    > Volume::new::<gallon>(0.5)
+            );
+        }
+
+        #[test]
+        fn pump_case_drain_leak_empties_reservoir_only_with_pump_running() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed.fail(FailureType::PumpCaseDrainLeak(HydraulicColor::Blue, 1));
+
+            let level_before_leak = test_bed.get_blue_reservoir_volume();
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(20));
+
+            assert!(
+                (level_before_leak - test_bed.get_blue_reservoir_volume()).abs()
+                    < Volume::new::<gallon>(0.01)
+            );
+
+            test_bed = test_bed
+                .press_blue_epump_override_button_once()
+                .run_waiting_for(Duration::from_secs(5));
+            let level_with_pump_running = test_bed.get_blue_reservoir_volume();
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(20));
+
+            assert!(
+                level_with_pump_running - test_bed.get_blue_reservoir_volume()
+                    > Volume::new::<gallon>(0.2)
+            );
+        }
+
         #[test]
         fn leak_meas_valve_closed_on_ground_ptu_is_working() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11656,6 +12264,47 @@ mod tests {
             assert!(test_bed.get_nose_steering_ratio() < Ratio::new::<ratio>(-0.9));
         }
 
+        #[test]
+        fn nose_wheel_steers_with_native_tug() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .attach_native_tug(true)
+                .set_native_tug_turn_command(1.)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.is_nw_disc_memo_shown());
+            assert!(test_bed.get_nose_steering_ratio() > Ratio::new::<ratio>(0.9));
+
+            test_bed = test_bed
+                .set_native_tug_turn_command(-1.)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_nose_steering_ratio() < Ratio::new::<ratio>(-0.9));
+            assert!(!test_bed.is_nose_gear_torque_link_overloaded());
+        }
+
+        #[test]
+        fn native_tug_overloads_torque_link_without_nws_pin() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .attach_native_tug(false)
+                .set_native_tug_turn_command(1.)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(!test_bed.is_nw_disc_memo_shown());
+            assert!(test_bed.is_nose_gear_torque_link_overloaded());
+        }
+
         #[test]
         fn high_pitch_ptu_simvar_on_ptu_first_start() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11970,6 +12619,150 @@ mod tests {
             assert!(test_bed.is_all_gears_really_up());
         }
 
+        #[test]
+        fn gear_door_actuator_fault_requires_gravity_extension() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+
+            test_bed.fail(FailureType::GearDoorActuatorFault(
+                systems::shared::GearActuatorId::GearDoorLeft,
+            ));
+
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(35.));
+
+            // Left door cannot open so gears are never commanded down
+            assert!(
+                test_bed.get_real_gear_door_position(GearWheel::LEFT) <= Ratio::new::<ratio>(0.01)
+            );
+            assert!(test_bed.is_all_gears_really_up());
+            assert!(test_bed.is_lgciu_control_fault());
+
+            test_bed = test_bed
+                .turn_emergency_gear_extension_n_turns(3)
+                .run_waiting_for(Duration::from_secs_f64(35.));
+
+            assert!(test_bed.is_all_doors_really_down());
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::LEFT));
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::RIGHT));
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::NOSE));
+
+            test_bed = test_bed
+                .stow_emergency_gear_extension()
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            // Failed door cannot be closed again hydraulically
+            assert!(
+                test_bed.get_real_gear_door_position(GearWheel::LEFT) >= Ratio::new::<ratio>(0.9)
+            );
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.gear_system_state() == GearSystemState::Extending);
+            assert!(test_bed.is_lgciu_control_fault());
+        }
+
+        #[test]
+        fn gear_uplock_jam_requires_gravity_extension() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+
+            test_bed.fail(FailureType::GearUplockJammed(
+                systems::shared::GearActuatorId::GearNose,
+            ));
+
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(35.));
+
+            assert!(test_bed.get_real_gear_position(GearWheel::NOSE) <= Ratio::new::<ratio>(0.01));
+            assert!(test_bed.get_real_gear_position(GearWheel::LEFT) >= Ratio::new::<ratio>(0.99));
+            assert!(test_bed.get_real_gear_position(GearWheel::RIGHT) >= Ratio::new::<ratio>(0.99));
+            assert!(test_bed.is_lgciu_control_fault());
+
+            test_bed = test_bed
+                .turn_emergency_gear_extension_n_turns(3)
+                .run_waiting_for(Duration::from_secs_f64(35.));
+
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::NOSE));
+
+            test_bed = test_bed
+                .stow_emergency_gear_extension()
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.is_all_doors_really_up());
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.gear_system_state() == GearSystemState::AllDownLocked);
+            assert!(!test_bed.is_lgciu_control_fault());
+        }
+
+        #[test]
+        fn gear_not_downlocked_after_gravity_extension_with_downlock_failure() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+
+            test_bed.fail(FailureType::GearDownlockFailure(
+                systems::shared::GearActuatorId::GearLeft,
+            ));
+
+            test_bed = test_bed
+                .turn_emergency_gear_extension_n_turns(1)
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(3.));
+
+            test_bed = test_bed
+                .turn_emergency_gear_extension_n_turns(3)
+                .run_waiting_for(Duration::from_secs_f64(35.));
+
+            assert!(test_bed.is_all_doors_really_down());
+            assert!(test_bed.get_real_gear_position(GearWheel::LEFT) > Ratio::new::<ratio>(0.9));
+            assert!(!test_bed.is_gear_downlocked_by_lgciu(GearWheel::LEFT));
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::RIGHT));
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::NOSE));
+
+            // Restoring hydraulic pressure pushes the gear into its downlock
+            test_bed = test_bed
+                .stow_emergency_gear_extension()
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::LEFT));
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.is_all_doors_really_up());
+            assert!(test_bed.gear_system_state() == GearSystemState::AllDownLocked);
+        }
+
+        #[test]
+        fn gear_downlock_failure_does_not_prevent_normal_extension() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed.fail(FailureType::GearDownlockFailure(
+                systems::shared::GearActuatorId::GearLeft,
+            ));
+
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(20.));
+
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::LEFT));
+            assert!(test_bed.gear_system_state() == GearSystemState::AllDownLocked);
+        }
+
         #[test]
         fn aileron_init_centered_if_spawning_in_air() {
             let mut test_bed = test_bed_in_flight_with()
@@ -12036,6 +12829,30 @@ mod tests {
             assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.1);
         }
 
+        #[test]
+        fn failed_anti_skid_reverts_to_alternate_braking_with_limited_pressure() {
+            let mut test_bed = test_bed_on_ground_with()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.fail(FailureType::AntiSkid);
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(950.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(1300.));
+            assert!(test_bed.get_brake_right_yellow_pressure() > Pressure::new::<psi>(950.));
+            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(1300.));
+        }
+
         #[test]
         fn brakes_on_ground_work_after_emergency_extension() {
             let mut test_bed = test_bed_in_flight_with()
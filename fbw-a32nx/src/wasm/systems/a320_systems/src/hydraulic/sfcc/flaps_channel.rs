@@ -2,7 +2,9 @@ use std::time::Duration;
 
 use crate::systems::shared::arinc429::{Arinc429Word, SignStatus};
 use systems::hydraulic::command_sensor_unit::{CSUMonitor, CSU};
-use systems::hydraulic::flap_slat::{ChannelCommand, SolenoidStatus, ValveBlock};
+use systems::hydraulic::flap_slat::{
+    AsymmetryPositionPickoffUnits, ChannelCommand, SecondarySurfaceType, SolenoidStatus, ValveBlock,
+};
 use systems::shared::{
     AdirsMeasurementOutputs, DelayedFalseLogicGate, DelayedPulseTrueLogicGate, ElectricalBusType,
     ElectricalBuses, PositionPickoffUnit,
@@ -17,6 +19,7 @@ use uom::si::{angle::degree, f64::*, velocity::knot};
 use uom::ConstZero;
 
 use super::utils::SlatFlapControlComputerMisc;
+use super::wing_tip_brake::WingTipBrakeMonitor;
 
 pub(super) struct FlapsChannel {
     flaps_fppu_angle_id: VariableIdentifier,
@@ -32,6 +35,7 @@ pub(super) struct FlapsChannel {
     recovered_power_pulse: DelayedPulseTrueLogicGate,
 
     csu_monitor: CSUMonitor,
+    wtb_monitor: WingTipBrakeMonitor,
 
     conf1_flaps: Angle,
     conf1f_flaps: Angle,
@@ -72,6 +76,7 @@ impl FlapsChannel {
             recovered_power_pulse: DelayedPulseTrueLogicGate::new(Duration::ZERO),
 
             csu_monitor: CSUMonitor::new(context),
+            wtb_monitor: WingTipBrakeMonitor::new(context, num, SecondarySurfaceType::Flaps),
 
             conf1_flaps: Angle::new::<degree>(Self::FLAP_CONF1_FPPU_ANGLE),
             conf1f_flaps: Angle::new::<degree>(Self::FLAP_CONF1F_FPPU_ANGLE),
@@ -417,7 +422,7 @@ impl FlapsChannel {
     pub(super) fn update(
         &mut self,
         context: &UpdateContext,
-        flaps_feedback: &impl PositionPickoffUnit,
+        flaps_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
         adirs: &impl AdirsMeasurementOutputs,
     ) {
         self.is_powered_delayed.update(context, self.is_powered);
@@ -437,6 +442,9 @@ impl FlapsChannel {
 
         self.flaps_feedback_angle = flaps_feedback.angle();
         self.fap_update();
+
+        self.wtb_monitor
+            .update(self.is_powered_delayed.output(), flaps_feedback);
     }
 
     // The result of `get_demanded_angle` shall not be used outside of the SFCC.
@@ -462,6 +470,14 @@ impl FlapsChannel {
         self.flap_auto_command_engaged
     }
 
+    pub(super) fn get_wtb_locked_out(&self) -> bool {
+        self.wtb_monitor.is_locked_out()
+    }
+
+    pub(super) fn get_flap_attachment_failure(&self) -> bool {
+        self.wtb_monitor.is_attachment_failure_detected()
+    }
+
     #[cfg(test)]
     pub fn get_fap(&self, idx: usize) -> bool {
         self.fap[idx]
@@ -488,7 +504,7 @@ impl FlapsChannel {
 // are held in position and can't move.
 impl ValveBlock for FlapsChannel {
     fn get_pob_status(&self) -> SolenoidStatus {
-        if !self.is_powered_delayed.output() {
+        if !self.is_powered_delayed.output() || self.wtb_monitor.is_locked_out() {
             return SolenoidStatus::DeEnergised;
         }
 
@@ -503,7 +519,7 @@ impl ValveBlock for FlapsChannel {
     }
 
     fn get_command_status(&self) -> Option<ChannelCommand> {
-        if !self.is_powered_delayed.output() {
+        if !self.is_powered_delayed.output() || self.wtb_monitor.is_locked_out() {
             return None;
         }
 
@@ -521,10 +537,20 @@ impl ValveBlock for FlapsChannel {
             Some(ChannelCommand::Retract)
         }
     }
+
+    // Once locked out, the WTB stay engaged even if the SFCC loses power
+    fn get_wtb_status(&self) -> SolenoidStatus {
+        if self.wtb_monitor.is_locked_out() {
+            SolenoidStatus::Energised
+        } else {
+            SolenoidStatus::DeEnergised
+        }
+    }
 }
 impl SimulationElement for FlapsChannel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.csu_monitor.accept(visitor);
+        self.wtb_monitor.accept(visitor);
         visitor.visit(self);
     }
 
@@ -3,7 +3,7 @@ use std::time::Duration;
 use crate::systems::shared::arinc429::{Arinc429Word, SignStatus};
 use systems::accept_iterable;
 use systems::hydraulic::command_sensor_unit::CSU;
-use systems::hydraulic::flap_slat::ValveBlock;
+use systems::hydraulic::flap_slat::{AsymmetryPositionPickoffUnits, ValveBlock};
 use systems::shared::{
     AdirsMeasurementOutputs, ConsumePower, DelayedFalseLogicGate, ElectricalBusType,
     ElectricalBuses, LgciuWeightOnWheels, PositionPickoffUnit,
@@ -23,6 +23,7 @@ mod test;
 mod flaps_channel;
 mod slats_channel;
 mod utils;
+mod wing_tip_brake;
 use flaps_channel::FlapsChannel;
 use slats_channel::SlatsChannel;
 
@@ -93,8 +94,8 @@ impl SlatFlapControlComputer {
     pub fn update(
         &mut self,
         context: &UpdateContext,
-        flaps_feedback: &impl PositionPickoffUnit,
-        slats_feedback: &impl PositionPickoffUnit,
+        flaps_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
+        slats_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
         adirs: &impl AdirsMeasurementOutputs,
         lgciu: &impl LgciuWeightOnWheels,
     ) {
@@ -119,23 +120,25 @@ impl SlatFlapControlComputer {
         let flap_auto_command_engaged = self.flaps_channel.get_flap_auto_command_engaged();
         let slat_baulk_engaged = self.slats_channel.get_slat_baulk_engaged();
         let slat_alpha_lock_engaged = self.slats_channel.get_slat_alpha_lock_engaged();
+        let slat_wtb_locked_out = self.slats_channel.get_wtb_locked_out();
+        let flap_wtb_locked_out = self.flaps_channel.get_wtb_locked_out();
 
         // label 046
         let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
 
-        word.set_bit(11, false);
-        word.set_bit(12, false);
+        word.set_bit(11, slat_wtb_locked_out);
+        word.set_bit(12, flap_wtb_locked_out);
         word.set_bit(13, false);
         word.set_bit(14, false);
-        word.set_bit(15, false);
-        word.set_bit(16, false);
+        word.set_bit(15, slat_wtb_locked_out);
+        word.set_bit(16, flap_wtb_locked_out);
         word.set_bit(17, current_detent == CSU::Conf0);
         word.set_bit(18, current_detent == CSU::Conf1);
         word.set_bit(19, current_detent == CSU::Conf2);
         word.set_bit(20, current_detent == CSU::Conf3);
         word.set_bit(21, current_detent == CSU::ConfFull);
         word.set_bit(22, false);
-        word.set_bit(23, false);
+        word.set_bit(23, self.flaps_channel.get_flap_attachment_failure());
         word.set_bit(24, slat_alpha_lock_engaged);
         word.set_bit(25, slat_baulk_engaged);
         word.set_bit(26, flap_auto_command_engaged);
@@ -180,8 +183,8 @@ impl SlatFlapControlComputer {
             fppu_slats_angle > Angle::new::<degree>(327.4)
                 && fppu_slats_angle < Angle::new::<degree>(337.),
         );
-        word.set_bit(16, false);
-        word.set_bit(17, false);
+        word.set_bit(16, self.slats_channel.get_wtb_locked_out());
+        word.set_bit(17, self.slats_channel.get_wtb_locked_out());
         word.set_bit(18, true);
         word.set_bit(
             19,
@@ -208,8 +211,8 @@ impl SlatFlapControlComputer {
             fppu_flaps_angle > Angle::new::<degree>(250.)
                 && fppu_flaps_angle < Angle::new::<degree>(254.),
         );
-        word.set_bit(24, false);
-        word.set_bit(25, false);
+        word.set_bit(24, self.flaps_channel.get_wtb_locked_out());
+        word.set_bit(25, self.flaps_channel.get_wtb_locked_out());
         word.set_bit(26, false);
         word.set_bit(27, false);
         word.set_bit(28, false);
@@ -303,8 +306,8 @@ impl SlatFlapComplex {
     pub fn update(
         &mut self,
         context: &UpdateContext,
-        flaps_feedback: &impl PositionPickoffUnit,
-        slats_feedback: &impl PositionPickoffUnit,
+        flaps_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
+        slats_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
         adirs: &impl AdirsMeasurementOutputs,
         lgciu1: &impl LgciuWeightOnWheels,
         lgciu2: &impl LgciuWeightOnWheels,
@@ -2,7 +2,9 @@ use std::time::Duration;
 
 use crate::systems::shared::arinc429::{Arinc429Word, SignStatus};
 use systems::hydraulic::command_sensor_unit::{CSUMonitor, CSU};
-use systems::hydraulic::flap_slat::{ChannelCommand, SolenoidStatus, ValveBlock};
+use systems::hydraulic::flap_slat::{
+    AsymmetryPositionPickoffUnits, ChannelCommand, SecondarySurfaceType, SolenoidStatus, ValveBlock,
+};
 use systems::shared::{
     AdirsMeasurementOutputs, DelayedFalseLogicGate, ElectricalBusType, ElectricalBuses,
     LgciuWeightOnWheels, PositionPickoffUnit,
@@ -18,6 +20,7 @@ use uom::si::{angle::degree, f64::*};
 use uom::ConstZero;
 
 use super::utils::SlatFlapControlComputerMisc;
+use super::wing_tip_brake::WingTipBrakeMonitor;
 
 pub(super) struct SlatsChannel {
     slats_fppu_angle_id: VariableIdentifier,
@@ -32,6 +35,7 @@ pub(super) struct SlatsChannel {
     is_powered_delayed: DelayedFalseLogicGate,
 
     csu_monitor: CSUMonitor,
+    wtb_monitor: WingTipBrakeMonitor,
 
     kts_60: Velocity,
     conf1_slats: Angle,
@@ -83,6 +87,7 @@ impl SlatsChannel {
             slat_alpha_lock_high_aoa: Angle::new::<degree>(Self::SLAT_LOCK_HIGH_ALPHA_DEGREES),
 
             csu_monitor: CSUMonitor::new(context),
+            wtb_monitor: WingTipBrakeMonitor::new(context, num, SecondarySurfaceType::Slats),
 
             // Set `sap` to false to match power-off state
             sap: [false; 7],
@@ -262,7 +267,7 @@ impl SlatsChannel {
     pub(super) fn update(
         &mut self,
         context: &UpdateContext,
-        slats_feedback: &impl PositionPickoffUnit,
+        slats_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
         adirs: &impl AdirsMeasurementOutputs,
         lgciu: &impl LgciuWeightOnWheels,
     ) {
@@ -279,6 +284,9 @@ impl SlatsChannel {
 
         self.slats_feedback_angle = slats_feedback.angle();
         self.sap_update();
+
+        self.wtb_monitor
+            .update(self.is_powered_delayed.output(), slats_feedback);
     }
 
     // `get_demanded_angle` shall not be called outside of the SFCC to reflect
@@ -300,6 +308,10 @@ impl SlatsChannel {
         self.slat_alpha_lock_engaged
     }
 
+    pub(super) fn get_wtb_locked_out(&self) -> bool {
+        self.wtb_monitor.is_locked_out()
+    }
+
     #[cfg(test)]
     pub fn get_sap(&self, idx: usize) -> bool {
         self.sap[idx]
@@ -326,7 +338,7 @@ impl SlatsChannel {
 // are held in position and can't move.
 impl ValveBlock for SlatsChannel {
     fn get_pob_status(&self) -> SolenoidStatus {
-        if !self.is_powered_delayed.output() {
+        if !self.is_powered_delayed.output() || self.wtb_monitor.is_locked_out() {
             return SolenoidStatus::DeEnergised;
         }
 
@@ -341,7 +353,7 @@ impl ValveBlock for SlatsChannel {
     }
 
     fn get_command_status(&self) -> Option<ChannelCommand> {
-        if !self.is_powered_delayed.output() {
+        if !self.is_powered_delayed.output() || self.wtb_monitor.is_locked_out() {
             return None;
         }
 
@@ -359,10 +371,20 @@ impl ValveBlock for SlatsChannel {
             Some(ChannelCommand::Retract)
         }
     }
+
+    // Once locked out, the WTB stay engaged even if the SFCC loses power
+    fn get_wtb_status(&self) -> SolenoidStatus {
+        if self.wtb_monitor.is_locked_out() {
+            SolenoidStatus::Energised
+        } else {
+            SolenoidStatus::DeEnergised
+        }
+    }
 }
 impl SimulationElement for SlatsChannel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.csu_monitor.accept(visitor);
+        self.wtb_monitor.accept(visitor);
         visitor.visit(self);
     }
 
@@ -5,7 +5,7 @@ use ntest::assert_about_eq;
 use std::{panic::Location, time::Duration};
 use systems::{
     electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
-    hydraulic::flap_slat::{ChannelCommand, SolenoidStatus},
+    hydraulic::flap_slat::{AsymmetryPositionPickoffUnits, ChannelCommand, SolenoidStatus},
     shared::PotentialOrigin,
     simulation::{
         test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -17,6 +17,8 @@ use uom::si::{angular_velocity::degree_per_second, pressure::psi, velocity::knot
 
 struct SlatFlapGear {
     current_angle: Angle,
+    left_asymmetry: Angle,
+    is_skewed: bool,
     speed: AngularVelocity,
     max_angle: Angle,
     left_position_percent_id: VariableIdentifier,
@@ -29,6 +31,19 @@ impl PositionPickoffUnit for SlatFlapGear {
         self.current_angle
     }
 }
+impl AsymmetryPositionPickoffUnits for SlatFlapGear {
+    fn left_angle(&self) -> Angle {
+        self.current_angle + self.left_asymmetry
+    }
+
+    fn right_angle(&self) -> Angle {
+        self.current_angle
+    }
+
+    fn is_skew_detected(&self) -> bool {
+        self.is_skewed
+    }
+}
 
 impl SlatFlapGear {
     const ANGLE_DELTA_DEGREE: f64 = 0.01;
@@ -41,6 +56,8 @@ impl SlatFlapGear {
     ) -> Self {
         Self {
             current_angle: Angle::new::<degree>(0.),
+            left_asymmetry: Angle::default(),
+            is_skewed: false,
             speed,
             max_angle,
 
@@ -307,6 +324,18 @@ impl A320FlapsTestAircraft {
     fn set_dc_ess_bus_power(&mut self, is_powered: bool) {
         self.is_dc_ess_powered = is_powered;
     }
+
+    fn set_flaps_left_asymmetry(&mut self, asymmetry: Angle) {
+        self.flap_gear.left_asymmetry = asymmetry;
+    }
+
+    fn set_slats_left_asymmetry(&mut self, asymmetry: Angle) {
+        self.slat_gear.left_asymmetry = asymmetry;
+    }
+
+    fn set_flaps_skewed(&mut self, is_skewed: bool) {
+        self.flap_gear.is_skewed = is_skewed;
+    }
 }
 
 impl Aircraft for A320FlapsTestAircraft {
@@ -552,6 +581,35 @@ impl A320FlapsTestBed {
         self
     }
 
+    fn set_flaps_left_asymmetry(mut self, degrees: f64) -> Self {
+        self.command(|a| a.set_flaps_left_asymmetry(Angle::new::<degree>(degrees)));
+        self
+    }
+
+    fn set_slats_left_asymmetry(mut self, degrees: f64) -> Self {
+        self.command(|a| a.set_slats_left_asymmetry(Angle::new::<degree>(degrees)));
+        self
+    }
+
+    fn set_flaps_skewed(mut self, is_skewed: bool) -> Self {
+        self.command(|a| a.set_flaps_skewed(is_skewed));
+        self
+    }
+
+    fn set_wtb_maintenance_reset(mut self, surface_type: &str, reset: bool) -> Self {
+        self.write_by_name(&format!("SFCC_1_{surface_type}_WTB_RESET"), reset);
+        self.write_by_name(&format!("SFCC_2_{surface_type}_WTB_RESET"), reset);
+        self
+    }
+
+    fn flaps_wtb_status(&self, idx: usize) -> SolenoidStatus {
+        self.query(|a| a.slat_flap_complex.flap_pcu(idx).get_wtb_status())
+    }
+
+    fn slats_wtb_status(&self, idx: usize) -> SolenoidStatus {
+        self.query(|a| a.slat_flap_complex.slat_pcu(idx).get_wtb_status())
+    }
+
     fn set_lgciu_on_ground(mut self, is_on_ground: bool) -> Self {
         self.set_on_ground(is_on_ground);
         self.command(|a| a.lgciu1.set_on_ground(is_on_ground));
@@ -3625,3 +3683,119 @@ fn flaps_autocommand_different_adiru_speeds() {
     test_bed = test_bed.set_adiru_airspeed(2, Some(220.)).run_one_tick();
     assert_eq!(test_bed.get_flaps_conf(), FlapsConf::Conf1F);
 }
+
+#[test]
+fn flaps_wtb_not_engaged_without_asymmetry() {
+    let mut test_bed = test_bed_with()
+        .set_green_hyd_pressure()
+        .set_yellow_hyd_pressure()
+        .set_blue_hyd_pressure()
+        .set_flaps_handle_position(4)
+        .run_for_some_time();
+
+    assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::DeEnergised);
+    assert_eq!(test_bed.flaps_wtb_status(1), SolenoidStatus::DeEnergised);
+    assert!(!test_bed.read_slat_flap_system_status_word(1).get_bit(12));
+    assert!(!test_bed.read_slat_flap_system_status_word(1).get_bit(16));
+}
+
+#[test]
+fn flaps_asymmetry_engages_and_locks_out_wtb() {
+    let mut test_bed = test_bed_with()
+        .set_green_hyd_pressure()
+        .set_yellow_hyd_pressure()
+        .set_blue_hyd_pressure()
+        .set_flaps_handle_position(2)
+        .run_waiting_for(Duration::from_secs(5))
+        .set_flaps_left_asymmetry(-10.)
+        .run_one_tick();
+
+    for idx in 0..2 {
+        assert_eq!(test_bed.flaps_wtb_status(idx), SolenoidStatus::Energised);
+        assert_eq!(
+            test_bed.query(|a| a.slat_flap_complex.flap_pcu(idx).get_pob_status()),
+            SolenoidStatus::DeEnergised
+        );
+        assert!(test_bed
+            .query(|a| a.slat_flap_complex.flap_pcu(idx).get_command_status())
+            .is_none());
+    }
+
+    for num in 1..=2 {
+        assert!(test_bed.read_slat_flap_system_status_word(num).get_bit(12));
+        assert!(test_bed.read_slat_flap_system_status_word(num).get_bit(16));
+        assert!(!test_bed.read_slat_flap_system_status_word(num).get_bit(15));
+        assert!(test_bed
+            .read_slat_flap_actual_position_word(num)
+            .get_bit(24));
+        assert!(test_bed
+            .read_slat_flap_actual_position_word(num)
+            .get_bit(25));
+        assert!(!test_bed
+            .read_slat_flap_actual_position_word(num)
+            .get_bit(17));
+    }
+    assert_eq!(test_bed.slats_wtb_status(0), SolenoidStatus::DeEnergised);
+
+    // Lockout remains after the asymmetry is gone and through a power loss
+    test_bed = test_bed
+        .set_flaps_left_asymmetry(0.)
+        .set_dc_2_bus_power(false)
+        .set_dc_ess_bus_power(false)
+        .run_waiting_for(Duration::from_secs(2))
+        .set_dc_2_bus_power(true)
+        .set_dc_ess_bus_power(true)
+        .run_waiting_for(Duration::from_secs(2));
+
+    assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::Energised);
+    assert_eq!(test_bed.flaps_wtb_status(1), SolenoidStatus::Energised);
+
+    test_bed = test_bed
+        .set_wtb_maintenance_reset("FLAPS", true)
+        .run_one_tick()
+        .set_wtb_maintenance_reset("FLAPS", false)
+        .run_one_tick();
+
+    assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::DeEnergised);
+    assert_eq!(test_bed.flaps_wtb_status(1), SolenoidStatus::DeEnergised);
+    assert!(!test_bed.read_slat_flap_system_status_word(1).get_bit(16));
+}
+
+#[test]
+fn slats_asymmetry_engages_slats_wtb() {
+    let mut test_bed = test_bed_with()
+        .set_green_hyd_pressure()
+        .set_yellow_hyd_pressure()
+        .set_blue_hyd_pressure()
+        .set_flaps_handle_position(1)
+        .run_waiting_for(Duration::from_secs(5))
+        .set_slats_left_asymmetry(10.)
+        .run_one_tick();
+
+    assert_eq!(test_bed.slats_wtb_status(0), SolenoidStatus::Energised);
+    assert_eq!(test_bed.slats_wtb_status(1), SolenoidStatus::Energised);
+    assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::DeEnergised);
+
+    assert!(test_bed.read_slat_flap_system_status_word(1).get_bit(11));
+    assert!(test_bed.read_slat_flap_system_status_word(1).get_bit(15));
+    assert!(test_bed.read_slat_flap_actual_position_word(1).get_bit(16));
+    assert!(test_bed.read_slat_flap_actual_position_word(1).get_bit(17));
+    assert!(!test_bed.read_slat_flap_actual_position_word(1).get_bit(24));
+    assert!(!test_bed.read_slat_flap_actual_position_word(1).get_bit(25));
+}
+
+#[test]
+fn flaps_skew_sets_attachment_failure_and_engages_wtb() {
+    let mut test_bed = test_bed_with()
+        .set_green_hyd_pressure()
+        .set_yellow_hyd_pressure()
+        .set_blue_hyd_pressure()
+        .set_flaps_handle_position(2)
+        .run_waiting_for(Duration::from_secs(5))
+        .set_flaps_skewed(true)
+        .run_one_tick();
+
+    assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::Energised);
+    assert!(test_bed.read_slat_flap_system_status_word(1).get_bit(23));
+    assert!(test_bed.read_slat_flap_system_status_word(2).get_bit(23));
+}
@@ -0,0 +1,76 @@
+use systems::hydraulic::flap_slat::{AsymmetryPositionPickoffUnits, SecondarySurfaceType};
+use systems::shared::PositionPickoffUnit;
+
+use systems::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, VariableIdentifier,
+};
+
+use uom::si::{angle::degree, f64::*};
+
+// The Wing Tip Brakes (WTB) monitoring compares both wing tip APPUs with each other and
+// with the FPPU on the PCU. On asymmetry, on a transmission failure or on a surface skew,
+// the WTB are engaged and locked out. The lockout is stored in non volatile memory so it
+// survives a SFCC power loss, and it can only be cleared by a maintenance reset.
+pub(super) struct WingTipBrakeMonitor {
+    maintenance_reset_id: VariableIdentifier,
+
+    maintenance_reset: bool,
+    is_locked_out: bool,
+    is_attachment_failure_detected: bool,
+}
+impl WingTipBrakeMonitor {
+    const ASYMMETRY_THRESHOLD_DEGREE: f64 = 5.; //deg (FPPU)
+
+    pub(super) fn new(
+        context: &mut InitContext,
+        num: u8,
+        surface_type: SecondarySurfaceType,
+    ) -> Self {
+        Self {
+            maintenance_reset_id: context
+                .get_identifier(format!("SFCC_{num}_{surface_type}_WTB_RESET")),
+
+            maintenance_reset: false,
+            is_locked_out: false,
+            is_attachment_failure_detected: false,
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        is_powered: bool,
+        feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
+    ) {
+        if !is_powered {
+            return;
+        }
+
+        if self.maintenance_reset {
+            self.is_locked_out = false;
+            self.is_attachment_failure_detected = false;
+        }
+
+        let threshold = Angle::new::<degree>(Self::ASYMMETRY_THRESHOLD_DEGREE);
+        let fppu_angle = feedback.angle();
+
+        let asymmetry_detected = (feedback.left_angle() - feedback.right_angle()).abs() > threshold
+            || (feedback.left_angle() - fppu_angle).abs() > threshold
+            || (feedback.right_angle() - fppu_angle).abs() > threshold;
+
+        self.is_attachment_failure_detected |= feedback.is_skew_detected();
+        self.is_locked_out |= asymmetry_detected || self.is_attachment_failure_detected;
+    }
+
+    pub(super) fn is_locked_out(&self) -> bool {
+        self.is_locked_out
+    }
+
+    pub(super) fn is_attachment_failure_detected(&self) -> bool {
+        self.is_attachment_failure_detected
+    }
+}
+impl SimulationElement for WingTipBrakeMonitor {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.maintenance_reset = reader.read(&self.maintenance_reset_id);
+    }
+}
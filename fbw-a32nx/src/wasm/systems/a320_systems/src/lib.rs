@@ -3,7 +3,9 @@ extern crate systems;
 mod air_conditioning;
 mod airframe;
 mod electrical;
+mod fire_protection;
 mod fuel;
+mod ground_services;
 pub mod hydraulic;
 mod navigation;
 mod payload;
@@ -13,7 +15,9 @@ mod surveillance;
 
 use self::{
     air_conditioning::A320AirConditioning,
+    fire_protection::A320FireProtection,
     fuel::A320Fuel,
+    ground_services::A320GroundServices,
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
     surveillance::A320EgpwsElectricalHarness,
@@ -21,7 +25,7 @@ use self::{
 use airframe::A320Airframe;
 use electrical::{
     A320Electrical, A320ElectricalOverheadPanel, A320EmergencyElectricalOverheadPanel,
-    APU_START_MOTOR_BUS_TYPE,
+    APU_START_MOTOR_BUS_TYPE, EGPWS, LGCIU_1, LGCIU_2,
 };
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
 use navigation::{A320AirDataInertialReferenceSystemBuilder, A320RadioAltimeters};
@@ -66,9 +70,11 @@ pub struct A320 {
     payload: A320Payload,
     airframe: A320Airframe,
     fuel: A320Fuel,
+    ground_services: A320GroundServices,
     engine_1: LeapEngine,
     engine_2: LeapEngine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
+    fire_protection: A320FireProtection,
     electrical: A320Electrical,
     power_consumption: A320PowerConsumption,
     ext_pwr: ExternalPowerSource,
@@ -108,16 +114,18 @@ impl A320 {
             payload: A320Payload::new(context),
             airframe: A320Airframe::new(context),
             fuel: A320Fuel::new(context),
+            ground_services: A320GroundServices::new(context),
             engine_1: LeapEngine::new(context, 1),
             engine_2: LeapEngine::new(context, 2),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            fire_protection: A320FireProtection::new(context),
             electrical: A320Electrical::new(context),
             power_consumption: A320PowerConsumption::new(context),
             ext_pwr: ExternalPowerSource::new(context, 1),
             lgcius: LandingGearControlInterfaceUnitSet::new(
                 context,
-                ElectricalBusType::DirectCurrentEssential,
-                ElectricalBusType::DirectCurrentGndFltService,
+                LGCIU_1.bus_type(),
+                LGCIU_2.bus_type(),
             ),
             hydraulic: A320Hydraulic::new(context),
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
@@ -128,7 +136,7 @@ impl A320 {
             radio_altimeters: A320RadioAltimeters::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
-                ElectricalBusType::AlternatingCurrent(1),
+                EGPWS.bus_type(),
                 vec![
                     Length::new::<nautical_mile>(10.0),
                     Length::new::<nautical_mile>(20.0),
@@ -139,10 +147,7 @@ impl A320 {
                 ],
                 0,
             ),
-            egpwc_2: EnhancedGroundProximityWarningComputer2::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(1),
-            ),
+            egpwc_2: EnhancedGroundProximityWarningComputer2::new(context, EGPWS.bus_type()),
             egpws_electrical_harness: A320EgpwsElectricalHarness::new(context),
             mmr: MultiModeReceiverShim::new(context),
             reverse_thrust: ReverserForce::new(context),
@@ -158,9 +163,10 @@ impl Aircraft for A320 {
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
-            false, // Todo: fire detection system
+            self.fire_protection.apu_fire_on_ground(),
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on()
+                && !self.pneumatic.apu_bleed_is_leak_isolated(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -190,6 +196,8 @@ impl Aircraft for A320 {
             .update_after_electrical(&self.electrical, electricity, &self.apu);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
+        self.ground_services
+            .update(context, &mut self.payload, &mut self.fuel);
         self.payload.update(context);
         self.airframe
             .update(&self.fuel, &self.payload, &self.payload);
@@ -202,7 +210,8 @@ impl Aircraft for A320 {
         );
         self.apu_overhead.update_after_apu(&self.apu);
 
-        self.asu.update();
+        self.asu
+            .update(context, self.pneumatic.air_starter_unit_delivered_flow());
 
         self.lgcius.update(
             context,
@@ -211,6 +220,12 @@ impl Aircraft for A320 {
             self.ext_pwr.output_potential().is_powered(),
         );
 
+        self.fire_protection.update(
+            context,
+            &self.engine_fire_overhead,
+            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+        );
+
         self.radio_altimeters.update(context);
 
         self.hydraulic.update(
@@ -258,6 +273,8 @@ impl Aircraft for A320 {
             &self.air_conditioning,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
+        self.pneumatic_overhead
+            .update_after_pneumatic(&self.pneumatic);
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
         self.air_conditioning.update(
@@ -297,10 +314,12 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.ground_services.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.engine_1.accept(visitor);
         self.engine_2.accept(visitor);
         self.engine_fire_overhead.accept(visitor);
+        self.fire_protection.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
         self.ext_pwr.accept(visitor);
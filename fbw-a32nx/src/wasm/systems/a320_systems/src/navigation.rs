@@ -1,3 +1,4 @@
+use crate::electrical::{RADIO_ALTIMETER_1, RADIO_ALTIMETER_2};
 use systems::navigation::adirs::{
     AirDataInertialReferenceSystem, AirDataInertialReferenceUnitProgramming,
     LowSpeedWarningThreshold,
@@ -54,7 +55,7 @@ impl A320RadioAltimeters {
             radio_altimeter_1: A320RadioAltimeter::new(
                 context,
                 1,
-                ElectricalBusType::AlternatingCurrent(1),
+                RADIO_ALTIMETER_1.bus_type(),
                 AntennaInstallation::new(
                     // Sim CG minus RA height over ground
                     Length::new::<foot>(8.617) - Length::new::<meter>(1.8),
@@ -72,7 +73,7 @@ impl A320RadioAltimeters {
             radio_altimeter_2: A320RadioAltimeter::new(
                 context,
                 2,
-                ElectricalBusType::AlternatingCurrent(2),
+                RADIO_ALTIMETER_2.bus_type(),
                 AntennaInstallation::new(
                     Length::new::<foot>(8.617) - Length::new::<meter>(1.8),
                     Length::new::<meter>(11.27),
@@ -5,6 +5,7 @@ use std::{cell::Cell, rc::Rc};
 use uom::si::{f64::Mass, mass::kilogram};
 
 use systems::{
+    ground_services::GroundServiceProcess,
     payload::{
         BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
         NumberOfPassengers, PassengerDeck, PassengerPayload, Pax, PaxInfo, PayloadManager,
@@ -365,6 +366,19 @@ impl CargoPayload for A320Payload {
         self.target_cargo_center_of_gravity().x
     }
 }
+impl GroundServiceProcess for A320Payload {
+    fn start(&mut self) {
+        self.payload_manager.start();
+    }
+
+    fn stop(&mut self) {
+        self.payload_manager.stop();
+    }
+
+    fn is_running(&self) -> bool {
+        self.payload_manager.is_running()
+    }
+}
 impl SimulationElement for A320Payload {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.payload_manager.accept(visitor);
@@ -15,21 +15,23 @@ use systems::{
     air_conditioning::PackFlowControllers,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerChannelOperationMode,
-        BleedMonitoringComputerIsAliveSignal, BleedTemperatureSensor, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
-        SolenoidSignal, TargetPressureTemperatureSignal, VariableVolumeContainer,
-        WingAntiIcePushButton, WingAntiIceSelected,
+        leak_detection::{BleedDuctArea, BleedLeakDetector},
+        valve::*,
+        BleedMonitoringComputerChannelOperationMode, BleedMonitoringComputerIsAliveSignal,
+        BleedTemperatureSensor, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, PneumaticContainer,
+        PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir, SolenoidSignal,
+        TargetPressureTemperatureSignal, VariableVolumeContainer, WingAntiIcePushButton,
+        WingAntiIceSelected,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
-        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
-        ReservoirAirPressure,
+        pid::PidController, update_iterator::MaxStepLoop, BleedLeakZone, ControllerSignal,
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineBleedPushbutton,
+        EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EngineStartState,
+        HydraulicColor, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        PneumaticValveId, ReservoirAirPressure,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -98,6 +100,9 @@ pub struct A320Pneumatic {
     bleed_monitoring_computers: [BleedMonitoringComputer; 2],
     engine_systems: [EngineBleedAirSystem; 2],
 
+    bleed_duct_areas: BleedDuctAreas,
+    leak_isolation: BleedLeakIsolation,
+
     cross_bleed_valve: CrossBleedValve,
 
     fadec: FullAuthorityDigitalEngineControl,
@@ -154,6 +159,8 @@ impl A320Pneumatic {
                 ),
                 EngineBleedAirSystem::new(context, 2, ElectricalBusType::DirectCurrent(2)),
             ],
+            bleed_duct_areas: BleedDuctAreas::new(),
+            leak_isolation: BleedLeakIsolation::default(),
             cross_bleed_valve: CrossBleedValve::new(Ratio::new::<ratio>(0.4)),
             fadec: FullAuthorityDigitalEngineControl::new(context),
             engine_starter_valve_controllers: [
@@ -161,7 +168,8 @@ impl A320Pneumatic {
                 EngineStarterValveController::new(2),
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
-            apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_air_valve: DefaultValve::new_closed()
+                .with_failures(PneumaticValveId::ApuBleed),
             air_starter_unit_compression_chamber: CompressionChamber::new(
                 Volume::new::<cubic_meter>(5.),
             ),
@@ -258,10 +266,21 @@ impl A320Pneumatic {
         self.apu_compression_chamber.update(apu);
         self.air_starter_unit_compression_chamber.update(asu);
 
+        for bleed_monitoring_computer in self.bleed_monitoring_computers.iter_mut() {
+            bleed_monitoring_computer.update_leak_detection(&self.bleed_duct_areas);
+        }
+
+        // Both BMCs are interconnected, so each of them isolates the leaks detected by the other one
+        let [bmc_one, bmc_two] = &self.bleed_monitoring_computers;
+        self.leak_isolation = bmc_one
+            .leak_isolation()
+            .combined_with(bmc_two.leak_isolation());
+
         for bleed_monitoring_computer in self.bleed_monitoring_computers.iter_mut() {
             bleed_monitoring_computer.update(
                 context,
                 &self.engine_systems,
+                &self.leak_isolation,
                 &self.apu_bleed_air_valve,
                 overhead_panel,
                 engine_fire_push_buttons,
@@ -348,6 +367,13 @@ impl A320Pneumatic {
             .for_each(|(pack, engine_system)| {
                 pack.update(context, engine_system, pack_flow_valve_signals)
             });
+
+        self.bleed_duct_areas.update(
+            context,
+            &self.engine_systems,
+            &self.apu_compression_chamber,
+            &self.apu_bleed_air_valve,
+        );
     }
 
     // TODO: Returning a mutable reference here is not great. I was running into an issue with the update order:
@@ -376,6 +402,27 @@ impl A320Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// The flow currently drawn from the ground cart through the HP ground connection.
+    pub fn air_starter_unit_delivered_flow(&self) -> MassRate {
+        self.air_starter_unit_bleed_air_valve.fluid_flow()
+    }
+
+    /// A leak in the pylon or wing of the given engine's side was detected
+    pub fn engine_bleed_leak_detected(&self, engine_number: usize) -> bool {
+        self.bleed_monitoring_computers[engine_number - 1].engine_bleed_leak_detected()
+    }
+
+    pub fn apu_bleed_leak_detected(&self) -> bool {
+        self.bleed_monitoring_computers
+            .iter()
+            .any(|bmc| bmc.apu_bleed_leak_detected())
+    }
+
+    /// The BMCs command the ECB to close the APU bleed valve, to isolate a leak
+    pub fn apu_bleed_is_leak_isolated(&self) -> bool {
+        self.leak_isolation.apu_bleed_valve_closed
+    }
 }
 impl PneumaticBleed for A320Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -408,8 +455,10 @@ impl PackFlowValveState for A320Pneumatic {
 impl SimulationElement for A320Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.cross_bleed_valve.accept(visitor);
+        self.apu_bleed_air_valve.accept(visitor);
         self.fadec.accept(visitor);
         self.wing_anti_ice.accept(visitor);
+        self.bleed_duct_areas.accept(visitor);
 
         accept_iterable!(self.bleed_monitoring_computers, visitor);
         accept_iterable!(self.engine_systems, visitor);
@@ -486,15 +535,133 @@ impl EngineStarterValveController {
     }
 }
 
+/// The valves closed by the BMCs to isolate a detected bleed leak
+#[derive(Clone, Copy, Default)]
+struct BleedLeakIsolation {
+    engine_bleed_valves_closed: [bool; 2],
+    cross_bleed_valve_closed: bool,
+    apu_bleed_valve_closed: bool,
+}
+impl BleedLeakIsolation {
+    fn combined_with(self, other: BleedLeakIsolation) -> Self {
+        Self {
+            engine_bleed_valves_closed: [
+                self.engine_bleed_valves_closed[0] || other.engine_bleed_valves_closed[0],
+                self.engine_bleed_valves_closed[1] || other.engine_bleed_valves_closed[1],
+            ],
+            cross_bleed_valve_closed: self.cross_bleed_valve_closed
+                || other.cross_bleed_valve_closed,
+            apu_bleed_valve_closed: self.apu_bleed_valve_closed || other.apu_bleed_valve_closed,
+        }
+    }
+
+    fn engine_bleed_valve_closed(&self, engine_number: usize) -> bool {
+        self.engine_bleed_valves_closed[engine_number - 1]
+    }
+}
+
+/// The areas around the hot air ducts which are monitored by the leak detection loops
+struct BleedDuctAreas {
+    pylons: [BleedDuctArea; 2],
+    wings: [BleedDuctArea; 2],
+    pack_bays: [BleedDuctArea; 2],
+    apu_duct: BleedDuctArea,
+}
+impl BleedDuctAreas {
+    fn new() -> Self {
+        Self {
+            pylons: [
+                BleedDuctArea::new(BleedLeakZone::Pylon(1)),
+                BleedDuctArea::new(BleedLeakZone::Pylon(2)),
+            ],
+            wings: [
+                BleedDuctArea::new(BleedLeakZone::Wing(1)),
+                BleedDuctArea::new(BleedLeakZone::Wing(2)),
+            ],
+            pack_bays: [
+                BleedDuctArea::new(BleedLeakZone::PackBay(1)),
+                BleedDuctArea::new(BleedLeakZone::PackBay(2)),
+            ],
+            apu_duct: BleedDuctArea::new(BleedLeakZone::ApuDuct),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_systems: &[EngineBleedAirSystem; 2],
+        apu_compression_chamber: &impl PneumaticContainer,
+        apu_bleed_valve: &impl PneumaticValve,
+    ) {
+        for (((pylon, wing), pack_bay), engine_system) in self
+            .pylons
+            .iter_mut()
+            .zip(&mut self.wings)
+            .zip(&mut self.pack_bays)
+            .zip(engine_systems)
+        {
+            // The pylon ducts carry the IP and HP air upstream of the bleed valve
+            pylon.update(
+                context,
+                engine_system.transfer_pressure(),
+                engine_system.transfer_temperature(),
+            );
+            wing.update(
+                context,
+                engine_system.pressure(),
+                engine_system.temperature(),
+            );
+            // The pack bay ducts are upstream of the pack flow control valve
+            pack_bay.update(
+                context,
+                engine_system.pressure(),
+                engine_system.temperature(),
+            );
+        }
+
+        // The APU duct is only pressurised while the APU bleed valve is open
+        if apu_bleed_valve.is_open() {
+            self.apu_duct.update(
+                context,
+                apu_compression_chamber.pressure(),
+                apu_compression_chamber.temperature(),
+            );
+        } else {
+            self.apu_duct.update(
+                context,
+                context.ambient_pressure(),
+                context.ambient_temperature(),
+            );
+        }
+    }
+}
+impl SimulationElement for BleedDuctAreas {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pylons, visitor);
+        accept_iterable!(self.wings, visitor);
+        accept_iterable!(self.pack_bays, visitor);
+        self.apu_duct.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 struct BleedMonitoringComputer {
     main_channel_engine_number: usize,
     backup_channel_engine_number: usize,
     main_channel: BleedMonitoringComputerChannel,
     backup_channel: BleedMonitoringComputerChannel,
+    pylon_leak_detector: BleedLeakDetector<1>,
+    wing_leak_detector: BleedLeakDetector<2>,
+    apu_duct_leak_detector: Option<BleedLeakDetector<1>>,
     powered_by: ElectricalBusType,
     is_powered: bool,
 }
 impl BleedMonitoringComputer {
+    const PYLON_LEAK_ALARM_TEMPERATURE_C: f64 = 204.;
+    const WING_LEAK_ALARM_TEMPERATURE_C: f64 = 124.;
+    const APU_DUCT_LEAK_ALARM_TEMPERATURE_C: f64 = 124.;
+
     fn new(
         context: &mut InitContext,
         main_channel_engine_number: usize,
@@ -514,15 +681,84 @@ impl BleedMonitoringComputer {
                 backup_channel_engine_number,
                 BleedMonitoringComputerChannelOperationMode::Slave,
             ),
+            pylon_leak_detector: BleedLeakDetector::new(
+                context,
+                BleedLeakZone::Pylon(main_channel_engine_number),
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::PYLON_LEAK_ALARM_TEMPERATURE_C,
+                ),
+            ),
+            // The wing loops also run through the pack bay of the same side
+            wing_leak_detector: BleedLeakDetector::new(
+                context,
+                BleedLeakZone::Wing(main_channel_engine_number),
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::WING_LEAK_ALARM_TEMPERATURE_C,
+                ),
+            ),
+            // Only BMC 1 monitors the APU duct loop
+            apu_duct_leak_detector: (main_channel_engine_number == 1).then(|| {
+                BleedLeakDetector::new(
+                    context,
+                    BleedLeakZone::ApuDuct,
+                    ThermodynamicTemperature::new::<degree_celsius>(
+                        Self::APU_DUCT_LEAK_ALARM_TEMPERATURE_C,
+                    ),
+                )
+            }),
             powered_by,
             is_powered: true,
         }
     }
 
+    fn update_leak_detection(&mut self, areas: &BleedDuctAreas) {
+        let index = self.main_channel_engine_number - 1;
+
+        self.pylon_leak_detector
+            .update(&[&areas.pylons[index]], self.is_powered);
+        self.wing_leak_detector.update(
+            &[&areas.wings[index], &areas.pack_bays[index]],
+            self.is_powered,
+        );
+
+        if let Some(apu_duct_leak_detector) = &mut self.apu_duct_leak_detector {
+            apu_duct_leak_detector.update(&[&areas.apu_duct], self.is_powered);
+        }
+    }
+
+    fn engine_bleed_leak_detected(&self) -> bool {
+        self.pylon_leak_detector.leak_detected() || self.wing_leak_detector.leak_detected()
+    }
+
+    fn apu_bleed_leak_detected(&self) -> bool {
+        self.apu_duct_leak_detector
+            .as_ref()
+            .is_some_and(|detector| detector.leak_detected())
+    }
+
+    fn leak_isolation(&self) -> BleedLeakIsolation {
+        let mut isolation = BleedLeakIsolation::default();
+
+        if self.engine_bleed_leak_detected() {
+            isolation.engine_bleed_valves_closed[self.main_channel_engine_number - 1] = true;
+            isolation.cross_bleed_valve_closed = true;
+
+            // The APU duct is connected to the left hand side of the crossbleed duct
+            isolation.apu_bleed_valve_closed = self.main_channel_engine_number == 1;
+        }
+
+        if self.apu_bleed_leak_detected() {
+            isolation.apu_bleed_valve_closed = true;
+        }
+
+        isolation
+    }
+
     fn update(
         &mut self,
         context: &UpdateContext,
         sensors: &[EngineBleedAirSystem; 2],
+        leak_isolation: &BleedLeakIsolation,
         apu_bleed_valve: &impl PneumaticValve,
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -533,6 +769,7 @@ impl BleedMonitoringComputer {
         self.main_channel.update(
             context,
             &sensors[self.main_channel_engine_number - 1],
+            leak_isolation,
             engine_fire_push_buttons,
             apu_bleed_valve,
             cross_bleed_valve,
@@ -544,6 +781,7 @@ impl BleedMonitoringComputer {
         self.backup_channel.update(
             context,
             &sensors[self.backup_channel_engine_number - 1],
+            leak_isolation,
             engine_fire_push_buttons,
             apu_bleed_valve,
             cross_bleed_valve,
@@ -599,6 +837,12 @@ impl SimulationElement for BleedMonitoringComputer {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.main_channel.accept(visitor);
         self.backup_channel.accept(visitor);
+        self.pylon_leak_detector.accept(visitor);
+        self.wing_leak_detector.accept(visitor);
+
+        if let Some(apu_duct_leak_detector) = &mut self.apu_duct_leak_detector {
+            apu_duct_leak_detector.accept(visitor);
+        }
 
         visitor.visit(self);
     }
@@ -643,6 +887,7 @@ struct BleedMonitoringComputerChannel {
     flight_phase_loop: FlightPhaseLoop,
     low_temperature_regulation_active: DelayedTrueLogicGate,
     should_command_onside_prv_closed: bool,
+    is_cross_bleed_valve_leak_isolated: bool,
 
     low_temperature_id: VariableIdentifier,
     overheat_id: VariableIdentifier,
@@ -695,6 +940,7 @@ impl BleedMonitoringComputerChannel {
             flight_phase_loop: FlightPhaseLoop::new(),
             low_temperature_regulation_active: DelayedTrueLogicGate::new(Duration::from_secs(20)),
             should_command_onside_prv_closed: false,
+            is_cross_bleed_valve_leak_isolated: false,
             is_in_dual_bleed_config: false,
             low_temperature_id: context
                 .get_identifier(format!("PNEU_ENG_{}_LOW_TEMPERATURE", engine_number)),
@@ -708,6 +954,7 @@ impl BleedMonitoringComputerChannel {
         &mut self,
         context: &UpdateContext,
         sensors: &EngineBleedAirSystem,
+        leak_isolation: &BleedLeakIsolation,
         engine_fire_pushbuttons: &impl EngineFirePushButtons,
         apu_bleed_valve: &impl PneumaticValve,
         cross_bleed_valve: &impl PneumaticValve,
@@ -724,6 +971,7 @@ impl BleedMonitoringComputerChannel {
 
         self.is_apu_bleed_valve_open = apu_bleed_valve.is_open();
         self.cross_bleed_valve_selector = overhead_panel.cross_bleed_mode();
+        self.is_cross_bleed_valve_leak_isolated = leak_isolation.cross_bleed_valve_closed;
 
         self.pressure_regulating_valve_is_closed = !sensors.pressure_regulating_valve_is_open();
 
@@ -732,6 +980,7 @@ impl BleedMonitoringComputerChannel {
         self.flight_phase_loop.update(context);
         self.update_dual_vs_single_bleed_operation(
             sensors,
+            leak_isolation,
             overhead_panel,
             engine_fire_pushbuttons,
             cross_bleed_valve,
@@ -899,6 +1148,7 @@ impl BleedMonitoringComputerChannel {
     fn update_dual_vs_single_bleed_operation(
         &mut self,
         sensors: &EngineBleedAirSystem,
+        leak_isolation: &BleedLeakIsolation,
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_pushbuttons: &impl EngineFirePushButtons,
         cross_bleed_valve: &impl PneumaticValve,
@@ -911,14 +1161,17 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         ) || sensors.engine_starter_valve_is_open()
             || self.overpressure_monitor.has_overpressure()
-            || self.overheat_monitor.has_overheat();
+            || self.overheat_monitor.has_overheat()
+            || leak_isolation.engine_bleed_valve_closed(self.engine_number);
 
+        let offside_engine_number = self.engine_number % 2 + 1;
         let should_command_offside_prv_closed = self.should_command_prv_closed(
-            self.engine_number % 2 + 1,
+            offside_engine_number,
             overhead_panel,
             engine_fire_pushbuttons,
             cross_bleed_valve,
-        );
+        ) || leak_isolation
+            .engine_bleed_valve_closed(offside_engine_number);
 
         self.is_in_dual_bleed_config = !fadec.is_single_vs_dual_bleed_config()
             && !self.should_command_onside_prv_closed
@@ -993,7 +1246,7 @@ impl ControllerSignal<CrossBleedValveSignal> for BleedMonitoringComputerChannel
                 CrossBleedValveSignalType::Manual,
             )),
             CrossBleedValveSelectorMode::Auto => {
-                if self.is_apu_bleed_valve_open {
+                if self.is_apu_bleed_valve_open && !self.is_cross_bleed_valve_leak_isolated {
                     Some(CrossBleedValveSignal::new_open(
                         CrossBleedValveSignalType::Automatic,
                     ))
@@ -1135,8 +1388,10 @@ impl EngineBleedAirSystem {
                     0.25, // -> 1 / 0.25 = 4 seconds to open
                 ),
                 powered_by,
-            ),
-            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by),
+            )
+            .with_failures(PneumaticValveId::HighPressure(number)),
+            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by)
+                .with_failures(PneumaticValveId::PressureRegulating(number)),
             overpressure_valve: OverpressureValve::new(
                 PneumaticValveCharacteristics::new(
                     Pressure::new::<psi>(0.), // psig
@@ -1565,6 +1820,15 @@ impl A320PneumaticOverheadPanel {
             _ => panic!("Invalid engine number"),
         }
     }
+
+    pub fn update_after_pneumatic(&mut self, pneumatic: &A320Pneumatic) {
+        self.engine_1_bleed
+            .set_fault(pneumatic.engine_bleed_leak_detected(1));
+        self.engine_2_bleed
+            .set_fault(pneumatic.engine_bleed_leak_detected(2));
+        self.apu_bleed
+            .set_fault(pneumatic.apu_bleed_leak_detected());
+    }
 }
 impl EngineBleedPushbutton<2> for A320PneumaticOverheadPanel {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; 2] {
@@ -1672,7 +1936,8 @@ impl PackComplex {
                 ThermodynamicTemperature::new::<degree_celsius>(15.),
             ),
             exhaust: PneumaticExhaust::new(0.3, 0.3, Pressure::new::<psi>(0.)),
-            pack_flow_valve: DefaultValve::new_closed(),
+            pack_flow_valve: DefaultValve::new_closed()
+                .with_failures(PneumaticValveId::PackFlowControl(engine_number)),
             pack_inlet_pressure_sensor: PressureTransducer::new(powered_by),
         }
     }
@@ -1770,6 +2035,7 @@ pub struct CrossBleedValve {
     is_powered_for_automatic_control: bool,
     target_open_amount: Ratio,
     valve_speed: Ratio,
+    failures: PneumaticValveFailures,
 }
 impl CrossBleedValve {
     pub fn new(valve_speed: Ratio) -> Self {
@@ -1780,6 +2046,7 @@ impl CrossBleedValve {
             is_powered_for_automatic_control: false,
             target_open_amount: Ratio::default(),
             valve_speed,
+            failures: PneumaticValveFailures::new(PneumaticValveId::CrossBleed(1)),
         }
     }
 
@@ -1791,16 +2058,19 @@ impl CrossBleedValve {
     ) {
         let open_amount_change = context.delta_as_secs_f64() * self.valve_speed;
 
-        self.open_amount = if self.target_open_amount > self.open_amount {
-            self.target_open_amount
-                .min(self.open_amount + open_amount_change)
-        } else {
-            self.target_open_amount
-                .max(self.open_amount - open_amount_change)
-        };
+        self.open_amount = self.failures.open_amount(
+            self.open_amount,
+            if self.target_open_amount > self.open_amount {
+                self.target_open_amount
+                    .min(self.open_amount + open_amount_change)
+            } else {
+                self.target_open_amount
+                    .max(self.open_amount - open_amount_change)
+            },
+        );
 
         self.connector
-            .with_transfer_speed_factor(self.open_amount)
+            .with_transfer_speed_factor(self.failures.transfer_open_amount(self.open_amount))
             .update_move_fluid(context, container_one, container_two);
     }
 
@@ -1835,6 +2105,12 @@ impl FullyClosed for CrossBleedValve {
     }
 }
 impl SimulationElement for CrossBleedValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered_for_manual_control =
             buses.is_powered(ElectricalBusType::DirectCurrentEssentialShed);
@@ -1914,11 +2190,11 @@ pub mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            interpolation, ApuBleedAirValveSignal, CabinAltitude, CabinSimulation,
+            interpolation, ApuBleedAirValveSignal, BleedLeakZone, CabinAltitude, CabinSimulation,
             ControllerSignal, ElectricalBusType, ElectricalBuses, EmergencyElectricalState,
-            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
-            InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
-            PneumaticBleed, PneumaticValve, PotentialOrigin,
+            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, FireDetectionLoopID,
+            HydraulicColor, InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber,
+            PackFlowValveState, PneumaticBleed, PneumaticValve, PneumaticValveId, PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -2284,7 +2560,8 @@ pub mod tests {
             self.electrical.update(context);
 
             self.apu.update(self.pneumatic.apu_bleed_air_valve());
-            self.asu.update();
+            self.asu
+                .update(context, self.pneumatic.air_starter_unit_delivered_flow());
             self.pneumatic.update(
                 context,
                 [&self.engine_1, &self.engine_2],
@@ -2295,6 +2572,8 @@ pub mod tests {
                 &self.air_conditioning,
                 [&self.lgciu; 2],
             );
+            self.pneumatic_overhead_panel
+                .update_after_pneumatic(&self.pneumatic);
             self.air_conditioning.update(
                 context,
                 [&self.engine_1, &self.engine_2],
@@ -5214,4 +5493,305 @@ pub mod tests {
             assert!(test_bed.pack_flow_valve_flow(2) < flow_rate_tolerance());
         }
     }
+
+    mod bleed_leak_tests {
+        use super::*;
+
+        fn leak_detected(test_bed: &mut PneumaticTestBed, zone: &str) -> bool {
+            test_bed.read_by_name(&format!("PNEU_{}_LEAK_DETECTED", zone))
+        }
+
+        fn engine_bleed_pb_has_fault(test_bed: &mut PneumaticTestBed, number: usize) -> bool {
+            test_bed.read_by_name(&format!("OVHD_PNEU_ENG_{}_BLEED_PB_HAS_FAULT", number))
+        }
+
+        #[test]
+        fn no_leak_detected_in_normal_operation() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            assert!(!leak_detected(&mut test_bed, "WING_1"));
+            assert!(!leak_detected(&mut test_bed, "PYLON_1"));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 1));
+            assert!(test_bed.pr_valve_is_open(1));
+        }
+
+        #[test]
+        fn wing_leak_closes_engine_bleed_valve_and_cross_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(leak_detected(&mut test_bed, "WING_1"));
+            assert!(engine_bleed_pb_has_fault(&mut test_bed, 1));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 2));
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(!test_bed.hp_valve_is_open(1));
+            assert!(test_bed.pr_valve_is_open(2));
+            assert!(!test_bed.cross_bleed_valve_is_open());
+            assert!(test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+        }
+
+        #[test]
+        fn pack_bay_leak_is_detected_by_wing_loops() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::PackBay(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(leak_detected(&mut test_bed, "WING_2"));
+            assert!(engine_bleed_pb_has_fault(&mut test_bed, 2));
+            assert!(!test_bed.pr_valve_is_open(2));
+            assert!(!test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+        }
+
+        #[test]
+        fn pylon_leak_closes_engine_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .toga_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Pylon(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(leak_detected(&mut test_bed, "PYLON_2"));
+            assert!(engine_bleed_pb_has_fault(&mut test_bed, 2));
+            assert!(!test_bed.pr_valve_is_open(2));
+            assert!(test_bed.pr_valve_is_open(1));
+        }
+
+        #[test]
+        fn cross_bleed_valve_can_be_opened_manually_after_leak_isolation() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .toga_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Pylon(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            test_bed = test_bed
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Open)
+                .and_stabilize();
+
+            assert!(test_bed.cross_bleed_valve_is_open());
+        }
+
+        #[test]
+        fn apu_duct_leak_closes_apu_bleed_valve() {
+            let mut test_bed = test_bed_with().set_bleed_air_running().and_stabilize();
+
+            assert!(!test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::ApuDuct));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            let apu_bleed_pb_has_fault: bool =
+                test_bed.read_by_name("OVHD_PNEU_APU_BLEED_PB_HAS_FAULT");
+
+            assert!(leak_detected(&mut test_bed, "APU_DUCT"));
+            assert!(apu_bleed_pb_has_fault);
+            assert!(test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 1));
+        }
+
+        #[test]
+        fn leak_is_not_detected_with_failed_pylon_loop() {
+            let mut test_bed = test_bed_with()
+                .toga_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedLeakDetectionLoop(
+                BleedLeakZone::Pylon(1),
+                FireDetectionLoopID::A,
+            ));
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Pylon(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(!leak_detected(&mut test_bed, "PYLON_1"));
+            assert!(test_bed.pr_valve_is_open(1));
+        }
+    }
+
+    mod valve_failure_tests {
+        use super::*;
+
+        #[test]
+        fn stuck_closed_pressure_regulating_valve_is_reported_closed_to_bmc() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            assert!(test_bed.pr_valve_is_open(1));
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::PressureRegulating(1),
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(10));
+
+            let pr_valve_open: bool = test_bed.read_by_name("PNEU_ENG_1_PR_VALVE_OPEN");
+
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(!pr_valve_open);
+            assert!(test_bed.query(|a| a.pneumatic.bleed_monitoring_computers[0]
+                .main_channel
+                .pressure_regulating_valve_is_closed));
+            assert!(test_bed.regulated_pressure(1) < test_bed.regulated_pressure(2));
+            assert!(test_bed.pr_valve_is_open(2));
+        }
+
+        #[test]
+        fn stuck_open_pressure_regulating_valve_does_not_close_with_bleed_pb_off() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckOpen(
+                PneumaticValveId::PressureRegulating(1),
+            ));
+            test_bed = test_bed.set_engine_bleed_push_button_off(1).and_stabilize();
+
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(
+                !test_bed.query(|a| a.pneumatic.bleed_monitoring_computers[0]
+                    .main_channel
+                    .pressure_regulating_valve_is_closed)
+            );
+        }
+
+        #[test]
+        fn internally_leaking_pressure_regulating_valve_passes_air_while_closed() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .set_engine_bleed_push_button_off(1)
+                .and_stabilize();
+
+            let regulated_pressure_without_leak = test_bed.regulated_pressure(1);
+
+            test_bed.fail(FailureType::PneumaticValveInternalLeak(
+                PneumaticValveId::PressureRegulating(1),
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(10));
+
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(test_bed.regulated_pressure(1) > regulated_pressure_without_leak);
+        }
+
+        #[test]
+        fn stuck_open_high_pressure_valve_stays_open_at_high_power() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckOpen(
+                PneumaticValveId::HighPressure(1),
+            ));
+            test_bed = test_bed.toga_eng1().toga_eng2().and_stabilize();
+
+            assert!(test_bed.hp_valve_is_open(1));
+            assert!(!test_bed.hp_valve_is_open(2));
+        }
+
+        #[test]
+        fn stuck_open_cross_bleed_valve_is_reported_open_with_selector_shut() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Auto)
+                .and_stabilize();
+
+            assert!(!test_bed.cross_bleed_valve_is_open());
+
+            test_bed.fail(FailureType::PneumaticValveStuckOpen(
+                PneumaticValveId::CrossBleed(1),
+            ));
+            test_bed = test_bed
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+                .and_stabilize();
+
+            let cross_bleed_valve_fully_open: bool =
+                test_bed.read_by_name("PNEU_XBLEED_VALVE_FULLY_OPEN");
+
+            assert!(test_bed.cross_bleed_valve_is_open());
+            assert!(cross_bleed_valve_fully_open);
+        }
+
+        #[test]
+        fn cross_bleed_valve_stuck_in_position_does_not_open() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .both_packs_auto()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+                .and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckInPosition(
+                PneumaticValveId::CrossBleed(1),
+            ));
+            test_bed = test_bed
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Open)
+                .and_stabilize();
+
+            assert!(!test_bed.cross_bleed_valve_is_open());
+        }
+
+        #[test]
+        fn stuck_closed_pack_flow_control_valve_does_not_open() {
+            let mut test_bed = test_bed_with().idle_eng1().idle_eng2().and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::PackFlowControl(1),
+            ));
+            test_bed = test_bed.both_packs_auto().and_stabilize();
+
+            let pack_flow_valve_open: bool =
+                test_bed.read_by_name("COND_PACK_FLOW_VALVE_1_IS_OPEN");
+
+            assert!(!test_bed.pack_flow_valve_is_open(1));
+            assert!(!pack_flow_valve_open);
+            assert!(test_bed.pack_flow_valve_is_open(2));
+        }
+
+        #[test]
+        fn stuck_closed_apu_bleed_valve_does_not_open() {
+            let mut test_bed = test_bed_with().and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::ApuBleed,
+            ));
+            test_bed = test_bed.set_bleed_air_running().and_stabilize();
+
+            let apu_bleed_valve_open: bool = test_bed.read_by_name("APU_BLEED_AIR_VALVE_OPEN");
+
+            assert!(!test_bed.apu_bleed_valve_is_open());
+            assert!(!apu_bleed_valve_open);
+        }
+    }
 }
@@ -2,10 +2,12 @@ mod ailerons;
 mod autobrakes;
 mod brakes;
 mod elevators;
+mod fire;
 mod flaps;
 mod gear;
 mod nose_wheel_steering;
 mod payload;
+mod pushback;
 mod reversers;
 mod rudder;
 mod spoilers;
@@ -16,10 +18,12 @@ use ailerons::ailerons;
 use autobrakes::autobrakes;
 use brakes::brakes;
 use elevators::elevators;
+use fire::fire;
 use flaps::flaps;
 use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
 use payload::payload;
+use pushback::pushback;
 use reversers::reversers;
 use rudder::rudder;
 use spoilers::spoilers;
@@ -28,9 +32,11 @@ use systems::air_conditioning::{
     acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, ZoneType,
 };
 use systems::failures::FailureType;
+use systems::hydraulic::flap_slat::{SecondarySurfaceSide, SecondarySurfaceType};
 use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedLeakZone, ElectricalBusType,
+    FireDetectionLoopID, FireDetectionZone, FlightControlSurfaceId, GearActuatorId, HydraulicColor,
+    HydraulicSectionId, LgciuId, PneumaticValveId, ProximityDetectorId,
 };
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -161,6 +167,364 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_113,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (24_200, FailureType::CircuitBreaker("AC_ESS_SHED")),
+        (24_201, FailureType::CircuitBreaker("DC_ESS_SHED")),
+        (26_001, FailureType::SetOnFire(FireDetectionZone::Engine(1))),
+        (26_002, FailureType::SetOnFire(FireDetectionZone::Engine(2))),
+        (26_003, FailureType::SetOnFire(FireDetectionZone::Apu)),
+        (26_004, FailureType::SetOnFire(FireDetectionZone::Mlg)),
+        (
+            26_005,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(1)),
+        ),
+        (
+            26_006,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(1)),
+        ),
+        (
+            26_007,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(2)),
+        ),
+        (
+            26_008,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(2)),
+        ),
+        (
+            26_009,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Apu),
+        ),
+        (
+            26_010,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Apu),
+        ),
+        (
+            26_011,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Mlg),
+        ),
+        (
+            26_012,
+            FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
+        ),
+        (26_013, FailureType::Smoke(FireDetectionZone::ForwardCargo)),
+        (26_014, FailureType::Smoke(FireDetectionZone::AftCargo)),
+        (26_015, FailureType::Smoke(FireDetectionZone::Lavatory)),
+        (26_016, FailureType::Smoke(FireDetectionZone::Avionics)),
+        (
+            26_017,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::ForwardCargo),
+        ),
+        (
+            26_018,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::ForwardCargo),
+        ),
+        (
+            26_019,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::AftCargo),
+        ),
+        (
+            26_020,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::AftCargo),
+        ),
+        (
+            26_021,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::Lavatory),
+        ),
+        (
+            26_022,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::Lavatory),
+        ),
+        (
+            26_023,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::Avionics),
+        ),
+        (
+            26_024,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::Avionics),
+        ),
+        (
+            27_100,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(1), 0),
+        ),
+        (
+            27_101,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(1), 1),
+        ),
+        (
+            27_102,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(1), 0),
+        ),
+        (
+            27_103,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(1), 1),
+        ),
+        (
+            27_104,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftAileron(1)),
+        ),
+        (
+            27_105,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(1), 0),
+        ),
+        (
+            27_106,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(1), 1),
+        ),
+        (
+            27_107,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(1), 0),
+        ),
+        (
+            27_108,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(1), 1),
+        ),
+        (
+            27_109,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightAileron(1)),
+        ),
+        (
+            27_110,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftElevator(1), 0),
+        ),
+        (
+            27_111,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftElevator(1), 1),
+        ),
+        (
+            27_112,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftElevator(1), 0),
+        ),
+        (
+            27_113,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftElevator(1), 1),
+        ),
+        (
+            27_114,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftElevator(1)),
+        ),
+        (
+            27_115,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightElevator(1), 0),
+        ),
+        (
+            27_116,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightElevator(1), 1),
+        ),
+        (
+            27_117,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightElevator(1), 0),
+        ),
+        (
+            27_118,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightElevator(1), 1),
+        ),
+        (
+            27_119,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightElevator(1)),
+        ),
+        (
+            27_120,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::Rudder(1), 0),
+        ),
+        (
+            27_121,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::Rudder(1), 1),
+        ),
+        (
+            27_122,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::Rudder(1), 2),
+        ),
+        (
+            27_123,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::Rudder(1), 0),
+        ),
+        (
+            27_124,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::Rudder(1), 1),
+        ),
+        (
+            27_125,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::Rudder(1), 2),
+        ),
+        (
+            27_126,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::Rudder(1)),
+        ),
+        (
+            27_127,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(1), 0),
+        ),
+        (
+            27_128,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(1), 0),
+        ),
+        (
+            27_129,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(1)),
+        ),
+        (
+            27_130,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(2), 0),
+        ),
+        (
+            27_131,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(2), 0),
+        ),
+        (
+            27_132,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(2)),
+        ),
+        (
+            27_133,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(3), 0),
+        ),
+        (
+            27_134,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(3), 0),
+        ),
+        (
+            27_135,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(3)),
+        ),
+        (
+            27_136,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(4), 0),
+        ),
+        (
+            27_137,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(4), 0),
+        ),
+        (
+            27_138,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(4)),
+        ),
+        (
+            27_139,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(5), 0),
+        ),
+        (
+            27_140,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(5), 0),
+        ),
+        (
+            27_141,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(5)),
+        ),
+        (
+            27_142,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(1), 0),
+        ),
+        (
+            27_143,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(1), 0),
+        ),
+        (
+            27_144,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(1)),
+        ),
+        (
+            27_145,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(2), 0),
+        ),
+        (
+            27_146,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(2), 0),
+        ),
+        (
+            27_147,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(2)),
+        ),
+        (
+            27_148,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(3), 0),
+        ),
+        (
+            27_149,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(3), 0),
+        ),
+        (
+            27_150,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(3)),
+        ),
+        (
+            27_151,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(4), 0),
+        ),
+        (
+            27_152,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(4), 0),
+        ),
+        (
+            27_153,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(4)),
+        ),
+        (
+            27_154,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(5), 0),
+        ),
+        (
+            27_155,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(5), 0),
+        ),
+        (
+            27_156,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(5)),
+        ),
+        (27_157, FailureType::ThsJam),
+        (27_158, FailureType::ThsElectricMotorRunaway(1)),
+        (27_159, FailureType::ThsElectricMotorRunaway(2)),
+        (27_160, FailureType::ThsElectricMotorRunaway(3)),
+        (
+            27_161,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Green),
+        ),
+        (
+            27_162,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Yellow),
+        ),
+        (
+            27_163,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Flaps,
+                SecondarySurfaceSide::Left,
+            ),
+        ),
+        (
+            27_164,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Flaps,
+                SecondarySurfaceSide::Right,
+            ),
+        ),
+        (
+            27_165,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Slats,
+                SecondarySurfaceSide::Left,
+            ),
+        ),
+        (
+            27_166,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Slats,
+                SecondarySurfaceSide::Right,
+            ),
+        ),
+        (
+            27_167,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Flaps, SecondarySurfaceSide::Left),
+        ),
+        (
+            27_168,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Flaps, SecondarySurfaceSide::Right),
+        ),
+        (
+            27_169,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Slats, SecondarySurfaceSide::Left),
+        ),
+        (
+            27_170,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Slats, SecondarySurfaceSide::Right),
+        ),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
         (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -198,6 +562,76 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_012,
             FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
         ),
+        (
+            29_013,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Green, 1),
+        ),
+        (
+            29_014,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Blue, 1),
+        ),
+        (
+            29_015,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Yellow, 1),
+        ),
+        (
+            29_016,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_017,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Blue, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_018,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_019,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::System),
+        ),
+        (
+            29_020,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Blue, HydraulicSectionId::System),
+        ),
+        (
+            29_021,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::System),
+        ),
+        (29_022, FailureType::PtuLeak(HydraulicColor::Green)),
+        (29_023, FailureType::PtuLeak(HydraulicColor::Yellow)),
+        (
+            29_024,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(1), 0),
+        ),
+        (
+            29_025,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(1), 1),
+        ),
+        (
+            29_026,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(1), 0),
+        ),
+        (
+            29_027,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(1), 1),
+        ),
+        (
+            29_028,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftElevator(1), 0),
+        ),
+        (
+            29_029,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftElevator(1), 1),
+        ),
+        (
+            29_030,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightElevator(1), 0),
+        ),
+        (
+            29_031,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightElevator(1), 1),
+        ),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -274,6 +708,54 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (
+            32_030,
+            FailureType::GearDoorActuatorFault(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_031,
+            FailureType::GearDoorActuatorFault(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_032,
+            FailureType::GearDoorActuatorFault(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_040,
+            FailureType::GearUplockJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_041,
+            FailureType::GearUplockJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_042,
+            FailureType::GearUplockJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_043,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_044,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_045,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_050,
+            FailureType::GearDownlockFailure(GearActuatorId::GearNose),
+        ),
+        (
+            32_051,
+            FailureType::GearDownlockFailure(GearActuatorId::GearLeft),
+        ),
+        (
+            32_052,
+            FailureType::GearDownlockFailure(GearActuatorId::GearRight),
+        ),
         (
             32_100,
             FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
@@ -283,6 +765,23 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
         ),
         (32_150, FailureType::BrakeAccumulatorGasLeak),
+        (32_200, FailureType::FlatTyre(1)),
+        (32_201, FailureType::FlatTyre(2)),
+        (32_202, FailureType::FlatTyre(3)),
+        (32_203, FailureType::FlatTyre(4)),
+        (32_210, FailureType::BrakeSeized(1)),
+        (32_211, FailureType::BrakeSeized(2)),
+        (32_212, FailureType::BrakeSeized(3)),
+        (32_213, FailureType::BrakeSeized(4)),
+        (32_220, FailureType::BrakeReleased(1)),
+        (32_221, FailureType::BrakeReleased(2)),
+        (32_222, FailureType::BrakeReleased(3)),
+        (32_223, FailureType::BrakeReleased(4)),
+        (32_230, FailureType::BrakeTemperatureProbe(1)),
+        (32_231, FailureType::BrakeTemperatureProbe(2)),
+        (32_232, FailureType::BrakeTemperatureProbe(3)),
+        (32_233, FailureType::BrakeTemperatureProbe(4)),
+        (32_240, FailureType::AntiSkid),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_010, FailureType::RadioAntennaInterrupted(1)),
@@ -293,6 +792,180 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             34_030,
             FailureType::EnhancedGroundProximityWarningSystemComputer,
         ),
+        (36_000, FailureType::BleedDuctLeak(BleedLeakZone::Pylon(1))),
+        (36_001, FailureType::BleedDuctLeak(BleedLeakZone::Pylon(2))),
+        (36_002, FailureType::BleedDuctLeak(BleedLeakZone::Wing(1))),
+        (36_003, FailureType::BleedDuctLeak(BleedLeakZone::Wing(2))),
+        (36_004, FailureType::BleedDuctLeak(BleedLeakZone::ApuDuct)),
+        (
+            36_005,
+            FailureType::BleedDuctLeak(BleedLeakZone::PackBay(1)),
+        ),
+        (
+            36_006,
+            FailureType::BleedDuctLeak(BleedLeakZone::PackBay(2)),
+        ),
+        (
+            36_010,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Pylon(1), FireDetectionLoopID::A),
+        ),
+        (
+            36_011,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Pylon(2), FireDetectionLoopID::A),
+        ),
+        (
+            36_012,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(1), FireDetectionLoopID::A),
+        ),
+        (
+            36_013,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(1), FireDetectionLoopID::B),
+        ),
+        (
+            36_014,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(2), FireDetectionLoopID::A),
+        ),
+        (
+            36_015,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(2), FireDetectionLoopID::B),
+        ),
+        (
+            36_016,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::ApuDuct, FireDetectionLoopID::A),
+        ),
+        (
+            36_020,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_021,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_022,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_023,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_024,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_025,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_026,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_027,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::ApuBleed),
+        ),
+        (
+            36_040,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_041,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_042,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_043,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_044,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_045,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_046,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_047,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::ApuBleed),
+        ),
+        (
+            36_060,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_061,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_062,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_063,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_064,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_065,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_066,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_067,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::ApuBleed),
+        ),
+        (
+            36_080,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_081,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_082,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_083,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_084,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_085,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_086,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_087,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::ApuBleed),
+        ),
+        (49_000, FailureType::ApuHungStart),
+        (49_001, FailureType::ApuEgtOvertemperature),
+        (49_002, FailureType::ApuLowOilPressure),
+        (49_003, FailureType::ApuAirIntakeFlapStuck),
+        (49_004, FailureType::ApuElectronicControlBox),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -314,6 +987,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 0)?
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 1)?
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 2)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 1)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 2)?
     .provides_aircraft_variable("FUEL TANK CENTER QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TANK LEFT MAIN QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TANK LEFT AUX QUANTITY", "gallons", 0)?
@@ -455,7 +1130,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_aspect(spoilers)?
     .with_aspect(ailerons)?
     .with_aspect(elevators)?
+    .with_aspect(fire)?
     .with_aspect(reversers)?
+    .with_aspect(pushback)?
     .with_aspect(rudder)?
     .with_aspect(gear)?
     .with_aspect(payload)?
@@ -0,0 +1,51 @@
+use std::error::Error;
+
+use msfs::sim_connect;
+use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+
+use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
+use systems_wasm::{set_data_on_sim_object, Variable};
+
+pub(super) fn pushback(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    builder.variables_to_object(Box::new(NativeTugMovement {
+        velocity_z: 0.,
+        rotation_velocity_y: 0.,
+    }));
+
+    Ok(())
+}
+
+const FOOT_PER_SECOND_PER_KNOT: f64 = 1.68781;
+
+/// Moves the aircraft with the native pushback tug while it is attached.
+#[sim_connect::data_definition]
+struct NativeTugMovement {
+    #[name = "VELOCITY BODY Z"]
+    #[unit = "Feet per second"]
+    velocity_z: f64,
+
+    #[name = "ROTATION VELOCITY BODY Y"]
+    #[unit = "Degrees per second"]
+    rotation_velocity_y: f64,
+}
+
+impl VariablesToObject for NativeTugMovement {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::named("PUSHBACK_TUG_ATTACHMENT"),
+            Variable::named("PUSHBACK_TUG_SPEED"),
+            Variable::named("PUSHBACK_TUG_YAW_RATE"),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+        let is_attached = values[0] as u8 != 0;
+
+        self.velocity_z = values[1] * FOOT_PER_SECOND_PER_KNOT;
+        self.rotation_velocity_y = values[2];
+
+        ObjectWrite::on(is_attached)
+    }
+
+    set_data_on_sim_object!();
+}
@@ -16,6 +16,7 @@ import {
   MsfsFlightModelPublisher,
   MsfsMiscPublisher,
   NotificationManager,
+  PersistentLocalVarSync,
   PilotSeatManager,
   TelexCheck,
   PilotSeatPublisher,
@@ -94,6 +95,16 @@ class ExtrasHost extends BaseInstrument {
 
   private readonly pilotSeatManager = new PilotSeatManager(ExtrasHost.flightDeckBounds);
 
+  private readonly persistentLocalVarSync = new PersistentLocalVarSync([
+    { configKey: 'APU_START_COUNT', localVarName: 'L:A32NX_APU_START_COUNT' },
+    { configKey: 'APU_OPERATING_HOURS', localVarName: 'L:A32NX_APU_OPERATING_HOURS' },
+    { configKey: 'APU_OIL_CONSUMED_QUANTITY', localVarName: 'L:A32NX_APU_OIL_CONSUMED_QUANTITY' },
+    ...Array.from({ length: 16 }, (_, i) => ({
+      configKey: `BRAKE_WEAR_${i + 1}`,
+      localVarName: `L:A32NX_BRAKE_WEAR_${i + 1}`,
+    })),
+  ]);
+
   private readonly telexCheck = new TelexCheck();
   /**interaction point 19 is GPU connection and 4 GPUs in total */
   private readonly gpuManagement = new GPUManagement(this.bus, 19, 4);
@@ -151,6 +162,7 @@ class ExtrasHost extends BaseInstrument {
     this.backplane.addPublisher('GsxSimVarPublisher', this.gsxSimVarPublusher);
     this.backplane.addPublisher('PilotSeatPublisher', new PilotSeatPublisher(this.bus));
     this.backplane.addInstrument('PilotSeatManager', this.pilotSeatManager);
+    this.backplane.addInstrument('PersistentLocalVarSync', this.persistentLocalVarSync);
     this.backplane.addInstrument('GPUManagement', this.gpuManagement);
     this.backplane.addInstrument('Clock', this.clock);
     this.backplane.addInstrument('LightSync', this.lightSync);
@@ -98,6 +98,12 @@ export const A380Failure = Object.freeze({
   DirectCurrentHot3: 24115,
   DirectCurrentHot4: 24116,
   DirectCurrentGndFltService: 24117,
+  CircuitBreakerLgciu1: 24200,
+  CircuitBreakerLgciu2: 24201,
+  CircuitBreakerRadioAltimeter1: 24202,
+  CircuitBreakerRadioAltimeter2: 24203,
+  CircuitBreakerRadioAltimeter3: 24204,
+  CircuitBreakerEgpwc: 24205,
 
   Engine1Fire: 26001,
   Engine2Fire: 26002,
@@ -117,6 +123,21 @@ export const A380Failure = Object.freeze({
   APULoopB: 26016,
   MLGLoopA: 26017,
   MLGLoopB: 26018,
+  FwdCargoSmoke: 26019,
+  AftCargoSmoke: 26020,
+  BulkCargoSmoke: 26021,
+  LavatorySmoke: 26022,
+  AvionicsSmoke: 26023,
+  FwdCargoSmokeDetectorA: 26024,
+  FwdCargoSmokeDetectorB: 26025,
+  AftCargoSmokeDetectorA: 26026,
+  AftCargoSmokeDetectorB: 26027,
+  BulkCargoSmokeDetectorA: 26028,
+  BulkCargoSmokeDetectorB: 26029,
+  LavatorySmokeDetectorA: 26030,
+  LavatorySmokeDetectorB: 26031,
+  AvionicsSmokeDetectorA: 26032,
+  AvionicsSmokeDetectorB: 26033,
 
   Prim1: 27000,
   Prim2: 27001,
@@ -126,6 +147,125 @@ export const A380Failure = Object.freeze({
   Sec3: 27005,
   Fcdc1: 27006,
   Fcdc2: 27007,
+  LeftOutwardAileronOutwardActuatorJam: 27100,
+  LeftOutwardAileronInwardActuatorJam: 27101,
+  LeftOutwardAileronOutwardActuatorRunaway: 27102,
+  LeftOutwardAileronInwardActuatorRunaway: 27103,
+  LeftOutwardAileronDisconnect: 27104,
+  LeftMiddleAileronOutwardActuatorJam: 27105,
+  LeftMiddleAileronInwardActuatorJam: 27106,
+  LeftMiddleAileronOutwardActuatorRunaway: 27107,
+  LeftMiddleAileronInwardActuatorRunaway: 27108,
+  LeftMiddleAileronDisconnect: 27109,
+  LeftInwardAileronOutwardActuatorJam: 27110,
+  LeftInwardAileronInwardActuatorJam: 27111,
+  LeftInwardAileronOutwardActuatorRunaway: 27112,
+  LeftInwardAileronInwardActuatorRunaway: 27113,
+  LeftInwardAileronDisconnect: 27114,
+  RightOutwardAileronOutwardActuatorJam: 27115,
+  RightOutwardAileronInwardActuatorJam: 27116,
+  RightOutwardAileronOutwardActuatorRunaway: 27117,
+  RightOutwardAileronInwardActuatorRunaway: 27118,
+  RightOutwardAileronDisconnect: 27119,
+  RightMiddleAileronOutwardActuatorJam: 27120,
+  RightMiddleAileronInwardActuatorJam: 27121,
+  RightMiddleAileronOutwardActuatorRunaway: 27122,
+  RightMiddleAileronInwardActuatorRunaway: 27123,
+  RightMiddleAileronDisconnect: 27124,
+  RightInwardAileronOutwardActuatorJam: 27125,
+  RightInwardAileronInwardActuatorJam: 27126,
+  RightInwardAileronOutwardActuatorRunaway: 27127,
+  RightInwardAileronInwardActuatorRunaway: 27128,
+  RightInwardAileronDisconnect: 27129,
+  LeftOutwardElevatorOutwardActuatorJam: 27130,
+  LeftOutwardElevatorInwardActuatorJam: 27131,
+  LeftOutwardElevatorOutwardActuatorRunaway: 27132,
+  LeftOutwardElevatorInwardActuatorRunaway: 27133,
+  LeftOutwardElevatorDisconnect: 27134,
+  LeftInwardElevatorOutwardActuatorJam: 27135,
+  LeftInwardElevatorInwardActuatorJam: 27136,
+  LeftInwardElevatorOutwardActuatorRunaway: 27137,
+  LeftInwardElevatorInwardActuatorRunaway: 27138,
+  LeftInwardElevatorDisconnect: 27139,
+  RightOutwardElevatorOutwardActuatorJam: 27140,
+  RightOutwardElevatorInwardActuatorJam: 27141,
+  RightOutwardElevatorOutwardActuatorRunaway: 27142,
+  RightOutwardElevatorInwardActuatorRunaway: 27143,
+  RightOutwardElevatorDisconnect: 27144,
+  RightInwardElevatorOutwardActuatorJam: 27145,
+  RightInwardElevatorInwardActuatorJam: 27146,
+  RightInwardElevatorOutwardActuatorRunaway: 27147,
+  RightInwardElevatorInwardActuatorRunaway: 27148,
+  RightInwardElevatorDisconnect: 27149,
+  UpperRudderUpperActuatorJam: 27150,
+  UpperRudderLowerActuatorJam: 27151,
+  UpperRudderUpperActuatorRunaway: 27152,
+  UpperRudderLowerActuatorRunaway: 27153,
+  UpperRudderDisconnect: 27154,
+  LowerRudderUpperActuatorJam: 27155,
+  LowerRudderLowerActuatorJam: 27156,
+  LowerRudderUpperActuatorRunaway: 27157,
+  LowerRudderLowerActuatorRunaway: 27158,
+  LowerRudderDisconnect: 27159,
+  LeftSpoiler1ActuatorJam: 27160,
+  LeftSpoiler1ActuatorRunaway: 27161,
+  LeftSpoiler1Disconnect: 27162,
+  LeftSpoiler2ActuatorJam: 27163,
+  LeftSpoiler2ActuatorRunaway: 27164,
+  LeftSpoiler2Disconnect: 27165,
+  LeftSpoiler3ActuatorJam: 27166,
+  LeftSpoiler3ActuatorRunaway: 27167,
+  LeftSpoiler3Disconnect: 27168,
+  LeftSpoiler4ActuatorJam: 27169,
+  LeftSpoiler4ActuatorRunaway: 27170,
+  LeftSpoiler4Disconnect: 27171,
+  LeftSpoiler5ActuatorJam: 27172,
+  LeftSpoiler5ActuatorRunaway: 27173,
+  LeftSpoiler5Disconnect: 27174,
+  LeftSpoiler6ActuatorJam: 27175,
+  LeftSpoiler6ActuatorRunaway: 27176,
+  LeftSpoiler6Disconnect: 27177,
+  LeftSpoiler7ActuatorJam: 27178,
+  LeftSpoiler7ActuatorRunaway: 27179,
+  LeftSpoiler7Disconnect: 27180,
+  LeftSpoiler8ActuatorJam: 27181,
+  LeftSpoiler8ActuatorRunaway: 27182,
+  LeftSpoiler8Disconnect: 27183,
+  RightSpoiler1ActuatorJam: 27184,
+  RightSpoiler1ActuatorRunaway: 27185,
+  RightSpoiler1Disconnect: 27186,
+  RightSpoiler2ActuatorJam: 27187,
+  RightSpoiler2ActuatorRunaway: 27188,
+  RightSpoiler2Disconnect: 27189,
+  RightSpoiler3ActuatorJam: 27190,
+  RightSpoiler3ActuatorRunaway: 27191,
+  RightSpoiler3Disconnect: 27192,
+  RightSpoiler4ActuatorJam: 27193,
+  RightSpoiler4ActuatorRunaway: 27194,
+  RightSpoiler4Disconnect: 27195,
+  RightSpoiler5ActuatorJam: 27196,
+  RightSpoiler5ActuatorRunaway: 27197,
+  RightSpoiler5Disconnect: 27198,
+  RightSpoiler6ActuatorJam: 27199,
+  RightSpoiler6ActuatorRunaway: 27200,
+  RightSpoiler6Disconnect: 27201,
+  RightSpoiler7ActuatorJam: 27202,
+  RightSpoiler7ActuatorRunaway: 27203,
+  RightSpoiler7Disconnect: 27204,
+  RightSpoiler8ActuatorJam: 27205,
+  RightSpoiler8ActuatorRunaway: 27206,
+  RightSpoiler8Disconnect: 27207,
+  ThsJam: 27208,
+  ThsGreenHydraulicMotor: 27209,
+  ThsYellowHydraulicMotor: 27210,
+  LeftFlapsTransmissionShaftBreak: 27211,
+  RightFlapsTransmissionShaftBreak: 27212,
+  LeftSlatsTransmissionShaftBreak: 27213,
+  RightSlatsTransmissionShaftBreak: 27214,
+  LeftFlapsSkew: 27215,
+  RightFlapsSkew: 27216,
+  LeftSlatsSkew: 27217,
+  RightSlatsSkew: 27218,
 
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
@@ -145,6 +285,53 @@ export const A380Failure = Object.freeze({
   EnginePump3BOHeat: 29015,
   EnginePump4AOHeat: 29016,
   EnginePump4BOHeat: 29017,
+  EnginePump1ACaseDrainLeak: 29018,
+  EnginePump1BCaseDrainLeak: 29019,
+  EnginePump2ACaseDrainLeak: 29020,
+  EnginePump2BCaseDrainLeak: 29021,
+  GreenElecPumpACaseDrainLeak: 29022,
+  GreenElecPumpBCaseDrainLeak: 29023,
+  EnginePump3ACaseDrainLeak: 29024,
+  EnginePump3BCaseDrainLeak: 29025,
+  EnginePump4ACaseDrainLeak: 29026,
+  EnginePump4BCaseDrainLeak: 29027,
+  YellowElecPumpACaseDrainLeak: 29028,
+  YellowElecPumpBCaseDrainLeak: 29029,
+  EnginePump1ASectionLeak: 29030,
+  EnginePump1BSectionLeak: 29031,
+  EnginePump2ASectionLeak: 29032,
+  EnginePump2BSectionLeak: 29033,
+  GreenElecPumpASectionLeak: 29034,
+  GreenElecPumpBSectionLeak: 29035,
+  EnginePump3ASectionLeak: 29036,
+  EnginePump3BSectionLeak: 29037,
+  EnginePump4ASectionLeak: 29038,
+  EnginePump4BSectionLeak: 29039,
+  YellowElecPumpASectionLeak: 29040,
+  YellowElecPumpBSectionLeak: 29041,
+  GreenSystemSectionLeak: 29042,
+  YellowSystemSectionLeak: 29043,
+  GreenAuxiliarySectionLeak: 29044,
+  LeftOutwardAileronOutwardActuatorLeak: 29045,
+  LeftOutwardAileronInwardActuatorLeak: 29046,
+  LeftMiddleAileronOutwardActuatorLeak: 29047,
+  LeftMiddleAileronInwardActuatorLeak: 29048,
+  LeftInwardAileronOutwardActuatorLeak: 29049,
+  LeftInwardAileronInwardActuatorLeak: 29050,
+  RightOutwardAileronOutwardActuatorLeak: 29051,
+  RightOutwardAileronInwardActuatorLeak: 29052,
+  RightMiddleAileronOutwardActuatorLeak: 29053,
+  RightMiddleAileronInwardActuatorLeak: 29054,
+  RightInwardAileronOutwardActuatorLeak: 29055,
+  RightInwardAileronInwardActuatorLeak: 29056,
+  LeftOutwardElevatorOutwardActuatorLeak: 29057,
+  LeftOutwardElevatorInwardActuatorLeak: 29058,
+  LeftInwardElevatorOutwardActuatorLeak: 29059,
+  LeftInwardElevatorInwardActuatorLeak: 29060,
+  RightOutwardElevatorOutwardActuatorLeak: 29061,
+  RightOutwardElevatorInwardActuatorLeak: 29062,
+  RightInwardElevatorOutwardActuatorLeak: 29063,
+  RightInwardElevatorInwardActuatorLeak: 29064,
 
   Fws1: 31100,
   Fws2: 31101,
@@ -162,6 +349,87 @@ export const A380Failure = Object.freeze({
   GearProxSensorDamageGearUplockNose1: 32006,
   GearProxSensorDamageDoorUplockLeft2: 32007,
 
+  GearDoorActuatorFaultGearDoorNose: 32030,
+  GearDoorActuatorFaultGearDoorLeft: 32031,
+  GearDoorActuatorFaultGearDoorRight: 32032,
+
+  GearUplockJammedGearNose: 32040,
+  GearUplockJammedGearLeft: 32041,
+  GearUplockJammedGearRight: 32042,
+  GearUplockJammedGearDoorNose: 32043,
+  GearUplockJammedGearDoorLeft: 32044,
+  GearUplockJammedGearDoorRight: 32045,
+
+  GearDownlockFailureGearNose: 32050,
+  GearDownlockFailureGearLeft: 32051,
+  GearDownlockFailureGearRight: 32052,
+
+  FlatTyre1: 32200,
+  FlatTyre2: 32201,
+  FlatTyre3: 32202,
+  FlatTyre4: 32203,
+  FlatTyre5: 32204,
+  FlatTyre6: 32205,
+  FlatTyre7: 32206,
+  FlatTyre8: 32207,
+  FlatTyre9: 32208,
+  FlatTyre10: 32209,
+  FlatTyre11: 32210,
+  FlatTyre12: 32211,
+  FlatTyre13: 32212,
+  FlatTyre14: 32213,
+  FlatTyre15: 32214,
+  FlatTyre16: 32215,
+  AntiSkid: 32240,
+  BrakeSeized1: 32300,
+  BrakeSeized2: 32301,
+  BrakeSeized3: 32302,
+  BrakeSeized4: 32303,
+  BrakeSeized5: 32304,
+  BrakeSeized6: 32305,
+  BrakeSeized7: 32306,
+  BrakeSeized8: 32307,
+  BrakeSeized9: 32308,
+  BrakeSeized10: 32309,
+  BrakeSeized11: 32310,
+  BrakeSeized12: 32311,
+  BrakeSeized13: 32312,
+  BrakeSeized14: 32313,
+  BrakeSeized15: 32314,
+  BrakeSeized16: 32315,
+  BrakeReleased1: 32320,
+  BrakeReleased2: 32321,
+  BrakeReleased3: 32322,
+  BrakeReleased4: 32323,
+  BrakeReleased5: 32324,
+  BrakeReleased6: 32325,
+  BrakeReleased7: 32326,
+  BrakeReleased8: 32327,
+  BrakeReleased9: 32328,
+  BrakeReleased10: 32329,
+  BrakeReleased11: 32330,
+  BrakeReleased12: 32331,
+  BrakeReleased13: 32332,
+  BrakeReleased14: 32333,
+  BrakeReleased15: 32334,
+  BrakeReleased16: 32335,
+  BrakeTemperatureProbe1: 32340,
+  BrakeTemperatureProbe2: 32341,
+  BrakeTemperatureProbe3: 32342,
+  BrakeTemperatureProbe4: 32343,
+  BrakeTemperatureProbe5: 32344,
+  BrakeTemperatureProbe6: 32345,
+  BrakeTemperatureProbe7: 32346,
+  BrakeTemperatureProbe8: 32347,
+  BrakeTemperatureProbe9: 32348,
+  BrakeTemperatureProbe10: 32349,
+  BrakeTemperatureProbe11: 32350,
+  BrakeTemperatureProbe12: 32351,
+  BrakeTemperatureProbe13: 32352,
+  BrakeTemperatureProbe14: 32353,
+  BrakeTemperatureProbe15: 32354,
+  BrakeTemperatureProbe16: 32355,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAltimeter3: 34002,
@@ -187,6 +455,95 @@ export const A380Failure = Object.freeze({
   FirstOfficerLaptop: 46005,
   CaptainOit: 46006,
   FirstOfficerOit: 46007,
+
+  Eng1BleedPylonLeak: 36000,
+  Eng2BleedPylonLeak: 36001,
+  Eng3BleedPylonLeak: 36002,
+  Eng4BleedPylonLeak: 36003,
+  LeftWingBleedLeak: 36004,
+  RightWingBleedLeak: 36005,
+  ApuBleedDuctLeak: 36006,
+  Pack1BayBleedLeak: 36007,
+  Pack2BayBleedLeak: 36008,
+  Eng1PylonLeakDetectionLoop: 36010,
+  Eng2PylonLeakDetectionLoop: 36011,
+  Eng3PylonLeakDetectionLoop: 36012,
+  Eng4PylonLeakDetectionLoop: 36013,
+  LeftWingLeakDetectionLoopA: 36014,
+  LeftWingLeakDetectionLoopB: 36015,
+  RightWingLeakDetectionLoopA: 36016,
+  RightWingLeakDetectionLoopB: 36017,
+  ApuDuctLeakDetectionLoop: 36018,
+  Eng1HpValveStuckOpen: 36020,
+  Eng2HpValveStuckOpen: 36021,
+  Eng3HpValveStuckOpen: 36022,
+  Eng4HpValveStuckOpen: 36023,
+  Eng1PrValveStuckOpen: 36024,
+  Eng2PrValveStuckOpen: 36025,
+  Eng3PrValveStuckOpen: 36026,
+  Eng4PrValveStuckOpen: 36027,
+  LeftCrossBleedValveStuckOpen: 36028,
+  CenterCrossBleedValveStuckOpen: 36029,
+  RightCrossBleedValveStuckOpen: 36030,
+  Pack1FlowControlValve1StuckOpen: 36031,
+  Pack1FlowControlValve2StuckOpen: 36032,
+  Pack2FlowControlValve1StuckOpen: 36033,
+  Pack2FlowControlValve2StuckOpen: 36034,
+  ApuBleedValveStuckOpen: 36035,
+  Eng1HpValveStuckClosed: 36040,
+  Eng2HpValveStuckClosed: 36041,
+  Eng3HpValveStuckClosed: 36042,
+  Eng4HpValveStuckClosed: 36043,
+  Eng1PrValveStuckClosed: 36044,
+  Eng2PrValveStuckClosed: 36045,
+  Eng3PrValveStuckClosed: 36046,
+  Eng4PrValveStuckClosed: 36047,
+  LeftCrossBleedValveStuckClosed: 36048,
+  CenterCrossBleedValveStuckClosed: 36049,
+  RightCrossBleedValveStuckClosed: 36050,
+  Pack1FlowControlValve1StuckClosed: 36051,
+  Pack1FlowControlValve2StuckClosed: 36052,
+  Pack2FlowControlValve1StuckClosed: 36053,
+  Pack2FlowControlValve2StuckClosed: 36054,
+  ApuBleedValveStuckClosed: 36055,
+  Eng1HpValveStuckInPosition: 36060,
+  Eng2HpValveStuckInPosition: 36061,
+  Eng3HpValveStuckInPosition: 36062,
+  Eng4HpValveStuckInPosition: 36063,
+  Eng1PrValveStuckInPosition: 36064,
+  Eng2PrValveStuckInPosition: 36065,
+  Eng3PrValveStuckInPosition: 36066,
+  Eng4PrValveStuckInPosition: 36067,
+  LeftCrossBleedValveStuckInPosition: 36068,
+  CenterCrossBleedValveStuckInPosition: 36069,
+  RightCrossBleedValveStuckInPosition: 36070,
+  Pack1FlowControlValve1StuckInPosition: 36071,
+  Pack1FlowControlValve2StuckInPosition: 36072,
+  Pack2FlowControlValve1StuckInPosition: 36073,
+  Pack2FlowControlValve2StuckInPosition: 36074,
+  ApuBleedValveStuckInPosition: 36075,
+  Eng1HpValveInternalLeak: 36080,
+  Eng2HpValveInternalLeak: 36081,
+  Eng3HpValveInternalLeak: 36082,
+  Eng4HpValveInternalLeak: 36083,
+  Eng1PrValveInternalLeak: 36084,
+  Eng2PrValveInternalLeak: 36085,
+  Eng3PrValveInternalLeak: 36086,
+  Eng4PrValveInternalLeak: 36087,
+  LeftCrossBleedValveInternalLeak: 36088,
+  CenterCrossBleedValveInternalLeak: 36089,
+  RightCrossBleedValveInternalLeak: 36090,
+  Pack1FlowControlValve1InternalLeak: 36091,
+  Pack1FlowControlValve2InternalLeak: 36092,
+  Pack2FlowControlValve1InternalLeak: 36093,
+  Pack2FlowControlValve2InternalLeak: 36094,
+  ApuBleedValveInternalLeak: 36095,
+
+  ApuHungStart: 49000,
+  ApuEgtOvertemperature: 49001,
+  ApuLowOilPressure: 49002,
+  ApuAirIntakeFlapStuck: 49003,
+  ApuElectronicControlBox: 49004,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
@@ -284,6 +641,12 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [24, A380Failure.DirectCurrentHot3, 'DC HOT ESS'],
   [24, A380Failure.DirectCurrentHot4, 'DC HOT APU'],
   [24, A380Failure.DirectCurrentGndFltService, 'DC GND FLT SRV'],
+  [24, A380Failure.CircuitBreakerLgciu1, 'LGCIU 1 CB'],
+  [24, A380Failure.CircuitBreakerLgciu2, 'LGCIU 2 CB'],
+  [24, A380Failure.CircuitBreakerRadioAltimeter1, 'RA 1 CB'],
+  [24, A380Failure.CircuitBreakerRadioAltimeter2, 'RA 2 CB'],
+  [24, A380Failure.CircuitBreakerRadioAltimeter3, 'RA 3 CB'],
+  [24, A380Failure.CircuitBreakerEgpwc, 'EGPWC CB'],
 
   [26, A380Failure.Engine1Fire, 'Fire - Engine 1'],
   [26, A380Failure.Engine2Fire, 'Fire - Engine 2'],
@@ -303,6 +666,21 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [26, A380Failure.APULoopB, 'APU Loop B'],
   [26, A380Failure.MLGLoopA, 'Main Landing Gear Bay Loop A'],
   [26, A380Failure.MLGLoopB, 'Main Landing Gear Bay Loop B'],
+  [26, A380Failure.FwdCargoSmoke, 'Smoke - Forward Cargo'],
+  [26, A380Failure.AftCargoSmoke, 'Smoke - Aft Cargo'],
+  [26, A380Failure.BulkCargoSmoke, 'Smoke - Bulk Cargo'],
+  [26, A380Failure.LavatorySmoke, 'Smoke - Lavatory'],
+  [26, A380Failure.AvionicsSmoke, 'Smoke - Avionics Bay'],
+  [26, A380Failure.FwdCargoSmokeDetectorA, 'Forward Cargo Smoke Detector A'],
+  [26, A380Failure.FwdCargoSmokeDetectorB, 'Forward Cargo Smoke Detector B'],
+  [26, A380Failure.AftCargoSmokeDetectorA, 'Aft Cargo Smoke Detector A'],
+  [26, A380Failure.AftCargoSmokeDetectorB, 'Aft Cargo Smoke Detector B'],
+  [26, A380Failure.BulkCargoSmokeDetectorA, 'Bulk Cargo Smoke Detector A'],
+  [26, A380Failure.BulkCargoSmokeDetectorB, 'Bulk Cargo Smoke Detector B'],
+  [26, A380Failure.LavatorySmokeDetectorA, 'Lavatory Smoke Detector A'],
+  [26, A380Failure.LavatorySmokeDetectorB, 'Lavatory Smoke Detector B'],
+  [26, A380Failure.AvionicsSmokeDetectorA, 'Avionics Bay Smoke Detector A'],
+  [26, A380Failure.AvionicsSmokeDetectorB, 'Avionics Bay Smoke Detector B'],
 
   [27, A380Failure.Prim1, 'PRIM 1'],
   [27, A380Failure.Prim2, 'PRIM 2'],
@@ -312,6 +690,125 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [27, A380Failure.Sec3, 'SEC 3'],
   [27, A380Failure.Fcdc1, 'FCDC 1'],
   [27, A380Failure.Fcdc2, 'FCDC 2'],
+  [27, A380Failure.LeftOutwardAileronOutwardActuatorJam, 'Left outward aileron outward actuator jam'],
+  [27, A380Failure.LeftOutwardAileronInwardActuatorJam, 'Left outward aileron inward actuator jam'],
+  [27, A380Failure.LeftOutwardAileronOutwardActuatorRunaway, 'Left outward aileron outward actuator runaway'],
+  [27, A380Failure.LeftOutwardAileronInwardActuatorRunaway, 'Left outward aileron inward actuator runaway'],
+  [27, A380Failure.LeftOutwardAileronDisconnect, 'Left outward aileron disconnect'],
+  [27, A380Failure.LeftMiddleAileronOutwardActuatorJam, 'Left middle aileron outward actuator jam'],
+  [27, A380Failure.LeftMiddleAileronInwardActuatorJam, 'Left middle aileron inward actuator jam'],
+  [27, A380Failure.LeftMiddleAileronOutwardActuatorRunaway, 'Left middle aileron outward actuator runaway'],
+  [27, A380Failure.LeftMiddleAileronInwardActuatorRunaway, 'Left middle aileron inward actuator runaway'],
+  [27, A380Failure.LeftMiddleAileronDisconnect, 'Left middle aileron disconnect'],
+  [27, A380Failure.LeftInwardAileronOutwardActuatorJam, 'Left inward aileron outward actuator jam'],
+  [27, A380Failure.LeftInwardAileronInwardActuatorJam, 'Left inward aileron inward actuator jam'],
+  [27, A380Failure.LeftInwardAileronOutwardActuatorRunaway, 'Left inward aileron outward actuator runaway'],
+  [27, A380Failure.LeftInwardAileronInwardActuatorRunaway, 'Left inward aileron inward actuator runaway'],
+  [27, A380Failure.LeftInwardAileronDisconnect, 'Left inward aileron disconnect'],
+  [27, A380Failure.RightOutwardAileronOutwardActuatorJam, 'Right outward aileron outward actuator jam'],
+  [27, A380Failure.RightOutwardAileronInwardActuatorJam, 'Right outward aileron inward actuator jam'],
+  [27, A380Failure.RightOutwardAileronOutwardActuatorRunaway, 'Right outward aileron outward actuator runaway'],
+  [27, A380Failure.RightOutwardAileronInwardActuatorRunaway, 'Right outward aileron inward actuator runaway'],
+  [27, A380Failure.RightOutwardAileronDisconnect, 'Right outward aileron disconnect'],
+  [27, A380Failure.RightMiddleAileronOutwardActuatorJam, 'Right middle aileron outward actuator jam'],
+  [27, A380Failure.RightMiddleAileronInwardActuatorJam, 'Right middle aileron inward actuator jam'],
+  [27, A380Failure.RightMiddleAileronOutwardActuatorRunaway, 'Right middle aileron outward actuator runaway'],
+  [27, A380Failure.RightMiddleAileronInwardActuatorRunaway, 'Right middle aileron inward actuator runaway'],
+  [27, A380Failure.RightMiddleAileronDisconnect, 'Right middle aileron disconnect'],
+  [27, A380Failure.RightInwardAileronOutwardActuatorJam, 'Right inward aileron outward actuator jam'],
+  [27, A380Failure.RightInwardAileronInwardActuatorJam, 'Right inward aileron inward actuator jam'],
+  [27, A380Failure.RightInwardAileronOutwardActuatorRunaway, 'Right inward aileron outward actuator runaway'],
+  [27, A380Failure.RightInwardAileronInwardActuatorRunaway, 'Right inward aileron inward actuator runaway'],
+  [27, A380Failure.RightInwardAileronDisconnect, 'Right inward aileron disconnect'],
+  [27, A380Failure.LeftOutwardElevatorOutwardActuatorJam, 'Left outward elevator outward actuator jam'],
+  [27, A380Failure.LeftOutwardElevatorInwardActuatorJam, 'Left outward elevator inward actuator jam'],
+  [27, A380Failure.LeftOutwardElevatorOutwardActuatorRunaway, 'Left outward elevator outward actuator runaway'],
+  [27, A380Failure.LeftOutwardElevatorInwardActuatorRunaway, 'Left outward elevator inward actuator runaway'],
+  [27, A380Failure.LeftOutwardElevatorDisconnect, 'Left outward elevator disconnect'],
+  [27, A380Failure.LeftInwardElevatorOutwardActuatorJam, 'Left inward elevator outward actuator jam'],
+  [27, A380Failure.LeftInwardElevatorInwardActuatorJam, 'Left inward elevator inward actuator jam'],
+  [27, A380Failure.LeftInwardElevatorOutwardActuatorRunaway, 'Left inward elevator outward actuator runaway'],
+  [27, A380Failure.LeftInwardElevatorInwardActuatorRunaway, 'Left inward elevator inward actuator runaway'],
+  [27, A380Failure.LeftInwardElevatorDisconnect, 'Left inward elevator disconnect'],
+  [27, A380Failure.RightOutwardElevatorOutwardActuatorJam, 'Right outward elevator outward actuator jam'],
+  [27, A380Failure.RightOutwardElevatorInwardActuatorJam, 'Right outward elevator inward actuator jam'],
+  [27, A380Failure.RightOutwardElevatorOutwardActuatorRunaway, 'Right outward elevator outward actuator runaway'],
+  [27, A380Failure.RightOutwardElevatorInwardActuatorRunaway, 'Right outward elevator inward actuator runaway'],
+  [27, A380Failure.RightOutwardElevatorDisconnect, 'Right outward elevator disconnect'],
+  [27, A380Failure.RightInwardElevatorOutwardActuatorJam, 'Right inward elevator outward actuator jam'],
+  [27, A380Failure.RightInwardElevatorInwardActuatorJam, 'Right inward elevator inward actuator jam'],
+  [27, A380Failure.RightInwardElevatorOutwardActuatorRunaway, 'Right inward elevator outward actuator runaway'],
+  [27, A380Failure.RightInwardElevatorInwardActuatorRunaway, 'Right inward elevator inward actuator runaway'],
+  [27, A380Failure.RightInwardElevatorDisconnect, 'Right inward elevator disconnect'],
+  [27, A380Failure.UpperRudderUpperActuatorJam, 'Upper rudder upper actuator jam'],
+  [27, A380Failure.UpperRudderLowerActuatorJam, 'Upper rudder lower actuator jam'],
+  [27, A380Failure.UpperRudderUpperActuatorRunaway, 'Upper rudder upper actuator runaway'],
+  [27, A380Failure.UpperRudderLowerActuatorRunaway, 'Upper rudder lower actuator runaway'],
+  [27, A380Failure.UpperRudderDisconnect, 'Upper rudder disconnect'],
+  [27, A380Failure.LowerRudderUpperActuatorJam, 'Lower rudder upper actuator jam'],
+  [27, A380Failure.LowerRudderLowerActuatorJam, 'Lower rudder lower actuator jam'],
+  [27, A380Failure.LowerRudderUpperActuatorRunaway, 'Lower rudder upper actuator runaway'],
+  [27, A380Failure.LowerRudderLowerActuatorRunaway, 'Lower rudder lower actuator runaway'],
+  [27, A380Failure.LowerRudderDisconnect, 'Lower rudder disconnect'],
+  [27, A380Failure.LeftSpoiler1ActuatorJam, 'Left spoiler 1 actuator jam'],
+  [27, A380Failure.LeftSpoiler1ActuatorRunaway, 'Left spoiler 1 actuator runaway'],
+  [27, A380Failure.LeftSpoiler1Disconnect, 'Left spoiler 1 disconnect'],
+  [27, A380Failure.LeftSpoiler2ActuatorJam, 'Left spoiler 2 actuator jam'],
+  [27, A380Failure.LeftSpoiler2ActuatorRunaway, 'Left spoiler 2 actuator runaway'],
+  [27, A380Failure.LeftSpoiler2Disconnect, 'Left spoiler 2 disconnect'],
+  [27, A380Failure.LeftSpoiler3ActuatorJam, 'Left spoiler 3 actuator jam'],
+  [27, A380Failure.LeftSpoiler3ActuatorRunaway, 'Left spoiler 3 actuator runaway'],
+  [27, A380Failure.LeftSpoiler3Disconnect, 'Left spoiler 3 disconnect'],
+  [27, A380Failure.LeftSpoiler4ActuatorJam, 'Left spoiler 4 actuator jam'],
+  [27, A380Failure.LeftSpoiler4ActuatorRunaway, 'Left spoiler 4 actuator runaway'],
+  [27, A380Failure.LeftSpoiler4Disconnect, 'Left spoiler 4 disconnect'],
+  [27, A380Failure.LeftSpoiler5ActuatorJam, 'Left spoiler 5 actuator jam'],
+  [27, A380Failure.LeftSpoiler5ActuatorRunaway, 'Left spoiler 5 actuator runaway'],
+  [27, A380Failure.LeftSpoiler5Disconnect, 'Left spoiler 5 disconnect'],
+  [27, A380Failure.LeftSpoiler6ActuatorJam, 'Left spoiler 6 actuator jam'],
+  [27, A380Failure.LeftSpoiler6ActuatorRunaway, 'Left spoiler 6 actuator runaway'],
+  [27, A380Failure.LeftSpoiler6Disconnect, 'Left spoiler 6 disconnect'],
+  [27, A380Failure.LeftSpoiler7ActuatorJam, 'Left spoiler 7 actuator jam'],
+  [27, A380Failure.LeftSpoiler7ActuatorRunaway, 'Left spoiler 7 actuator runaway'],
+  [27, A380Failure.LeftSpoiler7Disconnect, 'Left spoiler 7 disconnect'],
+  [27, A380Failure.LeftSpoiler8ActuatorJam, 'Left spoiler 8 actuator jam'],
+  [27, A380Failure.LeftSpoiler8ActuatorRunaway, 'Left spoiler 8 actuator runaway'],
+  [27, A380Failure.LeftSpoiler8Disconnect, 'Left spoiler 8 disconnect'],
+  [27, A380Failure.RightSpoiler1ActuatorJam, 'Right spoiler 1 actuator jam'],
+  [27, A380Failure.RightSpoiler1ActuatorRunaway, 'Right spoiler 1 actuator runaway'],
+  [27, A380Failure.RightSpoiler1Disconnect, 'Right spoiler 1 disconnect'],
+  [27, A380Failure.RightSpoiler2ActuatorJam, 'Right spoiler 2 actuator jam'],
+  [27, A380Failure.RightSpoiler2ActuatorRunaway, 'Right spoiler 2 actuator runaway'],
+  [27, A380Failure.RightSpoiler2Disconnect, 'Right spoiler 2 disconnect'],
+  [27, A380Failure.RightSpoiler3ActuatorJam, 'Right spoiler 3 actuator jam'],
+  [27, A380Failure.RightSpoiler3ActuatorRunaway, 'Right spoiler 3 actuator runaway'],
+  [27, A380Failure.RightSpoiler3Disconnect, 'Right spoiler 3 disconnect'],
+  [27, A380Failure.RightSpoiler4ActuatorJam, 'Right spoiler 4 actuator jam'],
+  [27, A380Failure.RightSpoiler4ActuatorRunaway, 'Right spoiler 4 actuator runaway'],
+  [27, A380Failure.RightSpoiler4Disconnect, 'Right spoiler 4 disconnect'],
+  [27, A380Failure.RightSpoiler5ActuatorJam, 'Right spoiler 5 actuator jam'],
+  [27, A380Failure.RightSpoiler5ActuatorRunaway, 'Right spoiler 5 actuator runaway'],
+  [27, A380Failure.RightSpoiler5Disconnect, 'Right spoiler 5 disconnect'],
+  [27, A380Failure.RightSpoiler6ActuatorJam, 'Right spoiler 6 actuator jam'],
+  [27, A380Failure.RightSpoiler6ActuatorRunaway, 'Right spoiler 6 actuator runaway'],
+  [27, A380Failure.RightSpoiler6Disconnect, 'Right spoiler 6 disconnect'],
+  [27, A380Failure.RightSpoiler7ActuatorJam, 'Right spoiler 7 actuator jam'],
+  [27, A380Failure.RightSpoiler7ActuatorRunaway, 'Right spoiler 7 actuator runaway'],
+  [27, A380Failure.RightSpoiler7Disconnect, 'Right spoiler 7 disconnect'],
+  [27, A380Failure.RightSpoiler8ActuatorJam, 'Right spoiler 8 actuator jam'],
+  [27, A380Failure.RightSpoiler8ActuatorRunaway, 'Right spoiler 8 actuator runaway'],
+  [27, A380Failure.RightSpoiler8Disconnect, 'Right spoiler 8 disconnect'],
+  [27, A380Failure.ThsJam, 'THS jam'],
+  [27, A380Failure.ThsGreenHydraulicMotor, 'THS green hydraulic motor'],
+  [27, A380Failure.ThsYellowHydraulicMotor, 'THS yellow hydraulic motor'],
+  [27, A380Failure.LeftFlapsTransmissionShaftBreak, 'Left flaps transmission shaft break'],
+  [27, A380Failure.RightFlapsTransmissionShaftBreak, 'Right flaps transmission shaft break'],
+  [27, A380Failure.LeftSlatsTransmissionShaftBreak, 'Left slats transmission shaft break'],
+  [27, A380Failure.RightSlatsTransmissionShaftBreak, 'Right slats transmission shaft break'],
+  [27, A380Failure.LeftFlapsSkew, 'Left flaps skew'],
+  [27, A380Failure.RightFlapsSkew, 'Right flaps skew'],
+  [27, A380Failure.LeftSlatsSkew, 'Left slats skew'],
+  [27, A380Failure.RightSlatsSkew, 'Right slats skew'],
 
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -331,6 +828,53 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [29, A380Failure.EnginePump3BOHeat, 'Engine 3 pump B overheat'],
   [29, A380Failure.EnginePump4AOHeat, 'Engine 4 pump A overheat'],
   [29, A380Failure.EnginePump4BOHeat, 'Engine 4 pump B overheat'],
+  [29, A380Failure.EnginePump1ACaseDrainLeak, 'Engine 1 pump A case drain leak'],
+  [29, A380Failure.EnginePump1BCaseDrainLeak, 'Engine 1 pump B case drain leak'],
+  [29, A380Failure.EnginePump2ACaseDrainLeak, 'Engine 2 pump A case drain leak'],
+  [29, A380Failure.EnginePump2BCaseDrainLeak, 'Engine 2 pump B case drain leak'],
+  [29, A380Failure.GreenElecPumpACaseDrainLeak, 'Green electric pump A case drain leak'],
+  [29, A380Failure.GreenElecPumpBCaseDrainLeak, 'Green electric pump B case drain leak'],
+  [29, A380Failure.EnginePump3ACaseDrainLeak, 'Engine 3 pump A case drain leak'],
+  [29, A380Failure.EnginePump3BCaseDrainLeak, 'Engine 3 pump B case drain leak'],
+  [29, A380Failure.EnginePump4ACaseDrainLeak, 'Engine 4 pump A case drain leak'],
+  [29, A380Failure.EnginePump4BCaseDrainLeak, 'Engine 4 pump B case drain leak'],
+  [29, A380Failure.YellowElecPumpACaseDrainLeak, 'Yellow electric pump A case drain leak'],
+  [29, A380Failure.YellowElecPumpBCaseDrainLeak, 'Yellow electric pump B case drain leak'],
+  [29, A380Failure.EnginePump1ASectionLeak, 'Engine 1 pump A section leak'],
+  [29, A380Failure.EnginePump1BSectionLeak, 'Engine 1 pump B section leak'],
+  [29, A380Failure.EnginePump2ASectionLeak, 'Engine 2 pump A section leak'],
+  [29, A380Failure.EnginePump2BSectionLeak, 'Engine 2 pump B section leak'],
+  [29, A380Failure.GreenElecPumpASectionLeak, 'Green electric pump A section leak'],
+  [29, A380Failure.GreenElecPumpBSectionLeak, 'Green electric pump B section leak'],
+  [29, A380Failure.EnginePump3ASectionLeak, 'Engine 3 pump A section leak'],
+  [29, A380Failure.EnginePump3BSectionLeak, 'Engine 3 pump B section leak'],
+  [29, A380Failure.EnginePump4ASectionLeak, 'Engine 4 pump A section leak'],
+  [29, A380Failure.EnginePump4BSectionLeak, 'Engine 4 pump B section leak'],
+  [29, A380Failure.YellowElecPumpASectionLeak, 'Yellow electric pump A section leak'],
+  [29, A380Failure.YellowElecPumpBSectionLeak, 'Yellow electric pump B section leak'],
+  [29, A380Failure.GreenSystemSectionLeak, 'Green system section leak'],
+  [29, A380Failure.YellowSystemSectionLeak, 'Yellow system section leak'],
+  [29, A380Failure.GreenAuxiliarySectionLeak, 'Green auxiliary section leak'],
+  [29, A380Failure.LeftOutwardAileronOutwardActuatorLeak, 'Left outward aileron outward actuator leak'],
+  [29, A380Failure.LeftOutwardAileronInwardActuatorLeak, 'Left outward aileron inward actuator leak'],
+  [29, A380Failure.LeftMiddleAileronOutwardActuatorLeak, 'Left middle aileron outward actuator leak'],
+  [29, A380Failure.LeftMiddleAileronInwardActuatorLeak, 'Left middle aileron inward actuator leak'],
+  [29, A380Failure.LeftInwardAileronOutwardActuatorLeak, 'Left inward aileron outward actuator leak'],
+  [29, A380Failure.LeftInwardAileronInwardActuatorLeak, 'Left inward aileron inward actuator leak'],
+  [29, A380Failure.RightOutwardAileronOutwardActuatorLeak, 'Right outward aileron outward actuator leak'],
+  [29, A380Failure.RightOutwardAileronInwardActuatorLeak, 'Right outward aileron inward actuator leak'],
+  [29, A380Failure.RightMiddleAileronOutwardActuatorLeak, 'Right middle aileron outward actuator leak'],
+  [29, A380Failure.RightMiddleAileronInwardActuatorLeak, 'Right middle aileron inward actuator leak'],
+  [29, A380Failure.RightInwardAileronOutwardActuatorLeak, 'Right inward aileron outward actuator leak'],
+  [29, A380Failure.RightInwardAileronInwardActuatorLeak, 'Right inward aileron inward actuator leak'],
+  [29, A380Failure.LeftOutwardElevatorOutwardActuatorLeak, 'Left outward elevator outward actuator leak'],
+  [29, A380Failure.LeftOutwardElevatorInwardActuatorLeak, 'Left outward elevator inward actuator leak'],
+  [29, A380Failure.LeftInwardElevatorOutwardActuatorLeak, 'Left inward elevator outward actuator leak'],
+  [29, A380Failure.LeftInwardElevatorInwardActuatorLeak, 'Left inward elevator inward actuator leak'],
+  [29, A380Failure.RightOutwardElevatorOutwardActuatorLeak, 'Right outward elevator outward actuator leak'],
+  [29, A380Failure.RightOutwardElevatorInwardActuatorLeak, 'Right outward elevator inward actuator leak'],
+  [29, A380Failure.RightInwardElevatorOutwardActuatorLeak, 'Right inward elevator outward actuator leak'],
+  [29, A380Failure.RightInwardElevatorInwardActuatorLeak, 'Right inward elevator inward actuator leak'],
 
   [31, A380Failure.Fws1, 'FWS 1'],
   [31, A380Failure.Fws2, 'FWS 2'],
@@ -345,6 +889,86 @@ export const A380FailureDefinitions: FailureDefinition[] = [
 
   [32, A380Failure.GearProxSensorDamageGearUplockNose1, 'Proximity sensor damage uplock nose gear #1'],
 
+  [32, A380Failure.GearDoorActuatorFaultGearDoorNose, 'Nose gear door actuator fault'],
+  [32, A380Failure.GearDoorActuatorFaultGearDoorLeft, 'Main left gear door actuator fault'],
+  [32, A380Failure.GearDoorActuatorFaultGearDoorRight, 'Main right gear door actuator fault'],
+
+  [32, A380Failure.GearUplockJammedGearNose, 'Nose gear uplock jammed'],
+  [32, A380Failure.GearUplockJammedGearLeft, 'Main left gear uplock jammed'],
+  [32, A380Failure.GearUplockJammedGearRight, 'Main right gear uplock jammed'],
+  [32, A380Failure.GearUplockJammedGearDoorNose, 'Nose gear door uplock jammed'],
+  [32, A380Failure.GearUplockJammedGearDoorLeft, 'Main left gear door uplock jammed'],
+  [32, A380Failure.GearUplockJammedGearDoorRight, 'Main right gear door uplock jammed'],
+
+  [32, A380Failure.GearDownlockFailureGearNose, 'Nose gear downlock failure'],
+  [32, A380Failure.GearDownlockFailureGearLeft, 'Main left gear downlock failure'],
+  [32, A380Failure.GearDownlockFailureGearRight, 'Main right gear downlock failure'],
+  [32, A380Failure.FlatTyre1, 'Flat tyre 1'],
+  [32, A380Failure.FlatTyre2, 'Flat tyre 2'],
+  [32, A380Failure.FlatTyre3, 'Flat tyre 3'],
+  [32, A380Failure.FlatTyre4, 'Flat tyre 4'],
+  [32, A380Failure.FlatTyre5, 'Flat tyre 5'],
+  [32, A380Failure.FlatTyre6, 'Flat tyre 6'],
+  [32, A380Failure.FlatTyre7, 'Flat tyre 7'],
+  [32, A380Failure.FlatTyre8, 'Flat tyre 8'],
+  [32, A380Failure.FlatTyre9, 'Flat tyre 9'],
+  [32, A380Failure.FlatTyre10, 'Flat tyre 10'],
+  [32, A380Failure.FlatTyre11, 'Flat tyre 11'],
+  [32, A380Failure.FlatTyre12, 'Flat tyre 12'],
+  [32, A380Failure.FlatTyre13, 'Flat tyre 13'],
+  [32, A380Failure.FlatTyre14, 'Flat tyre 14'],
+  [32, A380Failure.FlatTyre15, 'Flat tyre 15'],
+  [32, A380Failure.FlatTyre16, 'Flat tyre 16'],
+  [32, A380Failure.AntiSkid, 'Anti-skid inoperative'],
+  [32, A380Failure.BrakeSeized1, 'Brake 1 seized'],
+  [32, A380Failure.BrakeSeized2, 'Brake 2 seized'],
+  [32, A380Failure.BrakeSeized3, 'Brake 3 seized'],
+  [32, A380Failure.BrakeSeized4, 'Brake 4 seized'],
+  [32, A380Failure.BrakeSeized5, 'Brake 5 seized'],
+  [32, A380Failure.BrakeSeized6, 'Brake 6 seized'],
+  [32, A380Failure.BrakeSeized7, 'Brake 7 seized'],
+  [32, A380Failure.BrakeSeized8, 'Brake 8 seized'],
+  [32, A380Failure.BrakeSeized9, 'Brake 9 seized'],
+  [32, A380Failure.BrakeSeized10, 'Brake 10 seized'],
+  [32, A380Failure.BrakeSeized11, 'Brake 11 seized'],
+  [32, A380Failure.BrakeSeized12, 'Brake 12 seized'],
+  [32, A380Failure.BrakeSeized13, 'Brake 13 seized'],
+  [32, A380Failure.BrakeSeized14, 'Brake 14 seized'],
+  [32, A380Failure.BrakeSeized15, 'Brake 15 seized'],
+  [32, A380Failure.BrakeSeized16, 'Brake 16 seized'],
+  [32, A380Failure.BrakeReleased1, 'Brake 1 released'],
+  [32, A380Failure.BrakeReleased2, 'Brake 2 released'],
+  [32, A380Failure.BrakeReleased3, 'Brake 3 released'],
+  [32, A380Failure.BrakeReleased4, 'Brake 4 released'],
+  [32, A380Failure.BrakeReleased5, 'Brake 5 released'],
+  [32, A380Failure.BrakeReleased6, 'Brake 6 released'],
+  [32, A380Failure.BrakeReleased7, 'Brake 7 released'],
+  [32, A380Failure.BrakeReleased8, 'Brake 8 released'],
+  [32, A380Failure.BrakeReleased9, 'Brake 9 released'],
+  [32, A380Failure.BrakeReleased10, 'Brake 10 released'],
+  [32, A380Failure.BrakeReleased11, 'Brake 11 released'],
+  [32, A380Failure.BrakeReleased12, 'Brake 12 released'],
+  [32, A380Failure.BrakeReleased13, 'Brake 13 released'],
+  [32, A380Failure.BrakeReleased14, 'Brake 14 released'],
+  [32, A380Failure.BrakeReleased15, 'Brake 15 released'],
+  [32, A380Failure.BrakeReleased16, 'Brake 16 released'],
+  [32, A380Failure.BrakeTemperatureProbe1, 'Brake 1 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe2, 'Brake 2 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe3, 'Brake 3 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe4, 'Brake 4 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe5, 'Brake 5 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe6, 'Brake 6 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe7, 'Brake 7 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe8, 'Brake 8 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe9, 'Brake 9 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe10, 'Brake 10 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe11, 'Brake 11 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe12, 'Brake 12 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe13, 'Brake 13 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe14, 'Brake 14 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe15, 'Brake 15 temperature probe'],
+  [32, A380Failure.BrakeTemperatureProbe16, 'Brake 16 temperature probe'],
+
   [34, A380Failure.RadioAltimeter1, 'RA SYS A'],
   [34, A380Failure.RadioAltimeter2, 'RA SYS B'],
   [34, A380Failure.RadioAltimeter3, 'RA SYS C'],
@@ -361,6 +985,89 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [34, A380Failure.Transponder1, 'XPDR 1'],
   [34, A380Failure.Transponder2, 'XPDR 2'],
 
+  [36, A380Failure.Eng1BleedPylonLeak, 'Engine 1 Pylon Bleed Leak'],
+  [36, A380Failure.Eng2BleedPylonLeak, 'Engine 2 Pylon Bleed Leak'],
+  [36, A380Failure.Eng3BleedPylonLeak, 'Engine 3 Pylon Bleed Leak'],
+  [36, A380Failure.Eng4BleedPylonLeak, 'Engine 4 Pylon Bleed Leak'],
+  [36, A380Failure.LeftWingBleedLeak, 'Left Wing Bleed Leak'],
+  [36, A380Failure.RightWingBleedLeak, 'Right Wing Bleed Leak'],
+  [36, A380Failure.ApuBleedDuctLeak, 'APU Bleed Duct Leak'],
+  [36, A380Failure.Pack1BayBleedLeak, 'Pack 1 Bay Bleed Leak'],
+  [36, A380Failure.Pack2BayBleedLeak, 'Pack 2 Bay Bleed Leak'],
+  [36, A380Failure.Eng1PylonLeakDetectionLoop, 'Engine 1 Pylon Leak Detection Loop'],
+  [36, A380Failure.Eng2PylonLeakDetectionLoop, 'Engine 2 Pylon Leak Detection Loop'],
+  [36, A380Failure.Eng3PylonLeakDetectionLoop, 'Engine 3 Pylon Leak Detection Loop'],
+  [36, A380Failure.Eng4PylonLeakDetectionLoop, 'Engine 4 Pylon Leak Detection Loop'],
+  [36, A380Failure.LeftWingLeakDetectionLoopA, 'Left Wing Leak Detection Loop A'],
+  [36, A380Failure.LeftWingLeakDetectionLoopB, 'Left Wing Leak Detection Loop B'],
+  [36, A380Failure.RightWingLeakDetectionLoopA, 'Right Wing Leak Detection Loop A'],
+  [36, A380Failure.RightWingLeakDetectionLoopB, 'Right Wing Leak Detection Loop B'],
+  [36, A380Failure.ApuDuctLeakDetectionLoop, 'APU Duct Leak Detection Loop'],
+  [36, A380Failure.Eng1HpValveStuckOpen, 'Engine 1 HP Valve Stuck Open'],
+  [36, A380Failure.Eng2HpValveStuckOpen, 'Engine 2 HP Valve Stuck Open'],
+  [36, A380Failure.Eng3HpValveStuckOpen, 'Engine 3 HP Valve Stuck Open'],
+  [36, A380Failure.Eng4HpValveStuckOpen, 'Engine 4 HP Valve Stuck Open'],
+  [36, A380Failure.Eng1PrValveStuckOpen, 'Engine 1 Pressure Regulating Valve Stuck Open'],
+  [36, A380Failure.Eng2PrValveStuckOpen, 'Engine 2 Pressure Regulating Valve Stuck Open'],
+  [36, A380Failure.Eng3PrValveStuckOpen, 'Engine 3 Pressure Regulating Valve Stuck Open'],
+  [36, A380Failure.Eng4PrValveStuckOpen, 'Engine 4 Pressure Regulating Valve Stuck Open'],
+  [36, A380Failure.LeftCrossBleedValveStuckOpen, 'Left Cross Bleed Valve Stuck Open'],
+  [36, A380Failure.CenterCrossBleedValveStuckOpen, 'Center Cross Bleed Valve Stuck Open'],
+  [36, A380Failure.RightCrossBleedValveStuckOpen, 'Right Cross Bleed Valve Stuck Open'],
+  [36, A380Failure.Pack1FlowControlValve1StuckOpen, 'Pack 1 Flow Control Valve 1 Stuck Open'],
+  [36, A380Failure.Pack1FlowControlValve2StuckOpen, 'Pack 1 Flow Control Valve 2 Stuck Open'],
+  [36, A380Failure.Pack2FlowControlValve1StuckOpen, 'Pack 2 Flow Control Valve 1 Stuck Open'],
+  [36, A380Failure.Pack2FlowControlValve2StuckOpen, 'Pack 2 Flow Control Valve 2 Stuck Open'],
+  [36, A380Failure.ApuBleedValveStuckOpen, 'APU Bleed Valve Stuck Open'],
+  [36, A380Failure.Eng1HpValveStuckClosed, 'Engine 1 HP Valve Stuck Closed'],
+  [36, A380Failure.Eng2HpValveStuckClosed, 'Engine 2 HP Valve Stuck Closed'],
+  [36, A380Failure.Eng3HpValveStuckClosed, 'Engine 3 HP Valve Stuck Closed'],
+  [36, A380Failure.Eng4HpValveStuckClosed, 'Engine 4 HP Valve Stuck Closed'],
+  [36, A380Failure.Eng1PrValveStuckClosed, 'Engine 1 Pressure Regulating Valve Stuck Closed'],
+  [36, A380Failure.Eng2PrValveStuckClosed, 'Engine 2 Pressure Regulating Valve Stuck Closed'],
+  [36, A380Failure.Eng3PrValveStuckClosed, 'Engine 3 Pressure Regulating Valve Stuck Closed'],
+  [36, A380Failure.Eng4PrValveStuckClosed, 'Engine 4 Pressure Regulating Valve Stuck Closed'],
+  [36, A380Failure.LeftCrossBleedValveStuckClosed, 'Left Cross Bleed Valve Stuck Closed'],
+  [36, A380Failure.CenterCrossBleedValveStuckClosed, 'Center Cross Bleed Valve Stuck Closed'],
+  [36, A380Failure.RightCrossBleedValveStuckClosed, 'Right Cross Bleed Valve Stuck Closed'],
+  [36, A380Failure.Pack1FlowControlValve1StuckClosed, 'Pack 1 Flow Control Valve 1 Stuck Closed'],
+  [36, A380Failure.Pack1FlowControlValve2StuckClosed, 'Pack 1 Flow Control Valve 2 Stuck Closed'],
+  [36, A380Failure.Pack2FlowControlValve1StuckClosed, 'Pack 2 Flow Control Valve 1 Stuck Closed'],
+  [36, A380Failure.Pack2FlowControlValve2StuckClosed, 'Pack 2 Flow Control Valve 2 Stuck Closed'],
+  [36, A380Failure.ApuBleedValveStuckClosed, 'APU Bleed Valve Stuck Closed'],
+  [36, A380Failure.Eng1HpValveStuckInPosition, 'Engine 1 HP Valve Stuck In Position'],
+  [36, A380Failure.Eng2HpValveStuckInPosition, 'Engine 2 HP Valve Stuck In Position'],
+  [36, A380Failure.Eng3HpValveStuckInPosition, 'Engine 3 HP Valve Stuck In Position'],
+  [36, A380Failure.Eng4HpValveStuckInPosition, 'Engine 4 HP Valve Stuck In Position'],
+  [36, A380Failure.Eng1PrValveStuckInPosition, 'Engine 1 Pressure Regulating Valve Stuck In Position'],
+  [36, A380Failure.Eng2PrValveStuckInPosition, 'Engine 2 Pressure Regulating Valve Stuck In Position'],
+  [36, A380Failure.Eng3PrValveStuckInPosition, 'Engine 3 Pressure Regulating Valve Stuck In Position'],
+  [36, A380Failure.Eng4PrValveStuckInPosition, 'Engine 4 Pressure Regulating Valve Stuck In Position'],
+  [36, A380Failure.LeftCrossBleedValveStuckInPosition, 'Left Cross Bleed Valve Stuck In Position'],
+  [36, A380Failure.CenterCrossBleedValveStuckInPosition, 'Center Cross Bleed Valve Stuck In Position'],
+  [36, A380Failure.RightCrossBleedValveStuckInPosition, 'Right Cross Bleed Valve Stuck In Position'],
+  [36, A380Failure.Pack1FlowControlValve1StuckInPosition, 'Pack 1 Flow Control Valve 1 Stuck In Position'],
+  [36, A380Failure.Pack1FlowControlValve2StuckInPosition, 'Pack 1 Flow Control Valve 2 Stuck In Position'],
+  [36, A380Failure.Pack2FlowControlValve1StuckInPosition, 'Pack 2 Flow Control Valve 1 Stuck In Position'],
+  [36, A380Failure.Pack2FlowControlValve2StuckInPosition, 'Pack 2 Flow Control Valve 2 Stuck In Position'],
+  [36, A380Failure.ApuBleedValveStuckInPosition, 'APU Bleed Valve Stuck In Position'],
+  [36, A380Failure.Eng1HpValveInternalLeak, 'Engine 1 HP Valve Internal Leak'],
+  [36, A380Failure.Eng2HpValveInternalLeak, 'Engine 2 HP Valve Internal Leak'],
+  [36, A380Failure.Eng3HpValveInternalLeak, 'Engine 3 HP Valve Internal Leak'],
+  [36, A380Failure.Eng4HpValveInternalLeak, 'Engine 4 HP Valve Internal Leak'],
+  [36, A380Failure.Eng1PrValveInternalLeak, 'Engine 1 Pressure Regulating Valve Internal Leak'],
+  [36, A380Failure.Eng2PrValveInternalLeak, 'Engine 2 Pressure Regulating Valve Internal Leak'],
+  [36, A380Failure.Eng3PrValveInternalLeak, 'Engine 3 Pressure Regulating Valve Internal Leak'],
+  [36, A380Failure.Eng4PrValveInternalLeak, 'Engine 4 Pressure Regulating Valve Internal Leak'],
+  [36, A380Failure.LeftCrossBleedValveInternalLeak, 'Left Cross Bleed Valve Internal Leak'],
+  [36, A380Failure.CenterCrossBleedValveInternalLeak, 'Center Cross Bleed Valve Internal Leak'],
+  [36, A380Failure.RightCrossBleedValveInternalLeak, 'Right Cross Bleed Valve Internal Leak'],
+  [36, A380Failure.Pack1FlowControlValve1InternalLeak, 'Pack 1 Flow Control Valve 1 Internal Leak'],
+  [36, A380Failure.Pack1FlowControlValve2InternalLeak, 'Pack 1 Flow Control Valve 2 Internal Leak'],
+  [36, A380Failure.Pack2FlowControlValve1InternalLeak, 'Pack 2 Flow Control Valve 1 Internal Leak'],
+  [36, A380Failure.Pack2FlowControlValve2InternalLeak, 'Pack 2 Flow Control Valve 2 Internal Leak'],
+  [36, A380Failure.ApuBleedValveInternalLeak, 'APU Bleed Valve Internal Leak'],
+
   [46, A380Failure.NssAnsu1, 'NSS AVNCS ANSU 1'],
   [46, A380Failure.NssAnsu2, 'NSS AVNCS ANSU 2'],
   [46, A380Failure.FltOpsAnsu, 'FLT OPS ANSU'],
@@ -368,4 +1075,10 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [46, A380Failure.FirstOfficerLaptop, 'F/O Laptop'],
   [46, A380Failure.CaptainOit, 'Captain OIT'],
   [46, A380Failure.FirstOfficerOit, 'F/O OIT'],
+
+  [49, A380Failure.ApuHungStart, 'APU Hung Start'],
+  [49, A380Failure.ApuEgtOvertemperature, 'APU EGT Overtemperature'],
+  [49, A380Failure.ApuLowOilPressure, 'APU Low Oil Pressure'],
+  [49, A380Failure.ApuAirIntakeFlapStuck, 'APU Air Intake Flap Stuck'],
+  [49, A380Failure.ApuElectronicControlBox, 'APU ECB'],
 ];
@@ -629,6 +629,108 @@ export const EcamAbnormalSensedAta212223: { [n: number]: AbnormalProcedure } = {
       },
     ],
   },
+  211800057: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 1 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 1 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  211800058: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 2 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 2 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  211800059: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 3 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 3 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  211800060: {
+    title: '\x1b<4m\x1b4mAIR\x1bm ENG 4 BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 4 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
+  211800061: {
+    title: '\x1b<4m\x1b4mAIR\x1bm L WING LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 1 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+      {
+        name: 'ENG 2 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+      {
+        name: 'APU BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+      {
+        name: 'AVOID ICING CONDs',
+        sensed: false,
+        style: ChecklistLineStyle.Cyan,
+      },
+    ],
+  },
+  211800062: {
+    title: '\x1b<4m\x1b4mAIR\x1bm R WING LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'ENG 3 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+      {
+        name: 'ENG 4 BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+      {
+        name: 'AVOID ICING CONDs',
+        sensed: false,
+        style: ChecklistLineStyle.Cyan,
+      },
+    ],
+  },
+  211800063: {
+    title: '\x1b<4m\x1b4mAIR\x1bm APU BLEED LEAK',
+    sensed: true,
+    items: [
+      {
+        name: 'APU BLEED',
+        sensed: true,
+        labelNotCompleted: 'OFF',
+      },
+    ],
+  },
   // ATA 21: VENT
   212800001: {
     title: '\x1b<4m\x1b4mCOND\x1bm AFT VENT CTL 1 FAULT',
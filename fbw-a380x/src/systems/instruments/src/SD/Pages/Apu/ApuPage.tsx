@@ -507,6 +507,7 @@ const EgtGauge = ({ x, y }: ComponentPositionProps) => {
 
 const ApuMemos = ({ x, y }: ComponentPositionProps) => {
   const lowFuelPressure = useArinc429Var('L:A32NX_APU_LOW_FUEL_PRESSURE_FAULT', 1000);
+  const lowOilLevel = useArinc429Var('L:A32NX_APU_LOW_OIL_LEVEL', 1000);
 
   const [apuFlapOpenPercentage] = useSimVar('L:A32NX_APU_FLAP_OPEN_PERCENTAGE', 'Percent', 1000);
   const [isIntakeIndicationFlashing, setIsIntakeIndicationFlashing] = useState(false);
@@ -538,6 +539,12 @@ const ApuMemos = ({ x, y }: ComponentPositionProps) => {
           </text>
         )}
 
+        {lowOilLevel.value && (
+          <text className="Green F27 LS2 FillPulse" x={0} y={35}>
+            LOW OIL LEVEL
+          </text>
+        )}
+
         {apuFlapOpenPercentage >= 10 && (
           <text className={`Green F27 LS2 ${isIntakeIndicationFlashing ? 'FillPulse' : ''}`} x={0} y={70}>
             {apuFlapOpenPercentage <= 90 ? 'FLAP MOVING' : 'FLAP OPEN'}
@@ -864,6 +864,88 @@ export class FwsAbnormalSensed {
       sysPage: SdPages.Bleed,
       inopSysAllPhases: () => ['260300005'],
     },
+    211800057: {
+      // ENG 1 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8, 9],
+      simVarIsActive: this.fws.engBleedLeak[0],
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.engBleedPbAuto[0].get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+      inopSysAllPhases: () => ['260300002'],
+    },
+    211800058: {
+      // ENG 2 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8, 9],
+      simVarIsActive: this.fws.engBleedLeak[1],
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.engBleedPbAuto[1].get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+      inopSysAllPhases: () => ['260300003'],
+    },
+    211800059: {
+      // ENG 3 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8, 9],
+      simVarIsActive: this.fws.engBleedLeak[2],
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.engBleedPbAuto[2].get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+      inopSysAllPhases: () => ['260300004'],
+    },
+    211800060: {
+      // ENG 4 BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8, 9],
+      simVarIsActive: this.fws.engBleedLeak[3],
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.engBleedPbAuto[3].get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+      inopSysAllPhases: () => ['260300005'],
+    },
+    211800061: {
+      // L WING LEAK
+      flightPhaseInhib: [4, 5, 7, 8, 9],
+      simVarIsActive: this.fws.leftWingLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true, true, true, true],
+      whichItemsChecked: () => [
+        !this.fws.engBleedPbAuto[0].get(),
+        !this.fws.engBleedPbAuto[1].get(),
+        !this.fws.apuBleedPbOn.get(),
+        false,
+      ],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+      inopSysAllPhases: () => ['260300002', '260300003', '260300006'],
+    },
+    211800062: {
+      // R WING LEAK
+      flightPhaseInhib: [4, 5, 7, 8, 9],
+      simVarIsActive: this.fws.rightWingLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true, true, true],
+      whichItemsChecked: () => [!this.fws.engBleedPbAuto[2].get(), !this.fws.engBleedPbAuto[3].get(), false],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+      inopSysAllPhases: () => ['260300004', '260300005'],
+    },
+    211800063: {
+      // APU BLEED LEAK
+      flightPhaseInhib: [4, 5, 7, 8, 9],
+      simVarIsActive: this.fws.apuBleedLeak,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [true],
+      whichItemsChecked: () => [!this.fws.apuBleedPbOn.get()],
+      failure: 2,
+      sysPage: SdPages.Bleed,
+      inopSysAllPhases: () => ['260300006'],
+    },
     212800001: {
       // AFT VENT CTL 1 FAULT
       flightPhaseInhib: [2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
@@ -531,6 +531,26 @@ export class FwsCore {
   public readonly eng3BleedAbnormalOff = Subject.create(false);
   public readonly eng4BleedAbnormalOff = Subject.create(false);
 
+  public readonly engBleedPbAuto = [
+    Subject.create(false),
+    Subject.create(false),
+    Subject.create(false),
+    Subject.create(false),
+  ];
+
+  public readonly engBleedLeak = [
+    Subject.create(false),
+    Subject.create(false),
+    Subject.create(false),
+    Subject.create(false),
+  ];
+
+  public readonly leftWingLeak = Subject.create(false);
+
+  public readonly rightWingLeak = Subject.create(false);
+
+  public readonly apuBleedLeak = Subject.create(false);
+
   public readonly enginesOffAndOnGroundSignal = new NXLogicConfirmNode(7);
 
   public readonly excessCabinAltitude = Subject.create(false);
@@ -2982,6 +3002,18 @@ export class FwsCore {
         !SimVar.GetSimVarValue('L:A32NX_OVHD_PNEU_ENG_4_BLEED_PB_IS_AUTO', SimVarValueType.Bool),
     );
 
+    for (let i = 0; i < 4; i++) {
+      this.engBleedPbAuto[i].set(
+        SimVar.GetSimVarValue(`L:A32NX_OVHD_PNEU_ENG_${i + 1}_BLEED_PB_IS_AUTO`, SimVarValueType.Bool),
+      );
+      this.engBleedLeak[i].set(
+        SimVar.GetSimVarValue(`L:A32NX_PNEU_PYLON_${i + 1}_LEAK_DETECTED`, SimVarValueType.Bool),
+      );
+    }
+    this.leftWingLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_WING_1_LEAK_DETECTED', SimVarValueType.Bool));
+    this.rightWingLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_WING_2_LEAK_DETECTED', SimVarValueType.Bool));
+    this.apuBleedLeak.set(SimVar.GetSimVarValue('L:A32NX_PNEU_APU_DUCT_LEAK_DETECTED', SimVarValueType.Bool));
+
     this.toMemo.set(SimVar.GetSimVarValue('L:A32NX_FWC_TOMEMO', 'bool'));
 
     this.autoBrake.set(SimVar.GetSimVarValue('L:A32NX_AUTOBRAKES_ARMED_MODE', 'enum'));
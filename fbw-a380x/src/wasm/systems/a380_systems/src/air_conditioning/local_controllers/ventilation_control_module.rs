@@ -5,7 +5,9 @@ use systems::{
         PressurizationOverheadShared, VcmId, VcmShared,
     },
     failures::{Failure, FailureType},
-    shared::{ControllerSignal, ElectricalBusType, ElectricalBuses},
+    shared::{
+        ControllerSignal, ElectricalBusType, ElectricalBuses, FireDetectionZone, SmokeDetection,
+    },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
         VariableIdentifier, Write,
@@ -77,6 +79,7 @@ impl VentilationControlModule {
         acs_overhead: &impl AirConditioningOverheadShared,
         ocsm: [&impl OcsmShared; 4],
         pressurization_overhead: &impl PressurizationOverheadShared,
+        smoke_detection: &impl SmokeDetection,
     ) {
         self.fault_determination();
 
@@ -90,6 +93,7 @@ impl VentilationControlModule {
                     self.active_channel.has_fault(),
                     acs_overhead,
                     pressurization_overhead,
+                    smoke_detection.smoke_detected(FireDetectionZone::BulkCargo),
                 );
                 self.orvp.update(ocsm, pressurization_overhead);
             } else {
@@ -97,6 +101,7 @@ impl VentilationControlModule {
                     self.active_channel.has_fault(),
                     acs_overhead,
                     pressurization_overhead,
+                    smoke_detection.smoke_detected(FireDetectionZone::ForwardCargo),
                 )
             }
         }
@@ -237,11 +242,14 @@ impl ForwardCargoVentilationControlSystem {
         active_channel_has_fault: bool,
         acs_overhead: &impl AirConditioningOverheadShared,
         pressurization_overhead: &impl PressurizationOverheadShared,
+        smoke_detected: bool,
     ) {
+        // Discrete signal from the SDCU, the isolation valves close when smoke is detected
         self.isolation_valves_open_allowed = acs_overhead.fwd_cargo_isolation_valve_is_on()
             && !pressurization_overhead.ditching_is_on()
             && !active_channel_has_fault
-            && !self.fwd_isol_valve_failure.is_active();
+            && !self.fwd_isol_valve_failure.is_active()
+            && !smoke_detected;
         self.extraction_fan_is_on = self.isolation_valves_open_allowed
             && !pressurization_overhead.ditching_is_on()
             && !self.fwd_extract_fan_failure.is_active()
@@ -308,11 +316,14 @@ impl BulkVentilationControlSystem {
         active_channel_has_fault: bool,
         acs_overhead: &impl AirConditioningOverheadShared,
         pressurization_overhead: &impl PressurizationOverheadShared,
+        smoke_detected: bool,
     ) {
+        // Discrete signal from the SDCU, the isolation valves close when smoke is detected
         self.isolation_valves_open_allowed = acs_overhead.bulk_isolation_valve_is_on()
             && !pressurization_overhead.ditching_is_on()
             && !active_channel_has_fault
-            && !self.bulk_isol_valve_failure.is_active();
+            && !self.bulk_isol_valve_failure.is_active()
+            && !smoke_detected;
         self.extraction_fan_is_on = self.isolation_valves_open_allowed
             && !pressurization_overhead.ditching_is_on()
             && !self.bulk_extract_fan_failure.is_active()
@@ -6,8 +6,8 @@ use systems::{
         cabin_air::CabinAirSimulation,
         pressure_valve::{NegativeRelieveValveSignal, SafetyValve},
         AdirsToAirCondInterface, Air, AirConditioningOverheadShared, AirConditioningPack,
-        AirHeater, CabinFan, DuctTemperature, FdacId, MixerUnit, OcsmId, OutletAir,
-        OverheadFlowSelector, PackFlow, PackFlowControllers, PressurizationConstants,
+        AirHeater, CabinFan, DuctTemperature, FdacId, GroundAirConditioningUnit, MixerUnit, OcsmId,
+        OutletAir, OverheadFlowSelector, PackFlow, PackFlowControllers, PressurizationConstants,
         PressurizationOverheadShared, TrimAirSystem, VcmId, VcmShared, ZoneType,
     },
     integrated_modular_avionics::{
@@ -22,7 +22,7 @@ use systems::{
     shared::{
         update_iterator::MaxStepLoop, CabinSimulation, CargoDoorLocked, ControllerSignal,
         ElectricalBusType, EngineBleedPushbutton, EngineCorrectedN1, EngineFirePushButtons,
-        EngineStartState, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed,
+        EngineStartState, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, SmokeDetection,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -32,8 +32,9 @@ use systems::{
 
 use std::time::Duration;
 use uom::si::{
-    f64::*, length::foot, thermodynamic_temperature::degree_celsius, velocity::foot_per_minute,
-    volume::cubic_meter, volume_rate::liter_per_second,
+    f64::*, length::foot, mass_rate::kilogram_per_second,
+    thermodynamic_temperature::degree_celsius, velocity::foot_per_minute, volume::cubic_meter,
+    volume_rate::liter_per_second,
 };
 
 use crate::{
@@ -122,6 +123,7 @@ impl A380AirConditioning {
         pneumatic_overhead: &impl EngineBleedPushbutton<4>,
         pressurization_overhead: &A380PressurizationOverheadPanel,
         lgciu: [&impl LgciuWeightOnWheels; 2],
+        smoke_detection: &impl SmokeDetection,
     ) {
         self.pressurization_updater.update(context);
 
@@ -160,6 +162,7 @@ impl A380AirConditioning {
             pneumatic,
             pneumatic_overhead,
             pressurization_overhead,
+            smoke_detection,
         );
 
         // This is here due to the ADIRS updating at a different rate than the pressurization system
@@ -380,12 +383,14 @@ pub(super) struct A380AirConditioningSystem {
     // Temporary structure until packs are simulated
     packs: [AirConditioningPack; 2],
     trim_air_system: TrimAirSystem<18, 4>,
+    ground_air_conditioning_unit: GroundAirConditioningUnit,
 
     air_conditioning_overhead: A380AirConditioningSystemOverhead,
 }
 
 impl A380AirConditioningSystem {
     const CAB_FAN_DESIGN_FLOW_RATE_L_S: f64 = 1250.; // litres/sec
+    const LP_GROUND_CONNECTION_RATED_FLOW_KG_S: f64 = 4.; // kg/s
 
     fn new(context: &mut InitContext, cabin_zones: &[ZoneType; 18]) -> Self {
         Self {
@@ -460,6 +465,10 @@ impl A380AirConditioningSystem {
                 Volume::new::<cubic_meter>(7.),
                 Volume::new::<cubic_meter>(0.2),
             ),
+            ground_air_conditioning_unit: GroundAirConditioningUnit::new(
+                context,
+                MassRate::new::<kilogram_per_second>(Self::LP_GROUND_CONNECTION_RATED_FLOW_KG_S),
+            ),
 
             air_conditioning_overhead: A380AirConditioningSystemOverhead::new(context),
         }
@@ -477,6 +486,7 @@ impl A380AirConditioningSystem {
         pneumatic: &(impl EngineStartState + PackFlowValveState + PneumaticBleed),
         pneumatic_overhead: &impl EngineBleedPushbutton<4>,
         pressurization_overhead: &A380PressurizationOverheadPanel,
+        smoke_detection: &impl SmokeDetection,
     ) {
         self.update_local_controllers(
             context,
@@ -488,12 +498,15 @@ impl A380AirConditioningSystem {
             pneumatic,
             pneumatic_overhead,
             pressurization_overhead,
+            smoke_detection,
         );
 
         self.update_fans(cabin_simulation, cpiom_b);
 
         self.update_packs(context, cpiom_b);
 
+        self.ground_air_conditioning_unit.update(context);
+
         self.update_mixer_unit();
 
         self.update_trim_air_system(context);
@@ -514,6 +527,7 @@ impl A380AirConditioningSystem {
         pneumatic: &(impl EngineStartState + PackFlowValveState + PneumaticBleed),
         pneumatic_overhead: &impl EngineBleedPushbutton<4>,
         pressurization_overhead: &A380PressurizationOverheadPanel,
+        smoke_detection: &impl SmokeDetection,
     ) {
         // CPIOM B1 and B3 calculate the LH AGU Flow Demand
         // CPIOM B2 and B4 calculate the RH AGU Flow Demand
@@ -563,6 +577,7 @@ impl A380AirConditioningSystem {
                 &self.air_conditioning_overhead,
                 ocsm,
                 pressurization_overhead,
+                smoke_detection,
             )
         });
     }
@@ -589,7 +604,11 @@ impl A380AirConditioningSystem {
     }
 
     fn update_mixer_unit(&mut self) {
-        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![&self.packs[0], &self.packs[1]];
+        let mut mixer_intakes: Vec<&dyn OutletAir> = vec![
+            &self.packs[0],
+            &self.packs[1],
+            &self.ground_air_conditioning_unit,
+        ];
         for fan in self.cabin_fans.iter() {
             mixer_intakes.push(fan)
         }
@@ -788,6 +807,7 @@ impl SimulationElement for A380AirConditioningSystem {
         accept_iterable!(self.cabin_fans, visitor);
         accept_iterable!(self.packs, visitor);
         self.cargo_air_heater.accept(visitor);
+        self.ground_air_conditioning_unit.accept(visitor);
 
         self.air_conditioning_overhead.accept(visitor);
 
@@ -1211,7 +1231,7 @@ mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            InternationalStandardAtmosphere, PneumaticValve, PotentialOrigin,
+            FireDetectionZone, InternationalStandardAtmosphere, PneumaticValve, PotentialOrigin,
         },
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -1830,6 +1850,35 @@ mod tests {
         }
     }
 
+    struct TestSmokeDetection {
+        fwd_cargo_smoke_detected: bool,
+        bulk_cargo_smoke_detected: bool,
+    }
+    impl TestSmokeDetection {
+        fn new() -> Self {
+            Self {
+                fwd_cargo_smoke_detected: false,
+                bulk_cargo_smoke_detected: false,
+            }
+        }
+        fn set_smoke_detected(&mut self, zone: FireDetectionZone, detected: bool) {
+            match zone {
+                FireDetectionZone::ForwardCargo => self.fwd_cargo_smoke_detected = detected,
+                FireDetectionZone::BulkCargo => self.bulk_cargo_smoke_detected = detected,
+                _ => panic!("No smoke detection in {} zone", zone),
+            }
+        }
+    }
+    impl SmokeDetection for TestSmokeDetection {
+        fn smoke_detected(&self, zone: FireDetectionZone) -> bool {
+            match zone {
+                FireDetectionZone::ForwardCargo => self.fwd_cargo_smoke_detected,
+                FireDetectionZone::BulkCargo => self.bulk_cargo_smoke_detected,
+                _ => false,
+            }
+        }
+    }
+
     struct TestLgciu {
         compressed: bool,
     }
@@ -1885,6 +1934,7 @@ mod tests {
         pressurization_overhead: A380PressurizationOverheadPanel,
         lgciu1: TestLgciu,
         lgciu2: TestLgciu,
+        smoke_detection: TestSmokeDetection,
         powered_dc_source_1: TestElectricitySource,
         powered_dc_source_ess: TestElectricitySource,
         powered_ac_source_ess: TestElectricitySource,
@@ -1929,6 +1979,7 @@ mod tests {
                 pressurization_overhead: A380PressurizationOverheadPanel::new(context),
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
+                smoke_detection: TestSmokeDetection::new(),
                 powered_dc_source_1: TestElectricitySource::powered(
                     context,
                     PotentialOrigin::Battery(1),
@@ -2144,6 +2195,7 @@ mod tests {
                 &self.pneumatic_overhead,
                 &self.pressurization_overhead,
                 [&self.lgciu1, &self.lgciu2],
+                &self.smoke_detection,
             );
         }
     }
@@ -2470,6 +2522,19 @@ mod tests {
             self
         }
 
+        fn command_ground_air_conditioning_unit(
+            mut self,
+            on_off: bool,
+            supply_temperature: ThermodynamicTemperature,
+        ) -> Self {
+            self.write_by_name("GND_AIR_COND_TURNED_ON", on_off);
+            self.write_by_name(
+                "GND_AIR_COND_SUPPLY_TEMPERATURE",
+                supply_temperature.get::<degree_celsius>(),
+            );
+            self
+        }
+
         fn command_one_pack_on(mut self, pack_id: usize) -> Self {
             let opposite_pack_id = 1 + (pack_id == 1) as usize;
             self.write_by_name(
@@ -2518,6 +2583,11 @@ mod tests {
             self
         }
 
+        fn command_cargo_smoke_detected(mut self, zone: FireDetectionZone, detected: bool) -> Self {
+            self.command(|a| a.smoke_detection.set_smoke_detected(zone, detected));
+            self
+        }
+
         fn command_bulk_heater_pb_on(mut self, on_off: bool) -> Self {
             self.write_by_name("OVHD_CARGO_AIR_HEATER_PB_IS_ON", on_off);
             self
@@ -4964,6 +5034,53 @@ mod tests {
             }
         }
 
+        mod ground_air_conditioning_unit_tests {
+            use super::*;
+
+            #[test]
+            fn ground_air_conditioning_unit_supplies_mixer_unit_without_packs_and_fans() {
+                let test_bed = test_bed()
+                    .on_ground()
+                    .with()
+                    .command_cab_fans_pb_on(false)
+                    .and()
+                    .command_packs_on_off(false)
+                    .command_ground_air_conditioning_unit(
+                        true,
+                        ThermodynamicTemperature::new::<degree_celsius>(5.),
+                    )
+                    .iterate(30);
+
+                assert!(
+                    (test_bed.mixer_unit_outlet_air().flow_rate()
+                        - MassRate::new::<kilogram_per_second>(4.))
+                    .abs()
+                        < MassRate::new::<kilogram_per_second>(0.1)
+                );
+            }
+
+            #[test]
+            fn ground_air_conditioning_unit_cools_cabin_on_hot_day() {
+                let test_bed = test_bed()
+                    .on_ground()
+                    .with()
+                    .command_packs_on_off(false)
+                    .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(40.))
+                    .iterate(10)
+                    .then()
+                    .command_ground_air_conditioning_unit(
+                        true,
+                        ThermodynamicTemperature::new::<degree_celsius>(5.),
+                    )
+                    .iterate(1000);
+
+                assert!(
+                    test_bed.cabin_temperature()
+                        < ThermodynamicTemperature::new::<degree_celsius>(30.)
+                );
+            }
+        }
+
         mod trim_air_tests {
             use super::*;
 
@@ -5185,6 +5302,32 @@ mod tests {
                 assert!(!test_bed.bulk_isolation_valves_are_open());
             }
 
+            #[test]
+            fn isolation_valves_close_when_cargo_smoke_is_detected() {
+                let mut test_bed = test_bed()
+                    .command_fwd_isolation_valves_pb_on(true)
+                    .command_bulk_isolation_valves_pb_on(true)
+                    .iterate(5);
+
+                assert!(test_bed.fwd_isolation_valves_are_open());
+                assert!(test_bed.bulk_isolation_valves_are_open());
+
+                test_bed = test_bed
+                    .command_cargo_smoke_detected(FireDetectionZone::BulkCargo, true)
+                    .iterate(5);
+
+                assert!(test_bed.fwd_isolation_valves_are_open());
+                assert!(!test_bed.bulk_isolation_valves_are_open());
+                assert!(!test_bed.bulk_extraction_fan_is_on());
+
+                test_bed = test_bed
+                    .command_cargo_smoke_detected(FireDetectionZone::ForwardCargo, true)
+                    .iterate(5);
+
+                assert!(!test_bed.fwd_isolation_valves_are_open());
+                assert!(!test_bed.fwd_extraction_fan_is_on());
+            }
+
             #[test]
             fn bulk_heater_allowed_on_when_conditions_met() {
                 let mut test_bed = test_bed()
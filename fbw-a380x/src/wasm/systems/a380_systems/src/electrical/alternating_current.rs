@@ -2,6 +2,7 @@ use super::{
     A380AlternatingCurrentElectricalSystem, A380DirectCurrentElectricalSystem,
     A380ElectricalOverheadPanel,
 };
+use std::time::Duration;
 use systems::accept_iterable;
 use systems::shared::AdirsDiscreteOutputs;
 use systems::{
@@ -209,6 +210,10 @@ impl A380AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn any_engine_generator_overloaded(&self) -> bool {
+        self.main_power_sources.any_engine_generator_overloaded()
+    }
+
     pub fn gen_drive_connected(&self, number: usize) -> bool {
         self.main_power_sources.gen_drive_connected(number)
     }
@@ -384,6 +389,11 @@ impl A380MainPowerSources {
         ],
     ];
 
+    /// The engine generators are variable frequency generators which cannot be paralleled.
+    /// When the source powering an AC bus changes, the bus is unpowered for the time it
+    /// takes the new source's contactor to close.
+    pub const CONTACTOR_CLOSING_TIME: Duration = Duration::from_millis(50);
+
     fn new(context: &mut InitContext) -> Self {
         A380MainPowerSources {
             engine_gens: [1, 2, 3, 4].map(|i| {
@@ -394,14 +404,21 @@ impl A380MainPowerSources {
                     360.0..=800.0,
                 )
             }),
-            engine_generator_contactors: [1, 2, 3, 4]
-                .map(|id| Contactor::new(context, &format!("990XU{id}"))),
+            engine_generator_contactors: [1, 2, 3, 4].map(|id| {
+                Contactor::new(context, &format!("990XU{id}"))
+                    .with_closing_time(Self::CONTACTOR_CLOSING_TIME)
+            }),
             bus_tie_contactors: [1, 2, 3, 4, 5, 6]
                 .map(|id| Contactor::new(context, &format!("980XU{id}"))),
             system_isolation_contactor: Contactor::new(context, "900XU"),
-            apu_gen_contactors: [1, 2].map(|id| Contactor::new(context, &format!("990XS{id}"))),
-            ext_pwr_contactors: [1, 2, 3, 4]
-                .map(|id| Contactor::new(context, &format!("990XG{id}"))),
+            apu_gen_contactors: [1, 2].map(|id| {
+                Contactor::new(context, &format!("990XS{id}"))
+                    .with_closing_time(Self::CONTACTOR_CLOSING_TIME)
+            }),
+            ext_pwr_contactors: [1, 2, 3, 4].map(|id| {
+                Contactor::new(context, &format!("990XG{id}"))
+                    .with_closing_time(Self::CONTACTOR_CLOSING_TIME)
+            }),
         }
     }
 
@@ -442,11 +459,14 @@ impl A380MainPowerSources {
             .enumerate()
         {
             let bus_number = i + 1;
-            gen_contactor.close_when(matches!(power_source, Some(ACBusPowerSource::Generator)));
-            ext_pwr_contactor.close_when(matches!(
-                power_source,
-                Some(ACBusPowerSource::ExternalPower)
-            ));
+            gen_contactor.close_after_closing_time_when(
+                context,
+                matches!(power_source, Some(ACBusPowerSource::Generator)),
+            );
+            ext_pwr_contactor.close_after_closing_time_when(
+                context,
+                matches!(power_source, Some(ACBusPowerSource::ExternalPower)),
+            );
             let bus_not_self_powered = !matches!(
                 power_source,
                 Some(ACBusPowerSource::Generator) | Some(ACBusPowerSource::ExternalPower) | None
@@ -488,7 +508,8 @@ impl A380MainPowerSources {
 
         for (i, contactor) in self.apu_gen_contactors.iter_mut().enumerate() {
             let apu_gen = i + 1;
-            contactor.close_when(
+            contactor.close_after_closing_time_when(
+                context,
                 powered_by.iter().any(
                     |pb| matches!(*pb, Some(ACBusPowerSource::APUGenerator(n)) if n == apu_gen),
                 ),
@@ -640,6 +661,10 @@ impl A380MainPowerSources {
     fn gen_drive_connected(&self, number: usize) -> bool {
         self.engine_gens[number - 1].is_drive_connected()
     }
+
+    fn any_engine_generator_overloaded(&self) -> bool {
+        self.engine_gens.iter().any(|gen| gen.is_overloaded())
+    }
 }
 impl SimulationElement for A380MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
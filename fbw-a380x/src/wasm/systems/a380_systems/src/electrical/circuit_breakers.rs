@@ -0,0 +1,65 @@
+use systems::{electrical::CircuitBreakerDefinition, shared::ElectricalBusType};
+
+/// The primary electrical power distribution centre in the main avionics compartment.
+const PRIMARY_DISTRIBUTION_CENTRE: &str = "PEPDC";
+/// The secondary electrical power distribution centre in the upper avionics compartment.
+const SECONDARY_DISTRIBUTION_CENTRE: &str = "SEPDC";
+
+/// LGCIU 1.
+pub(crate) const LGCIU_1: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "LGCIU_1",
+    PRIMARY_DISTRIBUTION_CENTRE,
+    ElectricalBusType::DirectCurrentEssential,
+    5.,
+);
+
+/// LGCIU 2.
+pub(crate) const LGCIU_2: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "LGCIU_2",
+    SECONDARY_DISTRIBUTION_CENTRE,
+    ElectricalBusType::DirectCurrentGndFltService,
+    5.,
+);
+
+/// RA 1.
+pub(crate) const RADIO_ALTIMETER_1: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "RA_1",
+    SECONDARY_DISTRIBUTION_CENTRE,
+    ElectricalBusType::AlternatingCurrent(1),
+    3.,
+);
+
+/// RA 2.
+pub(crate) const RADIO_ALTIMETER_2: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "RA_2",
+    SECONDARY_DISTRIBUTION_CENTRE,
+    ElectricalBusType::AlternatingCurrent(2),
+    3.,
+);
+
+/// RA 3.
+pub(crate) const RADIO_ALTIMETER_3: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "RA_3",
+    PRIMARY_DISTRIBUTION_CENTRE,
+    ElectricalBusType::AlternatingCurrentEssential,
+    3.,
+);
+
+/// EGPWC.
+pub(crate) const EGPWC: CircuitBreakerDefinition = CircuitBreakerDefinition::new(
+    "EGPWC",
+    PRIMARY_DISTRIBUTION_CENTRE,
+    ElectricalBusType::AlternatingCurrentEssential,
+    3.,
+);
+
+/// The circuit breakers which are simulated, such that pulling them
+/// de-powers the computer they protect.
+pub(super) const A380_CIRCUIT_BREAKERS: [CircuitBreakerDefinition; 6] = [
+    LGCIU_1,
+    LGCIU_2,
+    RADIO_ALTIMETER_1,
+    RADIO_ALTIMETER_2,
+    RADIO_ALTIMETER_3,
+    EGPWC,
+];
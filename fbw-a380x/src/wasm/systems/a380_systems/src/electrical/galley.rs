@@ -3,10 +3,14 @@ use systems::electrical::{AlternatingCurrentElectricalSystem, Electricity};
 
 pub(super) struct MainGalley {
     is_shed: bool,
+    is_shed_by_overload: bool,
 }
 impl MainGalley {
     pub fn new() -> Self {
-        Self { is_shed: false }
+        Self {
+            is_shed: false,
+            is_shed_by_overload: false,
+        }
     }
 
     pub fn is_shed(&self) -> bool {
@@ -19,8 +23,17 @@ impl MainGalley {
         alternating_current: &A380AlternatingCurrentElectrical,
         overhead: &A380ElectricalOverheadPanel,
     ) {
+        // Shedding due to a generator overload is latched, as the overload disappears
+        // once the galley is shed. It is reset by switching the GALY & CAB pb off.
+        if alternating_current.any_engine_generator_overloaded() {
+            self.is_shed_by_overload = true;
+        } else if overhead.galy_and_cab_is_off() {
+            self.is_shed_by_overload = false;
+        }
+
         self.is_shed = !alternating_current.any_non_essential_bus_powered(electricity)
             || alternating_current.main_ac_buses_powered_by_two_generators_only(electricity)
+            || self.is_shed_by_overload
             || overhead.commercial_is_off()
             || overhead.galy_and_cab_is_off();
     }
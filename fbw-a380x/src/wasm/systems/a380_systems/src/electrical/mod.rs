@@ -1,12 +1,17 @@
 mod alternating_current;
+mod circuit_breakers;
 mod direct_current;
 mod galley;
 
 use self::{
     alternating_current::A380AlternatingCurrentElectrical,
+    circuit_breakers::A380_CIRCUIT_BREAKERS,
     direct_current::A380DirectCurrentElectrical,
     galley::{MainGalley, SecondaryGalley},
 };
+pub(super) use circuit_breakers::{
+    EGPWC, LGCIU_1, LGCIU_2, RADIO_ALTIMETER_1, RADIO_ALTIMETER_2, RADIO_ALTIMETER_3,
+};
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
 use uom::si::{angular_velocity::revolution_per_minute, f64::*, ratio::percent};
@@ -18,9 +23,10 @@ use std::time::Duration;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-        EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource,
-        GeneratorControlUnit, RamAirTurbine, StaticInverter, TransformerRectifier,
+        AlternatingCurrentElectricalSystem, BatteryPushButtons, CircuitBreakerPanel,
+        ElectricalElement, Electricity, EmergencyElectrical, EmergencyGenerator,
+        EngineGeneratorPushButtons, ExternalPowerSource, GeneratorControlUnit, RamAirTurbine,
+        StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -56,6 +62,8 @@ pub(super) struct A380Electrical {
     rat_controller: A380RamAirTurbineController,
     tefo_condition: LatchedTrueLogicGate,
     emer_config: LatchedTrueLogicGate,
+
+    circuit_breakers: CircuitBreakerPanel,
 }
 impl A380Electrical {
     const MIN_EMERGENCY_GENERATOR_RPM_TO_ALLOW_CURRENT_SUPPLY: f64 = 2000.;
@@ -92,6 +100,8 @@ impl A380Electrical {
             ),
             tefo_condition: LatchedTrueLogicGate::default(),
             emer_config: LatchedTrueLogicGate::default(),
+
+            circuit_breakers: CircuitBreakerPanel::new(context, &A380_CIRCUIT_BREAKERS),
         }
     }
 
@@ -213,6 +223,8 @@ impl A380Electrical {
             flt_condition,
             self.emer_config.output(),
         );
+
+        self.circuit_breakers.update(electricity);
     }
 
     fn emergency_generator_contactor_is_closed(&self) -> bool {
@@ -307,6 +319,7 @@ impl SimulationElement for A380Electrical {
         self.emergency_gen.accept(visitor);
         self.ram_air_turbine.accept(visitor);
         self.rat_controller.accept(visitor);
+        self.circuit_breakers.accept(visitor);
 
         visitor.visit(self);
     }
@@ -572,9 +585,10 @@ mod a380_electrical_circuit_tests {
     use systems::{
         apu::ApuGenerator,
         electrical::{
-            ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-            Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            CircuitBreakerDefinition, ElectricalElement, ElectricalElementIdentifier,
+            ElectricalElementIdentifierProvider, Electricity, ElectricitySource,
+            ExternalPowerSource, Potential, ProvideFrequency, ProvidePotential,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
         },
         failures::FailureType,
         shared::{
@@ -3123,6 +3137,27 @@ mod a380_electrical_circuit_tests {
         assert!(test_bed.dc_named_bus_output("108PH").is_unpowered());
     }
 
+    #[rstest]
+    #[case(LGCIU_2)]
+    #[case(RADIO_ALTIMETER_3)]
+    #[case(EGPWC)]
+    fn pulling_a_circuit_breaker_depowers_only_its_computer(
+        #[case] circuit_breaker: CircuitBreakerDefinition,
+    ) {
+        let test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .pulled_circuit_breaker(circuit_breaker)
+            .run();
+
+        for definition in A380_CIRCUIT_BREAKERS {
+            assert_eq!(
+                test_bed.circuit_breaker_bus_output(definition).is_powered(),
+                definition.id() != circuit_breaker.id()
+            );
+        }
+    }
+
     fn test_bed_with() -> A380ElectricalTestBed {
         test_bed()
     }
@@ -3791,6 +3826,11 @@ mod a380_electrical_circuit_tests {
             self
         }
 
+        fn pulled_circuit_breaker(mut self, definition: CircuitBreakerDefinition) -> Self {
+            self.write_by_name(&format!("ELEC_CB_{}_IS_PULLED", definition.id()), true);
+            self
+        }
+
         fn ext_pwr_on(self, number: usize) -> Self {
             self.ext_pwr(number, true)
         }
@@ -3982,6 +4022,13 @@ mod a380_electrical_circuit_tests {
             })
         }
 
+        fn circuit_breaker_bus_output(
+            &'_ self,
+            definition: CircuitBreakerDefinition,
+        ) -> Ref<'_, Potential> {
+            self.query_elec_ref(|_, elec| elec.potential_of(definition.bus_type()))
+        }
+
         fn dc_gnd_flt_service_bus_output(&'_ self) -> Ref<'_, Potential> {
             self.query_elec_ref(|_, elec| {
                 elec.potential_of(ElectricalBusType::DirectCurrentGndFltService)
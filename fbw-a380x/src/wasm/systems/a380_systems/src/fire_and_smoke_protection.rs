@@ -2,12 +2,16 @@ use std::time::Duration;
 
 use systems::{
     accept_iterable,
-    failures::{Failure, FailureType},
-    overhead::{FirePushButton, MomentaryPushButton},
+    fire_protection::{
+        cargo_fire_extinguishing::CargoFireExtinguishingSystem,
+        smoke_detection::{SmokeDetectionControlUnit, SmokeGenerationModule},
+        ExtinguishingAgentBottle, FireDetectionUnit, SetOnFireModule,
+    },
+    overhead::FirePushButton,
     shared::{
         arinc429::{Arinc429Word, SignStatus},
-        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineFirePushButtons,
-        FireDetectionLoopID, FireDetectionZone, LgciuWeightOnWheels,
+        DelayedTrueLogicGate, ElectricalBusType, EngineFirePushButtons, FireDetectionLoopID,
+        FireDetectionZone, LgciuWeightOnWheels, SmokeDetection,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -15,20 +19,44 @@ use systems::{
     },
 };
 
-use std::iter::zip;
+const FIRE_DETECTION_ZONES: [FireDetectionZone; 6] = [
+    FireDetectionZone::Engine(1),
+    FireDetectionZone::Engine(2),
+    FireDetectionZone::Engine(3),
+    FireDetectionZone::Engine(4),
+    FireDetectionZone::Apu,
+    FireDetectionZone::Mlg,
+];
+
+const SMOKE_DETECTION_ZONES: [FireDetectionZone; 5] = [
+    FireDetectionZone::ForwardCargo,
+    FireDetectionZone::AftCargo,
+    FireDetectionZone::BulkCargo,
+    FireDetectionZone::Lavatory,
+    FireDetectionZone::Avionics,
+];
+
+const CARGO_COMPARTMENTS: [FireDetectionZone; 3] = [
+    FireDetectionZone::ForwardCargo,
+    FireDetectionZone::AftCargo,
+    FireDetectionZone::BulkCargo,
+];
 
 pub(super) struct A380FireAndSmokeProtection {
     a380_fire_protection_system: FireProtectionSystem,
-    // a380_smoke_detection_function
-    set_zone_on_fire: SetOnFireModule,
+    a380_smoke_detection_function: SmokeDetectionFunction,
+    set_zone_on_fire: SetOnFireModule<6>,
+    generate_smoke: SmokeGenerationModule<5>,
 }
 
 impl A380FireAndSmokeProtection {
     pub(super) fn new(context: &mut InitContext) -> Self {
         Self {
             a380_fire_protection_system: FireProtectionSystem::new(context),
+            a380_smoke_detection_function: SmokeDetectionFunction::new(context),
 
-            set_zone_on_fire: SetOnFireModule::new(context),
+            set_zone_on_fire: SetOnFireModule::new(context, FIRE_DETECTION_ZONES),
+            generate_smoke: SmokeGenerationModule::new(context, SMOKE_DETECTION_ZONES),
         }
     }
 
@@ -41,8 +69,14 @@ impl A380FireAndSmokeProtection {
         self.a380_fire_protection_system
             .update(context, engine_fire_push_buttons, lgciu);
 
+        self.a380_smoke_detection_function.update(context);
+
         self.set_zone_on_fire
-            .update(self.a380_fire_protection_system.bottle_discharge());
+            .update(self.a380_fire_protection_system.bottles_discharged());
+        self.generate_smoke.update(
+            context,
+            self.a380_smoke_detection_function.agent_is_present(),
+        );
     }
 
     pub fn apu_fire_on_ground(&self) -> bool {
@@ -50,90 +84,102 @@ impl A380FireAndSmokeProtection {
     }
 }
 
+impl SmokeDetection for A380FireAndSmokeProtection {
+    fn smoke_detected(&self, zone: FireDetectionZone) -> bool {
+        self.a380_smoke_detection_function.smoke_detected(zone)
+    }
+}
+
 impl SimulationElement for A380FireAndSmokeProtection {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a380_fire_protection_system.accept(visitor);
+        self.a380_smoke_detection_function.accept(visitor);
         self.set_zone_on_fire.accept(visitor);
+        self.generate_smoke.accept(visitor);
 
         visitor.visit(self);
     }
 }
 
-struct FireProtectionSystem {
-    fire_detection_unit: FireDetectionUnit,
-    fire_extinguishing_system: FireExtinguishingSystem,
-
-    fire_test_pushbutton_id: VariableIdentifier,
-    fire_test_pushbutton_is_pressed: bool,
-    fire_test_pushbutton_signal: DelayedTrueLogicGate,
+/// Smoke detection in the cargo compartments, lavatories and avionics bay, with the cargo fire
+/// extinguishing system. The cargo smoke detection is given to the ventilation controllers to
+/// close the cargo isolation valves.
+struct SmokeDetectionFunction {
+    smoke_detection_control_unit: SmokeDetectionControlUnit<5>,
+    cargo_fire_extinguishing_system: CargoFireExtinguishingSystem<3>,
+    cargo_smoke_test_pushbutton_id: VariableIdentifier,
+    cargo_smoke_test_pushbutton_is_pressed: bool,
 }
 
-impl FireProtectionSystem {
-    const DELAY_FIRE_TEST_MILLIS: Duration = Duration::from_millis(500);
-
+impl SmokeDetectionFunction {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            fire_detection_unit: FireDetectionUnit::new(context),
-            fire_extinguishing_system: FireExtinguishingSystem::new(context),
-
-            fire_test_pushbutton_id: context
-                .get_identifier("OVHD_FIRE_TEST_PB_IS_PRESSED".to_owned()),
-            fire_test_pushbutton_is_pressed: false,
-            fire_test_pushbutton_signal: DelayedTrueLogicGate::new(Self::DELAY_FIRE_TEST_MILLIS),
+            smoke_detection_control_unit: SmokeDetectionControlUnit::new(
+                context,
+                SMOKE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            cargo_fire_extinguishing_system: CargoFireExtinguishingSystem::new(
+                context,
+                CARGO_COMPARTMENTS,
+                [
+                    ElectricalBusType::DirectCurrentHot(1),
+                    ElectricalBusType::DirectCurrentEssential,
+                ],
+            ),
+            cargo_smoke_test_pushbutton_id: context.get_identifier("FIRE_TEST_CARGO".to_owned()),
+            cargo_smoke_test_pushbutton_is_pressed: false,
         }
     }
 
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        engine_fire_push_buttons: &impl EngineFirePushButtons,
-        lgciu: [&impl LgciuWeightOnWheels; 2],
-    ) {
-        // We add a delay between button press and response based on references
-        self.fire_test_pushbutton_signal
-            .update(context, self.fire_test_pushbutton_is_pressed);
-        self.fire_detection_unit
-            .update(context, self.fire_test_pushbutton_signal.output(), lgciu);
-        self.fire_extinguishing_system.update(
-            context,
-            engine_fire_push_buttons,
-            self.fire_test_pushbutton_signal.output(),
-            self.fire_detection_unit.should_extinguish_apu_fire(),
-        )
+    fn update(&mut self, context: &UpdateContext) {
+        // The CARGO SMOKE TEST pushbutton only tests the cargo compartment detectors
+        let cargo_test = self.cargo_smoke_test_pushbutton_is_pressed;
+        self.smoke_detection_control_unit
+            .update([cargo_test, cargo_test, cargo_test, false, false]);
+        self.cargo_fire_extinguishing_system.update(context);
     }
 
-    fn apu_fire_on_ground(&self) -> bool {
-        self.fire_detection_unit.apu_fire_on_ground()
+    /// Whether fire suppression agent is present in each of the smoke detection zones.
+    /// Only the cargo compartments have a fire extinguishing system
+    fn agent_is_present(&self) -> [bool; 5] {
+        let [fwd_cargo, aft_cargo, bulk_cargo] =
+            self.cargo_fire_extinguishing_system.agent_is_present();
+
+        [fwd_cargo, aft_cargo, bulk_cargo, false, false]
     }
+}
 
-    fn bottle_discharge(&self) -> [bool; 9] {
-        self.fire_extinguishing_system.bottle_discharge()
+impl SmokeDetection for SmokeDetectionFunction {
+    fn smoke_detected(&self, zone: FireDetectionZone) -> bool {
+        self.smoke_detection_control_unit.smoke_detected(zone)
     }
 }
 
-impl SimulationElement for FireProtectionSystem {
+impl SimulationElement for SmokeDetectionFunction {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.fire_test_pushbutton_is_pressed = reader.read(&self.fire_test_pushbutton_id);
+        self.cargo_smoke_test_pushbutton_is_pressed =
+            reader.read(&self.cargo_smoke_test_pushbutton_id);
     }
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.fire_detection_unit.accept(visitor);
-        self.fire_extinguishing_system.accept(visitor);
+        self.smoke_detection_control_unit.accept(visitor);
+        self.cargo_fire_extinguishing_system.accept(visitor);
 
         visitor.visit(self);
     }
 }
 
-struct FireDetectionUnit {
-    fire_detection_loop: [FireDetectionLoop; 2],
-
-    fire_detected_id: [VariableIdentifier; 6],
+struct FireProtectionSystem {
+    fire_detection_unit: FireDetectionUnit<6>,
+    fire_extinguishing_system: FireExtinguishingSystem,
 
-    fire_detected: [bool; 6],
-    fire_detection_zones: [FireDetectionZone; 6],
-    interval_between_loop_failures: [Duration; 6],
-    apu_fire_on_ground: bool,
-    should_extinguish_apu_fire: DelayedTrueLogicGate,
+    fire_test_pushbutton_id: VariableIdentifier,
+    fire_test_pushbutton_is_pressed: bool,
+    fire_test_pushbutton_signal: DelayedTrueLogicGate,
 
     // The FDU sends discrete signals to the overhead panel and arinc signals to the FWS
     // Fixme: We assume a discrete word is sent, validate with references
@@ -141,147 +187,61 @@ struct FireDetectionUnit {
     discrete_word: Arinc429Word<u32>,
 }
 
-impl FireDetectionUnit {
-    const DELAY_APU_FIRE_EXTINGUISHING: Duration = Duration::from_secs(10);
+impl FireProtectionSystem {
+    const DELAY_FIRE_TEST_MILLIS: Duration = Duration::from_millis(500);
 
     fn new(context: &mut InitContext) -> Self {
-        let fire_detection_zones = [
-            FireDetectionZone::Engine(1),
-            FireDetectionZone::Engine(2),
-            FireDetectionZone::Engine(3),
-            FireDetectionZone::Engine(4),
-            FireDetectionZone::Apu,
-            FireDetectionZone::Mlg,
-        ];
-
         Self {
-            fire_detection_loop: [
-                FireDetectionLoop::new(
-                    context,
-                    FireDetectionLoopID::A,
-                    &fire_detection_zones,
+            fire_detection_unit: FireDetectionUnit::new(
+                context,
+                FIRE_DETECTION_ZONES,
+                [
                     ElectricalBusType::DirectCurrentEssential,
-                ),
-                FireDetectionLoop::new(
-                    context,
-                    FireDetectionLoopID::B,
-                    &fire_detection_zones,
                     ElectricalBusType::DirectCurrent(2),
-                ),
-            ],
-
-            fire_detected_id: fire_detection_zones.map(|zone| Self::init_identifier(context, zone)),
-
-            fire_detected: [false; 6],
-            fire_detection_zones,
-            interval_between_loop_failures: [Duration::ZERO; 6],
-            apu_fire_on_ground: false,
-            should_extinguish_apu_fire: DelayedTrueLogicGate::new(
-                Self::DELAY_APU_FIRE_EXTINGUISHING,
+                ],
             ),
+            fire_extinguishing_system: FireExtinguishingSystem::new(context),
+
+            fire_test_pushbutton_id: context
+                .get_identifier("OVHD_FIRE_TEST_PB_IS_PRESSED".to_owned()),
+            fire_test_pushbutton_is_pressed: false,
+            fire_test_pushbutton_signal: DelayedTrueLogicGate::new(Self::DELAY_FIRE_TEST_MILLIS),
 
             discrete_word_id: context.get_identifier("FIRE_FDU_DISCRETE_WORD".to_owned()),
             discrete_word: Arinc429Word::new(0, SignStatus::NoComputedData),
         }
     }
 
-    fn init_identifier(
-        context: &mut InitContext,
-        zone_id: FireDetectionZone,
-    ) -> VariableIdentifier {
-        if matches!(zone_id, FireDetectionZone::Engine(_)) {
-            context.get_identifier(format!("FIRE_DETECTED_ENG{}", zone_id))
-        } else {
-            context.get_identifier(format!("FIRE_DETECTED_{}", zone_id))
-        }
-    }
-
     fn update(
         &mut self,
         context: &UpdateContext,
-        fire_test_pushbutton_is_pressed: bool,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
-        self.interval_between_loop_failures = self.calculate_interval_between_failures(context);
-
-        self.fire_detected = self.fire_detection_determination(fire_test_pushbutton_is_pressed);
-
-        self.fire_detection_loop
-            .iter_mut()
-            .for_each(|l| l.update_was_powered());
-
-        // If a fire is detected in the APU while the aircraft is on the ground, the extinguishim system is automatically activated after a delay
-        self.apu_fire_on_ground = self.fire_detected[4]
-            && !fire_test_pushbutton_is_pressed
-            && lgciu.iter().all(|a| a.left_and_right_gear_compressed(true));
-        self.should_extinguish_apu_fire
-            .update(context, self.apu_fire_on_ground);
+        // We add a delay between button press and response based on references
+        self.fire_test_pushbutton_signal
+            .update(context, self.fire_test_pushbutton_is_pressed);
+        self.fire_detection_unit.update(
+            context,
+            [self.fire_test_pushbutton_signal.output(); 6],
+            lgciu,
+        );
+        self.fire_extinguishing_system.update(
+            context,
+            engine_fire_push_buttons,
+            self.fire_test_pushbutton_signal.output(),
+            self.fire_detection_unit.should_extinguish_apu_fire(),
+        );
 
         self.update_discrete_word();
     }
 
-    fn fire_detection_determination(&self, fire_test_pb: bool) -> [bool; 6] {
-        let mut fire_detected = [false; 6];
-        for ((&zone, &interval_between_loop_failures), fire_detected) in self
-            .fire_detection_zones
-            .iter()
-            .zip(&self.interval_between_loop_failures)
-            .zip(&mut fire_detected)
-        {
-            *fire_detected = (self.fire_detection_loop[0]
-                .fire_detected_in_loop(zone, fire_test_pb)
-                && self.fire_detection_loop[1].fire_detected_in_loop(zone, fire_test_pb))
-                || (self
-                    .fire_detection_loop
-                    .iter()
-                    .any(|l| l.fire_detected_in_loop(zone, fire_test_pb))
-                    && self
-                        .fire_detection_loop
-                        .iter()
-                        .any(|l| l.loop_has_failed(zone)))
-                || (self
-                    .fire_detection_loop
-                    .iter()
-                    .all(|l| l.loop_has_failed(zone))
-                    && interval_between_loop_failures < Duration::from_secs(5)
-                    && zone != FireDetectionZone::Mlg);
-        }
-        fire_detected
-    }
-
-    fn calculate_interval_between_failures(&self, context: &UpdateContext) -> [Duration; 6] {
-        let mut interval = [Duration::ZERO; 6];
-        for ((&zone, &interval_between_loop_failures), interval) in self
-            .fire_detection_zones
-            .iter()
-            .zip(&self.interval_between_loop_failures)
-            .zip(&mut interval)
-        {
-            *interval = if self
-                .fire_detection_loop
-                .iter()
-                .all(|l| !l.loop_has_failed(zone))
-            {
-                Duration::ZERO
-            } else if self
-                .fire_detection_loop
-                .iter()
-                .all(|l| l.loop_has_failed(zone))
-            {
-                interval_between_loop_failures
-            } else {
-                interval_between_loop_failures + context.delta()
-            }
-        }
-        interval
-    }
-
-    fn should_extinguish_apu_fire(&self) -> bool {
-        self.should_extinguish_apu_fire.output()
+    fn apu_fire_on_ground(&self) -> bool {
+        self.fire_detection_unit.apu_fire_on_ground()
     }
 
-    fn apu_fire_on_ground(&self) -> bool {
-        self.apu_fire_on_ground
+    fn bottles_discharged(&self) -> [usize; 6] {
+        self.fire_extinguishing_system.bottles_discharged()
     }
 
     fn update_discrete_word(&mut self) {
@@ -289,207 +249,41 @@ impl FireDetectionUnit {
         self.discrete_word = Arinc429Word::new(0, SignStatus::NormalOperation);
 
         // Fixme: The bit order is assumed as no references
-        self.discrete_word.set_bit(11, self.fire_detected[0]); // FIRE ENG 1
-        self.discrete_word.set_bit(12, self.fire_detected[1]); // FIRE ENG 2
-        self.discrete_word.set_bit(13, self.fire_detected[2]); // FIRE ENG 3
-        self.discrete_word.set_bit(14, self.fire_detected[3]); // FIRE ENG 4
-        self.discrete_word.set_bit(15, self.fire_detected[4]); // FIRE APU
-        self.discrete_word.set_bit(16, self.fire_detected[5]); // FIRE MLG
-        self.discrete_word.set_bit(
-            18,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(1)),
-        ); // ENG 1 LOOP A has failed
-        self.discrete_word.set_bit(
-            19,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(1)),
-        ); // ENG 1 LOOP B has failed
-        self.discrete_word.set_bit(
-            20,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(2)),
-        ); // ENG 2 LOOP A has failed
-        self.discrete_word.set_bit(
-            21,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(2)),
-        ); // ENG 2 LOOP B has failed
-        self.discrete_word.set_bit(
-            22,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(3)),
-        ); // ENG 3 LOOP A has failed
-        self.discrete_word.set_bit(
-            23,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(3)),
-        ); // ENG 3 LOOP B has failed
-        self.discrete_word.set_bit(
-            24,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(4)),
-        ); // ENG 4 LOOP A has failed
-        self.discrete_word.set_bit(
-            25,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(4)),
-        ); // ENG 4 LOOP B has failed
-        self.discrete_word.set_bit(
-            26,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Apu),
-        ); // APU LOOP A has failed
-        self.discrete_word.set_bit(
-            27,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Apu),
-        ); // APU LOOP B has failed
-        self.discrete_word.set_bit(
-            28,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Mlg),
-        ); // MLG LOOP A has failed
-        self.discrete_word.set_bit(
-            29,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Mlg),
-        ); // MLG LOOP B has failed
-    }
-}
-
-impl SimulationElement for FireDetectionUnit {
-    fn write(&self, writer: &mut SimulatorWriter) {
-        for (id, fire_detected) in self.fire_detected_id.iter().zip(self.fire_detected) {
-            writer.write(id, fire_detected);
+        // Bits 11 to 16: FIRE ENG 1 to 4, FIRE APU and FIRE MLG
+        for (bit, &zone) in (11..).zip(FIRE_DETECTION_ZONES.iter()) {
+            self.discrete_word
+                .set_bit(bit, self.fire_detection_unit.fire_detected(zone));
+        }
+        // Bits 18 to 29: LOOP A and LOOP B failures of ENG 1 to 4, APU and MLG
+        for (bit, &zone) in (18..).step_by(2).zip(FIRE_DETECTION_ZONES.iter()) {
+            self.discrete_word.set_bit(
+                bit,
+                self.fire_detection_unit
+                    .loop_has_failed(FireDetectionLoopID::A, zone),
+            );
+            self.discrete_word.set_bit(
+                bit + 1,
+                self.fire_detection_unit
+                    .loop_has_failed(FireDetectionLoopID::B, zone),
+            );
         }
-        writer.write(&self.discrete_word_id, self.discrete_word);
-    }
-
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.fire_detection_loop, visitor);
-
-        visitor.visit(self);
     }
 }
 
-struct FireDetectionLoop {
-    loop_id: FireDetectionLoopID,
-    powered_by: ElectricalBusType,
-    is_powered: bool,
-    was_powered_before: bool,
-    failures: [Failure; 6],
-
-    fire_detectors: [FireDetector; 6],
-}
-
-impl FireDetectionLoop {
-    fn new(
-        context: &mut InitContext,
-        loop_id: FireDetectionLoopID,
-        fire_detection_zones: &[FireDetectionZone; 6],
-        powered_by: ElectricalBusType,
-    ) -> Self {
-        Self {
-            loop_id,
-            powered_by,
-            is_powered: false,
-            was_powered_before: false,
-            failures: fire_detection_zones
-                .map(|zone| Failure::new(FailureType::FireDetectionLoop(loop_id, zone))),
-
-            fire_detectors: fire_detection_zones.map(|zone| FireDetector::new(context, zone)),
-        }
-    }
-
-    fn fire_detected_in_loop(
-        &self,
-        fire_detection_zone: FireDetectionZone,
-        fire_test_pushbutton_is_pressed: bool,
-    ) -> bool {
-        let failure = self
-            .failures
-            .iter()
-            .find(|&f| {
-                f.failure_type()
-                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
-            })
-            .unwrap();
-
-        !failure.is_active()
-            && self.is_powered
-            && (self
-                .fire_detectors
-                .iter()
-                .find(|detector| fire_detection_zone == detector.zone_id())
-                .unwrap()
-                .fire_detected()
-                || fire_test_pushbutton_is_pressed)
-    }
-
-    fn loop_has_failed(&self, fire_detection_zone: FireDetectionZone) -> bool {
-        let failure = self
-            .failures
-            .iter()
-            .find(|&f| {
-                f.failure_type()
-                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
-            })
-            .unwrap();
-
-        failure.is_active() || (!self.is_powered && self.was_powered_before)
+impl SimulationElement for FireProtectionSystem {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.fire_test_pushbutton_is_pressed = reader.read(&self.fire_test_pushbutton_id);
     }
 
-    /// This is to avoid a fire detection on initial load
-    fn update_was_powered(&mut self) {
-        self.was_powered_before = self.is_powered
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.discrete_word_id, self.discrete_word);
     }
-}
 
-impl SimulationElement for FireDetectionLoop {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.failures, visitor);
-        accept_iterable!(self.fire_detectors, visitor);
-        visitor.visit(self);
-    }
-
-    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = buses.is_powered(self.powered_by);
-    }
-}
-
-// Electro-pneumatic fire detectors. There are multiple detectors in 3 fire zones per engine, one per pylon, one in the APU and one in the MLG
-// For simplicity here we simulate just one detection zone per engine, when we have deep engine simulation we can modify this accordingly
-struct FireDetector {
-    zone_id: FireDetectionZone,
-
-    fire_detection_id: VariableIdentifier,
-    fire_detected: bool,
-}
-
-impl FireDetector {
-    const ENGINE_ON_FIRE: &'static str = "ENG ON FIRE:";
-
-    fn new(context: &mut InitContext, fire_zone_id: FireDetectionZone) -> Self {
-        Self {
-            zone_id: fire_zone_id,
-
-            fire_detection_id: Self::init_identifier(context, fire_zone_id),
-            fire_detected: false,
-        }
-    }
-
-    fn init_identifier(
-        context: &mut InitContext,
-        zone_id: FireDetectionZone,
-    ) -> VariableIdentifier {
-        if matches!(zone_id, FireDetectionZone::Engine(_)) {
-            context.get_identifier(format!("{}{}", Self::ENGINE_ON_FIRE, zone_id))
-        } else {
-            context.get_identifier(format!("{}_ON_FIRE", zone_id))
-        }
-    }
-
-    fn zone_id(&self) -> FireDetectionZone {
-        self.zone_id
-    }
-
-    fn fire_detected(&self) -> bool {
-        self.fire_detected
-    }
-}
+        self.fire_detection_unit.accept(visitor);
+        self.fire_extinguishing_system.accept(visitor);
 
-impl SimulationElement for FireDetector {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.fire_detected = reader.read(&self.fire_detection_id);
+        visitor.visit(self);
     }
 }
 
@@ -529,46 +323,18 @@ impl FireExtinguishingSystem {
         fire_test_pushbutton_is_pressed: bool,
         should_extinguish_apu_fire: bool,
     ) {
-        self.fire_extinguishing_bottles[..2]
-            .iter_mut()
-            .for_each(|bottle| {
-                bottle.update(
-                    context,
-                    engine_fire_push_buttons.is_released(1),
-                    fire_test_pushbutton_is_pressed,
-                    None,
-                )
-            });
-        self.fire_extinguishing_bottles[2..4]
-            .iter_mut()
-            .for_each(|bottle| {
-                bottle.update(
-                    context,
-                    engine_fire_push_buttons.is_released(2),
-                    fire_test_pushbutton_is_pressed,
-                    None,
-                )
-            });
-        self.fire_extinguishing_bottles[4..6]
-            .iter_mut()
-            .for_each(|bottle| {
-                bottle.update(
-                    context,
-                    engine_fire_push_buttons.is_released(3),
-                    fire_test_pushbutton_is_pressed,
-                    None,
-                )
-            });
-        self.fire_extinguishing_bottles[6..8]
-            .iter_mut()
-            .for_each(|bottle| {
+        for (engine_number, bottles) in
+            (1..).zip(self.fire_extinguishing_bottles[..8].chunks_mut(2))
+        {
+            bottles.iter_mut().for_each(|bottle| {
                 bottle.update(
                     context,
-                    engine_fire_push_buttons.is_released(4),
+                    engine_fire_push_buttons.is_released(engine_number),
                     fire_test_pushbutton_is_pressed,
                     None,
                 )
             });
+        }
         self.fire_extinguishing_bottles[8].update(
             context,
             self.apu_fire_push_button.is_released(),
@@ -577,233 +343,31 @@ impl FireExtinguishingSystem {
         );
     }
 
-    /// The array of 9 bottles represents two bottles for ENG1-4 and one for the APU
-    fn bottle_discharge(&self) -> [bool; 9] {
-        self.fire_extinguishing_bottles
-            .iter()
-            .map(|bottle| bottle.bottle_discharge())
-            .collect::<Vec<bool>>()
-            .try_into()
-            .unwrap_or_else(|v: Vec<bool>| {
-                panic!("Expected a Vec of length {} but it was {}", 9, v.len())
-            })
-    }
-}
-
-impl SimulationElement for FireExtinguishingSystem {
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.apu_fire_push_button.accept(visitor);
-        accept_iterable!(self.fire_extinguishing_bottles, visitor);
-
-        visitor.visit(self);
-    }
-}
-
-/// This struct represents the physical bottle of Halon 1301, and its possible states of armed, disarmed, full and empty
-/// There are two squibs per bottle, but for simplicity we simulate a "single" squib with two possible power sources
-struct ExtinguishingAgentBottle {
-    squib_armed_id: VariableIdentifier,
-    bottle_discharged_id: VariableIdentifier,
-    agent_pb: MomentaryPushButton,
-
-    squib_is_armed: bool,
-    bottle_is_discharged: bool,
-    system_test: bool,
-
-    timer: Duration,
-    powered_by: [ElectricalBusType; 2],
-    is_powered: bool,
-}
-
-impl ExtinguishingAgentBottle {
-    fn new(context: &mut InitContext, id: &str, powered_by: [ElectricalBusType; 2]) -> Self {
-        Self {
-            squib_armed_id: context.get_identifier(format!("FIRE_SQUIB_{}_IS_ARMED", id)),
-            bottle_discharged_id: context
-                .get_identifier(format!("FIRE_SQUIB_{}_IS_DISCHARGED", id)),
-            agent_pb: MomentaryPushButton::new(context, &format!("FIRE_AGENT_{}", id)),
-
-            squib_is_armed: false,
-            bottle_is_discharged: false,
-            system_test: false,
-
-            timer: Duration::ZERO,
-            powered_by,
-            is_powered: false,
-        }
-    }
-
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        engine_fire_push_button_is_pressed: bool,
-        fire_test_pushbutton_is_pressed: bool,
-        should_extinguish_fire: Option<bool>,
-    ) {
-        self.system_test = fire_test_pushbutton_is_pressed && self.is_powered;
-        self.squib_is_armed = self.is_powered && engine_fire_push_button_is_pressed;
-        if self.is_powered
-            && ((self.squib_is_armed || should_extinguish_fire.unwrap_or(false))
-                && self.timer >= Duration::from_secs(1))
-        {
-            // Once the bottle is discharged, it can't be recharged
-            self.bottle_is_discharged = true
-        } else if self.is_powered
-            && (((self.squib_is_armed)
-                && (self.agent_pb.is_pressed() || self.timer > Duration::ZERO))
-                || should_extinguish_fire.unwrap_or(false))
-            && self.timer <= Duration::from_secs(1)
-        {
-            self.timer += context.delta()
-        } else {
-            self.timer = Duration::ZERO
+    /// Number of bottles discharged into each of the ENG1-4, APU and MLG zones.
+    /// Each engine has two bottles, the APU one and the MLG does not have a fire extinguishing system
+    fn bottles_discharged(&self) -> [usize; 6] {
+        let discharged_count = |bottles: &[ExtinguishingAgentBottle]| {
+            bottles.iter().filter(|b| b.bottle_discharge()).count()
         };
-    }
-
-    fn bottle_discharge(&self) -> bool {
-        self.bottle_is_discharged
-    }
-}
-
-impl SimulationElement for ExtinguishingAgentBottle {
-    fn write(&self, writer: &mut SimulatorWriter) {
-        writer.write(
-            &self.squib_armed_id,
-            self.squib_is_armed || self.system_test,
-        );
-        writer.write(
-            &self.bottle_discharged_id,
-            self.bottle_is_discharged || self.system_test,
-        );
-    }
-
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.agent_pb.accept(visitor);
-
-        visitor.visit(self);
-    }
-
-    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = self.powered_by.iter().any(|&p| buses.is_powered(p));
-    }
-}
-
-/// Small module that sets each zone on fire when the failure is triggered. This is independent to the system implementation.
-struct SetOnFireModule {
-    fire_id: [VariableIdentifier; 6],
-
-    fire: [Failure; 6],
-    should_set_zone_on_fire: [bool; 6],
-    should_extinguish_zone: [bool; 6],
-    // We use this to avoid having a previously discharged bottle extinguish a fire
-    bottle_already_discharged: [bool; 9],
-    // We use this to know when to cancel the fire command when the failure is resolved
-    was_on_fire: [bool; 6],
-}
-
-impl SetOnFireModule {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            fire_id: [
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 1)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 2)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 3)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 4)),
-                context.get_identifier("APU_ON_FIRE".to_owned()),
-                context.get_identifier("MLG_ON_FIRE".to_owned()),
-            ],
-
-            fire: [
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(1))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(2))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(3))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(4))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Apu)),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Mlg)),
-            ],
-            should_set_zone_on_fire: [false; 6],
-            should_extinguish_zone: [false; 6],
-            bottle_already_discharged: [false; 9],
-            was_on_fire: [false; 6],
-        }
-    }
 
-    fn update(&mut self, bottle_discharge: [bool; 9]) {
-        for id in 0..6 {
-            self.should_set_zone_on_fire[id] = self.fire[id].is_active()
-                && !self.should_set_zone_on_fire[id]
-                && !self.was_on_fire[id]
-        }
-
-        self.should_extinguish_zone = self.zone_extinguishing_determination(bottle_discharge);
-        self.bottle_already_discharged = bottle_discharge;
-        self.was_on_fire = self
-            .fire
-            .iter()
-            .map(|f| f.is_active())
-            .collect::<Vec<bool>>()
-            .try_into()
-            .unwrap_or_else(|v: Vec<bool>| {
-                panic!("Expected a Vec of length {} but it was {}", 6, v.len())
-            });
-    }
-
-    /// We check any "new" bottle discharges and then add a random factor on whether it should extinguish a fire
-    /// We also use this function to "extinguish" a fire if the user deselects the failure
-    fn zone_extinguishing_determination(&self, bottle_discharge: [bool; 9]) -> [bool; 6] {
         [
-            (zip(
-                bottle_discharge[..2].iter(),
-                self.bottle_already_discharged[..2].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[0] && !self.fire[0].is_active()),
-            (zip(
-                bottle_discharge[2..4].iter(),
-                self.bottle_already_discharged[2..4].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[1] && !self.fire[1].is_active()),
-            (zip(
-                bottle_discharge[4..6].iter(),
-                self.bottle_already_discharged[4..6].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[2] && !self.fire[2].is_active()),
-            (zip(
-                bottle_discharge[6..8].iter(),
-                self.bottle_already_discharged[6..8].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[3] && !self.fire[3].is_active()),
-            (bottle_discharge[8] && !self.bottle_already_discharged[8] && rand::random())
-                || (self.was_on_fire[4] && !self.fire[4].is_active()),
-            // MLG does not have a fire extinguishing system
-            false,
+            discharged_count(&self.fire_extinguishing_bottles[0..2]),
+            discharged_count(&self.fire_extinguishing_bottles[2..4]),
+            discharged_count(&self.fire_extinguishing_bottles[4..6]),
+            discharged_count(&self.fire_extinguishing_bottles[6..8]),
+            discharged_count(&self.fire_extinguishing_bottles[8..9]),
+            0,
         ]
     }
 }
 
-impl SimulationElement for SetOnFireModule {
+impl SimulationElement for FireExtinguishingSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.fire, visitor);
+        self.apu_fire_push_button.accept(visitor);
+        accept_iterable!(self.fire_extinguishing_bottles, visitor);
 
         visitor.visit(self);
     }
-
-    fn write(&self, writer: &mut SimulatorWriter) {
-        for (id, zone) in self.fire_id.iter().enumerate() {
-            if self.should_set_zone_on_fire[id] {
-                writer.write(zone, true)
-            } else if self.should_extinguish_zone[id] {
-                writer.write(zone, false)
-            }
-        }
-    }
 }
 
 #[cfg(test)]
@@ -811,6 +375,7 @@ mod a380_fire_and_smoke_protection_tests {
     use systems::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         engine::EngineFireOverheadPanel,
+        failures::FailureType,
         shared::PotentialOrigin,
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -1119,6 +684,45 @@ mod a380_fire_and_smoke_protection_tests {
         fn squib_apu_is_discharged(&mut self) -> bool {
             self.read_by_name("FIRE_SQUIB_1_APU_1_IS_DISCHARGED")
         }
+
+        fn set_smoke_through_failure(mut self, zone: FireDetectionZone) -> Self {
+            self.fail(FailureType::Smoke(zone));
+            self
+        }
+
+        fn set_smoke_detector_failure(
+            mut self,
+            zone: FireDetectionZone,
+            loop_id: FireDetectionLoopID,
+        ) -> Self {
+            self.fail(FailureType::SmokeDetector(loop_id, zone));
+            self
+        }
+
+        fn set_cargo_smoke_test_pushbutton(mut self, test_pb: bool) -> Self {
+            self.write_by_name("FIRE_TEST_CARGO", test_pb);
+            self
+        }
+
+        fn set_cargo_agent_pb(mut self, zone: &str, pressed: bool) -> Self {
+            self.write_by_name(
+                &format!("OVHD_CARGO_FIRE_AGENT_{}_IS_PRESSED", zone),
+                pressed,
+            );
+            self
+        }
+
+        fn smoke_detected(&mut self, zone: &str) -> bool {
+            self.read_by_name(&format!("SMOKE_DETECTED_{}", zone))
+        }
+
+        fn smoke_detector_fault(&mut self, zone: &str, loop_name: &str) -> bool {
+            self.read_by_name(&format!("SMOKE_{}_DETECTOR_{}_FAULT", zone, loop_name))
+        }
+
+        fn cargo_bottle_is_discharged(&mut self) -> bool {
+            self.read_by_name("CARGO_FIRE_BOTTLE_1_IS_DISCHARGED")
+        }
     }
     impl TestBed for FireProtectionTestBed {
         type Aircraft = TestAircraft;
@@ -1560,4 +1164,69 @@ mod a380_fire_and_smoke_protection_tests {
             );
         }
     }
+
+    mod a380_smoke_detection_tests {
+        use super::*;
+
+        #[test]
+        fn bulk_cargo_smoke_is_detected() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_through_failure(FireDetectionZone::BulkCargo)
+                .and_double_run();
+
+            assert!(test_bed.smoke_detected("BULK_CARGO"));
+            assert!(!test_bed.smoke_detected("FWD_CARGO"));
+            assert!(!test_bed.smoke_detected("AVIONICS"));
+        }
+
+        #[test]
+        fn avionics_smoke_is_detected_with_one_detector_failed() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_detector_failure(FireDetectionZone::Avionics, FireDetectionLoopID::A)
+                .and()
+                .set_smoke_through_failure(FireDetectionZone::Avionics)
+                .and_double_run();
+
+            assert!(test_bed.smoke_detected("AVIONICS"));
+            assert!(test_bed.smoke_detector_fault("AVIONICS", "A"));
+            assert!(!test_bed.smoke_detector_fault("AVIONICS", "B"));
+        }
+
+        #[test]
+        fn cargo_smoke_test_does_not_test_lavatory_and_avionics() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_cargo_smoke_test_pushbutton(true)
+                .and_run();
+
+            assert!(test_bed.smoke_detected("FWD_CARGO"));
+            assert!(test_bed.smoke_detected("AFT_CARGO"));
+            assert!(test_bed.smoke_detected("BULK_CARGO"));
+            assert!(!test_bed.smoke_detected("LAVATORY"));
+            assert!(!test_bed.smoke_detected("AVIONICS"));
+        }
+
+        #[test]
+        fn discharging_cargo_bottle_suppresses_the_smoke() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_through_failure(FireDetectionZone::ForwardCargo)
+                .and_double_run()
+                .then()
+                .set_cargo_agent_pb("FWD_CARGO", true)
+                .and_run()
+                .set_cargo_agent_pb("FWD_CARGO", false);
+
+            assert!(test_bed.smoke_detected("FWD_CARGO"));
+            assert!(test_bed.cargo_bottle_is_discharged());
+
+            for _ in 0..35 {
+                test_bed = test_bed.run_with_delta_of(Duration::from_secs(1));
+            }
+
+            assert!(!test_bed.smoke_detected("FWD_CARGO"));
+        }
+    }
 }
@@ -13,6 +13,7 @@ use serde_with::{serde_as, DisplayFromStr};
 use std::{collections::HashMap, time::Duration};
 use systems::{
     fuel::{self, FuelPayload, RefuelRate},
+    ground_services::GroundServiceProcess,
     payload::LoadsheetInfo,
     pneumatic::EngineState,
     shared::{
@@ -234,6 +235,19 @@ impl IntegratedRefuelPanel {
         self.input.target_zero_fuel_weight_cg_mac()
     }
 }
+impl GroundServiceProcess for IntegratedRefuelPanel {
+    fn start(&mut self) {
+        self.set_refuel_status(true);
+    }
+
+    fn stop(&mut self) {
+        self.set_refuel_status(false);
+    }
+
+    fn is_running(&self) -> bool {
+        self.refuel_status()
+    }
+}
 impl SimulationElement for IntegratedRefuelPanel {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         // TODO: should only be powered when the refuel panel is open
@@ -708,6 +722,14 @@ impl A380FuelQuantityManagementSystem {
         }
     }
 
+    pub(super) fn integrated_refuel_panel(&self) -> &IntegratedRefuelPanel {
+        &self.integrated_refuel_panel
+    }
+
+    pub(super) fn integrated_refuel_panel_mut(&mut self) -> &mut IntegratedRefuelPanel {
+        &mut self.integrated_refuel_panel
+    }
+
     pub(super) fn update(
         &mut self,
         context: &UpdateContext,
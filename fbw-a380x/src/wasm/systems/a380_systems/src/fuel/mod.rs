@@ -12,6 +12,7 @@ use nalgebra::Vector3;
 use systems::{
     accept_iterable,
     fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
+    ground_services::GroundServiceProcess,
     integrated_modular_avionics::AvionicsDataCommunicationNetwork,
     payload::LoadsheetInfo,
     shared::{arinc429::Arinc429Word, ElectricalBusType},
@@ -188,6 +189,25 @@ impl FuelCG for A380Fuel {
         self.fuel_system.center_of_gravity()
     }
 }
+impl GroundServiceProcess for A380Fuel {
+    fn start(&mut self) {
+        self.fuel_quantity_management_system
+            .integrated_refuel_panel_mut()
+            .start();
+    }
+
+    fn stop(&mut self) {
+        self.fuel_quantity_management_system
+            .integrated_refuel_panel_mut()
+            .stop();
+    }
+
+    fn is_running(&self) -> bool {
+        self.fuel_quantity_management_system
+            .integrated_refuel_panel()
+            .is_running()
+    }
+}
 impl SimulationElement for A380Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
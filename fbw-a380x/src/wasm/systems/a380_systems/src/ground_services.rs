@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use systems::{
+    ground_services::{
+        GroundServiceDoor, GroundServiceId, GroundServiceInfo, GroundServicePrerequisite,
+        GroundServiceProcess, GroundServices,
+    },
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+
+pub(super) struct A380GroundServices {
+    ground_services: GroundServices<3, 7>,
+}
+impl A380GroundServices {
+    const PASSENGER_DOOR: usize = 0;
+    const SERVICE_DOOR: usize = 1;
+    const CARGO_DOOR: usize = 2;
+
+    const A380_DOORS: [&'static str; 3] = [
+        "INTERACTIVE POINT OPEN:0",
+        "INTERACTIVE POINT OPEN:3",
+        "FWD_DOOR_CARGO_POSITION",
+    ];
+
+    const A380_SERVICES: [GroundServiceInfo<'static>; 7] = [
+        GroundServiceInfo {
+            id: GroundServiceId::Stairs,
+            connection_time: Duration::from_secs(30),
+            service_time: None,
+            disconnection_time: Duration::from_secs(30),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Jetway,
+            connection_time: Duration::from_secs(60),
+            service_time: None,
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Catering,
+            connection_time: Duration::from_secs(180),
+            service_time: Some(Duration::from_secs(1200)),
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[GroundServicePrerequisite::DoorOpen(
+                A380GroundServices::SERVICE_DOOR,
+            )],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Cleaning,
+            connection_time: Duration::from_secs(60),
+            service_time: Some(Duration::from_secs(1800)),
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[
+                GroundServicePrerequisite::PassengerAccess,
+                GroundServicePrerequisite::DoorOpen(A380GroundServices::PASSENGER_DOOR),
+            ],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::FuelTruck,
+            connection_time: Duration::from_secs(90),
+            service_time: None,
+            disconnection_time: Duration::from_secs(90),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::PotableWater,
+            connection_time: Duration::from_secs(60),
+            service_time: Some(Duration::from_secs(600)),
+            disconnection_time: Duration::from_secs(60),
+            prerequisites: &[],
+        },
+        GroundServiceInfo {
+            id: GroundServiceId::Boarding,
+            connection_time: Duration::from_secs(10),
+            service_time: None,
+            disconnection_time: Duration::from_secs(10),
+            prerequisites: &[
+                GroundServicePrerequisite::PassengerAccess,
+                GroundServicePrerequisite::DoorOpen(A380GroundServices::PASSENGER_DOOR),
+                GroundServicePrerequisite::DoorOpen(A380GroundServices::CARGO_DOOR),
+            ],
+        },
+    ];
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        let doors = Self::A380_DOORS.map(|door| GroundServiceDoor::new(context, door));
+
+        Self {
+            ground_services: GroundServices::new(context, doors, Self::A380_SERVICES),
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        boarding: &mut impl GroundServiceProcess,
+        refuel: &mut impl GroundServiceProcess,
+    ) {
+        self.ground_services.update(context, boarding, refuel);
+    }
+}
+impl SimulationElement for A380GroundServices {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.ground_services.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
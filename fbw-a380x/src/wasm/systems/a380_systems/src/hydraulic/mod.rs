@@ -6,6 +6,7 @@ use uom::si::{
     angular_velocity::{degree_per_second, radian_per_second, revolution_per_minute},
     electric_current::ampere,
     f64::*,
+    force::newton,
     length::{inch, meter},
     mass::kilogram,
     pressure::psi,
@@ -21,7 +22,8 @@ use systems::{
     engine::Engine,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
-        brake::{BrakeAssembly, BrakeProperties},
+        anti_skid::{AntiSkid, AntiSkidSide},
+        brake::{BrakeAssembly, BrakeProperties, WheelsCondition},
         brake_circuit::{BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController},
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
@@ -31,9 +33,10 @@ use systems::{
         landing_gear::{GearGravityExtension, GearSystemController, HydraulicGearSystem},
         linear_actuator::{
             Actuator, BoundedLinearLength, ElectroHydrostaticActuatorType,
-            ElectroHydrostaticBackup, ElectroHydrostaticPowered, HydraulicAssemblyController,
-            HydraulicLinearActuatorAssembly, HydraulicLocking, LinearActuatedRigidBodyOnHingeAxis,
-            LinearActuator, LinearActuatorCharacteristics, LinearActuatorMode,
+            ElectroHydrostaticBackup, ElectroHydrostaticPowered, FlightControlSurfaceFailures,
+            HydraulicAssemblyController, HydraulicLinearActuatorAssembly, HydraulicLocking,
+            LinearActuatedRigidBodyOnHingeAxis, LinearActuator, LinearActuatorCharacteristics,
+            LinearActuatorMode,
         },
         nose_steering::{
             SteeringActuator, SteeringAngleLimiter, SteeringController, SteeringRatioToAngle,
@@ -47,15 +50,17 @@ use systems::{
         HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear},
+    landing_gear::{
+        GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear, TyreProperties,
+    },
     overhead::{AutoOffFaultPushButton, AutoOnFaultPushButton},
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
         AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId, CargoDoorLocked,
         ControllerSignal, DelayedFalseLogicGate, DelayedTrueLogicGate, ElectricalBusType,
-        ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle,
-        LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure,
-        SurfacesPositions,
+        ElectricalBuses, EngineFirePushButtons, FlightControlSurfaceId, GearWheel, HydraulicColor,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure,
+        SectionPressure, SurfacesPositions,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -241,6 +246,7 @@ impl A380HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            Some("FUEL_TANK_QUANTITY_5"),
         )
     }
 
@@ -267,6 +273,7 @@ impl A380HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            Some("FUEL_TANK_QUANTITY_6"),
         )
     }
 }
@@ -1569,8 +1576,14 @@ impl A380GearSystemFactory {
     }
 }
 
-// FIXME Placeholder logic to remove when eha inhibit is handled on flight computer side
-struct A380EhaInhibitPlaceholder {
+/// Decides when flight control electro-hydrostatic (EHA) and electrical backup hydraulic
+/// (EBHA) actuators may run on electrical power, and which hydraulic circuits they must
+/// take over from.
+///
+/// Electrical modes are inhibited after a while on ground with no engine started to save
+/// the batteries. When a green or yellow circuit is lost, actuators of that circuit revert to
+/// their electrical backup.
+struct A380ElectricalBackupLogic {
     general_eng_1_starter_active_id: VariableIdentifier,
     general_eng_2_starter_active_id: VariableIdentifier,
     general_eng_3_starter_active_id: VariableIdentifier,
@@ -1579,9 +1592,12 @@ struct A380EhaInhibitPlaceholder {
     any_engine_master_on: bool,
     elec_backup_allowed: bool,
 
+    green_circuit_lost: bool,
+    yellow_circuit_lost: bool,
+
     on_ground_delay: DelayedTrueLogicGate,
 }
-impl A380EhaInhibitPlaceholder {
+impl A380ElectricalBackupLogic {
     fn new(context: &mut InitContext) -> Self {
         Self {
             general_eng_1_starter_active_id: context
@@ -1596,6 +1612,9 @@ impl A380EhaInhibitPlaceholder {
             any_engine_master_on: false,
             elec_backup_allowed: false,
 
+            green_circuit_lost: false,
+            yellow_circuit_lost: false,
+
             on_ground_delay: DelayedTrueLogicGate::new(Duration::from_secs(120)).starting_as(true),
         }
     }
@@ -1605,6 +1624,8 @@ impl A380EhaInhibitPlaceholder {
         context: &UpdateContext,
         lgciu1: &impl LgciuWeightOnWheels,
         lgciu2: &impl LgciuWeightOnWheels,
+        green_section: &impl SectionPressure,
+        yellow_section: &impl SectionPressure,
     ) {
         let is_on_ground = lgciu1.left_and_right_gear_compressed(true)
             || lgciu2.left_and_right_gear_compressed(true);
@@ -1612,13 +1633,24 @@ impl A380EhaInhibitPlaceholder {
         self.on_ground_delay.update(context, is_on_ground);
 
         self.elec_backup_allowed = !self.on_ground_delay.output() || self.any_engine_master_on;
+
+        self.green_circuit_lost = !green_section.is_pressure_switch_pressurised();
+        self.yellow_circuit_lost = !yellow_section.is_pressure_switch_pressurised();
     }
 
     fn is_allowed(&self) -> bool {
         self.elec_backup_allowed
     }
+
+    fn should_revert_green_actuators(&self) -> bool {
+        self.elec_backup_allowed && self.green_circuit_lost
+    }
+
+    fn should_revert_yellow_actuators(&self) -> bool {
+        self.elec_backup_allowed && self.yellow_circuit_lost
+    }
 }
-impl SimulationElement for A380EhaInhibitPlaceholder {
+impl SimulationElement for A380ElectricalBackupLogic {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.any_engine_master_on = reader.read(&self.general_eng_1_starter_active_id)
             || reader.read(&self.general_eng_2_starter_active_id)
@@ -1628,7 +1660,7 @@ impl SimulationElement for A380EhaInhibitPlaceholder {
 }
 
 pub(super) struct A380Hydraulic {
-    eha_backup_inhibit_logic: A380EhaInhibitPlaceholder,
+    electrical_backup_logic: A380ElectricalBackupLogic,
     nose_steering: SteeringActuator,
     body_wheel_steering_left: SteeringActuator,
     body_wheel_steering_right: SteeringActuator,
@@ -1715,6 +1747,7 @@ pub(super) struct A380Hydraulic {
     gear_system_hydraulic_controller: A380GearHydraulicController,
     gear_system: HydraulicGearSystem,
     brake_properties: BrakeProperties,
+    tyre_properties: TyreProperties,
     left_wing_brake_assembly: BrakeAssembly<4>,
     right_wing_brake_assembly: BrakeAssembly<4>,
     left_body_brake_assembly: BrakeAssembly<4>,
@@ -1785,7 +1818,7 @@ impl A380Hydraulic {
             A380SlatsFactory::a380_slats_factory(context, SecondarySurfaceSide::Right);
 
         A380Hydraulic {
-            eha_backup_inhibit_logic: A380EhaInhibitPlaceholder::new(context),
+            electrical_backup_logic: A380ElectricalBackupLogic::new(context),
             nose_steering: SteeringActuator::new(
                 context,
                 "NOSE_WHEEL",
@@ -2071,6 +2104,14 @@ impl A380Hydraulic {
                 Length::new::<inch>(20.),
                 Mass::new::<kilogram>(110.),
             ),
+            tyre_properties: TyreProperties::new(
+                Pressure::new::<psi>(218.),
+                Force::new::<newton>(323000.),
+                Velocity::new::<knot>(204.),
+                Length::new::<inch>(56.) / 2.,
+                // The main gears carry about 95% of the aircraft weight on twenty tyres
+                Ratio::new::<ratio>(0.0475),
+            ),
             left_wing_brake_assembly: BrakeAssembly::new(
                 context,
                 "WHEEL RPM:1".to_owned(), // Should be "WHEEL RPM:3" but MSFS has weird definitions...
@@ -2238,6 +2279,23 @@ impl A380Hydraulic {
         self.rudder.aerodynamic_torques_up_down()
     }
 
+    /// The tyre speeds of the braked wheels of one side, wing gear wheels first.
+    fn side_tyre_speeds(
+        wing_brake_assembly: &BrakeAssembly<4>,
+        body_brake_assembly: &BrakeAssembly<4>,
+    ) -> [Velocity; 8] {
+        let wing_speeds = wing_brake_assembly.tyre_speeds();
+        let body_speeds = body_brake_assembly.tyre_speeds();
+
+        std::array::from_fn(|i| {
+            if i < 4 {
+                wing_speeds[i]
+            } else {
+                body_speeds[i - 4]
+            }
+        })
+    }
+
     #[cfg(test)]
     fn nose_wheel_steering_pin_is_inserted(&self) -> bool {
         self.bypass_pin.is_nose_wheel_steering_pin_inserted()
@@ -2393,14 +2451,14 @@ impl A380Hydraulic {
             context,
             self.green_circuit.system_section(),
             self.yellow_circuit.system_section(),
-            &self.eha_backup_inhibit_logic,
+            &self.electrical_backup_logic,
         );
 
         self.right_spoilers.update(
             context,
             self.green_circuit.system_section(),
             self.yellow_circuit.system_section(),
-            &self.eha_backup_inhibit_logic,
+            &self.electrical_backup_logic,
         );
 
         self.gear_system.update(
@@ -2423,22 +2481,27 @@ impl A380Hydraulic {
         adirs: &impl AdirsMeasurementOutputs,
         lgcius: &LandingGearControlInterfaceUnitSet,
     ) {
-        self.eha_backup_inhibit_logic
-            .update(context, lgciu1, lgciu2);
+        self.electrical_backup_logic.update(
+            context,
+            lgciu1,
+            lgciu2,
+            self.green_circuit.system_section(),
+            self.yellow_circuit.system_section(),
+        );
 
         self.gear_system_gravity_extension_controller
             .update(context);
 
         self.aileron_system_controller
-            .update(&self.eha_backup_inhibit_logic);
+            .update(&self.electrical_backup_logic);
 
         self.elevator_system_controller
-            .update(&self.eha_backup_inhibit_logic);
+            .update(&self.electrical_backup_logic);
 
         self.ths_system_controller.update();
 
         self.rudder_system_controller
-            .update(&self.eha_backup_inhibit_logic);
+            .update(&self.electrical_backup_logic);
 
         self.tilting_gears.update(context);
 
@@ -2479,9 +2542,20 @@ impl A380Hydraulic {
             self.left_spoilers.ground_spoilers_are_requested()
                 && self.right_spoilers.ground_spoilers_are_requested(),
             self.nose_steering.position_feedback(),
+            [
+                Self::side_tyre_speeds(
+                    &self.left_wing_brake_assembly,
+                    &self.left_body_brake_assembly,
+                ),
+                Self::side_tyre_speeds(
+                    &self.right_wing_brake_assembly,
+                    &self.right_body_brake_assembly,
+                ),
+            ],
         );
 
-        self.pushback_tug.update(context);
+        self.pushback_tug
+            .update(context, self.nose_steering.position_feedback());
         self.bypass_pin.update(&self.pushback_tug);
 
         for (brake_assembly, braking_pressure_norm, braking_pressure_altn, gear_position) in [
@@ -2513,12 +2587,26 @@ impl A380Hydraulic {
             brake_assembly.update(
                 context,
                 &self.brake_properties,
+                &self.tyre_properties,
                 braking_pressure_norm.max(braking_pressure_altn),
                 false,
                 gear_position.get::<ratio>() > 0.25,
             );
         }
 
+        let anti_skid = self.brake_steer_computer.anti_skid();
+        self.braking_force.update_wheels_condition(
+            WheelsCondition::from_assemblies(&[
+                &self.left_wing_brake_assembly,
+                &self.left_body_brake_assembly,
+            ])
+            .with_braking_force_limit(anti_skid.braking_force_limit(AntiSkidSide::Left)),
+            WheelsCondition::from_assemblies(&[
+                &self.right_wing_brake_assembly,
+                &self.right_body_brake_assembly,
+            ])
+            .with_braking_force_limit(anti_skid.braking_force_limit(AntiSkidSide::Right)),
+        );
         self.braking_force.update_forces(
             context,
             &self.braking_circuit_norm,
@@ -3158,7 +3246,7 @@ impl CargoDoorLocked for A380Hydraulic {
 
 impl SimulationElement for A380Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.eha_backup_inhibit_logic.accept(visitor);
+        self.electrical_backup_logic.accept(visitor);
 
         self.engine_driven_pump_1a.accept(visitor);
         self.engine_driven_pump_1a_controller.accept(visitor);
@@ -4295,6 +4383,7 @@ struct A380HydraulicBrakeSteerComputerUnit {
     normal_brakes_available: bool,
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
+    anti_skid: AntiSkid<8>,
 
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
@@ -4368,6 +4457,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 Duration::from_secs_f64(Self::AUTOBRAKE_GEAR_RETRACTION_DURATION_S),
             ),
             anti_skid_activated: true,
+            anti_skid: AntiSkid::new(),
 
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
@@ -4398,11 +4488,19 @@ impl A380HydraulicBrakeSteerComputerUnit {
     }
 
     fn allow_autobrake_arming(&self, adirs: &impl AdirsMeasurementOutputs) -> bool {
-        self.anti_skid_activated
+        self.anti_skid_available()
             && self.normal_brakes_available
             && (adirs.is_fully_aligned(1) || adirs.is_fully_aligned(2) || adirs.is_fully_aligned(3))
     }
 
+    fn anti_skid_available(&self) -> bool {
+        self.anti_skid_activated && self.anti_skid.is_operative()
+    }
+
+    fn anti_skid(&self) -> &AntiSkid<8> {
+        &self.anti_skid
+    }
+
     fn update_normal_braking_availability(&mut self, normal_braking_circuit_pressure: Pressure) {
         if normal_braking_circuit_pressure.get::<psi>() > Self::MIN_PRESSURE_BRAKE_ALTN_HYST_HI
             && (self.left_brake_pilot_input.get::<ratio>() < Self::PILOT_INPUT_DETECTION_TRESHOLD
@@ -4435,7 +4533,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 // Else manual action limited to a higher max nominal pressure
                 2538.
             }
-        } else if !self.anti_skid_activated {
+        } else if !self.anti_skid_available() {
             1160.
         } else {
             // Else if any manual braking we use standard limit
@@ -4460,6 +4558,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
         adirs: &impl AdirsMeasurementOutputs,
         placeholder_ground_spoilers_out: bool,
         nose_steering_feedback: Angle,
+        wheel_speeds: [[Velocity; 8]; 2],
     ) {
         // TODO split steering part from braking part in two different computers instances
         self.update_steering_demands(
@@ -4506,7 +4605,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
             self.alternate_brake_outputs.set_no_demands();
         } else {
             let green_used_for_brakes = self.normal_brakes_available
-                && self.anti_skid_activated
+                && self.anti_skid_available()
                 && !self.parking_brake_demand;
 
             if green_used_for_brakes {
@@ -4546,6 +4645,55 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 }
             }
         }
+
+        // Unpowered LGCIUs report neither compressed nor extended gears
+        let main_gear_extended = lgciu1.left_and_right_gear_extended(false)
+            && lgciu2.left_and_right_gear_extended(false);
+        self.update_anti_skid(
+            context,
+            !main_gear_extended,
+            wheel_speeds,
+            !is_in_flight_gear_lever_up && !self.parking_brake_demand,
+        );
+    }
+
+    /// Anti-skid modulates normal and alternate brake demands, but not the parking brake nor
+    /// the braking of the wheels during gear retraction.
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        main_gear_compressed: bool,
+        wheel_speeds: [[Velocity; 8]; 2],
+        brakes_are_modulated: bool,
+    ) {
+        self.anti_skid.update(
+            context,
+            self.anti_skid_activated,
+            main_gear_compressed,
+            wheel_speeds,
+            [
+                self.norm_brake_outputs
+                    .left_demand()
+                    .max(self.alternate_brake_outputs.left_demand()),
+                self.norm_brake_outputs
+                    .right_demand()
+                    .max(self.alternate_brake_outputs.right_demand()),
+            ],
+        );
+
+        if brakes_are_modulated {
+            for outputs in [
+                &mut self.norm_brake_outputs,
+                &mut self.alternate_brake_outputs,
+            ] {
+                outputs.set_brake_demands(
+                    self.anti_skid
+                        .modulated_demand(AntiSkidSide::Left, outputs.left_demand()),
+                    self.anti_skid
+                        .modulated_demand(AntiSkidSide::Right, outputs.right_demand()),
+                );
+            }
+        }
     }
 
     fn update_steering_demands(
@@ -4646,6 +4794,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A380HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.anti_skid.accept(visitor);
         visitor.visit(self);
     }
 
@@ -4685,6 +4834,9 @@ struct A380BrakingForce {
     left_braking_force: f64,
     right_braking_force: f64,
 
+    left_wheels_condition: WheelsCondition,
+    right_wheels_condition: WheelsCondition,
+
     flap_position: f64,
 
     is_chocks_enabled: bool,
@@ -4713,6 +4865,9 @@ impl A380BrakingForce {
             left_braking_force: 0.,
             right_braking_force: 0.,
 
+            left_wheels_condition: WheelsCondition::default(),
+            right_wheels_condition: WheelsCondition::default(),
+
             flap_position: 0.,
 
             is_chocks_enabled: false,
@@ -4749,9 +4904,25 @@ impl A380BrakingForce {
 
         self.correct_with_flaps_state(context);
 
+        self.left_braking_force = self
+            .left_wheels_condition
+            .correct_braking_force(self.left_braking_force);
+        self.right_braking_force = self
+            .right_wheels_condition
+            .correct_braking_force(self.right_braking_force);
+
         self.update_chocks_braking(context, engine1, engine2, bypass_pin);
     }
 
+    pub fn update_wheels_condition(
+        &mut self,
+        left_wheels_condition: WheelsCondition,
+        right_wheels_condition: WheelsCondition,
+    ) {
+        self.left_wheels_condition = left_wheels_condition;
+        self.right_wheels_condition = right_wheels_condition;
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -5228,14 +5399,42 @@ impl AileronSystemHydraulicController {
         &self.right_aileron_controllers[panel as usize][..]
     }
 
-    fn update(&mut self, eha_backup_placeholder: &A380EhaInhibitPlaceholder) {
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
+    fn update(&mut self, electrical_backup_logic: &A380ElectricalBackupLogic) {
+        self.eha_backup_allowed = electrical_backup_logic.is_allowed();
+
+        self.revert_to_electro_hydrostatic_actuators(electrical_backup_logic);
 
         self.update_aileron_controllers_positions();
         self.update_aileron_controllers_modes();
         self.filter_dual_control();
     }
 
+    fn revert_to_electro_hydrostatic_actuators(
+        &mut self,
+        electrical_backup_logic: &A380ElectricalBackupLogic,
+    ) {
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.left_inboard_solenoid_energized_from_fbw,
+            &mut self.left_inboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_green_actuators(),
+        );
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.right_inboard_solenoid_energized_from_fbw,
+            &mut self.right_inboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_green_actuators(),
+        );
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.left_midboard_solenoid_energized_from_fbw,
+            &mut self.left_midboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_yellow_actuators(),
+        );
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.right_midboard_solenoid_energized_from_fbw,
+            &mut self.right_midboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_yellow_actuators(),
+        );
+    }
+
     fn update_aileron_controllers_positions(&mut self) {
         self.left_aileron_controllers[AileronPanelPosition::Outward as usize]
             [AileronActuatorPosition::Outward as usize]
@@ -5703,8 +5902,10 @@ impl ElevatorSystemHydraulicController {
         &self.right_controllers[panel as usize][..]
     }
 
-    fn update(&mut self, eha_backup_placeholder: &A380EhaInhibitPlaceholder) {
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
+    fn update(&mut self, electrical_backup_logic: &A380ElectricalBackupLogic) {
+        self.eha_backup_allowed = electrical_backup_logic.is_allowed();
+
+        self.revert_to_electro_hydrostatic_actuators(electrical_backup_logic);
 
         self.update_elevator_controllers_positions();
         self.update_elevator_controllers_solenoids();
@@ -5762,6 +5963,32 @@ impl ElevatorSystemHydraulicController {
             );
     }
 
+    fn revert_to_electro_hydrostatic_actuators(
+        &mut self,
+        electrical_backup_logic: &A380ElectricalBackupLogic,
+    ) {
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.left_inboard_solenoid_energized_from_fbw,
+            &mut self.left_inboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_green_actuators(),
+        );
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.left_outboard_solenoid_energized_from_fbw,
+            &mut self.left_outboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_green_actuators(),
+        );
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.right_inboard_solenoid_energized_from_fbw,
+            &mut self.right_inboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_yellow_actuators(),
+        );
+        revert_to_electro_hydrostatic_actuator(
+            &mut self.right_outboard_solenoid_energized_from_fbw,
+            &mut self.right_outboard_position_requests_from_fbw,
+            electrical_backup_logic.should_revert_yellow_actuators(),
+        );
+    }
+
     fn update_elevator_controllers_solenoids(&mut self) {
         if self
             .left_outboard_solenoid_energized_from_fbw
@@ -6047,6 +6274,7 @@ impl SimulationElement for TrimmableHorizontalStabilizerSystemHydraulicControlle
 struct RudderController {
     mode: LinearActuatorMode,
     electric_mode_active: bool,
+    electric_mode_switchover_allowed: bool,
     requested_position: Ratio,
 }
 impl RudderController {
@@ -6055,6 +6283,7 @@ impl RudderController {
             mode: LinearActuatorMode::ClosedCircuitDamping,
 
             electric_mode_active: false,
+            electric_mode_switchover_allowed: false,
 
             requested_position: Ratio::new::<ratio>(0.),
         }
@@ -6065,6 +6294,10 @@ impl RudderController {
         self.electric_mode_active = electric_mode_active;
     }
 
+    fn set_electric_mode_switchover_allowed(&mut self, is_allowed: bool) {
+        self.electric_mode_switchover_allowed = is_allowed;
+    }
+
     /// Receives a [0;1] position request, 0 is down 1 is up
     fn set_requested_position(&mut self, requested_position: Ratio) {
         self.requested_position = requested_position
@@ -6094,6 +6327,10 @@ impl ElectroHydrostaticPowered for RudderController {
     fn should_activate_electrical_mode(&self) -> bool {
         self.electric_mode_active
     }
+
+    fn should_switch_to_electrical_mode_on_hydraulic_loss(&self) -> bool {
+        self.electric_mode_switchover_allowed && self.mode == LinearActuatorMode::PositionControl
+    }
 }
 
 struct RudderSystemHydraulicController {
@@ -6180,10 +6417,14 @@ impl RudderSystemHydraulicController {
         &self.rudder_controllers[panel as usize][..]
     }
 
-    fn update(&mut self, eha_backup_placeholder: &A380EhaInhibitPlaceholder) {
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
+    fn update(&mut self, electrical_backup_logic: &A380ElectricalBackupLogic) {
+        self.eha_backup_allowed = electrical_backup_logic.is_allowed();
         self.update_rudder_controllers_positions();
         self.update_rudder_controllers_solenoids();
+
+        for controller in self.rudder_controllers.iter_mut().flatten() {
+            controller.set_electric_mode_switchover_allowed(self.eha_backup_allowed);
+        }
     }
 
     fn update_rudder_controllers_positions(&mut self) {
@@ -6394,6 +6635,8 @@ struct AileronAssembly {
 
     positions: [f64; 3],
     aerodynamic_models: [AerodynamicModel; 3],
+
+    failures: [FlightControlSurfaceFailures<2>; 3],
 }
 impl AileronAssembly {
     fn new(
@@ -6442,6 +6685,14 @@ impl AileronAssembly {
                 aerodynamic_model_middle,
                 aerodynamic_model_inner,
             ],
+
+            // Panel numbers follow AileronPanelPosition order
+            failures: std::array::from_fn(|idx| {
+                FlightControlSurfaceFailures::new(match id {
+                    ActuatorSide::Left => FlightControlSurfaceId::LeftAileron(idx + 1),
+                    ActuatorSide::Right => FlightControlSurfaceId::RightAileron(idx + 1),
+                })
+            }),
         }
     }
 
@@ -6462,8 +6713,14 @@ impl AileronAssembly {
         current_pressure_inward: [&impl SectionPressure; 3],
     ) {
         for idx in 0..3 {
+            self.failures[idx].apply_to(&mut self.hydraulic_assemblies[idx]);
+
             self.aerodynamic_models[idx]
                 .update_body(context, self.hydraulic_assemblies[idx].body());
+            self.hydraulic_assemblies[idx].set_fluid_viscosity_flow_ratios([
+                current_pressure_outward[idx].fluid_viscosity_flow_ratio(),
+                current_pressure_inward[idx].fluid_viscosity_flow_ratio(),
+            ]);
             self.hydraulic_assemblies[idx].update(
                 context,
                 controllers[idx],
@@ -6486,6 +6743,7 @@ impl AileronAssembly {
 impl SimulationElement for AileronAssembly {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.hydraulic_assemblies, visitor);
+        accept_iterable!(self.failures, visitor);
 
         visitor.visit(self);
     }
@@ -6517,6 +6775,8 @@ struct ElevatorAssembly {
     positions: [Ratio; 2],
 
     aerodynamic_models: [AerodynamicModel; 2],
+
+    failures: [FlightControlSurfaceFailures<2>; 2],
 }
 impl ElevatorAssembly {
     fn new(
@@ -6548,6 +6808,14 @@ impl ElevatorAssembly {
 
             positions: [Ratio::new::<ratio>(0.); 2],
             aerodynamic_models: [aerodynamic_model_outer, aerodynamic_model_inner],
+
+            // Panel numbers follow ElevatorPanelPosition order
+            failures: std::array::from_fn(|idx| {
+                FlightControlSurfaceFailures::new(match id {
+                    ActuatorSide::Left => FlightControlSurfaceId::LeftElevator(idx + 1),
+                    ActuatorSide::Right => FlightControlSurfaceId::RightElevator(idx + 1),
+                })
+            }),
         }
     }
 
@@ -6568,9 +6836,16 @@ impl ElevatorAssembly {
         current_pressure_inward: [&impl SectionPressure; 2],
     ) {
         for idx in 0..2 {
+            self.failures[idx].apply_to(&mut self.hydraulic_assemblies[idx]);
+
             self.aerodynamic_models[idx]
                 .update_body(context, self.hydraulic_assemblies[idx].body());
 
+            self.hydraulic_assemblies[idx].set_fluid_viscosity_flow_ratios([
+                current_pressure_outward[idx].fluid_viscosity_flow_ratio(),
+                current_pressure_inward[idx].fluid_viscosity_flow_ratio(),
+            ]);
+
             self.hydraulic_assemblies[idx].update(
                 context,
                 elevator_controllers[idx],
@@ -6595,6 +6870,7 @@ impl ElevatorAssembly {
 impl SimulationElement for ElevatorAssembly {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.hydraulic_assemblies, visitor);
+        accept_iterable!(self.failures, visitor);
 
         visitor.visit(self);
     }
@@ -6630,6 +6906,8 @@ struct RudderAssembly {
     positions: [Ratio; 2],
 
     aerodynamic_models: [AerodynamicModel; 2],
+
+    failures: [FlightControlSurfaceFailures<2>; 2],
 }
 impl RudderAssembly {
     fn new(
@@ -6649,6 +6927,11 @@ impl RudderAssembly {
             positions: [Ratio::new::<ratio>(0.); 2],
 
             aerodynamic_models: [aerodynamic_model_upper, aerodynamic_model_lower],
+
+            // Panel numbers follow RudderPanelPosition order
+            failures: std::array::from_fn(|idx| {
+                FlightControlSurfaceFailures::new(FlightControlSurfaceId::Rudder(idx + 1))
+            }),
         }
     }
 
@@ -6669,9 +6952,16 @@ impl RudderAssembly {
         current_pressure_lower: [&impl SectionPressure; 2],
     ) {
         for idx in 0..2 {
+            self.failures[idx].apply_to(&mut self.hydraulic_assemblies[idx]);
+
             self.aerodynamic_models[idx]
                 .update_body(context, self.hydraulic_assemblies[idx].body());
 
+            self.hydraulic_assemblies[idx].set_fluid_viscosity_flow_ratios([
+                current_pressure_upper[idx].fluid_viscosity_flow_ratio(),
+                current_pressure_lower[idx].fluid_viscosity_flow_ratio(),
+            ]);
+
             self.hydraulic_assemblies[idx].update(
                 context,
                 rudder_controllers[idx],
@@ -6696,6 +6986,7 @@ impl RudderAssembly {
 impl SimulationElement for RudderAssembly {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.hydraulic_assemblies, visitor);
+        accept_iterable!(self.failures, visitor);
 
         visitor.visit(self);
     }
@@ -6720,6 +7011,8 @@ struct SpoilerElement {
     position: Ratio,
 
     aerodynamic_model: AerodynamicModel,
+
+    failures: FlightControlSurfaceFailures<1>,
 }
 impl SpoilerElement {
     fn new(
@@ -6741,6 +7034,10 @@ impl SpoilerElement {
             },
             position: Ratio::new::<ratio>(0.),
             aerodynamic_model,
+            failures: FlightControlSurfaceFailures::new(match id {
+                ActuatorSide::Left => FlightControlSurfaceId::LeftSpoiler(id_num),
+                ActuatorSide::Right => FlightControlSurfaceId::RightSpoiler(id_num),
+            }),
         }
     }
 
@@ -6754,14 +7051,18 @@ impl SpoilerElement {
         spoiler_controller: &(impl HydraulicAssemblyController
               + HydraulicLocking
               + ElectroHydrostaticPowered),
-        current_pressure: Pressure,
+        section: &impl SectionPressure,
     ) {
+        self.failures.apply_to(&mut self.hydraulic_assembly);
+
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly
+            .set_fluid_viscosity_flow_ratios([section.fluid_viscosity_flow_ratio()]);
         self.hydraulic_assembly.update(
             context,
             std::slice::from_ref(spoiler_controller),
-            [current_pressure],
+            [section.pressure_downstream_leak_valve()],
         );
 
         self.position = self.hydraulic_assembly.position_normalized();
@@ -6774,6 +7075,7 @@ impl SpoilerElement {
 impl SimulationElement for SpoilerElement {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.hydraulic_assembly.accept(visitor);
+        self.failures.accept(visitor);
 
         visitor.visit(self);
     }
@@ -6817,52 +7119,19 @@ impl SpoilerGroup {
         context: &UpdateContext,
         green_section: &impl SectionPressure,
         yellow_section: &impl SectionPressure,
-        eha_backup_placeholder: &A380EhaInhibitPlaceholder,
+        electrical_backup_logic: &A380ElectricalBackupLogic,
     ) {
-        // FIXME REMOVE elec backup inhibit once handles from flight computers
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
+        self.eha_backup_allowed = electrical_backup_logic.is_allowed();
         self.hydraulic_controllers[5].set_elec_backup_allowed(self.eha_backup_allowed);
 
-        self.spoilers[0].update(
-            context,
-            &self.hydraulic_controllers[0],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[1].update(
-            context,
-            &self.hydraulic_controllers[1],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[2].update(
-            context,
-            &self.hydraulic_controllers[2],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[3].update(
-            context,
-            &self.hydraulic_controllers[3],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[4].update(
-            context,
-            &self.hydraulic_controllers[4],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[5].update(
-            context,
-            &self.hydraulic_controllers[5],
-            green_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[6].update(
-            context,
-            &self.hydraulic_controllers[6],
-            yellow_section.pressure_downstream_leak_valve(),
-        );
-        self.spoilers[7].update(
-            context,
-            &self.hydraulic_controllers[7],
-            green_section.pressure_downstream_leak_valve(),
-        );
+        self.spoilers[0].update(context, &self.hydraulic_controllers[0], yellow_section);
+        self.spoilers[1].update(context, &self.hydraulic_controllers[1], green_section);
+        self.spoilers[2].update(context, &self.hydraulic_controllers[2], yellow_section);
+        self.spoilers[3].update(context, &self.hydraulic_controllers[3], green_section);
+        self.spoilers[4].update(context, &self.hydraulic_controllers[4], yellow_section);
+        self.spoilers[5].update(context, &self.hydraulic_controllers[5], green_section);
+        self.spoilers[6].update(context, &self.hydraulic_controllers[6], yellow_section);
+        self.spoilers[7].update(context, &self.hydraulic_controllers[7], green_section);
 
         self.spoiler_positions = [
             self.spoilers[0].position(),
@@ -7093,6 +7362,23 @@ impl SimulationElement for A380TiltingGears {
     }
 }
 
+/// Hands over control of a surface from its hydraulic actuator to its electro-hydrostatic
+/// actuator when the hydraulic circuit is lost.
+/// Arrays are given as [hydraulic actuator, electro-hydrostatic actuator]
+fn revert_to_electro_hydrostatic_actuator(
+    solenoids_energized: &mut [bool; 2],
+    position_requests: &mut [Ratio; 2],
+    should_revert: bool,
+) {
+    if should_revert && solenoids_energized[0] {
+        if !solenoids_energized[1] {
+            position_requests[1] = position_requests[0];
+        }
+
+        *solenoids_energized = [false, true];
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7109,7 +7395,10 @@ mod tests {
             },
             engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
             failures::FailureType,
-            hydraulic::cargo_doors::{DoorControlState, HydraulicDoorController},
+            hydraulic::{
+                cargo_doors::{DoorControlState, HydraulicDoorController},
+                pushback::TugAttachment,
+            },
             landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
             shared::{
                 arinc429::{Arinc429Word, SignStatus},
@@ -7907,6 +8196,12 @@ mod tests {
                 self
             }
 
+            fn spin_up_main_wheels(mut self, wheel_speed: AngularVelocity) -> Self {
+                self.write_by_name("WHEEL RPM:1", wheel_speed.get::<revolution_per_minute>());
+                self.write_by_name("WHEEL RPM:2", wheel_speed.get::<revolution_per_minute>());
+                self
+            }
+
             fn air_press_low(mut self) -> Self {
                 self.command(|a| a.pneumatics.set_low_air_pressure());
                 self
@@ -8003,6 +8298,21 @@ mod tests {
                 self
             }
 
+            fn attach_native_tug(mut self, nws_pin_inserted: bool) -> Self {
+                self.write_by_name("PUSHBACK_TUG_ATTACHMENT", TugAttachment::Towbarless);
+                self.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", nws_pin_inserted);
+                self
+            }
+
+            fn set_native_tug_turn_command(mut self, turn_command: f64) -> Self {
+                self.write_by_name("PUSHBACK_TUG_TURN_COMMAND", turn_command);
+                self
+            }
+
+            fn is_nose_gear_torque_link_overloaded(&mut self) -> bool {
+                self.read_by_name("PUSHBACK_TUG_TORQUE_LINK_OVERLOAD")
+            }
+
             fn is_nw_disc_memo_shown(&mut self) -> bool {
                 self.read_by_name("HYD_NW_STRG_DISC_ECAM_MEMO")
             }
@@ -8577,6 +8887,97 @@ mod tests {
                 self
             }
 
+            fn base_string_for_elevator_hydraulic_jack(
+                side: ActuatorSide,
+                panel: ElevatorPanelPosition,
+            ) -> &'static str {
+                match (side, panel) {
+                    (ActuatorSide::Left, ElevatorPanelPosition::Inward) => {
+                        "LEFT_INBOARD_ELEV_GREEN"
+                    }
+                    (ActuatorSide::Left, ElevatorPanelPosition::Outward) => {
+                        "LEFT_OUTBOARD_ELEV_GREEN"
+                    }
+                    (ActuatorSide::Right, ElevatorPanelPosition::Inward) => {
+                        "RIGHT_INBOARD_ELEV_YELLOW"
+                    }
+                    (ActuatorSide::Right, ElevatorPanelPosition::Outward) => {
+                        "RIGHT_OUTBOARD_ELEV_YELLOW"
+                    }
+                }
+            }
+
+            /// Commands an elevator panel through its hydraulic jack only, angle is in degrees
+            fn set_elevator_panel_hydraulic_jack_position(
+                mut self,
+                side: ActuatorSide,
+                panel: ElevatorPanelPosition,
+                angle: f64,
+            ) -> Self {
+                let base_string = Self::base_string_for_elevator_hydraulic_jack(side, panel);
+                self.write_by_name(
+                    format!("{}_SERVO_SOLENOID_ENERGIZED", base_string).as_str(),
+                    1.,
+                );
+                self.write_by_name(
+                    format!("{}_COMMANDED_POSITION", base_string).as_str(),
+                    angle,
+                );
+                self
+            }
+
+            fn get_elevator_panel_position(
+                &mut self,
+                side: ActuatorSide,
+                panel: ElevatorPanelPosition,
+            ) -> Ratio {
+                let side_string = match side {
+                    ActuatorSide::Left => "LEFT",
+                    ActuatorSide::Right => "RIGHT",
+                };
+                let panel_string = match panel {
+                    ElevatorPanelPosition::Outward => "OUTWARD",
+                    ElevatorPanelPosition::Inward => "INWARD",
+                };
+
+                Ratio::new::<ratio>(self.read_by_name(
+                    format!("HYD_ELEV_{}_{}_DEFLECTION", side_string, panel_string).as_str(),
+                ))
+            }
+
+            /// Commands both rudder panels through the hydraulic mode of their EBHAs, angle is in degrees
+            fn set_rudder_hydraulic_mode_position(mut self, angle: f64) -> Self {
+                for panel in ["UPPER", "LOWER"] {
+                    for circuit in ["YELLOW", "GREEN"] {
+                        self.write_by_name(
+                            format!(
+                                "{}_RUDDER_{}_EBHA_HYDRAULIC_MODE_SOLENOID_ENERGIZED",
+                                panel, circuit
+                            )
+                            .as_str(),
+                            1.,
+                        );
+                        self.write_by_name(
+                            format!("{}_RUDDER_{}_EBHA_COMMANDED_POSITION", panel, circuit)
+                                .as_str(),
+                            angle,
+                        );
+                    }
+                }
+                self
+            }
+
+            fn get_rudder_panel_position(&mut self, panel: RudderPanelPosition) -> Ratio {
+                match panel {
+                    RudderPanelPosition::Upper => {
+                        Ratio::new::<ratio>(self.read_by_name("HYD_UPPER_RUD_DEFLECTION"))
+                    }
+                    RudderPanelPosition::Lower => {
+                        Ratio::new::<ratio>(self.read_by_name("HYD_LOWER_RUD_DEFLECTION"))
+                    }
+                }
+            }
+
             fn set_ailerons_left_turn(mut self) -> Self {
                 self.write_by_name("LEFT_AIL_BLUE_COMMANDED_POSITION", -25.);
                 self.write_by_name("RIGHT_AIL_BLUE_COMMANDED_POSITION", -25.);
@@ -8708,6 +9109,28 @@ mod tests {
                 self.query(|a| a.lgcius.active_lgciu().gear_system_state())
             }
 
+            fn is_gear_downlocked_by_lgciu(&self, wheel_id: GearWheel) -> bool {
+                let downlocked_bit = match wheel_id {
+                    GearWheel::LEFT => 23,
+                    GearWheel::RIGHT => 24,
+                    GearWheel::NOSE => 25,
+                    GearWheel::WINGLEFT | GearWheel::WINGRIGHT => {
+                        panic!("TODO WING GEARS HYD NOT IMPLEMENTED YET ON 380")
+                    }
+                };
+
+                self.query(|a| {
+                    a.lgcius
+                        .active_lgciu()
+                        .discrete_word_1()
+                        .get_bit(downlocked_bit)
+                })
+            }
+
+            fn is_lgciu_control_fault(&self) -> bool {
+                self.query(|a| a.lgcius.active_lgciu().discrete_word_2().get_bit(29))
+            }
+
             fn _set_elevator_full_up(mut self) -> Self {
                 self.write_by_name("LEFT_ELEV_BLUE_COMMANDED_POSITION", -30.);
                 self.write_by_name("RIGHT_ELEV_BLUE_COMMANDED_POSITION", -30.);
@@ -8908,6 +9331,45 @@ mod tests {
             );
         }
 
+        #[test]
+        fn disconnected_outward_left_aileron_panel_droops_with_pressure_available() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .reset_all_aileron_commands()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Left,
+                    AileronPanelPosition::Outward,
+                    AileronActuatorPosition::Outward,
+                )
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(
+                test_bed
+                    .get_left_aileron_panel_position(AileronPanelPosition::Outward)
+                    .get::<ratio>()
+                    > 0.35
+            );
+
+            test_bed.fail(FailureType::FlightControlSurfaceDisconnect(
+                FlightControlSurfaceId::LeftAileron(AileronPanelPosition::Outward as usize + 1),
+            ));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+            assert!(
+                test_bed
+                    .get_left_aileron_panel_position(AileronPanelPosition::Outward)
+                    .get::<ratio>()
+                    < 0.1
+            );
+        }
+
         #[test]
         fn outward_right_aileron_panel_responds_only_with_green_pressure_on_outward_jack() {
             let mut test_bed = test_bed_on_ground_with()
@@ -9219,72 +9681,303 @@ mod tests {
         }
 
         #[test]
-        fn middle_right_aileron_panel_responds_with_no_hyds_on_eha_jack() {
+        fn inner_left_aileron_panel_reverts_to_eha_jack_on_green_loss() {
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
                 .reset_all_aileron_commands()
-                .ac_ess_lost()
-                .set_aileron_panel_neutral(
-                    ActuatorSide::Right,
-                    AileronPanelPosition::Middle,
-                    AileronActuatorPosition::Inward,
-                )
                 .run_waiting_for(Duration::from_secs(1));
 
             // Artificially set plane not on ground to allow EHA activation
             test_bed.set_on_ground(false);
 
-            assert!(!test_bed.is_green_pressure_switch_pressurised());
-            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
-
-            assert!(
-                test_bed
-                    .get_right_aileron_panel_position(AileronPanelPosition::Middle)
-                    .get::<ratio>()
-                    < 0.1
-            );
-
+            // Only green jack is commanded but green is not pressurised
             test_bed = test_bed
-                .reset_all_aileron_commands()
-                .ac_ess_active()
                 .set_aileron_panel_neutral(
-                    ActuatorSide::Right,
-                    AileronPanelPosition::Middle,
-                    AileronActuatorPosition::Inward,
+                    ActuatorSide::Left,
+                    AileronPanelPosition::Inward,
+                    AileronActuatorPosition::Outward,
                 )
                 .run_waiting_for(Duration::from_secs(5));
 
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
             assert!(
                 test_bed
-                    .get_right_aileron_panel_position(AileronPanelPosition::Middle,)
+                    .get_left_aileron_panel_position(AileronPanelPosition::Inward)
                     .get::<ratio>()
                     > 0.35
             );
             assert!(
                 test_bed
-                    .get_right_aileron_panel_position(AileronPanelPosition::Middle,)
+                    .get_left_aileron_panel_position(AileronPanelPosition::Inward)
                     .get::<ratio>()
                     < 0.55
             );
         }
 
         #[test]
-        fn inner_right_aileron_panel_responds_only_with_green_pressure_on_outward_jack() {
+        fn middle_right_aileron_panel_reverts_to_eha_jack_on_yellow_loss() {
             let mut test_bed = test_bed_on_ground_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
                 .reset_all_aileron_commands()
+                .run_waiting_for(Duration::from_secs(1));
+
+            // Artificially set plane not on ground to allow EHA activation
+            test_bed.set_on_ground(false);
+
+            // Only yellow jack is commanded but yellow is not pressurised
+            test_bed = test_bed
                 .set_aileron_panel_neutral(
                     ActuatorSide::Right,
-                    AileronPanelPosition::Inward,
+                    AileronPanelPosition::Middle,
                     AileronActuatorPosition::Outward,
                 )
-                .run_waiting_for(Duration::from_secs(1));
+                .run_waiting_for(Duration::from_secs(5));
 
-            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            assert!(
+                test_bed
+                    .get_right_aileron_panel_position(AileronPanelPosition::Middle)
+                    .get::<ratio>()
+                    > 0.35
+            );
+            assert!(
+                test_bed
+                    .get_right_aileron_panel_position(AileronPanelPosition::Middle)
+                    .get::<ratio>()
+                    < 0.55
+            );
+        }
+
+        #[test]
+        fn left_elevator_panels_revert_to_eha_on_green_loss() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_waiting_for(Duration::from_secs(1));
+
+            // Artificially set plane not on ground to allow EHA activation
+            test_bed.set_on_ground(false);
+
+            // Only green jacks are commanded 10° nose up but green is not pressurised
+            test_bed = test_bed
+                .set_elevator_panel_hydraulic_jack_position(
+                    ActuatorSide::Left,
+                    ElevatorPanelPosition::Inward,
+                    -10.,
+                )
+                .set_elevator_panel_hydraulic_jack_position(
+                    ActuatorSide::Left,
+                    ElevatorPanelPosition::Outward,
+                    -10.,
+                )
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+
+            for panel in [
+                ElevatorPanelPosition::Inward,
+                ElevatorPanelPosition::Outward,
+            ] {
+                let position = test_bed
+                    .get_elevator_panel_position(ActuatorSide::Left, panel)
+                    .get::<ratio>();
+                assert!(position > 0.55 && position < 0.65);
+            }
+        }
+
+        #[test]
+        fn right_elevator_panels_revert_to_eha_on_yellow_loss() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_waiting_for(Duration::from_secs(1));
+
+            // Artificially set plane not on ground to allow EHA activation
+            test_bed.set_on_ground(false);
+
+            // Only yellow jacks are commanded 10° nose up but yellow is not pressurised
+            test_bed = test_bed
+                .set_elevator_panel_hydraulic_jack_position(
+                    ActuatorSide::Right,
+                    ElevatorPanelPosition::Inward,
+                    -10.,
+                )
+                .set_elevator_panel_hydraulic_jack_position(
+                    ActuatorSide::Right,
+                    ElevatorPanelPosition::Outward,
+                    -10.,
+                )
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            for panel in [
+                ElevatorPanelPosition::Inward,
+                ElevatorPanelPosition::Outward,
+            ] {
+                let position = test_bed
+                    .get_elevator_panel_position(ActuatorSide::Right, panel)
+                    .get::<ratio>();
+                assert!(position > 0.55 && position < 0.65);
+            }
+        }
+
+        #[test]
+        fn elevator_panels_do_not_revert_to_eha_when_inhibited_on_ground() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_elevator_panel_hydraulic_jack_position(
+                    ActuatorSide::Left,
+                    ElevatorPanelPosition::Inward,
+                    -10.,
+                )
+                .set_elevator_panel_hydraulic_jack_position(
+                    ActuatorSide::Right,
+                    ElevatorPanelPosition::Inward,
+                    -10.,
+                )
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            for side in [ActuatorSide::Left, ActuatorSide::Right] {
+                assert!(
+                    test_bed
+                        .get_elevator_panel_position(side, ElevatorPanelPosition::Inward)
+                        .get::<ratio>()
+                        < 0.5
+                );
+            }
+        }
+
+        #[test]
+        fn rudder_switches_to_ebha_electric_mode_on_green_and_yellow_loss() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_waiting_for(Duration::from_secs(1));
+
+            // Artificially set plane not on ground to allow EBHA activation
+            test_bed.set_on_ground(false);
+
+            // Rudder is commanded 15° in hydraulic mode but neither circuit is pressurised
+            test_bed = test_bed
+                .set_rudder_hydraulic_mode_position(15.)
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            for panel in [RudderPanelPosition::Upper, RudderPanelPosition::Lower] {
+                let position = test_bed.get_rudder_panel_position(panel).get::<ratio>();
+                assert!(position > 0.2 && position < 0.3);
+            }
+        }
+
+        #[test]
+        fn inner_left_aileron_panel_does_not_revert_to_eha_jack_when_inhibited_on_ground() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .reset_all_aileron_commands()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Left,
+                    AileronPanelPosition::Inward,
+                    AileronActuatorPosition::Outward,
+                )
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+
+            assert!(
+                test_bed
+                    .get_left_aileron_panel_position(AileronPanelPosition::Inward)
+                    .get::<ratio>()
+                    < 0.1
+            );
+        }
+
+        #[test]
+        fn middle_right_aileron_panel_responds_with_no_hyds_on_eha_jack() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .reset_all_aileron_commands()
+                .ac_ess_lost()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Right,
+                    AileronPanelPosition::Middle,
+                    AileronActuatorPosition::Inward,
+                )
+                .run_waiting_for(Duration::from_secs(1));
+
+            // Artificially set plane not on ground to allow EHA activation
+            test_bed.set_on_ground(false);
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            assert!(
+                test_bed
+                    .get_right_aileron_panel_position(AileronPanelPosition::Middle)
+                    .get::<ratio>()
+                    < 0.1
+            );
+
+            test_bed = test_bed
+                .reset_all_aileron_commands()
+                .ac_ess_active()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Right,
+                    AileronPanelPosition::Middle,
+                    AileronActuatorPosition::Inward,
+                )
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(
+                test_bed
+                    .get_right_aileron_panel_position(AileronPanelPosition::Middle,)
+                    .get::<ratio>()
+                    > 0.35
+            );
+            assert!(
+                test_bed
+                    .get_right_aileron_panel_position(AileronPanelPosition::Middle,)
+                    .get::<ratio>()
+                    < 0.55
+            );
+        }
+
+        #[test]
+        fn inner_right_aileron_panel_responds_only_with_green_pressure_on_outward_jack() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .reset_all_aileron_commands()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Right,
+                    AileronPanelPosition::Inward,
+                    AileronActuatorPosition::Outward,
+                )
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
             assert!(!test_bed.is_yellow_pressure_switch_pressurised());
 
             assert!(
@@ -10726,6 +11419,34 @@ mod tests {
             assert!(!test_bed.is_flaps_moving());
         }
 
+        #[test]
+        fn flaps_shaft_break_locks_flaps_with_wing_tip_brakes() {
+            let mut test_bed = test_bed_on_ground_with()
+                .on_the_ground()
+                .set_park_brake(true)
+                .start_eng1(Ratio::new::<percent>(50.))
+                .start_eng2(Ratio::new::<percent>(50.))
+                .set_flaps_handle_position(1)
+                .run_waiting_for(Duration::from_secs(40));
+
+            test_bed.fail(FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Flaps,
+                SecondarySurfaceSide::Left,
+            ));
+            test_bed = test_bed
+                .set_flaps_handle_position(4)
+                .run_waiting_for(Duration::from_secs(60));
+
+            let left_flaps_position = test_bed.get_flaps_left_position_percent();
+            let right_flaps_position = test_bed.get_flaps_right_position_percent();
+            assert!(right_flaps_position < 99.);
+            assert!((right_flaps_position - left_flaps_position).abs() < 5.);
+            assert!(!test_bed.is_flaps_moving());
+
+            assert!(test_bed.get_slats_left_position_percent() > 99.);
+            assert!(test_bed.get_slats_right_position_percent() > 99.);
+        }
+
         #[test]
         fn flaps_slats_moving() {
             let mut test_bed = test_bed_on_ground_with()
@@ -11105,6 +11826,47 @@ mod tests {
             assert!(test_bed.get_nose_steering_ratio() < Ratio::new::<ratio>(-0.9));
         }
 
+        #[test]
+        fn nose_wheel_steers_with_native_tug() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .attach_native_tug(true)
+                .set_native_tug_turn_command(1.)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.is_nw_disc_memo_shown());
+            assert!(test_bed.get_nose_steering_ratio() > Ratio::new::<ratio>(0.9));
+
+            test_bed = test_bed
+                .set_native_tug_turn_command(-1.)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_nose_steering_ratio() < Ratio::new::<ratio>(-0.9));
+            assert!(!test_bed.is_nose_gear_torque_link_overloaded());
+        }
+
+        #[test]
+        fn native_tug_overloads_torque_link_without_nws_pin() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .attach_native_tug(false)
+                .set_native_tug_turn_command(1.)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(!test_bed.is_nw_disc_memo_shown());
+            assert!(test_bed.is_nose_gear_torque_link_overloaded());
+        }
+
         #[test]
         fn nominal_gear_retraction_extension_cycles_in_flight() {
             let mut test_bed = test_bed_on_ground_with().set_cold_dark_inputs().in_flight();
@@ -11261,6 +12023,7 @@ mod tests {
 
             test_bed = test_bed
                 .on_the_ground_after_touchdown()
+                .spin_up_main_wheels(AngularVelocity::new::<revolution_per_minute>(1250.))
                 .set_left_brake(Ratio::new::<ratio>(1.))
                 .set_right_brake(Ratio::new::<ratio>(1.))
                 .run_waiting_for(Duration::from_secs_f64(2.));
@@ -11269,6 +12032,117 @@ mod tests {
             assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(500.));
         }
 
+        #[test]
+        fn gear_door_actuator_fault_requires_emergency_extension() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+
+            test_bed.fail(FailureType::GearDoorActuatorFault(
+                systems::shared::GearActuatorId::GearDoorNose,
+            ));
+
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(35.));
+
+            // Door is only held by its unpressurised actuator and slightly sags under its own weight
+            assert!(
+                test_bed.get_real_gear_door_position(GearWheel::NOSE) <= Ratio::new::<ratio>(0.05)
+            );
+            assert!(test_bed.is_all_gears_really_up());
+            assert!(test_bed.is_lgciu_control_fault());
+
+            test_bed = test_bed
+                .activate_emergency_gear_extension()
+                .run_waiting_for(Duration::from_secs_f64(70.));
+
+            assert!(test_bed.is_all_doors_really_down());
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::NOSE));
+        }
+
+        #[test]
+        fn gear_uplock_jam_requires_emergency_extension() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+
+            test_bed.fail(FailureType::GearUplockJammed(
+                systems::shared::GearActuatorId::GearRight,
+            ));
+
+            test_bed = test_bed
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs_f64(35.));
+
+            assert!(test_bed.get_real_gear_position(GearWheel::RIGHT) <= Ratio::new::<ratio>(0.01));
+            assert!(test_bed.get_real_gear_position(GearWheel::LEFT) >= Ratio::new::<ratio>(0.99));
+            assert!(test_bed.get_real_gear_position(GearWheel::NOSE) >= Ratio::new::<ratio>(0.99));
+            assert!(test_bed.is_lgciu_control_fault());
+
+            test_bed = test_bed
+                .activate_emergency_gear_extension()
+                .run_waiting_for(Duration::from_secs_f64(70.));
+
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::RIGHT));
+
+            test_bed = test_bed
+                .stow_emergency_gear_extension()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(test_bed.is_all_doors_really_up());
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.gear_system_state() == GearSystemState::AllDownLocked);
+            assert!(!test_bed.is_lgciu_control_fault());
+        }
+
+        #[test]
+        fn gear_not_downlocked_after_emergency_extension_with_downlock_failure() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(test_bed.gear_system_state() == GearSystemState::AllUpLocked);
+
+            test_bed.fail(FailureType::GearDownlockFailure(
+                systems::shared::GearActuatorId::GearNose,
+            ));
+
+            test_bed = test_bed
+                .set_green_ed_pump(false)
+                .activate_emergency_gear_extension()
+                .run_waiting_for(Duration::from_secs_f64(70.));
+
+            assert!(test_bed.is_all_doors_really_down());
+            assert!(test_bed.get_real_gear_position(GearWheel::NOSE) > Ratio::new::<ratio>(0.9));
+            assert!(!test_bed.is_gear_downlocked_by_lgciu(GearWheel::NOSE));
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::LEFT));
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::RIGHT));
+
+            // Restoring hydraulic pressure pushes the gear into its downlock
+            test_bed = test_bed
+                .stow_emergency_gear_extension()
+                .set_gear_lever_down()
+                .set_green_ed_pump(true)
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(test_bed.is_gear_downlocked_by_lgciu(GearWheel::NOSE));
+            assert!(test_bed.is_all_gears_really_down());
+            assert!(test_bed.gear_system_state() == GearSystemState::AllDownLocked);
+        }
+
         #[test]
         fn green_epump_buildup_auxiliary_section_when_cargo_doors() {
             let mut test_bed = test_bed_on_ground_with()
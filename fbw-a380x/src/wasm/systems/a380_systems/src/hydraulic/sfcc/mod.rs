@@ -1,7 +1,7 @@
 use crate::systems::shared::arinc429::{Arinc429Word, SignStatus};
 use systems::accept_iterable;
 use systems::hydraulic::command_sensor_unit::{CSUMonitor, CSU};
-use systems::hydraulic::flap_slat::ValveBlock;
+use systems::hydraulic::flap_slat::{AsymmetryPositionPickoffUnits, ValveBlock};
 use systems::shared::{AdirsMeasurementOutputs, PositionPickoffUnit};
 
 use systems::simulation::{
@@ -13,6 +13,7 @@ use uom::si::{angle::degree, f64::*, length::foot, velocity::knot};
 
 mod flaps_channel;
 mod slats_channel;
+mod wing_tip_brake;
 use flaps_channel::FlapsChannel;
 use slats_channel::SlatsChannel;
 
@@ -90,8 +91,8 @@ impl SlatFlapControlComputer {
             flap_actual_position_word_id: context
                 .get_identifier(format!("SFCC_{num}_FLAP_ACTUAL_POSITION_WORD")),
 
-            flaps_channel: FlapsChannel::new(),
-            slats_channel: SlatsChannel::new(),
+            flaps_channel: FlapsChannel::new(context, num),
+            slats_channel: SlatsChannel::new(context, num),
 
             flaps_conf: FlapsConf::Conf0,
             flap_load_relief_active: false,
@@ -256,8 +257,8 @@ impl SlatFlapControlComputer {
         &mut self,
         context: &UpdateContext,
         adirs: &impl AdirsMeasurementOutputs,
-        flaps_feedback: &impl PositionPickoffUnit,
-        slats_feedback: &impl PositionPickoffUnit,
+        flaps_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
+        slats_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
     ) {
         self.csu_monitor.update(context);
 
@@ -272,21 +273,23 @@ impl SlatFlapControlComputer {
 
     fn slat_flap_system_status_word(&self) -> Arinc429Word<u32> {
         let flaps_handle_position = self.csu_monitor.get_current_detent();
+        let slat_wtb_locked_out = self.slats_channel.get_wtb_locked_out();
+        let flap_wtb_locked_out = self.flaps_channel.get_wtb_locked_out();
         let mut word = Arinc429Word::new(0, SignStatus::NormalOperation);
 
-        word.set_bit(11, false);
-        word.set_bit(12, false);
+        word.set_bit(11, slat_wtb_locked_out);
+        word.set_bit(12, flap_wtb_locked_out);
         word.set_bit(13, false);
         word.set_bit(14, false);
-        word.set_bit(15, false);
-        word.set_bit(16, false);
+        word.set_bit(15, slat_wtb_locked_out);
+        word.set_bit(16, flap_wtb_locked_out);
         word.set_bit(17, flaps_handle_position == CSU::Conf0);
         word.set_bit(18, flaps_handle_position == CSU::Conf1);
         word.set_bit(19, flaps_handle_position == CSU::Conf2);
         word.set_bit(20, flaps_handle_position == CSU::Conf3);
         word.set_bit(21, flaps_handle_position == CSU::ConfFull);
         word.set_bit(22, self.flap_load_relief_active);
-        word.set_bit(23, false);
+        word.set_bit(23, self.flaps_channel.get_flap_attachment_failure());
         word.set_bit(24, self.alpha_speed_lock_active);
         word.set_bit(25, self.cruise_baulk_active);
         word.set_bit(
@@ -334,8 +337,8 @@ impl SlatFlapControlComputer {
             slats_fppu_angle >= Angle::new::<degree>(317.810)
                 && slats_fppu_angle <= Angle::new::<degree>(343.695),
         );
-        word.set_bit(16, false);
-        word.set_bit(17, false);
+        word.set_bit(16, self.slats_channel.get_wtb_locked_out());
+        word.set_bit(17, self.slats_channel.get_wtb_locked_out());
         word.set_bit(18, true);
         // Flaps retracted
         word.set_bit(
@@ -367,8 +370,8 @@ impl SlatFlapControlComputer {
             flaps_fppu_angle >= Angle::new::<degree>(331.343)
                 && flaps_fppu_angle <= Angle::new::<degree>(349.495),
         );
-        word.set_bit(24, false);
-        word.set_bit(25, false);
+        word.set_bit(24, self.flaps_channel.get_wtb_locked_out());
+        word.set_bit(25, self.flaps_channel.get_wtb_locked_out());
         word.set_bit(26, false);
         word.set_bit(27, false);
         word.set_bit(28, false);
@@ -397,6 +400,8 @@ impl SlatFlapControlComputer {
 impl SimulationElement for SlatFlapControlComputer {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.csu_monitor.accept(visitor);
+        self.flaps_channel.accept(visitor);
+        self.slats_channel.accept(visitor);
         visitor.visit(self);
     }
 
@@ -440,8 +445,8 @@ impl SlatFlapComplex {
         &mut self,
         context: &UpdateContext,
         adirs: &impl AdirsMeasurementOutputs,
-        flaps_feedback: &impl PositionPickoffUnit,
-        slats_feedback: &impl PositionPickoffUnit,
+        flaps_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
+        slats_feedback: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
     ) {
         self.sfcc[0].update(context, adirs, flaps_feedback, slats_feedback);
         self.sfcc[1].update(context, adirs, flaps_feedback, slats_feedback);
@@ -466,6 +471,7 @@ impl SimulationElement for SlatFlapComplex {
 mod tests {
     use super::*;
     use std::time::Duration;
+    use systems::hydraulic::flap_slat::SolenoidStatus;
     use systems::simulation::{
         test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
         Aircraft, Read, SimulatorReader,
@@ -475,6 +481,8 @@ mod tests {
 
     struct SlatFlapGear {
         current_angle: Angle,
+        left_asymmetry: Angle,
+        is_skewed: bool,
         speed: AngularVelocity,
         max_angle: Angle,
         left_position_percent_id: VariableIdentifier,
@@ -487,6 +495,19 @@ mod tests {
             self.current_angle
         }
     }
+    impl AsymmetryPositionPickoffUnits for SlatFlapGear {
+        fn left_angle(&self) -> Angle {
+            self.current_angle + self.left_asymmetry
+        }
+
+        fn right_angle(&self) -> Angle {
+            self.current_angle
+        }
+
+        fn is_skew_detected(&self) -> bool {
+            self.is_skewed
+        }
+    }
 
     impl SlatFlapGear {
         const ANGLE_DELTA_DEGREE: f64 = 0.01;
@@ -499,6 +520,8 @@ mod tests {
         ) -> Self {
             Self {
                 current_angle: Angle::new::<degree>(0.),
+                left_asymmetry: Angle::default(),
+                is_skewed: false,
                 speed,
                 max_angle,
 
@@ -618,6 +641,18 @@ mod tests {
             self.adirs.set_angle_of_attack(v);
         }
 
+        fn set_flaps_left_asymmetry(&mut self, asymmetry: Angle) {
+            self.flap_gear.left_asymmetry = asymmetry;
+        }
+
+        fn set_slats_left_asymmetry(&mut self, asymmetry: Angle) {
+            self.slat_gear.left_asymmetry = asymmetry;
+        }
+
+        fn set_flaps_skewed(&mut self, is_skewed: bool) {
+            self.flap_gear.is_skewed = is_skewed;
+        }
+
         fn surface_movement_required(demanded_angle: Angle, feedback_angle: Angle) -> bool {
             (demanded_angle - feedback_angle).get::<degree>().abs() > Self::EQUAL_ANGLE_DELTA_DEGREE
         }
@@ -802,6 +837,35 @@ mod tests {
             self
         }
 
+        fn set_flaps_left_asymmetry(mut self, degrees: f64) -> Self {
+            self.command(|a| a.set_flaps_left_asymmetry(Angle::new::<degree>(degrees)));
+            self
+        }
+
+        fn set_slats_left_asymmetry(mut self, degrees: f64) -> Self {
+            self.command(|a| a.set_slats_left_asymmetry(Angle::new::<degree>(degrees)));
+            self
+        }
+
+        fn set_flaps_skewed(mut self, is_skewed: bool) -> Self {
+            self.command(|a| a.set_flaps_skewed(is_skewed));
+            self
+        }
+
+        fn set_wtb_maintenance_reset(mut self, surface_type: &str, reset: bool) -> Self {
+            self.write_by_name(&format!("SFCC_1_{surface_type}_WTB_RESET"), reset);
+            self.write_by_name(&format!("SFCC_2_{surface_type}_WTB_RESET"), reset);
+            self
+        }
+
+        fn flaps_wtb_status(&self, idx: usize) -> SolenoidStatus {
+            self.query(|a| a.slat_flap_complex.flap_pcu(idx).get_wtb_status())
+        }
+
+        fn slats_wtb_status(&self, idx: usize) -> SolenoidStatus {
+            self.query(|a| a.slat_flap_complex.slat_pcu(idx).get_wtb_status())
+        }
+
         fn set_on_ground(mut self, on_ground: bool) -> Self {
             self.write_by_name("SIM ON GROUND", on_ground);
             self
@@ -2133,4 +2197,115 @@ mod tests {
         assert!(!test_bed.read_slat_flap_system_status_word(1).get_bit(24));
         assert!(!test_bed.read_slat_flap_system_status_word(2).get_bit(24));
     }
+
+    #[test]
+    fn flaps_wtb_not_engaged_without_asymmetry() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_blue_hyd_pressure()
+            .set_flaps_handle_position(4)
+            .run_waiting_for(Duration::from_secs(40));
+
+        assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::DeEnergised);
+        assert_eq!(test_bed.flaps_wtb_status(1), SolenoidStatus::DeEnergised);
+        assert!(!test_bed.read_slat_flap_system_status_word(1).get_bit(12));
+        assert!(!test_bed.read_slat_flap_system_status_word(1).get_bit(16));
+    }
+
+    #[test]
+    fn flaps_asymmetry_engages_and_locks_out_wtb() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_blue_hyd_pressure()
+            .set_flaps_handle_position(2)
+            .run_waiting_for(Duration::from_secs(5))
+            .set_flaps_left_asymmetry(-10.)
+            .run_one_tick();
+
+        for idx in 0..2 {
+            assert_eq!(test_bed.flaps_wtb_status(idx), SolenoidStatus::Energised);
+            assert_eq!(
+                test_bed.query(|a| a.slat_flap_complex.flap_pcu(idx).get_pob_status()),
+                SolenoidStatus::DeEnergised
+            );
+            assert!(test_bed
+                .query(|a| a.slat_flap_complex.flap_pcu(idx).get_command_status())
+                .is_none());
+        }
+
+        for num in 1..=2 {
+            assert!(test_bed.read_slat_flap_system_status_word(num).get_bit(12));
+            assert!(test_bed.read_slat_flap_system_status_word(num).get_bit(16));
+            assert!(!test_bed.read_slat_flap_system_status_word(num).get_bit(15));
+            assert!(test_bed
+                .read_slat_flap_actual_position_word(num)
+                .get_bit(24));
+            assert!(test_bed
+                .read_slat_flap_actual_position_word(num)
+                .get_bit(25));
+            assert!(!test_bed
+                .read_slat_flap_actual_position_word(num)
+                .get_bit(17));
+        }
+        assert_eq!(test_bed.slats_wtb_status(0), SolenoidStatus::DeEnergised);
+
+        // Lockout remains after the asymmetry is gone
+        test_bed = test_bed
+            .set_flaps_left_asymmetry(0.)
+            .run_waiting_for(Duration::from_secs(2));
+
+        assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::Energised);
+        assert_eq!(test_bed.flaps_wtb_status(1), SolenoidStatus::Energised);
+
+        test_bed = test_bed
+            .set_wtb_maintenance_reset("FLAPS", true)
+            .run_one_tick()
+            .set_wtb_maintenance_reset("FLAPS", false)
+            .run_one_tick();
+
+        assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::DeEnergised);
+        assert_eq!(test_bed.flaps_wtb_status(1), SolenoidStatus::DeEnergised);
+        assert!(!test_bed.read_slat_flap_system_status_word(1).get_bit(16));
+    }
+
+    #[test]
+    fn slats_asymmetry_engages_slats_wtb() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_blue_hyd_pressure()
+            .set_flaps_handle_position(1)
+            .run_waiting_for(Duration::from_secs(5))
+            .set_slats_left_asymmetry(10.)
+            .run_one_tick();
+
+        assert_eq!(test_bed.slats_wtb_status(0), SolenoidStatus::Energised);
+        assert_eq!(test_bed.slats_wtb_status(1), SolenoidStatus::Energised);
+        assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::DeEnergised);
+
+        assert!(test_bed.read_slat_flap_system_status_word(1).get_bit(11));
+        assert!(test_bed.read_slat_flap_system_status_word(1).get_bit(15));
+        assert!(test_bed.read_slat_flap_actual_position_word(1).get_bit(16));
+        assert!(test_bed.read_slat_flap_actual_position_word(1).get_bit(17));
+        assert!(!test_bed.read_slat_flap_actual_position_word(1).get_bit(24));
+        assert!(!test_bed.read_slat_flap_actual_position_word(1).get_bit(25));
+    }
+
+    #[test]
+    fn flaps_skew_sets_attachment_failure_and_engages_wtb() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_blue_hyd_pressure()
+            .set_flaps_handle_position(2)
+            .run_waiting_for(Duration::from_secs(5))
+            .set_flaps_skewed(true)
+            .run_one_tick();
+
+        assert_eq!(test_bed.flaps_wtb_status(0), SolenoidStatus::Energised);
+        assert!(test_bed.read_slat_flap_system_status_word(1).get_bit(23));
+        assert!(test_bed.read_slat_flap_system_status_word(2).get_bit(23));
+    }
 }
@@ -1,20 +1,29 @@
 use systems::{
-    hydraulic::flap_slat::{ChannelCommand, SolenoidStatus, ValveBlock},
+    hydraulic::flap_slat::{
+        AsymmetryPositionPickoffUnits, ChannelCommand, SecondarySurfaceType, SolenoidStatus,
+        ValveBlock,
+    },
     shared::PositionPickoffUnit,
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
 };
 use uom::si::{angle::degree, f64::*};
 
+use super::wing_tip_brake::WingTipBrakeMonitor;
 use super::{FlapsConf, SlatFlapControlComputerMisc};
 
 pub(super) struct SlatsChannel {
     demanded_angle: Angle,
     feedback_angle: Angle,
+
+    wtb_monitor: WingTipBrakeMonitor,
 }
 impl SlatsChannel {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(context: &mut InitContext, num: u8) -> Self {
         Self {
             demanded_angle: Angle::new::<degree>(0.),
             feedback_angle: Angle::new::<degree>(0.),
+
+            wtb_monitor: WingTipBrakeMonitor::new(context, num, SecondarySurfaceType::Slats),
         }
     }
 
@@ -35,10 +44,12 @@ impl SlatsChannel {
     pub(super) fn update(
         &mut self,
         flaps_conf: FlapsConf,
-        feedback_position: &impl PositionPickoffUnit,
+        feedback_position: &(impl PositionPickoffUnit + AsymmetryPositionPickoffUnits),
     ) {
         self.demanded_angle = Self::demanded_slats_fppu_angle_from_conf(flaps_conf);
         self.feedback_angle = feedback_position.angle();
+
+        self.wtb_monitor.update(feedback_position);
     }
 
     pub(super) fn get_demanded_angle(&self) -> Angle {
@@ -48,12 +59,20 @@ impl SlatsChannel {
     pub(super) fn get_feedback_angle(&self) -> Angle {
         self.feedback_angle
     }
+
+    pub(super) fn get_wtb_locked_out(&self) -> bool {
+        self.wtb_monitor.is_locked_out()
+    }
 }
 // When the POB (Pressure OFF Brake) solenoid is energised, then the hydraulic motors are allowed to move.
 // When the POB solenoid is de-energised (due to SFCC command or no SFCC power), then the hydraulic motors
 // are held in position and can't move.
 impl ValveBlock for SlatsChannel {
     fn get_pob_status(&self) -> SolenoidStatus {
+        if self.wtb_monitor.is_locked_out() {
+            return SolenoidStatus::DeEnergised;
+        }
+
         let demanded_angle = self.get_demanded_angle();
         let feedback_angle = self.get_feedback_angle();
         let in_target_position =
@@ -65,6 +84,10 @@ impl ValveBlock for SlatsChannel {
     }
 
     fn get_command_status(&self) -> Option<ChannelCommand> {
+        if self.wtb_monitor.is_locked_out() {
+            return None;
+        }
+
         let demanded_angle = self.get_demanded_angle();
         let feedback_angle = self.get_feedback_angle();
         let in_target_position = SlatFlapControlComputerMisc::in_positioning_threshold_range(
@@ -79,4 +102,18 @@ impl ValveBlock for SlatsChannel {
             Some(ChannelCommand::Retract)
         }
     }
+
+    fn get_wtb_status(&self) -> SolenoidStatus {
+        if self.wtb_monitor.is_locked_out() {
+            SolenoidStatus::Energised
+        } else {
+            SolenoidStatus::DeEnergised
+        }
+    }
+}
+impl SimulationElement for SlatsChannel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.wtb_monitor.accept(visitor);
+        visitor.visit(self);
+    }
 }
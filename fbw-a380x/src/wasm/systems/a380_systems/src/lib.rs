@@ -7,6 +7,7 @@ mod control_display_system;
 mod electrical;
 mod fire_and_smoke_protection;
 mod fuel;
+mod ground_services;
 pub mod hydraulic;
 mod icing;
 mod navigation;
@@ -21,6 +22,7 @@ use self::{
     avionics_data_communication_network::A380AvionicsDataCommunicationNetwork,
     control_display_system::A380ControlDisplaySystem,
     fuel::A380Fuel,
+    ground_services::A380GroundServices,
     pneumatic::{A380Pneumatic, A380PneumaticOverheadPanel},
     structural_flex::A380StructuralFlex,
 };
@@ -28,7 +30,7 @@ use airframe::A380Airframe;
 use avionics_data_communication_network::A380AvionicsDataCommunicationNetworkSimvarTranslator;
 use electrical::{
     A380Electrical, A380ElectricalOverheadPanel, A380EmergencyElectricalOverheadPanel,
-    APU_START_MOTOR_BUS_TYPE,
+    APU_START_MOTOR_BUS_TYPE, EGPWC, LGCIU_1, LGCIU_2,
 };
 use fire_and_smoke_protection::A380FireAndSmokeProtection;
 use hydraulic::{autobrakes::A380AutobrakePanel, A380Hydraulic, A380HydraulicOverheadPanel};
@@ -41,6 +43,7 @@ use uom::si::{f64::Length, length::nautical_mile};
 
 use systems::{
     accept_iterable,
+    air_starter_unit::AirStarterUnit,
     apu::{
         AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory, AuxiliaryPowerUnitFireOverheadPanel,
         AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator, Pw980Constants, Pw980StartMotor,
@@ -67,6 +70,7 @@ pub struct A380 {
     apu: AuxiliaryPowerUnit<Pw980ApuGenerator, Pw980StartMotor, Pw980Constants, 2>,
     apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
     apu_overhead: AuxiliaryPowerUnitOverheadPanel,
+    asu: AirStarterUnit,
     pneumatic_overhead: A380PneumaticOverheadPanel,
     pressurization_overhead: A380PressurizationOverheadPanel,
     electrical_overhead: A380ElectricalOverheadPanel,
@@ -75,6 +79,7 @@ pub struct A380 {
     airframe: A380Airframe,
     fire_and_smoke_protection: A380FireAndSmokeProtection,
     fuel: A380Fuel,
+    ground_services: A380GroundServices,
     engine_1: TrentEngine,
     engine_2: TrentEngine,
     engine_3: TrentEngine,
@@ -118,6 +123,7 @@ impl A380 {
             ),
             apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
             apu_overhead: AuxiliaryPowerUnitOverheadPanel::new(context),
+            asu: AirStarterUnit::new(context),
             pneumatic_overhead: A380PneumaticOverheadPanel::new(context),
             pressurization_overhead: A380PressurizationOverheadPanel::new(context),
             electrical_overhead: A380ElectricalOverheadPanel::new(context),
@@ -126,6 +132,7 @@ impl A380 {
             airframe: A380Airframe::new(context),
             fire_and_smoke_protection: A380FireAndSmokeProtection::new(context),
             fuel: A380Fuel::new(context),
+            ground_services: A380GroundServices::new(context),
             engine_1: TrentEngine::new(context, 1),
             engine_2: TrentEngine::new(context, 2),
             engine_3: TrentEngine::new(context, 3),
@@ -136,8 +143,8 @@ impl A380 {
             ext_pwrs: [1, 2, 3, 4].map(|i| ExternalPowerSource::new(context, i)),
             lgcius: LandingGearControlInterfaceUnitSet::new(
                 context,
-                ElectricalBusType::DirectCurrentEssential,
-                ElectricalBusType::DirectCurrentGndFltService,
+                LGCIU_1.bus_type(),
+                LGCIU_2.bus_type(),
             ),
             hydraulic: A380Hydraulic::new(context),
             hydraulic_overhead: A380HydraulicOverheadPanel::new(context),
@@ -148,7 +155,7 @@ impl A380 {
             cds: A380ControlDisplaySystem::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
-                ElectricalBusType::AlternatingCurrentEssential,
+                EGPWC.bus_type(),
                 vec![
                     Length::new::<nautical_mile>(0.0),
                     Length::new::<nautical_mile>(10.0),
@@ -184,7 +191,8 @@ impl Aircraft for A380 {
             &self.apu_overhead,
             self.fire_and_smoke_protection.apu_fire_on_ground(),
             &self.apu_fire_overhead,
-            self.pneumatic_overhead.apu_bleed_is_on(),
+            self.pneumatic_overhead.apu_bleed_is_on()
+                && !self.pneumatic.apu_bleed_is_leak_isolated(),
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
@@ -218,6 +226,8 @@ impl Aircraft for A380 {
             .update_after_electrical(&self.electrical, electricity);
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
+        self.ground_services
+            .update(context, &mut self.payload, &mut self.fuel);
         self.payload.update(context);
         self.airframe
             .update(&self.fuel, &self.payload, &self.payload);
@@ -235,6 +245,9 @@ impl Aircraft for A380 {
         );
         self.apu_overhead.update_after_apu(&self.apu);
 
+        self.asu
+            .update(context, self.pneumatic.air_starter_unit_delivered_flow());
+
         self.adcn.update();
         self.adcn_simvar_translation.update(&self.adcn);
         self.lgcius.update(
@@ -291,8 +304,11 @@ impl Aircraft for A380 {
             &self.pneumatic_overhead,
             &self.engine_fire_overhead,
             &self.apu,
+            &self.asu,
             &self.air_conditioning,
         );
+        self.pneumatic_overhead
+            .update_after_pneumatic(&self.pneumatic);
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
         self.air_conditioning.update(
@@ -312,6 +328,7 @@ impl Aircraft for A380 {
             &self.pneumatic_overhead,
             &self.pressurization_overhead,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            &self.fire_and_smoke_protection,
         );
 
         self.cds.update();
@@ -367,10 +384,12 @@ impl SimulationElement for A380 {
         self.apu.accept(visitor);
         self.apu_fire_overhead.accept(visitor);
         self.apu_overhead.accept(visitor);
+        self.asu.accept(visitor);
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fire_and_smoke_protection.accept(visitor);
         self.fuel.accept(visitor);
+        self.ground_services.accept(visitor);
         self.payload.accept(visitor);
         self.airframe.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
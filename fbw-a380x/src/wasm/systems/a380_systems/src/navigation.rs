@@ -1,3 +1,4 @@
+use crate::electrical::{RADIO_ALTIMETER_1, RADIO_ALTIMETER_2, RADIO_ALTIMETER_3};
 use systems::navigation::adirs::{
     AirDataInertialReferenceSystem, AirDataInertialReferenceUnitProgramming,
     LowSpeedWarningThreshold,
@@ -56,7 +57,7 @@ impl A380RadioAltimeters {
             radio_altimeter_1: A380RadioAltimeter::new(
                 context,
                 1,
-                ElectricalBusType::AlternatingCurrent(1),
+                RADIO_ALTIMETER_1.bus_type(),
                 AntennaInstallation::new(
                     // Sim alt over ground minus RA height over ground
                     Length::new::<foot>(14.75) - Length::new::<meter>(3.78),
@@ -74,7 +75,7 @@ impl A380RadioAltimeters {
             radio_altimeter_2: A380RadioAltimeter::new(
                 context,
                 2,
-                ElectricalBusType::AlternatingCurrent(2),
+                RADIO_ALTIMETER_2.bus_type(),
                 AntennaInstallation::new(
                     Length::new::<foot>(14.75) - Length::new::<meter>(3.89),
                     Length::new::<meter>(13.35),
@@ -89,7 +90,7 @@ impl A380RadioAltimeters {
             radio_altimeter_3: A380RadioAltimeter::new(
                 context,
                 3,
-                ElectricalBusType::AlternatingCurrentEssential,
+                RADIO_ALTIMETER_3.bus_type(),
                 AntennaInstallation::new(
                     Length::new::<foot>(14.75) - Length::new::<meter>(4.27),
                     Length::new::<meter>(15.90),
@@ -5,6 +5,7 @@ use std::{cell::Cell, rc::Rc};
 use uom::si::{f64::Mass, mass::kilogram};
 
 use systems::{
+    ground_services::GroundServiceProcess,
     payload::{
         BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
         NumberOfPassengers, PassengerDeck, PassengerPayload, Pax, PaxInfo, PayloadManager,
@@ -453,6 +454,19 @@ impl CargoPayload for A380Payload {
         self.target_cargo_center_of_gravity().x
     }
 }
+impl GroundServiceProcess for A380Payload {
+    fn start(&mut self) {
+        self.payload_manager.start();
+    }
+
+    fn stop(&mut self) {
+        self.payload_manager.stop();
+    }
+
+    fn is_running(&self) -> bool {
+        self.payload_manager.is_running()
+    }
+}
 impl SimulationElement for A380Payload {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.payload_manager.accept(visitor);
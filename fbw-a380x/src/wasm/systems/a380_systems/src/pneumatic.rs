@@ -14,18 +14,20 @@ use systems::{
     air_conditioning::PackFlowControllers,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
-        valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
-        ControllablePneumaticValve, CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode,
-        DifferentialPressureTransducer, EngineCompressionChamberController, EngineModeSelector,
-        EngineState, PneumaticContainer, PneumaticPipe, PneumaticValveSignal, Precooler,
-        PressureTransducer, PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
+        leak_detection::{BleedDuctArea, BleedLeakDetector},
+        valve::*,
+        BleedMonitoringComputerIsAliveSignal, CompressionChamber, ControllablePneumaticValve,
+        CrossBleedValveSelectorKnob, CrossBleedValveSelectorMode, DifferentialPressureTransducer,
+        EngineCompressionChamberController, EngineModeSelector, EngineState, PneumaticContainer,
+        PneumaticPipe, PneumaticValveSignal, Precooler, PressureTransducer,
+        PressurisedReservoirWithExhaustValve, PressurizeableReservoir,
         TargetPressureTemperatureSignal, VariableVolumeContainer,
     },
     shared::{
-        pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
-        ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
-        PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        pid::PidController, update_iterator::MaxStepLoop, BleedLeakZone, ControllerSignal,
+        ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
+        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
+        PackFlowValveState, PneumaticBleed, PneumaticValve, PneumaticValveId, ReservoirAirPressure,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -110,6 +112,8 @@ pub struct A380Pneumatic {
     core_processing_input_output_module_a: CoreProcessingInputOutputModuleA,
     engine_systems: [EngineBleedAirSystem; 4],
 
+    bleed_duct_areas: BleedDuctAreas,
+
     cross_bleed_valves: [CrossBleedValve; 3],
 
     fadec: FullAuthorityDigitalEngineControl,
@@ -118,6 +122,9 @@ pub struct A380Pneumatic {
     apu_compression_chamber: CompressionChamber,
     apu_bleed_air_valve: DefaultValve,
 
+    air_starter_unit_compression_chamber: CompressionChamber,
+    air_starter_unit_bleed_air_valve: PurelyPneumaticValve,
+
     hydraulic_reservoir_bleed_air_valves: [PurelyPneumaticValve; 2],
     hydraulic_reservoir_bleed_air_pipe: PneumaticPipe,
 
@@ -139,6 +146,7 @@ impl A380Pneumatic {
             apu_bleed_air_pressure_id: context
                 .get_identifier("PNEU_APU_BLEED_CONTAINER_PRESSURE".to_owned()),
             core_processing_input_output_module_a: CoreProcessingInputOutputModuleA::new(
+                context,
                 ElectricalBusType::DirectCurrentEssential, // TTM 2
             ),
             engine_systems: [
@@ -147,10 +155,20 @@ impl A380Pneumatic {
                 EngineBleedAirSystem::new(context, 3, ElectricalBusType::DirectCurrent(2)),
                 EngineBleedAirSystem::new(context, 4, ElectricalBusType::DirectCurrent(2)),
             ],
+            bleed_duct_areas: BleedDuctAreas::new(),
             cross_bleed_valves: [
-                CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_L_OPEN".to_owned())),
-                CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_C_OPEN".to_owned())),
-                CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_R_OPEN".to_owned())),
+                CrossBleedValve::new(
+                    context.get_identifier("PNEU_XBLEED_VALVE_L_OPEN".to_owned()),
+                    1,
+                ),
+                CrossBleedValve::new(
+                    context.get_identifier("PNEU_XBLEED_VALVE_C_OPEN".to_owned()),
+                    2,
+                ),
+                CrossBleedValve::new(
+                    context.get_identifier("PNEU_XBLEED_VALVE_R_OPEN".to_owned()),
+                    3,
+                ),
             ],
             fadec: FullAuthorityDigitalEngineControl::new(context),
             engine_starter_valve_controllers: [
@@ -160,7 +178,12 @@ impl A380Pneumatic {
                 EngineStarterValveController::new(4),
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
-            apu_bleed_air_valve: DefaultValve::new_closed(),
+            apu_bleed_air_valve: DefaultValve::new_closed()
+                .with_failures(PneumaticValveId::ApuBleed),
+            air_starter_unit_compression_chamber: CompressionChamber::new(
+                Volume::new::<cubic_meter>(5.),
+            ),
+            air_starter_unit_bleed_air_valve: PurelyPneumaticValve::default(),
             hydraulic_reservoir_bleed_air_valves: [
                 PurelyPneumaticValve::new(),
                 PurelyPneumaticValve::new(),
@@ -203,6 +226,7 @@ impl A380Pneumatic {
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
+        asu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers,
     ) {
         self.physics_updater.update(context);
@@ -214,6 +238,7 @@ impl A380Pneumatic {
                 pneumatic_overhead_panel,
                 engine_fire_push_buttons,
                 apu,
+                asu,
                 pack_flow_valve_signals,
             );
         }
@@ -226,23 +251,33 @@ impl A380Pneumatic {
         overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
+        asu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers,
     ) {
         self.apu_compression_chamber.update(apu);
+        self.air_starter_unit_compression_chamber.update(asu);
 
         self.core_processing_input_output_module_a.update(
             context,
             &self.engine_systems,
+            &self.bleed_duct_areas,
             &self.apu_bleed_air_valve,
             overhead_panel,
             engine_fire_push_buttons,
         );
 
-        for cross_bleed_valve in self.cross_bleed_valves.iter_mut() {
+        let leak_isolation = self.core_processing_input_output_module_a.leak_isolation();
+        for (cross_bleed_valve, is_leak_isolated) in self
+            .cross_bleed_valves
+            .iter_mut()
+            .zip(leak_isolation.cross_bleed_valves_closed)
+        {
             // TODO: See TTM 3. There is a relay system that connects the 4 CPIOM units (PADS application) to the 3 crossbleed valves and the APU isolation valve
             // TLDR: CPIOM-A1+A2 control left xbleed and APU isolation valve, CPIOM-A3+A4 control center and right xbleed valve
-            cross_bleed_valve
-                .update_open_amount(&self.core_processing_input_output_module_a.units[0])
+            cross_bleed_valve.update_open_amount(&LeakIsolatingCrossBleedValveController::new(
+                &self.core_processing_input_output_module_a.units[0],
+                is_leak_isolated,
+            ))
         }
 
         for controller in self.engine_starter_valve_controllers.iter_mut() {
@@ -272,6 +307,11 @@ impl A380Pneumatic {
             &mut self.apu_compression_chamber,
             engine_1_system,
         );
+        self.air_starter_unit_bleed_air_valve.update_move_fluid(
+            context,
+            &mut self.air_starter_unit_compression_chamber,
+            engine_1_system,
+        );
 
         // Hydraulic reservoir pressurization.
         // G+Y are pressurized through a pipe that is shared between engine 1 and engine 4 systems (downstream the bleed valve)
@@ -326,6 +366,13 @@ impl A380Pneumatic {
             engine_4_system,
             pack_flow_valve_signals,
         );
+
+        self.bleed_duct_areas.update(
+            context,
+            &self.engine_systems,
+            &self.apu_compression_chamber,
+            &self.apu_bleed_air_valve,
+        );
     }
 
     // TODO: Returning a mutable reference here is not great. I was running into an issue with the update order:
@@ -351,6 +398,29 @@ impl A380Pneumatic {
     pub fn packs(&mut self) -> &mut [PackComplex; 2] {
         &mut self.packs
     }
+
+    /// The flow currently drawn from the ground cart through the HP ground connection.
+    pub fn air_starter_unit_delivered_flow(&self) -> MassRate {
+        self.air_starter_unit_bleed_air_valve.fluid_flow()
+    }
+
+    /// A leak in the pylon or wing of the given engine was detected
+    pub fn engine_bleed_leak_detected(&self, engine_number: usize) -> bool {
+        self.core_processing_input_output_module_a
+            .engine_bleed_leak_detected(engine_number)
+    }
+
+    pub fn apu_bleed_leak_detected(&self) -> bool {
+        self.core_processing_input_output_module_a
+            .apu_bleed_leak_detected()
+    }
+
+    /// The CPIOM-A commands the ECB to close the APU bleed valve, to isolate a leak
+    pub fn apu_bleed_is_leak_isolated(&self) -> bool {
+        self.core_processing_input_output_module_a
+            .leak_isolation()
+            .apu_bleed_valve_closed
+    }
 }
 impl PneumaticBleed for A380Pneumatic {
     fn apu_bleed_is_on(&self) -> bool {
@@ -399,6 +469,9 @@ impl PackFlowValveState for A380Pneumatic {
 impl SimulationElement for A380Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fadec.accept(visitor);
+        self.core_processing_input_output_module_a.accept(visitor);
+        self.bleed_duct_areas.accept(visitor);
+        self.apu_bleed_air_valve.accept(visitor);
 
         accept_iterable!(self.cross_bleed_valves, visitor);
         accept_iterable!(self.engine_systems, visitor);
@@ -460,16 +533,139 @@ impl EngineStarterValveController {
     }
 }
 
+/// The valves closed by the CPIOM-A to isolate a detected bleed leak
+#[derive(Clone, Copy, Default)]
+struct BleedLeakIsolation {
+    engine_bleed_valves_closed: [bool; 4],
+    /// Left, center and right crossbleed valves
+    cross_bleed_valves_closed: [bool; 3],
+    apu_bleed_valve_closed: bool,
+}
+impl BleedLeakIsolation {
+    fn engine_bleed_valve_closed(&self, engine_number: usize) -> bool {
+        self.engine_bleed_valves_closed[engine_number - 1]
+    }
+}
+
+/// The areas around the hot air ducts which are monitored by the leak detection loops
+struct BleedDuctAreas {
+    pylons: [BleedDuctArea; 4],
+    wings: [BleedDuctArea; 2],
+    pack_bays: [BleedDuctArea; 2],
+    apu_duct: BleedDuctArea,
+}
+impl BleedDuctAreas {
+    fn new() -> Self {
+        Self {
+            pylons: [
+                BleedDuctArea::new(BleedLeakZone::Pylon(1)),
+                BleedDuctArea::new(BleedLeakZone::Pylon(2)),
+                BleedDuctArea::new(BleedLeakZone::Pylon(3)),
+                BleedDuctArea::new(BleedLeakZone::Pylon(4)),
+            ],
+            wings: [
+                BleedDuctArea::new(BleedLeakZone::Wing(1)),
+                BleedDuctArea::new(BleedLeakZone::Wing(2)),
+            ],
+            pack_bays: [
+                BleedDuctArea::new(BleedLeakZone::PackBay(1)),
+                BleedDuctArea::new(BleedLeakZone::PackBay(2)),
+            ],
+            apu_duct: BleedDuctArea::new(BleedLeakZone::ApuDuct),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_systems: &[EngineBleedAirSystem; 4],
+        apu_compression_chamber: &impl PneumaticContainer,
+        apu_bleed_valve: &impl PneumaticValve,
+    ) {
+        for (pylon, engine_system) in self.pylons.iter_mut().zip(engine_systems) {
+            // The pylon ducts carry the IP and HP air upstream of the bleed valve
+            pylon.update(
+                context,
+                engine_system.transfer_pressure(),
+                engine_system.transfer_temperature(),
+            );
+        }
+
+        // Each wing duct and the pack bay behind it are supplied by the two engines of its side
+        for ((wing, pack_bay), side_engine_systems) in self
+            .wings
+            .iter_mut()
+            .zip(&mut self.pack_bays)
+            .zip(engine_systems.chunks(2))
+        {
+            let supplying_system =
+                if side_engine_systems[0].pressure() >= side_engine_systems[1].pressure() {
+                    &side_engine_systems[0]
+                } else {
+                    &side_engine_systems[1]
+                };
+
+            wing.update(
+                context,
+                supplying_system.pressure(),
+                supplying_system.temperature(),
+            );
+            pack_bay.update(
+                context,
+                supplying_system.pressure(),
+                supplying_system.temperature(),
+            );
+        }
+
+        // The APU duct is only pressurised while the APU bleed valve is open
+        if apu_bleed_valve.is_open() {
+            self.apu_duct.update(
+                context,
+                apu_compression_chamber.pressure(),
+                apu_compression_chamber.temperature(),
+            );
+        } else {
+            self.apu_duct.update(
+                context,
+                context.ambient_pressure(),
+                context.ambient_temperature(),
+            );
+        }
+    }
+}
+impl SimulationElement for BleedDuctAreas {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pylons, visitor);
+        accept_iterable!(self.wings, visitor);
+        accept_iterable!(self.pack_bays, visitor);
+        self.apu_duct.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 /**
  * The CPIOM A. It consists of four individual units
  */
 struct CoreProcessingInputOutputModuleA {
     units: [CoreProcessingInputOutputModuleAUnit; 4],
+    pylon_leak_detectors: [BleedLeakDetector<1>; 4],
+    wing_leak_detectors: [BleedLeakDetector<2>; 2],
+    apu_duct_leak_detector: BleedLeakDetector<1>,
     powered_by: ElectricalBusType,
     is_powered: bool,
 }
 impl CoreProcessingInputOutputModuleA {
-    fn new(powered_by: ElectricalBusType) -> Self {
+    const PYLON_LEAK_ALARM_TEMPERATURE_C: f64 = 140.;
+    const WING_LEAK_ALARM_TEMPERATURE_C: f64 = 110.;
+    const APU_DUCT_LEAK_ALARM_TEMPERATURE_C: f64 = 110.;
+
+    fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        let pylon_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::PYLON_LEAK_ALARM_TEMPERATURE_C);
+        let wing_alarm_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::WING_LEAK_ALARM_TEMPERATURE_C);
+
         Self {
             units: [
                 CoreProcessingInputOutputModuleAUnit::new(1),
@@ -477,6 +673,24 @@ impl CoreProcessingInputOutputModuleA {
                 CoreProcessingInputOutputModuleAUnit::new(3),
                 CoreProcessingInputOutputModuleAUnit::new(4),
             ],
+            pylon_leak_detectors: [1, 2, 3, 4].map(|engine_number| {
+                BleedLeakDetector::new(
+                    context,
+                    BleedLeakZone::Pylon(engine_number),
+                    pylon_alarm_temperature,
+                )
+            }),
+            // The wing loops also run through the pack bay of the same side
+            wing_leak_detectors: [1, 2].map(|side| {
+                BleedLeakDetector::new(context, BleedLeakZone::Wing(side), wing_alarm_temperature)
+            }),
+            apu_duct_leak_detector: BleedLeakDetector::new(
+                context,
+                BleedLeakZone::ApuDuct,
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    Self::APU_DUCT_LEAK_ALARM_TEMPERATURE_C,
+                ),
+            ),
             powered_by,
             is_powered: true,
         }
@@ -486,26 +700,99 @@ impl CoreProcessingInputOutputModuleA {
         &mut self,
         context: &UpdateContext,
         sensors: &[EngineBleedAirSystem; 4],
+        bleed_duct_areas: &BleedDuctAreas,
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
     ) {
+        self.update_leak_detection(bleed_duct_areas);
+        let leak_isolation = self.leak_isolation();
+
         for (unit, sensor) in self.units.iter_mut().zip(sensors) {
             unit.update(
                 context,
                 sensor,
                 engine_fire_push_buttons.is_released(unit.engine_number),
+                leak_isolation.engine_bleed_valve_closed(unit.engine_number),
                 apu_bleed_valve,
                 pneumatic_overhead_panel,
             );
         }
     }
 
+    fn update_leak_detection(&mut self, areas: &BleedDuctAreas) {
+        for (detector, pylon) in self.pylon_leak_detectors.iter_mut().zip(&areas.pylons) {
+            detector.update(&[pylon], self.is_powered);
+        }
+
+        for ((detector, wing), pack_bay) in self
+            .wing_leak_detectors
+            .iter_mut()
+            .zip(&areas.wings)
+            .zip(&areas.pack_bays)
+        {
+            detector.update(&[wing, pack_bay], self.is_powered);
+        }
+
+        self.apu_duct_leak_detector
+            .update(&[&areas.apu_duct], self.is_powered);
+    }
+
+    fn engine_bleed_leak_detected(&self, engine_number: usize) -> bool {
+        self.pylon_leak_detectors[engine_number - 1].leak_detected()
+            || self.wing_leak_detectors[(engine_number - 1) / 2].leak_detected()
+    }
+
+    fn apu_bleed_leak_detected(&self) -> bool {
+        self.apu_duct_leak_detector.leak_detected()
+    }
+
+    fn leak_isolation(&self) -> BleedLeakIsolation {
+        let mut isolation = BleedLeakIsolation::default();
+
+        for (engine_number, detector) in (1..=4).zip(&self.pylon_leak_detectors) {
+            if detector.leak_detected() {
+                isolation.engine_bleed_valves_closed[engine_number - 1] = true;
+            }
+        }
+
+        let [left_wing_leak_detector, right_wing_leak_detector] = &self.wing_leak_detectors;
+        if left_wing_leak_detector.leak_detected() {
+            isolation.engine_bleed_valves_closed[0] = true;
+            isolation.engine_bleed_valves_closed[1] = true;
+            isolation.cross_bleed_valves_closed[0] = true;
+            isolation.cross_bleed_valves_closed[1] = true;
+
+            // The APU duct is connected to the left hand side of the crossbleed duct
+            isolation.apu_bleed_valve_closed = true;
+        }
+        if right_wing_leak_detector.leak_detected() {
+            isolation.engine_bleed_valves_closed[2] = true;
+            isolation.engine_bleed_valves_closed[3] = true;
+            isolation.cross_bleed_valves_closed[1] = true;
+            isolation.cross_bleed_valves_closed[2] = true;
+        }
+
+        if self.apu_duct_leak_detector.leak_detected() {
+            isolation.apu_bleed_valve_closed = true;
+        }
+
+        isolation
+    }
+
     fn is_powered(&self) -> bool {
         self.is_powered
     }
 }
 impl SimulationElement for CoreProcessingInputOutputModuleA {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pylon_leak_detectors, visitor);
+        accept_iterable!(self.wing_leak_detectors, visitor);
+        self.apu_duct_leak_detector.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by)
     }
@@ -529,6 +816,7 @@ struct CoreProcessingInputOutputModuleAUnit {
     engine_starter_valve_is_open: bool,
     is_engine_bleed_pushbutton_auto: bool,
     is_engine_fire_pushbutton_released: bool,
+    is_engine_bleed_leak_isolated: bool,
     is_apu_bleed_valve_open: bool,
     is_apu_bleed_on: bool,
     is_any_bleed_pushbutton_off: bool,
@@ -550,6 +838,7 @@ impl CoreProcessingInputOutputModuleAUnit {
             engine_starter_valve_is_open: false,
             is_engine_bleed_pushbutton_auto: true,
             is_engine_fire_pushbutton_released: false,
+            is_engine_bleed_leak_isolated: false,
             is_apu_bleed_valve_open: false,
             is_apu_bleed_on: false,
             is_any_bleed_pushbutton_off: false,
@@ -573,6 +862,7 @@ impl CoreProcessingInputOutputModuleAUnit {
         context: &UpdateContext,
         sensors: &EngineBleedAirSystem,
         is_engine_fire_pushbutton_released: bool,
+        is_engine_bleed_leak_isolated: bool,
         apu_bleed_valve: &impl PneumaticValve,
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
     ) {
@@ -606,6 +896,7 @@ impl CoreProcessingInputOutputModuleAUnit {
         self.is_engine_bleed_pushbutton_auto =
             pneumatic_overhead_panel.engine_bleed_pb_is_auto(self.engine_number);
         self.is_engine_fire_pushbutton_released = is_engine_fire_pushbutton_released;
+        self.is_engine_bleed_leak_isolated = is_engine_bleed_leak_isolated;
 
         self.is_any_bleed_pushbutton_off =
             (1..=4).any(|e| !pneumatic_overhead_panel.engine_bleed_pb_is_auto(e));
@@ -639,6 +930,7 @@ impl ControllerSignal<PressureRegulatingValveSignal> for CoreProcessingInputOutp
     fn signal(&self) -> Option<PressureRegulatingValveSignal> {
         if self.transfer_pressure < Pressure::new::<psi>(15.)
             || (!self.is_engine_bleed_pushbutton_auto || self.is_engine_fire_pushbutton_released)
+            || self.is_engine_bleed_leak_isolated
             || self.should_close_pressure_regulating_valve_because_apu_bleed_is_on()
             || self.engine_starter_valve_is_open
         {
@@ -681,6 +973,37 @@ impl ControllerSignal<CrossBleedValveSignal> for CoreProcessingInputOutputModule
     }
 }
 
+/// Keeps a crossbleed valve closed in automatic mode while it isolates a bleed leak.
+/// Manual control of the valve is still possible.
+struct LeakIsolatingCrossBleedValveController<'a> {
+    controller: &'a CoreProcessingInputOutputModuleAUnit,
+    is_leak_isolated: bool,
+}
+impl<'a> LeakIsolatingCrossBleedValveController<'a> {
+    fn new(controller: &'a CoreProcessingInputOutputModuleAUnit, is_leak_isolated: bool) -> Self {
+        Self {
+            controller,
+            is_leak_isolated,
+        }
+    }
+}
+impl ControllerSignal<CrossBleedValveSignal> for LeakIsolatingCrossBleedValveController<'_> {
+    fn signal(&self) -> Option<CrossBleedValveSignal> {
+        let signal: Option<CrossBleedValveSignal> = self.controller.signal();
+        match signal {
+            Some(signal)
+                if self.is_leak_isolated
+                    && signal.signal_type == CrossBleedValveSignalType::Automatic =>
+            {
+                Some(CrossBleedValveSignal::new_closed(
+                    CrossBleedValveSignalType::Automatic,
+                ))
+            }
+            signal => signal,
+        }
+    }
+}
+
 struct EngineBleedAirSystem {
     high_pressure_id: VariableIdentifier,
     starter_container_pressure_id: VariableIdentifier,
@@ -776,8 +1099,10 @@ impl EngineBleedAirSystem {
                 1.,
             )),
             intermediate_pressure_valve: PurelyPneumaticValve::new(),
-            high_pressure_valve: ElectroPneumaticValve::new(powered_by),
-            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by),
+            high_pressure_valve: ElectroPneumaticValve::new(powered_by)
+                .with_failures(PneumaticValveId::HighPressure(number)),
+            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by)
+                .with_failures(PneumaticValveId::PressureRegulating(number)),
             fan_air_valve: ElectroPneumaticValve::new(powered_by),
             transfer_pressure_pipe: PneumaticPipe::new(
                 Volume::new::<cubic_meter>(1.),
@@ -1105,6 +1430,19 @@ impl A380PneumaticOverheadPanel {
             _ => panic!("Invalid engine number"),
         }
     }
+
+    pub fn update_after_pneumatic(&mut self, pneumatic: &A380Pneumatic) {
+        self.engine_1_bleed
+            .set_fault(pneumatic.engine_bleed_leak_detected(1));
+        self.engine_2_bleed
+            .set_fault(pneumatic.engine_bleed_leak_detected(2));
+        self.engine_3_bleed
+            .set_fault(pneumatic.engine_bleed_leak_detected(3));
+        self.engine_4_bleed
+            .set_fault(pneumatic.engine_bleed_leak_detected(4));
+        self.apu_bleed
+            .set_fault(pneumatic.apu_bleed_leak_detected());
+    }
 }
 impl EngineBleedPushbutton<4> for A380PneumaticOverheadPanel {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; 4] {
@@ -1221,10 +1559,12 @@ impl PackComplex {
             // They can also be electrically closed by DC ESS BUS.
             left_pack_flow_valve: ElectroPneumaticValve::new(
                 ElectricalBusType::DirectCurrentEssential,
-            ),
+            )
+            .with_failures(PneumaticValveId::PackFlowControl(pack_number * 2 - 1)),
             right_pack_flow_valve: ElectroPneumaticValve::new(
                 ElectricalBusType::DirectCurrentEssential,
-            ),
+            )
+            .with_failures(PneumaticValveId::PackFlowControl(pack_number * 2)),
             left_inlet_pressure_sensor: PressureTransducer::new(
                 ElectricalBusType::DirectCurrentEssential, // TODO: This is almost definitely not correct, just copied from the A320
             ),
@@ -1361,17 +1701,19 @@ pub struct CrossBleedValve {
     connector: PneumaticContainerConnector,
     is_powered_for_manual_control: bool,
     is_powered_for_automatic_control: bool,
+    failures: PneumaticValveFailures,
 }
 impl CrossBleedValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
 
-    pub fn new(valve_id: VariableIdentifier) -> Self {
+    pub fn new(valve_id: VariableIdentifier, number: usize) -> Self {
         Self {
             valve_id,
             open_amount: Ratio::new::<ratio>(0.),
             connector: PneumaticContainerConnector::new(),
             is_powered_for_manual_control: false,
             is_powered_for_automatic_control: false,
+            failures: PneumaticValveFailures::new(PneumaticValveId::CrossBleed(number)),
         }
     }
 
@@ -1385,19 +1727,26 @@ impl CrossBleedValve {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
+        } else {
+            self.open_amount = self
+                .failures
+                .open_amount(self.open_amount, self.open_amount);
         }
 
         self.connector
-            .with_transfer_speed_factor(self.open_amount)
+            .with_transfer_speed_factor(self.failures.transfer_open_amount(self.open_amount))
             .update_move_fluid(context, container_one, container_two);
     }
 
     fn set_open_amount_from_pressure_difference(&mut self, pressure_difference: Pressure) {
-        self.open_amount = Ratio::new::<ratio>(
-            2. / PI
-                * (pressure_difference.get::<psi>() * Self::SPRING_CHARACTERISTIC)
-                    .atan()
-                    .max(0.),
+        self.open_amount = self.failures.open_amount(
+            self.open_amount,
+            Ratio::new::<ratio>(
+                2. / PI
+                    * (pressure_difference.get::<psi>() * Self::SPRING_CHARACTERISTIC)
+                        .atan()
+                        .max(0.),
+            ),
         );
     }
 
@@ -1408,7 +1757,9 @@ impl CrossBleedValve {
                 || signal.signal_type == CrossBleedValveSignalType::Automatic
                     && self.is_powered_for_automatic_control
             {
-                self.open_amount = signal.target_open_amount()
+                self.open_amount = self
+                    .failures
+                    .open_amount(self.open_amount, signal.target_open_amount())
             }
         }
     }
@@ -1419,6 +1770,12 @@ impl PneumaticValve for CrossBleedValve {
     }
 }
 impl SimulationElement for CrossBleedValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failures.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered_for_manual_control =
             buses.is_powered(ElectricalBusType::DirectCurrentEssentialShed);
@@ -1437,6 +1794,7 @@ mod tests {
     use rstest::rstest;
     use systems::{
         air_conditioning::{AdirsToAirCondInterface, PackFlowControllers},
+        air_starter_unit::{AirStarterUnit, AirStarterUnitType},
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         engine::trent_engine::TrentEngine,
         failures::FailureType,
@@ -1447,14 +1805,15 @@ mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            ApuBleedAirValveSignal, CargoDoorLocked, ControllerSignal, ElectricalBusType,
-            ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton, EngineCorrectedN1,
-            EngineFirePushButtons, EngineStartState, HydraulicColor,
-            InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
-            PneumaticBleed, PneumaticValve, PotentialOrigin,
+            ApuBleedAirValveSignal, BleedLeakZone, CargoDoorLocked, ControllerSignal,
+            ElectricalBusType, ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton,
+            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, FireDetectionZone,
+            HydraulicColor, InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber,
+            PackFlowValveState, PneumaticBleed, PneumaticValve, PneumaticValveId, PotentialOrigin,
+            SmokeDetection,
         },
         simulation::{
-            test::{SimulationTestBed, TestBed, WriteByName},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
@@ -1480,6 +1839,7 @@ mod tests {
         dsms: TestDsms,
         payload: TestPayload,
         pressurization_overhead: A380PressurizationOverheadPanel,
+        smoke_detection: TestSmokeDetection,
     }
     impl TestAirConditioning {
         fn new(context: &mut InitContext) -> Self {
@@ -1490,6 +1850,7 @@ mod tests {
                 dsms: TestDsms {},
                 payload: TestPayload {},
                 pressurization_overhead: A380PressurizationOverheadPanel::new(context),
+                smoke_detection: TestSmokeDetection {},
             }
         }
         fn update(
@@ -1513,6 +1874,7 @@ mod tests {
                 pneumatic_overhead,
                 &self.pressurization_overhead,
                 lgciu,
+                &self.smoke_detection,
             );
         }
     }
@@ -1569,6 +1931,14 @@ mod tests {
         }
     }
 
+    struct TestSmokeDetection {}
+
+    impl SmokeDetection for TestSmokeDetection {
+        fn smoke_detected(&self, _zone: FireDetectionZone) -> bool {
+            false
+        }
+    }
+
     struct TestPayload;
     impl NumberOfPassengers for TestPayload {
         fn number_of_passengers(&self, _ps: usize) -> i8 {
@@ -1708,6 +2078,7 @@ mod tests {
         air_conditioning: TestAirConditioning,
         lgciu: TestLgciu,
         apu: TestApu,
+        asu: AirStarterUnit,
         engine_1: TrentEngine,
         engine_2: TrentEngine,
         engine_3: TrentEngine,
@@ -1738,6 +2109,7 @@ mod tests {
                 air_conditioning: TestAirConditioning::new(context),
                 lgciu: TestLgciu::new(true),
                 apu: TestApu::new(),
+                asu: AirStarterUnit::new(context),
                 engine_1: TrentEngine::new(context, 1),
                 engine_2: TrentEngine::new(context, 2),
                 engine_3: TrentEngine::new(context, 3),
@@ -1768,6 +2140,10 @@ mod tests {
             }
         }
 
+        fn set_dc_ess_bus_power(&mut self, is_powered: bool) {
+            self.is_dc_ess_powered = is_powered;
+        }
+
         fn set_dc_ess_shed_bus_power(&mut self, is_powered: bool) {
             self.is_dc_ess_shed_powered = is_powered;
         }
@@ -1810,6 +2186,8 @@ mod tests {
             self.electrical.update(context);
 
             self.apu.update(self.pneumatic.apu_bleed_air_valve());
+            self.asu
+                .update(context, self.pneumatic.air_starter_unit_delivered_flow());
             self.pneumatic.update(
                 context,
                 [
@@ -1821,8 +2199,11 @@ mod tests {
                 &self.pneumatic_overhead_panel,
                 &self.fire_pushbuttons,
                 &self.apu,
+                &self.asu,
                 &self.air_conditioning,
             );
+            self.pneumatic_overhead_panel
+                .update_after_pneumatic(&self.pneumatic);
             self.air_conditioning.update(
                 context,
                 [
@@ -1842,6 +2223,7 @@ mod tests {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.electrical.accept(visitor);
             self.pneumatic.accept(visitor);
+            self.asu.accept(visitor);
             self.engine_1.accept(visitor);
             self.engine_2.accept(visitor);
             self.engine_3.accept(visitor);
@@ -1890,6 +2272,10 @@ mod tests {
             self
         }
 
+        fn run_multiple_frames(&mut self, duration: Duration) {
+            self.test_bed.run_multiple_frames(duration);
+        }
+
         fn mach_number(mut self, mach: MachNumber) -> Self {
             self.write_by_name("AIRSPEED MACH", mach);
 
@@ -2226,6 +2612,10 @@ mod tests {
             self.query(|a| a.pneumatic.apu_bleed_air_valve.is_open())
         }
 
+        fn asu_bleed_valve_is_open(&self) -> bool {
+            self.query(|a| a.pneumatic.air_starter_unit_bleed_air_valve.is_open())
+        }
+
         fn hp_valve_is_powered(&self, number: usize) -> bool {
             self.query(|a| {
                 a.pneumatic.engine_systems[number - 1]
@@ -2281,6 +2671,18 @@ mod tests {
                 .set_apu_bleed_air_pb(true)
         }
 
+        fn set_asu(mut self, value: bool) -> Self {
+            self.write_by_name("ASU_TURNED_ON", value);
+
+            self
+        }
+
+        fn set_asu_type(mut self, unit_type: AirStarterUnitType) -> Self {
+            self.write_by_name("ASU_TYPE", unit_type);
+
+            self
+        }
+
         fn release_fire_pushbutton(mut self, number: usize) -> Self {
             self.command(|a| a.fire_pushbuttons.release(number));
 
@@ -2349,6 +2751,12 @@ mod tests {
                 .set_pack_flow_pb_is_auto(2, true)
         }
 
+        fn set_dc_ess_bus_power(mut self, is_powered: bool) -> Self {
+            self.command(|a| a.set_dc_ess_bus_power(is_powered));
+
+            self
+        }
+
         fn set_dc_ess_shed_bus_power(mut self, is_powered: bool) -> Self {
             self.command(|a| a.set_dc_ess_shed_bus_power(is_powered));
 
@@ -3481,4 +3889,345 @@ mod tests {
             assert!(test_bed.right_pack_flow_valve_flow(2) < flow_rate_tolerance());
         }
     }
+
+    mod bleed_leak_tests {
+        use super::*;
+
+        fn leak_detected(test_bed: &mut PneumaticTestBed, zone: &str) -> bool {
+            test_bed.read_by_name(&format!("PNEU_{}_LEAK_DETECTED", zone))
+        }
+
+        fn engine_bleed_pb_has_fault(test_bed: &mut PneumaticTestBed, number: usize) -> bool {
+            test_bed.read_by_name(&format!("OVHD_PNEU_ENG_{}_BLEED_PB_HAS_FAULT", number))
+        }
+
+        fn cross_bleed_valve_is_open(test_bed: &PneumaticTestBed, index: usize) -> bool {
+            test_bed.query(|a| a.pneumatic.cross_bleed_valves[index].is_open())
+        }
+
+        #[test]
+        fn no_leak_detected_in_normal_operation() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .and_stabilize();
+
+            assert!(!leak_detected(&mut test_bed, "WING_1"));
+            assert!(!leak_detected(&mut test_bed, "WING_2"));
+            assert!(!leak_detected(&mut test_bed, "PYLON_1"));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 1));
+            assert!(test_bed.pr_valve_is_open(1));
+        }
+
+        #[test]
+        fn left_wing_leak_isolates_left_wing() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .set_engine_bleed_push_button_off(4)
+                .and_stabilize();
+
+            assert!(test_bed.cross_bleed_valves_are_open());
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(leak_detected(&mut test_bed, "WING_1"));
+            assert!(engine_bleed_pb_has_fault(&mut test_bed, 1));
+            assert!(engine_bleed_pb_has_fault(&mut test_bed, 2));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 3));
+            assert!(!test_bed.pr_valve_is_open(1));
+            assert!(!test_bed.pr_valve_is_open(2));
+            assert!(test_bed.pr_valve_is_open(3));
+            assert!(!cross_bleed_valve_is_open(&test_bed, 0));
+            assert!(!cross_bleed_valve_is_open(&test_bed, 1));
+            assert!(cross_bleed_valve_is_open(&test_bed, 2));
+            assert!(test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+        }
+
+        #[test]
+        fn right_pack_bay_leak_is_detected_by_right_wing_loops() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::PackBay(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(leak_detected(&mut test_bed, "WING_2"));
+            assert!(!test_bed.pr_valve_is_open(3));
+            assert!(!test_bed.pr_valve_is_open(4));
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(!test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+        }
+
+        #[test]
+        fn pylon_leak_closes_engine_bleed_valve() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .toga_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Pylon(3)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(leak_detected(&mut test_bed, "PYLON_3"));
+            assert!(engine_bleed_pb_has_fault(&mut test_bed, 3));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 4));
+            assert!(!test_bed.pr_valve_is_open(3));
+            assert!(test_bed.pr_valve_is_open(4));
+        }
+
+        #[test]
+        fn leak_is_not_detected_when_cpiom_a_is_unpowered() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .toga_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .set_dc_ess_bus_power(false)
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Pylon(3)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            assert!(!leak_detected(&mut test_bed, "PYLON_3"));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 3));
+        }
+
+        #[test]
+        fn cross_bleed_valves_can_be_opened_manually_after_leak_isolation() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(2)));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            test_bed = test_bed
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Open)
+                .and_stabilize();
+
+            assert!(test_bed.cross_bleed_valves_are_open());
+        }
+
+        #[test]
+        fn apu_duct_leak_is_detected() {
+            let mut test_bed = test_bed_with().set_bleed_air_running().and_stabilize();
+
+            assert!(!test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+
+            test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::ApuDuct));
+            test_bed.run_multiple_frames(Duration::from_secs(15));
+
+            let apu_bleed_pb_has_fault: bool =
+                test_bed.read_by_name("OVHD_PNEU_APU_BLEED_PB_HAS_FAULT");
+
+            assert!(leak_detected(&mut test_bed, "APU_DUCT"));
+            assert!(apu_bleed_pb_has_fault);
+            assert!(test_bed.query(|a| a.pneumatic.apu_bleed_is_leak_isolated()));
+            assert!(!engine_bleed_pb_has_fault(&mut test_bed, 1));
+        }
+    }
+
+    mod valve_failure_tests {
+        use super::*;
+
+        fn cpiom_reports_pressure_regulating_valve_closed(
+            test_bed: &PneumaticTestBed,
+            number: usize,
+        ) -> bool {
+            test_bed.query(|a| {
+                a.pneumatic.core_processing_input_output_module_a.units[number - 1]
+                    .pressure_regulating_valve_is_closed
+            })
+        }
+
+        #[test]
+        fn stuck_closed_pressure_regulating_valve_is_reported_closed_to_cpiom() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .and_stabilize();
+
+            assert!(test_bed.pr_valve_is_open(2));
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::PressureRegulating(2),
+            ));
+            test_bed.run_multiple_frames(Duration::from_secs(10));
+
+            assert!(!test_bed.pr_valve_is_open(2));
+            assert!(cpiom_reports_pressure_regulating_valve_closed(&test_bed, 2));
+            assert!(test_bed.pr_valve_is_open(1));
+            assert!(!cpiom_reports_pressure_regulating_valve_closed(
+                &test_bed, 1
+            ));
+        }
+
+        #[test]
+        fn stuck_open_pressure_regulating_valve_does_not_close_with_bleed_pb_off() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .both_packs_auto()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckOpen(
+                PneumaticValveId::PressureRegulating(3),
+            ));
+            test_bed = test_bed.set_engine_bleed_push_button_off(3).and_stabilize();
+
+            assert!(test_bed.pr_valve_is_open(3));
+            assert!(!cpiom_reports_pressure_regulating_valve_closed(
+                &test_bed, 3
+            ));
+        }
+
+        #[test]
+        fn stuck_closed_high_pressure_valve_does_not_open() {
+            let mut test_bed = test_bed_with().and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::HighPressure(1),
+            ));
+            test_bed = test_bed
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .and_stabilize();
+
+            assert!(!test_bed.hp_valve_is_open(1));
+            assert!(test_bed.hp_valve_is_open(2));
+        }
+
+        #[test]
+        fn stuck_closed_cross_bleed_valve_does_not_open_with_selector_open() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut)
+                .and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::CrossBleed(2),
+            ));
+            test_bed = test_bed
+                .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Open)
+                .and_stabilize();
+
+            let center_cross_bleed_valve_open: bool =
+                test_bed.read_by_name("PNEU_XBLEED_VALVE_C_OPEN");
+            let left_cross_bleed_valve_open: bool =
+                test_bed.read_by_name("PNEU_XBLEED_VALVE_L_OPEN");
+
+            assert!(!center_cross_bleed_valve_open);
+            assert!(left_cross_bleed_valve_open);
+        }
+
+        #[test]
+        fn stuck_closed_pack_flow_control_valve_does_not_open() {
+            let mut test_bed = test_bed_with()
+                .idle_eng1()
+                .idle_eng2()
+                .idle_eng3()
+                .idle_eng4()
+                .and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::PackFlowControl(4),
+            ));
+            test_bed = test_bed.both_packs_auto().and_stabilize();
+
+            assert!(test_bed.left_pack_flow_valve_is_open(2));
+            assert!(!test_bed.right_pack_flow_valve_is_open(2));
+            assert!(test_bed.right_pack_flow_valve_is_open(1));
+        }
+
+        #[test]
+        fn stuck_closed_apu_bleed_valve_does_not_open() {
+            let mut test_bed = test_bed_with().and_stabilize();
+
+            test_bed.fail(FailureType::PneumaticValveStuckClosed(
+                PneumaticValveId::ApuBleed,
+            ));
+            test_bed = test_bed.set_bleed_air_running().and_stabilize();
+
+            assert!(!test_bed.apu_bleed_valve_is_open());
+        }
+    }
+
+    mod air_starter_unit_tests {
+        use super::*;
+
+        fn test_bed_with_engines_stopped() -> PneumaticTestBed {
+            test_bed_with()
+                .stop_eng1()
+                .stop_eng2()
+                .stop_eng3()
+                .stop_eng4()
+                .set_pack_flow_pb_is_auto(1, false)
+                .set_pack_flow_pb_is_auto(2, false)
+        }
+
+        #[test]
+        fn asu_bleed_valve_stays_closed_without_cart() {
+            let test_bed = test_bed_with_engines_stopped().and_stabilize();
+
+            assert!(!test_bed.asu_bleed_valve_is_open());
+        }
+
+        #[test]
+        fn asu_bleed_provides_at_least_35_psi_to_engine_1_system() {
+            let test_bed = test_bed_with_engines_stopped()
+                .set_asu(true)
+                .and_stabilize();
+
+            assert!(test_bed.asu_bleed_valve_is_open());
+            assert!(test_bed.precooler_outlet_pressure(1) > Pressure::new::<psi>(35.));
+        }
+
+        #[test]
+        fn heavy_cart_provides_more_pressure_than_light_cart() {
+            let light_cart_test_bed = test_bed_with_engines_stopped()
+                .set_asu_type(AirStarterUnitType::Light)
+                .set_asu(true)
+                .and_stabilize();
+            let heavy_cart_test_bed = test_bed_with_engines_stopped()
+                .set_asu_type(AirStarterUnitType::Heavy)
+                .set_asu(true)
+                .and_stabilize();
+
+            assert!(
+                heavy_cart_test_bed.precooler_outlet_pressure(1)
+                    > light_cart_test_bed.precooler_outlet_pressure(1) + Pressure::new::<psi>(5.)
+            );
+        }
+    }
 }
@@ -10,6 +10,7 @@ mod fuel;
 mod gear;
 mod nose_wheel_steering;
 mod payload;
+mod pushback;
 mod reversers;
 mod rudder;
 mod spoilers;
@@ -28,16 +29,19 @@ use fuel::fuel;
 use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
 use payload::payload;
+use pushback::pushback;
 use reversers::reversers;
 use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
 use systems::air_conditioning::{Channel, FdacId, OcsmId, VcmId};
 use systems::failures::FailureType;
+use systems::hydraulic::flap_slat::{SecondarySurfaceSide, SecondarySurfaceType};
 use systems::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedLeakZone, ElectricalBusType,
+    FireDetectionLoopID, FireDetectionZone, FlightControlSurfaceId, GearActuatorId, HydraulicColor,
+    HydraulicSectionId, LgciuId, PneumaticValveId, ProximityDetectorId,
 };
 
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -215,6 +219,12 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_117,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (24_200, FailureType::CircuitBreaker("LGCIU_1")),
+        (24_201, FailureType::CircuitBreaker("LGCIU_2")),
+        (24_202, FailureType::CircuitBreaker("RA_1")),
+        (24_203, FailureType::CircuitBreaker("RA_2")),
+        (24_204, FailureType::CircuitBreaker("RA_3")),
+        (24_205, FailureType::CircuitBreaker("EGPWC")),
         (26_001, FailureType::SetOnFire(FireDetectionZone::Engine(1))),
         (26_002, FailureType::SetOnFire(FireDetectionZone::Engine(2))),
         (26_003, FailureType::SetOnFire(FireDetectionZone::Engine(3))),
@@ -269,6 +279,536 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             26_018,
             FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
         ),
+        (26_019, FailureType::Smoke(FireDetectionZone::ForwardCargo)),
+        (26_020, FailureType::Smoke(FireDetectionZone::AftCargo)),
+        (26_021, FailureType::Smoke(FireDetectionZone::BulkCargo)),
+        (26_022, FailureType::Smoke(FireDetectionZone::Lavatory)),
+        (26_023, FailureType::Smoke(FireDetectionZone::Avionics)),
+        (
+            26_024,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::ForwardCargo),
+        ),
+        (
+            26_025,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::ForwardCargo),
+        ),
+        (
+            26_026,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::AftCargo),
+        ),
+        (
+            26_027,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::AftCargo),
+        ),
+        (
+            26_028,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::BulkCargo),
+        ),
+        (
+            26_029,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::BulkCargo),
+        ),
+        (
+            26_030,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::Lavatory),
+        ),
+        (
+            26_031,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::Lavatory),
+        ),
+        (
+            26_032,
+            FailureType::SmokeDetector(FireDetectionLoopID::A, FireDetectionZone::Avionics),
+        ),
+        (
+            26_033,
+            FailureType::SmokeDetector(FireDetectionLoopID::B, FireDetectionZone::Avionics),
+        ),
+        (
+            27_100,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(1), 0),
+        ),
+        (
+            27_101,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(1), 1),
+        ),
+        (
+            27_102,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(1), 0),
+        ),
+        (
+            27_103,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(1), 1),
+        ),
+        (
+            27_104,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftAileron(1)),
+        ),
+        (
+            27_105,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(2), 0),
+        ),
+        (
+            27_106,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(2), 1),
+        ),
+        (
+            27_107,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(2), 0),
+        ),
+        (
+            27_108,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(2), 1),
+        ),
+        (
+            27_109,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftAileron(2)),
+        ),
+        (
+            27_110,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(3), 0),
+        ),
+        (
+            27_111,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftAileron(3), 1),
+        ),
+        (
+            27_112,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(3), 0),
+        ),
+        (
+            27_113,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftAileron(3), 1),
+        ),
+        (
+            27_114,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftAileron(3)),
+        ),
+        (
+            27_115,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(1), 0),
+        ),
+        (
+            27_116,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(1), 1),
+        ),
+        (
+            27_117,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(1), 0),
+        ),
+        (
+            27_118,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(1), 1),
+        ),
+        (
+            27_119,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightAileron(1)),
+        ),
+        (
+            27_120,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(2), 0),
+        ),
+        (
+            27_121,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(2), 1),
+        ),
+        (
+            27_122,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(2), 0),
+        ),
+        (
+            27_123,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(2), 1),
+        ),
+        (
+            27_124,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightAileron(2)),
+        ),
+        (
+            27_125,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(3), 0),
+        ),
+        (
+            27_126,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightAileron(3), 1),
+        ),
+        (
+            27_127,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(3), 0),
+        ),
+        (
+            27_128,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightAileron(3), 1),
+        ),
+        (
+            27_129,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightAileron(3)),
+        ),
+        (
+            27_130,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftElevator(1), 0),
+        ),
+        (
+            27_131,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftElevator(1), 1),
+        ),
+        (
+            27_132,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftElevator(1), 0),
+        ),
+        (
+            27_133,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftElevator(1), 1),
+        ),
+        (
+            27_134,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftElevator(1)),
+        ),
+        (
+            27_135,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftElevator(2), 0),
+        ),
+        (
+            27_136,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftElevator(2), 1),
+        ),
+        (
+            27_137,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftElevator(2), 0),
+        ),
+        (
+            27_138,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftElevator(2), 1),
+        ),
+        (
+            27_139,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftElevator(2)),
+        ),
+        (
+            27_140,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightElevator(1), 0),
+        ),
+        (
+            27_141,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightElevator(1), 1),
+        ),
+        (
+            27_142,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightElevator(1), 0),
+        ),
+        (
+            27_143,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightElevator(1), 1),
+        ),
+        (
+            27_144,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightElevator(1)),
+        ),
+        (
+            27_145,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightElevator(2), 0),
+        ),
+        (
+            27_146,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightElevator(2), 1),
+        ),
+        (
+            27_147,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightElevator(2), 0),
+        ),
+        (
+            27_148,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightElevator(2), 1),
+        ),
+        (
+            27_149,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightElevator(2)),
+        ),
+        (
+            27_150,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::Rudder(1), 0),
+        ),
+        (
+            27_151,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::Rudder(1), 1),
+        ),
+        (
+            27_152,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::Rudder(1), 0),
+        ),
+        (
+            27_153,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::Rudder(1), 1),
+        ),
+        (
+            27_154,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::Rudder(1)),
+        ),
+        (
+            27_155,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::Rudder(2), 0),
+        ),
+        (
+            27_156,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::Rudder(2), 1),
+        ),
+        (
+            27_157,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::Rudder(2), 0),
+        ),
+        (
+            27_158,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::Rudder(2), 1),
+        ),
+        (
+            27_159,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::Rudder(2)),
+        ),
+        (
+            27_160,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(1), 0),
+        ),
+        (
+            27_161,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(1), 0),
+        ),
+        (
+            27_162,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(1)),
+        ),
+        (
+            27_163,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(2), 0),
+        ),
+        (
+            27_164,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(2), 0),
+        ),
+        (
+            27_165,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(2)),
+        ),
+        (
+            27_166,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(3), 0),
+        ),
+        (
+            27_167,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(3), 0),
+        ),
+        (
+            27_168,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(3)),
+        ),
+        (
+            27_169,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(4), 0),
+        ),
+        (
+            27_170,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(4), 0),
+        ),
+        (
+            27_171,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(4)),
+        ),
+        (
+            27_172,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(5), 0),
+        ),
+        (
+            27_173,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(5), 0),
+        ),
+        (
+            27_174,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(5)),
+        ),
+        (
+            27_175,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(6), 0),
+        ),
+        (
+            27_176,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(6), 0),
+        ),
+        (
+            27_177,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(6)),
+        ),
+        (
+            27_178,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(7), 0),
+        ),
+        (
+            27_179,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(7), 0),
+        ),
+        (
+            27_180,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(7)),
+        ),
+        (
+            27_181,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::LeftSpoiler(8), 0),
+        ),
+        (
+            27_182,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::LeftSpoiler(8), 0),
+        ),
+        (
+            27_183,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::LeftSpoiler(8)),
+        ),
+        (
+            27_184,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(1), 0),
+        ),
+        (
+            27_185,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(1), 0),
+        ),
+        (
+            27_186,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(1)),
+        ),
+        (
+            27_187,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(2), 0),
+        ),
+        (
+            27_188,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(2), 0),
+        ),
+        (
+            27_189,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(2)),
+        ),
+        (
+            27_190,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(3), 0),
+        ),
+        (
+            27_191,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(3), 0),
+        ),
+        (
+            27_192,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(3)),
+        ),
+        (
+            27_193,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(4), 0),
+        ),
+        (
+            27_194,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(4), 0),
+        ),
+        (
+            27_195,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(4)),
+        ),
+        (
+            27_196,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(5), 0),
+        ),
+        (
+            27_197,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(5), 0),
+        ),
+        (
+            27_198,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(5)),
+        ),
+        (
+            27_199,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(6), 0),
+        ),
+        (
+            27_200,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(6), 0),
+        ),
+        (
+            27_201,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(6)),
+        ),
+        (
+            27_202,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(7), 0),
+        ),
+        (
+            27_203,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(7), 0),
+        ),
+        (
+            27_204,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(7)),
+        ),
+        (
+            27_205,
+            FailureType::FlightControlActuatorJam(FlightControlSurfaceId::RightSpoiler(8), 0),
+        ),
+        (
+            27_206,
+            FailureType::FlightControlActuatorRunaway(FlightControlSurfaceId::RightSpoiler(8), 0),
+        ),
+        (
+            27_207,
+            FailureType::FlightControlSurfaceDisconnect(FlightControlSurfaceId::RightSpoiler(8)),
+        ),
+        (27_208, FailureType::ThsJam),
+        (
+            27_209,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Green),
+        ),
+        (
+            27_210,
+            FailureType::ThsHydraulicMotor(HydraulicColor::Yellow),
+        ),
+        (
+            27_211,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Flaps,
+                SecondarySurfaceSide::Left,
+            ),
+        ),
+        (
+            27_212,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Flaps,
+                SecondarySurfaceSide::Right,
+            ),
+        ),
+        (
+            27_213,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Slats,
+                SecondarySurfaceSide::Left,
+            ),
+        ),
+        (
+            27_214,
+            FailureType::FlapSlatTransmissionShaftBreak(
+                SecondarySurfaceType::Slats,
+                SecondarySurfaceSide::Right,
+            ),
+        ),
+        (
+            27_215,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Flaps, SecondarySurfaceSide::Left),
+        ),
+        (
+            27_216,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Flaps, SecondarySurfaceSide::Right),
+        ),
+        (
+            27_217,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Slats, SecondarySurfaceSide::Left),
+        ),
+        (
+            27_218,
+            FailureType::FlapSlatSkew(SecondarySurfaceType::Slats, SecondarySurfaceSide::Right),
+        ),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
         (29_002, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
@@ -332,6 +872,194 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             29_017,
             FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp4b),
         ),
+        (
+            29_018,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Green, 1),
+        ),
+        (
+            29_019,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Green, 2),
+        ),
+        (
+            29_020,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Green, 3),
+        ),
+        (
+            29_021,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Green, 4),
+        ),
+        (
+            29_022,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Green, 5),
+        ),
+        (
+            29_023,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Green, 6),
+        ),
+        (
+            29_024,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Yellow, 1),
+        ),
+        (
+            29_025,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Yellow, 2),
+        ),
+        (
+            29_026,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Yellow, 3),
+        ),
+        (
+            29_027,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Yellow, 4),
+        ),
+        (
+            29_028,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Yellow, 5),
+        ),
+        (
+            29_029,
+            FailureType::PumpCaseDrainLeak(HydraulicColor::Yellow, 6),
+        ),
+        (
+            29_030,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_031,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(2)),
+        ),
+        (
+            29_032,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(3)),
+        ),
+        (
+            29_033,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(4)),
+        ),
+        (
+            29_034,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(5)),
+        ),
+        (
+            29_035,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Pump(6)),
+        ),
+        (
+            29_036,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(1)),
+        ),
+        (
+            29_037,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(2)),
+        ),
+        (
+            29_038,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(3)),
+        ),
+        (
+            29_039,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(4)),
+        ),
+        (
+            29_040,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(5)),
+        ),
+        (
+            29_041,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::Pump(6)),
+        ),
+        (
+            29_042,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::System),
+        ),
+        (
+            29_043,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Yellow, HydraulicSectionId::System),
+        ),
+        (
+            29_044,
+            FailureType::HydraulicSectionLeak(HydraulicColor::Green, HydraulicSectionId::Auxiliary),
+        ),
+        (
+            29_045,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(1), 0),
+        ),
+        (
+            29_046,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(1), 1),
+        ),
+        (
+            29_047,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(2), 0),
+        ),
+        (
+            29_048,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(2), 1),
+        ),
+        (
+            29_049,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(3), 0),
+        ),
+        (
+            29_050,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftAileron(3), 1),
+        ),
+        (
+            29_051,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(1), 0),
+        ),
+        (
+            29_052,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(1), 1),
+        ),
+        (
+            29_053,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(2), 0),
+        ),
+        (
+            29_054,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(2), 1),
+        ),
+        (
+            29_055,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(3), 0),
+        ),
+        (
+            29_056,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightAileron(3), 1),
+        ),
+        (
+            29_057,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftElevator(1), 0),
+        ),
+        (
+            29_058,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftElevator(1), 1),
+        ),
+        (
+            29_059,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftElevator(2), 0),
+        ),
+        (
+            29_060,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::LeftElevator(2), 1),
+        ),
+        (
+            29_061,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightElevator(1), 0),
+        ),
+        (
+            29_062,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightElevator(1), 1),
+        ),
+        (
+            29_063,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightElevator(2), 0),
+        ),
+        (
+            29_064,
+            FailureType::FlightControlActuatorLeak(FlightControlSurfaceId::RightElevator(2), 1),
+        ),
         (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
         (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
         (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
@@ -408,6 +1136,119 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (
+            32_030,
+            FailureType::GearDoorActuatorFault(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_031,
+            FailureType::GearDoorActuatorFault(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_032,
+            FailureType::GearDoorActuatorFault(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_040,
+            FailureType::GearUplockJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_041,
+            FailureType::GearUplockJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_042,
+            FailureType::GearUplockJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_043,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_044,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_045,
+            FailureType::GearUplockJammed(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_050,
+            FailureType::GearDownlockFailure(GearActuatorId::GearNose),
+        ),
+        (
+            32_051,
+            FailureType::GearDownlockFailure(GearActuatorId::GearLeft),
+        ),
+        (
+            32_052,
+            FailureType::GearDownlockFailure(GearActuatorId::GearRight),
+        ),
+        (32_200, FailureType::FlatTyre(1)),
+        (32_201, FailureType::FlatTyre(2)),
+        (32_202, FailureType::FlatTyre(3)),
+        (32_203, FailureType::FlatTyre(4)),
+        (32_204, FailureType::FlatTyre(5)),
+        (32_205, FailureType::FlatTyre(6)),
+        (32_206, FailureType::FlatTyre(7)),
+        (32_207, FailureType::FlatTyre(8)),
+        (32_208, FailureType::FlatTyre(9)),
+        (32_209, FailureType::FlatTyre(10)),
+        (32_210, FailureType::FlatTyre(11)),
+        (32_211, FailureType::FlatTyre(12)),
+        (32_212, FailureType::FlatTyre(13)),
+        (32_213, FailureType::FlatTyre(14)),
+        (32_214, FailureType::FlatTyre(15)),
+        (32_215, FailureType::FlatTyre(16)),
+        (32_240, FailureType::AntiSkid),
+        (32_300, FailureType::BrakeSeized(1)),
+        (32_301, FailureType::BrakeSeized(2)),
+        (32_302, FailureType::BrakeSeized(3)),
+        (32_303, FailureType::BrakeSeized(4)),
+        (32_304, FailureType::BrakeSeized(5)),
+        (32_305, FailureType::BrakeSeized(6)),
+        (32_306, FailureType::BrakeSeized(7)),
+        (32_307, FailureType::BrakeSeized(8)),
+        (32_308, FailureType::BrakeSeized(9)),
+        (32_309, FailureType::BrakeSeized(10)),
+        (32_310, FailureType::BrakeSeized(11)),
+        (32_311, FailureType::BrakeSeized(12)),
+        (32_312, FailureType::BrakeSeized(13)),
+        (32_313, FailureType::BrakeSeized(14)),
+        (32_314, FailureType::BrakeSeized(15)),
+        (32_315, FailureType::BrakeSeized(16)),
+        (32_320, FailureType::BrakeReleased(1)),
+        (32_321, FailureType::BrakeReleased(2)),
+        (32_322, FailureType::BrakeReleased(3)),
+        (32_323, FailureType::BrakeReleased(4)),
+        (32_324, FailureType::BrakeReleased(5)),
+        (32_325, FailureType::BrakeReleased(6)),
+        (32_326, FailureType::BrakeReleased(7)),
+        (32_327, FailureType::BrakeReleased(8)),
+        (32_328, FailureType::BrakeReleased(9)),
+        (32_329, FailureType::BrakeReleased(10)),
+        (32_330, FailureType::BrakeReleased(11)),
+        (32_331, FailureType::BrakeReleased(12)),
+        (32_332, FailureType::BrakeReleased(13)),
+        (32_333, FailureType::BrakeReleased(14)),
+        (32_334, FailureType::BrakeReleased(15)),
+        (32_335, FailureType::BrakeReleased(16)),
+        (32_340, FailureType::BrakeTemperatureProbe(1)),
+        (32_341, FailureType::BrakeTemperatureProbe(2)),
+        (32_342, FailureType::BrakeTemperatureProbe(3)),
+        (32_343, FailureType::BrakeTemperatureProbe(4)),
+        (32_344, FailureType::BrakeTemperatureProbe(5)),
+        (32_345, FailureType::BrakeTemperatureProbe(6)),
+        (32_346, FailureType::BrakeTemperatureProbe(7)),
+        (32_347, FailureType::BrakeTemperatureProbe(8)),
+        (32_348, FailureType::BrakeTemperatureProbe(9)),
+        (32_349, FailureType::BrakeTemperatureProbe(10)),
+        (32_350, FailureType::BrakeTemperatureProbe(11)),
+        (32_351, FailureType::BrakeTemperatureProbe(12)),
+        (32_352, FailureType::BrakeTemperatureProbe(13)),
+        (32_353, FailureType::BrakeTemperatureProbe(14)),
+        (32_354, FailureType::BrakeTemperatureProbe(15)),
+        (32_355, FailureType::BrakeTemperatureProbe(16)),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_002, FailureType::RadioAltimeter(3)),
@@ -417,6 +1258,318 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (34_020, FailureType::RadioAntennaDirectCoupling(1)),
         (34_021, FailureType::RadioAntennaDirectCoupling(2)),
         (34_022, FailureType::RadioAntennaDirectCoupling(3)),
+        (36_000, FailureType::BleedDuctLeak(BleedLeakZone::Pylon(1))),
+        (36_001, FailureType::BleedDuctLeak(BleedLeakZone::Pylon(2))),
+        (36_002, FailureType::BleedDuctLeak(BleedLeakZone::Pylon(3))),
+        (36_003, FailureType::BleedDuctLeak(BleedLeakZone::Pylon(4))),
+        (36_004, FailureType::BleedDuctLeak(BleedLeakZone::Wing(1))),
+        (36_005, FailureType::BleedDuctLeak(BleedLeakZone::Wing(2))),
+        (36_006, FailureType::BleedDuctLeak(BleedLeakZone::ApuDuct)),
+        (
+            36_007,
+            FailureType::BleedDuctLeak(BleedLeakZone::PackBay(1)),
+        ),
+        (
+            36_008,
+            FailureType::BleedDuctLeak(BleedLeakZone::PackBay(2)),
+        ),
+        (
+            36_010,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Pylon(1), FireDetectionLoopID::A),
+        ),
+        (
+            36_011,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Pylon(2), FireDetectionLoopID::A),
+        ),
+        (
+            36_012,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Pylon(3), FireDetectionLoopID::A),
+        ),
+        (
+            36_013,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Pylon(4), FireDetectionLoopID::A),
+        ),
+        (
+            36_014,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(1), FireDetectionLoopID::A),
+        ),
+        (
+            36_015,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(1), FireDetectionLoopID::B),
+        ),
+        (
+            36_016,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(2), FireDetectionLoopID::A),
+        ),
+        (
+            36_017,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::Wing(2), FireDetectionLoopID::B),
+        ),
+        (
+            36_018,
+            FailureType::BleedLeakDetectionLoop(BleedLeakZone::ApuDuct, FireDetectionLoopID::A),
+        ),
+        (
+            36_020,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_021,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_022,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::HighPressure(3)),
+        ),
+        (
+            36_023,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::HighPressure(4)),
+        ),
+        (
+            36_024,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_025,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_026,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PressureRegulating(3)),
+        ),
+        (
+            36_027,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PressureRegulating(4)),
+        ),
+        (
+            36_028,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_029,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::CrossBleed(2)),
+        ),
+        (
+            36_030,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::CrossBleed(3)),
+        ),
+        (
+            36_031,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_032,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_033,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PackFlowControl(3)),
+        ),
+        (
+            36_034,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::PackFlowControl(4)),
+        ),
+        (
+            36_035,
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::ApuBleed),
+        ),
+        (
+            36_040,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_041,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_042,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::HighPressure(3)),
+        ),
+        (
+            36_043,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::HighPressure(4)),
+        ),
+        (
+            36_044,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_045,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_046,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PressureRegulating(3)),
+        ),
+        (
+            36_047,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PressureRegulating(4)),
+        ),
+        (
+            36_048,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_049,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::CrossBleed(2)),
+        ),
+        (
+            36_050,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::CrossBleed(3)),
+        ),
+        (
+            36_051,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_052,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_053,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PackFlowControl(3)),
+        ),
+        (
+            36_054,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::PackFlowControl(4)),
+        ),
+        (
+            36_055,
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::ApuBleed),
+        ),
+        (
+            36_060,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_061,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_062,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::HighPressure(3)),
+        ),
+        (
+            36_063,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::HighPressure(4)),
+        ),
+        (
+            36_064,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_065,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_066,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PressureRegulating(3)),
+        ),
+        (
+            36_067,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PressureRegulating(4)),
+        ),
+        (
+            36_068,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_069,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::CrossBleed(2)),
+        ),
+        (
+            36_070,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::CrossBleed(3)),
+        ),
+        (
+            36_071,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_072,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_073,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PackFlowControl(3)),
+        ),
+        (
+            36_074,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::PackFlowControl(4)),
+        ),
+        (
+            36_075,
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::ApuBleed),
+        ),
+        (
+            36_080,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::HighPressure(1)),
+        ),
+        (
+            36_081,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::HighPressure(2)),
+        ),
+        (
+            36_082,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::HighPressure(3)),
+        ),
+        (
+            36_083,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::HighPressure(4)),
+        ),
+        (
+            36_084,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PressureRegulating(1)),
+        ),
+        (
+            36_085,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PressureRegulating(2)),
+        ),
+        (
+            36_086,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PressureRegulating(3)),
+        ),
+        (
+            36_087,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PressureRegulating(4)),
+        ),
+        (
+            36_088,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::CrossBleed(1)),
+        ),
+        (
+            36_089,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::CrossBleed(2)),
+        ),
+        (
+            36_090,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::CrossBleed(3)),
+        ),
+        (
+            36_091,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PackFlowControl(1)),
+        ),
+        (
+            36_092,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PackFlowControl(2)),
+        ),
+        (
+            36_093,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PackFlowControl(3)),
+        ),
+        (
+            36_094,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::PackFlowControl(4)),
+        ),
+        (
+            36_095,
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::ApuBleed),
+        ),
+        (49_000, FailureType::ApuHungStart),
+        (49_001, FailureType::ApuEgtOvertemperature),
+        (49_002, FailureType::ApuLowOilPressure),
+        (49_003, FailureType::ApuAirIntakeFlapStuck),
+        (49_004, FailureType::ApuElectronicControlBox),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -591,6 +1744,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         Ok(())
     })?
     .with_aspect(reversers)?
+    .with_aspect(pushback)?
     .with_aspect(brakes)?
     .with_aspect(cargo_doors)?
     .with_aspect(autobrakes)?
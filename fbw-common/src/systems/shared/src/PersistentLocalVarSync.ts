@@ -0,0 +1,64 @@
+// Copyright (c) 2026 FlyByWire Simulations
+// SPDX-License-Identifier: GPL-3.0
+
+import { GameStateProvider, Instrument, SimVarValueType, Wait } from '@microsoft/msfs-sdk';
+import { NXDataStore } from '@flybywiresim/fbw-sdk';
+
+export interface PersistentLocalVarDefinition {
+  /** The NXDataStore key to store the value under. */
+  configKey: string;
+  /** The local var to restore and store, including the L: part. */
+  localVarName: string;
+}
+
+/**
+ * Keeps local vars owned by the aircraft systems across sessions, such as maintenance counters.
+ * The stored values are written to the local vars once the aircraft is loaded, after which the
+ * latest values are periodically written back to persistent storage.
+ */
+export class PersistentLocalVarSync implements Instrument {
+  private static readonly STORE_INTERVAL_MS = 10_000;
+
+  private readonly storedValues: number[];
+
+  private isRestored = false;
+
+  private lastStoreTime = 0;
+
+  constructor(private readonly definitions: PersistentLocalVarDefinition[]) {
+    this.storedValues = definitions.map(() => 0);
+  }
+
+  public init(): void {
+    Wait.awaitSubscribable(GameStateProvider.get(), (state) => state === GameState.ingame, true).then(() =>
+      this.restore(),
+    );
+  }
+
+  public onUpdate(): void {
+    const now = Date.now();
+    if (!this.isRestored || now - this.lastStoreTime < PersistentLocalVarSync.STORE_INTERVAL_MS) {
+      return;
+    }
+    this.lastStoreTime = now;
+
+    this.definitions.forEach((definition, index) => {
+      const value = SimVar.GetSimVarValue(definition.localVarName, SimVarValueType.Number);
+      if (value !== this.storedValues[index]) {
+        this.storedValues[index] = value;
+        NXDataStore.setLegacy(definition.configKey, value.toString());
+      }
+    });
+  }
+
+  private restore(): void {
+    this.definitions.forEach((definition, index) => {
+      const value = Number(NXDataStore.getLegacy(definition.configKey, '0'));
+      this.storedValues[index] = Number.isFinite(value) ? value : 0;
+
+      SimVar.SetSimVarValue(definition.localVarName, SimVarValueType.Number, this.storedValues[index]);
+    });
+
+    this.isRestored = true;
+  }
+}
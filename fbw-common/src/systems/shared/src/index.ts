@@ -18,6 +18,7 @@ export * from './Vec2Utils';
 export * from './MsfsDetect';
 export * from './ObjectUtils';
 export * from './PathVector';
+export * from './PersistentLocalVarSync';
 export * from './PilotSeat';
 export * from './publishers';
 export * from './RadioTypes';
@@ -11,8 +11,8 @@ use crate::{
         ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses,
     },
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 
@@ -1293,6 +1293,95 @@ impl SimulationElement for AirHeater {
     // TODO: Add power consumtion of cargo heater
 }
 
+/// Low pressure ground air conditioning cart (preconditioned air unit) connected to the LP ground
+/// connection of the mixer unit. It supplies conditioned air to the cabin without the packs running.
+pub struct GroundAirConditioningUnit {
+    turned_on_id: VariableIdentifier,
+    supply_temperature_id: VariableIdentifier,
+
+    turned_on: bool,
+    selected_supply_temperature: ThermodynamicTemperature,
+    rated_flow: MassRate,
+    supply_temperature: LowPassFilter<f64>, // Degree Celsius
+    outlet_air: Air,
+}
+
+impl GroundAirConditioningUnit {
+    const FLOW_RATE_OF_CHANGE_KG_PER_S_PER_S: f64 = 0.2;
+    const SUPPLY_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(60);
+    const MIN_SUPPLY_TEMPERATURE_DEG_C: f64 = 0.;
+    const MAX_SUPPLY_TEMPERATURE_DEG_C: f64 = 70.;
+
+    pub fn new(context: &mut InitContext, rated_flow: MassRate) -> Self {
+        Self {
+            turned_on_id: context.get_identifier("GND_AIR_COND_TURNED_ON".to_owned()),
+            supply_temperature_id: context
+                .get_identifier("GND_AIR_COND_SUPPLY_TEMPERATURE".to_owned()),
+
+            turned_on: false,
+            selected_supply_temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::MIN_SUPPLY_TEMPERATURE_DEG_C,
+            ),
+            rated_flow,
+            supply_temperature: LowPassFilter::new_with_init_value(
+                Self::SUPPLY_TEMPERATURE_TIME_CONSTANT,
+                15.,
+            ),
+            outlet_air: Air::new(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        let flow_change = MassRate::new::<kilogram_per_second>(
+            Self::FLOW_RATE_OF_CHANGE_KG_PER_S_PER_S * context.delta_as_secs_f64(),
+        );
+        let flow_rate = if self.turned_on {
+            (self.outlet_air.flow_rate() + flow_change).min(self.rated_flow)
+        } else {
+            (self.outlet_air.flow_rate() - flow_change).max(MassRate::default())
+        };
+
+        // When the cart isn't running, the air in the hose settles to the outside temperature
+        let target_temperature = if self.turned_on {
+            self.selected_supply_temperature
+        } else {
+            context.ambient_temperature()
+        };
+        self.supply_temperature
+            .update(context.delta(), target_temperature.get::<degree_celsius>());
+
+        self.outlet_air.set_flow_rate(flow_rate);
+        self.outlet_air
+            .set_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+                self.supply_temperature.output(),
+            ));
+        self.outlet_air.set_pressure(context.ambient_pressure());
+    }
+
+    pub fn is_supplying_air(&self) -> bool {
+        self.outlet_air.flow_rate() > MassRate::default()
+    }
+}
+
+impl OutletAir for GroundAirConditioningUnit {
+    fn outlet_air(&self) -> Air {
+        self.outlet_air
+    }
+}
+
+impl SimulationElement for GroundAirConditioningUnit {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.turned_on = reader.read(&self.turned_on_id);
+
+        let selected_supply_temperature: f64 = reader.read(&self.supply_temperature_id);
+        self.selected_supply_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(selected_supply_temperature.clamp(
+                Self::MIN_SUPPLY_TEMPERATURE_DEG_C,
+                Self::MAX_SUPPLY_TEMPERATURE_DEG_C,
+            ));
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct Air {
     temperature: ThermodynamicTemperature,
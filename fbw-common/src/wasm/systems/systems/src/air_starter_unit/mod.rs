@@ -1,38 +1,150 @@
 use crate::{
     pneumatic::TargetPressureTemperatureSignal,
-    shared::ControllerSignal,
-    simulation::{Read, SimulationElement, SimulatorReader},
+    shared::{interpolation, low_pass_filter::LowPassFilter, ControllerSignal},
+    simulation::{Read, Reader, SimulationElement, SimulatorReader, UpdateContext, Write, Writer},
 };
+use std::time::Duration;
 use uom::si::thermodynamic_temperature::degree_celsius;
-use uom::si::{f64::*, pressure::psi};
+use uom::si::{f64::*, mass_rate::kilogram_per_second, pressure::psi, ratio::ratio};
 
 use crate::simulation::{InitContext, VariableIdentifier};
 
+/// The kind of high pressure ground cart connected to the aircraft. Each kind delivers air
+/// according to its own pressure curve, as the delivery pressure of a cart drops with the
+/// flow it has to supply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AirStarterUnitType {
+    Standard = 0,
+    Light = 1,
+    Heavy = 2,
+}
+
+read_write_enum!(AirStarterUnitType);
+
+impl From<f64> for AirStarterUnitType {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => AirStarterUnitType::Standard,
+            1 => AirStarterUnitType::Light,
+            2 => AirStarterUnitType::Heavy,
+            _ => AirStarterUnitType::Standard,
+        }
+    }
+}
+
+struct AirStarterUnitCharacteristics {
+    delivered_flow_breakpoints_kg_per_s: [f64; 4],
+    delivery_pressure_breakpoints_psig: [f64; 4],
+    delivery_temperature: ThermodynamicTemperature,
+    spool_up_time: Duration,
+}
+impl AirStarterUnitCharacteristics {
+    fn new(
+        delivered_flow_breakpoints_kg_per_s: [f64; 4],
+        delivery_pressure_breakpoints_psig: [f64; 4],
+        delivery_temperature: ThermodynamicTemperature,
+        spool_up_time: Duration,
+    ) -> Self {
+        Self {
+            delivered_flow_breakpoints_kg_per_s,
+            delivery_pressure_breakpoints_psig,
+            delivery_temperature,
+            spool_up_time,
+        }
+    }
+
+    fn for_type(unit_type: AirStarterUnitType) -> Self {
+        match unit_type {
+            AirStarterUnitType::Standard => Self::new(
+                [0., 1., 2., 3.],
+                [40., 38., 32., 18.],
+                ThermodynamicTemperature::new::<degree_celsius>(165.),
+                Duration::from_secs(5),
+            ),
+            AirStarterUnitType::Light => Self::new(
+                [0., 0.8, 1.6, 2.4],
+                [35., 32., 25., 10.],
+                ThermodynamicTemperature::new::<degree_celsius>(150.),
+                Duration::from_secs(4),
+            ),
+            AirStarterUnitType::Heavy => Self::new(
+                [0., 2., 4., 6.],
+                [45., 43., 37., 22.],
+                ThermodynamicTemperature::new::<degree_celsius>(190.),
+                Duration::from_secs(8),
+            ),
+        }
+    }
+
+    /// Gauge pressure delivered by the cart when running at full speed with the given flow.
+    fn delivery_pressure(&self, delivered_flow: MassRate) -> Pressure {
+        Pressure::new::<psi>(interpolation(
+            &self.delivered_flow_breakpoints_kg_per_s,
+            &self.delivery_pressure_breakpoints_psig,
+            delivered_flow.get::<kilogram_per_second>(),
+        ))
+    }
+}
+
 pub struct AirStarterUnit {
     bleed_air_pressure: Pressure,
     bleed_air_temperature: ThermodynamicTemperature,
     turned_on_id: VariableIdentifier,
     turned_on: bool,
+    type_id: VariableIdentifier,
+    unit_type: AirStarterUnitType,
+    characteristics: AirStarterUnitCharacteristics,
+    spool_ratio: Ratio,
+    delivered_flow: LowPassFilter<MassRate>,
 }
 impl AirStarterUnit {
+    // The cart does not react instantly to the flow drawn by the aircraft. Without smoothing,
+    // the delivery pressure and the flow through the ground connection check valve would
+    // feed back on each other every frame.
+    const DELIVERED_FLOW_TIME_CONSTANT: Duration = Duration::from_millis(1000);
+
     pub fn new(context: &mut InitContext) -> AirStarterUnit {
         AirStarterUnit {
             bleed_air_pressure: Pressure::new::<psi>(0.1),
             bleed_air_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
             turned_on_id: context.get_identifier("ASU_TURNED_ON".to_owned()),
             turned_on: false,
+            type_id: context.get_identifier("ASU_TYPE".to_owned()),
+            unit_type: AirStarterUnitType::Standard,
+            characteristics: AirStarterUnitCharacteristics::for_type(AirStarterUnitType::Standard),
+            spool_ratio: Ratio::default(),
+            delivered_flow: LowPassFilter::new(Self::DELIVERED_FLOW_TIME_CONSTANT),
         }
     }
 
-    pub fn update(&mut self) {
-        if self.turned_on {
-            self.bleed_air_pressure = Pressure::new::<psi>(50.0);
-            self.bleed_air_temperature = ThermodynamicTemperature::new::<degree_celsius>(165.);
+    pub fn update(&mut self, context: &UpdateContext, delivered_flow: MassRate) {
+        self.update_spool(context);
+        self.delivered_flow.update(context.delta(), delivered_flow);
+
+        if self.spool_ratio > Ratio::default() {
+            self.bleed_air_pressure = context.ambient_pressure()
+                + self
+                    .characteristics
+                    .delivery_pressure(self.delivered_flow.output())
+                    * self.spool_ratio.get::<ratio>();
+            self.bleed_air_temperature = self.characteristics.delivery_temperature;
         } else {
             self.bleed_air_pressure = Pressure::new::<psi>(0.1);
             self.bleed_air_temperature = ThermodynamicTemperature::new::<degree_celsius>(15.);
         }
     }
+
+    fn update_spool(&mut self, context: &UpdateContext) {
+        let spool_change = Ratio::new::<ratio>(
+            context.delta_as_secs_f64() / self.characteristics.spool_up_time.as_secs_f64(),
+        );
+
+        self.spool_ratio = if self.turned_on {
+            (self.spool_ratio + spool_change).min(Ratio::new::<ratio>(1.))
+        } else {
+            (self.spool_ratio - spool_change).max(Ratio::default())
+        };
+    }
 }
 impl ControllerSignal<TargetPressureTemperatureSignal> for AirStarterUnit {
     fn signal(&self) -> Option<TargetPressureTemperatureSignal> {
@@ -44,6 +156,151 @@ impl ControllerSignal<TargetPressureTemperatureSignal> for AirStarterUnit {
 }
 impl SimulationElement for AirStarterUnit {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.turned_on = reader.read(&self.turned_on_id)
+        self.turned_on = reader.read(&self.turned_on_id);
+
+        let unit_type = reader.read(&self.type_id);
+        if unit_type != self.unit_type {
+            self.unit_type = unit_type;
+            self.characteristics = AirStarterUnitCharacteristics::for_type(unit_type);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+
+    struct TestAircraft {
+        asu: AirStarterUnit,
+        delivered_flow: MassRate,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                asu: AirStarterUnit::new(context),
+                delivered_flow: MassRate::default(),
+            }
+        }
+
+        fn set_delivered_flow(&mut self, delivered_flow: MassRate) {
+            self.delivered_flow = delivered_flow;
+        }
+
+        fn delivery_pressure(&self) -> Pressure {
+            self.asu.signal().unwrap().target_pressure()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.asu.update(context, self.delivered_flow);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.asu.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_pressure(Pressure::new::<psi>(14.7));
+
+        test_bed
+    }
+
+    fn gauge_pressure(test_bed: &SimulationTestBed<TestAircraft>) -> Pressure {
+        test_bed.query(|a| a.delivery_pressure()) - Pressure::new::<psi>(14.7)
+    }
+
+    #[test]
+    fn delivers_no_pressure_when_turned_off() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.delivery_pressure()) < Pressure::new::<psi>(1.));
+    }
+
+    #[test]
+    fn pressure_builds_up_while_spooling_up() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        let spooling_pressure = gauge_pressure(&test_bed);
+        assert!(spooling_pressure > Pressure::new::<psi>(5.));
+        assert!(spooling_pressure < Pressure::new::<psi>(35.));
+
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(
+            (gauge_pressure(&test_bed) - Pressure::new::<psi>(40.)).abs()
+                < Pressure::new::<psi>(0.1)
+        );
+    }
+
+    #[test]
+    fn pressure_drops_with_delivered_flow() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        let no_flow_pressure = gauge_pressure(&test_bed);
+
+        test_bed.command(|a| a.set_delivered_flow(MassRate::new::<kilogram_per_second>(2.5)));
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(gauge_pressure(&test_bed) < no_flow_pressure - Pressure::new::<psi>(10.));
+    }
+
+    #[test]
+    fn heavy_cart_delivers_more_pressure_at_high_flow_than_standard_cart() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.command(|a| a.set_delivered_flow(MassRate::new::<kilogram_per_second>(3.)));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        let standard_pressure = gauge_pressure(&test_bed);
+
+        test_bed.write_by_name("ASU_TYPE", AirStarterUnitType::Heavy);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(gauge_pressure(&test_bed) > standard_pressure);
+    }
+
+    #[test]
+    fn unknown_cart_type_delivers_as_standard_cart() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.command(|a| a.set_delivered_flow(MassRate::new::<kilogram_per_second>(3.)));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        let standard_pressure = gauge_pressure(&test_bed);
+
+        test_bed.write_by_name("ASU_TYPE", 7.);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!((gauge_pressure(&test_bed) - standard_pressure).abs() < Pressure::new::<psi>(0.5));
+    }
+
+    #[test]
+    fn pressure_decays_after_turning_off() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ASU_TURNED_ON", true);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        test_bed.write_by_name("ASU_TURNED_ON", false);
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(gauge_pressure(&test_bed) > Pressure::new::<psi>(5.));
+
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.delivery_pressure()) < Pressure::new::<psi>(1.));
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
+    failures::{Failure, FailureType},
     shared::{random_number, ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
-    simulation::{SimulationElement, UpdateContext},
+    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 use std::time::Duration;
 use uom::si::{f64::*, power::watt, ratio::percent};
@@ -16,6 +17,7 @@ pub(super) struct AirIntakeFlap {
     open_amount: Ratio,
     travel_time: Duration,
     is_moving: bool,
+    stuck_failure: Failure,
 }
 impl AirIntakeFlap {
     const MINIMUM_TRAVEL_TIME_SECS: u8 = 6;
@@ -35,6 +37,7 @@ impl AirIntakeFlap {
             open_amount: Ratio::new::<percent>(0.),
             travel_time,
             is_moving: false,
+            stuck_failure: Failure::new(FailureType::ApuAirIntakeFlapStuck),
         }
     }
 
@@ -43,7 +46,7 @@ impl AirIntakeFlap {
         context: &UpdateContext,
         controller: &impl ControllerSignal<AirIntakeFlapSignal>,
     ) {
-        if !self.is_powered {
+        if !self.is_powered || self.stuck_failure.is_active() {
             self.is_moving = false;
         } else {
             match controller.signal() {
@@ -98,6 +101,12 @@ impl AirIntakeFlap {
     }
 }
 impl SimulationElement for AirIntakeFlap {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
     }
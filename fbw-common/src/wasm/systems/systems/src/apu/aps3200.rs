@@ -19,7 +19,10 @@ use crate::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineFailures, TurbineSignal,
+    TurbineState,
+};
 
 pub struct Aps3200Constants;
 
@@ -30,6 +33,9 @@ impl ApuConstants for Aps3200Constants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64 = 7.;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool = true;
     const FUEL_LINE_ID: u8 = 18;
+    const OIL_TANK_CAPACITY_QUARTS: f64 = 6.5;
+    const OIL_LOW_LEVEL_QUARTS: f64 = 4.5;
+    const OIL_CONSUMPTION_QUARTS_PER_HOUR: f64 = 0.05;
 }
 
 pub struct ShutdownAps3200Turbine {
@@ -53,6 +59,7 @@ impl Turbine for ShutdownAps3200Turbine {
         _: bool,
         _: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
+        _: &TurbineFailures,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
 
@@ -199,13 +206,14 @@ impl Turbine for Starting {
         _: bool,
         _: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
+        failures: &TurbineFailures,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
         if context.aircraft_preset_quick_mode() {
             self.n = Ratio::new::<percent>(100.);
             println!("apu/apu3200.rs: Aircraft Preset Quick Mode is active, setting N to 100%.");
         } else {
-            self.n = self.calculate_n();
+            self.n = failures.starting_n(self.calculate_n());
         };
         self.egt = self.calculate_egt(context);
 
@@ -347,6 +355,8 @@ struct Running {
     base_egt_deviation: TemperatureInterval,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
+    // EGT above the normal running EGT, caused by an overtemperature failure.
+    egt_excess: TemperatureInterval,
 }
 impl Running {
     fn new(egt: ThermodynamicTemperature) -> Running {
@@ -362,6 +372,7 @@ impl Running {
             ),
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
+            egt_excess: TemperatureInterval::default(),
         }
     }
 
@@ -396,8 +407,11 @@ impl Turbine for Running {
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
+        failures: &TurbineFailures,
     ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+        self.egt_excess = failures.running_egt_excess(context, self.egt_excess);
+        self.egt =
+            self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used) + self.egt_excess;
 
         match controller.signal() {
             Some(TurbineSignal::StartOrContinue) => self,
@@ -530,6 +544,7 @@ impl Turbine for Stopping {
         _: bool,
         _: bool,
         _: &dyn ControllerSignal<TurbineSignal>,
+        _: &TurbineFailures,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
         if context.aircraft_preset_quick_mode() {
@@ -9,19 +9,22 @@ use uom::si::{
 use crate::shared::{EngineCorrectedN1, LgciuWeightOnWheels};
 use crate::simulation::{InitContext, SimulatorReader, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
     pneumatic::PneumaticValveSignal,
     shared::{
         arinc429::SignStatus, ApuBleedAirValveSignal, ApuMaster, ApuStart, ConsumePower,
         ContactorSignal, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve,
     },
-    simulation::{Read, SimulationElement, SimulatorWriter, UpdateContext, Write},
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+    },
 };
 
 use super::ApuConstants;
 use super::{
     air_intake_flap::AirIntakeFlapSignal, AirIntakeFlap, ApuStartMotor,
     AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel, FuelPressureSwitch,
-    Turbine, TurbineSignal, TurbineState,
+    OilPressureSwitch, OilSystem, Turbine, TurbineSignal, TurbineState,
 };
 
 pub(super) struct ElectronicControlBox<C: ApuConstants> {
@@ -39,6 +42,9 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     apu_is_emergency_shutdown_id: VariableIdentifier,
     apu_bleed_air_pressure_id: VariableIdentifier,
     apu_fuel_line_flow_id: VariableIdentifier,
+    apu_oil_quantity_id: VariableIdentifier,
+    apu_oil_temperature_id: VariableIdentifier,
+    apu_low_oil_level_id: VariableIdentifier,
 
     powered_by: ElectricalBusType,
     is_powered: bool,
@@ -58,7 +64,12 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     air_intake_flap_open_amount: Ratio,
     egt: ThermodynamicTemperature,
     egt_warning_temperature: ThermodynamicTemperature,
+    oil_quantity: Volume,
+    oil_temperature: ThermodynamicTemperature,
+    has_low_oil_level: bool,
     n_above_95_duration: Duration,
+    n_not_increasing_duration: Duration,
+    air_intake_flap_not_open_duration: Duration,
     fire_button_is_released: bool,
     engines_on: bool,
     on_ground: bool,
@@ -69,11 +80,16 @@ pub(super) struct ElectronicControlBox<C: ApuConstants> {
     /// In the context of the ecb this means that the APU cooldown is skipped.
     aircraft_preset_quick_mode: bool,
 
+    failure: Failure,
+
     constants: PhantomData<C>,
 }
 impl<C: ApuConstants> ElectronicControlBox<C> {
     const START_MOTOR_POWERED_UNTIL_N: f64 = 55.;
     const JET_A_1_DENSITY: f64 = 0.804; // Kilograms per Liter
+    const NO_ACCELERATION_TIMEOUT: Duration = Duration::from_secs(10);
+    const AIR_INTAKE_FLAP_OPENING_TIMEOUT: Duration = Duration::from_secs(20);
+    const LOW_OIL_PRESSURE_MONITORING_N: f64 = 95.;
 
     pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
         ElectronicControlBox {
@@ -94,6 +110,9 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             apu_bleed_air_pressure_id: context.get_identifier("APU_BLEED_AIR_PRESSURE".to_owned()),
             apu_fuel_line_flow_id: context
                 .get_identifier(format!("FUELSYSTEM LINE FUEL FLOW:{}", C::FUEL_LINE_ID)),
+            apu_oil_quantity_id: context.get_identifier("APU_OIL_QUANTITY".to_owned()),
+            apu_oil_temperature_id: context.get_identifier("APU_OIL_TEMPERATURE".to_owned()),
+            apu_low_oil_level_id: context.get_identifier("APU_LOW_OIL_LEVEL".to_owned()),
 
             powered_by,
             is_powered: false,
@@ -115,13 +134,20 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             egt_warning_temperature: ThermodynamicTemperature::new::<degree_celsius>(
                 C::RUNNING_WARNING_EGT,
             ),
+            oil_quantity: Volume::default(),
+            oil_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            has_low_oil_level: false,
             n_above_95_duration: Duration::from_secs(0),
+            n_not_increasing_duration: Duration::ZERO,
+            air_intake_flap_not_open_duration: Duration::ZERO,
             fire_button_is_released: false,
             engines_on: false,
             on_ground: false,
             inlet_pressure: Pressure::new::<bar>(0.94),
             aircraft_preset_quick_mode: false,
 
+            failure: Failure::new(FailureType::ApuElectronicControlBox),
+
             constants: PhantomData,
         }
     }
@@ -158,8 +184,28 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
-    pub fn update_air_intake_flap_state(&mut self, air_intake_flap: &AirIntakeFlap) {
+    pub fn update_air_intake_flap_state(
+        &mut self,
+        context: &UpdateContext,
+        air_intake_flap: &AirIntakeFlap,
+    ) {
         self.air_intake_flap_open_amount = air_intake_flap.open_amount();
+
+        if matches!(
+            <ElectronicControlBox<C> as ControllerSignal<AirIntakeFlapSignal>>::signal(self),
+            Some(AirIntakeFlapSignal::Open)
+        ) && !self.air_intake_flap_is_fully_open()
+        {
+            self.air_intake_flap_not_open_duration += context.delta();
+        } else {
+            self.air_intake_flap_not_open_duration = Duration::ZERO;
+        }
+
+        if self.fault.is_none()
+            && self.air_intake_flap_not_open_duration > Self::AIR_INTAKE_FLAP_OPENING_TIMEOUT
+        {
+            self.fault = Some(ApuFault::AirIntakeFlap);
+        }
     }
 
     pub fn update_air_intake_state(&mut self, context: &UpdateContext) {
@@ -185,12 +231,19 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         self.update_air_intake_state(context);
         self.update_fuel_used(context);
 
+        let previous_n = self.n;
         self.n2 = turbine.n2();
         self.n = turbine.n();
         self.egt = turbine.egt();
         self.turbine_state = turbine.state();
         self.bleed_air_pressure = turbine.bleed_air_pressure();
 
+        if self.turbine_state == TurbineState::Starting && self.n <= previous_n {
+            self.n_not_increasing_duration += context.delta();
+        } else {
+            self.n_not_increasing_duration = Duration::ZERO;
+        }
+
         self.egt_warning_temperature = self.calculate_egt_warning_temperature(&self.turbine_state);
         if self.n.get::<percent>() > 95. {
             self.n_above_95_duration += context.delta();
@@ -198,8 +251,22 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
             self.n_above_95_duration = Duration::from_secs(0);
         }
 
+        if self.fault.is_none() {
+            if self.n_not_increasing_duration > Self::NO_ACCELERATION_TIMEOUT {
+                self.fault = Some(ApuFault::NoAcceleration);
+            } else if matches!(
+                self.turbine_state,
+                TurbineState::Starting | TurbineState::Running
+            ) && self.egt > self.egt_warning_temperature
+            {
+                self.fault = Some(ApuFault::EgtOvertemperature);
+            }
+        }
+
         if !self.is_on() {
             self.fault = None;
+        } else if self.failure.is_active() && !self.is_emergency_shutdown() {
+            self.fault = Some(ApuFault::ElectronicControlBox);
         }
 
         if !self.master_is_on {
@@ -230,6 +297,21 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
+    pub fn update_oil_pressure_switch_state(&mut self, oil_pressure_switch: &OilPressureSwitch) {
+        if self.fault.is_none()
+            && self.n.get::<percent>() > Self::LOW_OIL_PRESSURE_MONITORING_N
+            && !oil_pressure_switch.has_pressure()
+        {
+            self.fault = Some(ApuFault::LowOilPressure);
+        }
+    }
+
+    pub fn update_oil_system_state(&mut self, oil_system: &OilSystem<C>) {
+        self.oil_quantity = oil_system.quantity();
+        self.oil_temperature = oil_system.temperature();
+        self.has_low_oil_level = oil_system.has_low_level();
+    }
+
     fn update_fuel_used(&mut self, context: &UpdateContext) {
         self.fuel_used += self.fuel_flow
             * MassConcentration::new::<kilogram_per_liter>(Self::JET_A_1_DENSITY)
@@ -482,8 +564,14 @@ impl<C: ApuConstants> ControllerSignal<ApuBleedAirValveSignal> for ElectronicCon
     }
 }
 impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
-        let ssm = if self.is_on() {
+        let ssm = if self.is_on() && !self.failure.is_active() {
             SignStatus::NormalOperation
         } else {
             SignStatus::FailureWarning
@@ -517,6 +605,9 @@ impl<C: ApuConstants> SimulationElement for ElectronicControlBox<C> {
             self.bleed_air_pressure,
             ssm,
         );
+        writer.write_arinc429(&self.apu_oil_quantity_id, self.oil_quantity, ssm);
+        writer.write_arinc429(&self.apu_oil_temperature_id, self.oil_temperature, ssm);
+        writer.write_arinc429(&self.apu_low_oil_level_id, self.has_low_oil_level, ssm);
 
         // Flight Warning Computer related information.
         writer.write(&self.ecam_inop_sys_apu_id, self.is_inoperable());
@@ -548,4 +639,9 @@ enum ApuFault {
     ApuFire,
     FuelLowPressure,
     DcPowerLoss,
+    NoAcceleration,
+    EgtOvertemperature,
+    LowOilPressure,
+    AirIntakeFlap,
+    ElectronicControlBox,
 }
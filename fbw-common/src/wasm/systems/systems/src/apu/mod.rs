@@ -1,9 +1,11 @@
 use self::{
     air_intake_flap::AirIntakeFlap, aps3200::ShutdownAps3200Turbine,
-    electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
+    electronic_control_box::ElectronicControlBox, oil_system::OilSystem,
+    operating_counters::OperatingCounters, pw980::ShutdownPw980Turbine,
 };
 use crate::{
     electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    failures::{Failure, FailureType},
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
@@ -17,10 +19,12 @@ use crate::{
 };
 use std::time::Duration;
 use uom::si::f64::*;
-use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::{ratio::percent, temperature_interval, thermodynamic_temperature::degree_celsius};
 
 mod air_intake_flap;
 mod aps3200;
+mod oil_system;
+mod operating_counters;
 mod pw980;
 use crate::simulation::{InitContext, VariableIdentifier};
 pub use aps3200::{Aps3200ApuGenerator, Aps3200Constants, Aps3200StartMotor};
@@ -94,6 +98,93 @@ impl FuelPressureSwitch {
     }
 }
 
+/// Low oil pressure switch of the APU lubrication system, read by the ECB.
+/// Oil pressure builds up as soon as the turbine rotates at a sufficient speed.
+pub struct OilPressureSwitch {
+    has_pressure: bool,
+    failure: Failure,
+}
+impl OilPressureSwitch {
+    const OIL_PRESSURE_ESTABLISHED_N_PERCENT: f64 = 20.;
+
+    fn new() -> Self {
+        OilPressureSwitch {
+            has_pressure: false,
+            failure: Failure::new(FailureType::ApuLowOilPressure),
+        }
+    }
+
+    fn update(&mut self, n: Ratio) {
+        self.has_pressure = !self.failure.is_active()
+            && n.get::<percent>() >= Self::OIL_PRESSURE_ESTABLISHED_N_PERCENT;
+    }
+
+    fn has_pressure(&self) -> bool {
+        self.has_pressure
+    }
+}
+impl SimulationElement for OilPressureSwitch {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Failures affecting the turbine itself, as opposed to its controller.
+pub struct TurbineFailures {
+    hung_start: Failure,
+    egt_overtemperature: Failure,
+}
+impl TurbineFailures {
+    const HUNG_START_N_PERCENT: f64 = 35.;
+    const EGT_OVERTEMPERATURE_RATE_DEGREES_PER_SECOND: f64 = 20.;
+
+    fn new() -> Self {
+        TurbineFailures {
+            hung_start: Failure::new(FailureType::ApuHungStart),
+            egt_overtemperature: Failure::new(FailureType::ApuEgtOvertemperature),
+        }
+    }
+
+    /// During a hung start the turbine stops accelerating before reaching self sustaining speed.
+    fn starting_n(&self, n: Ratio) -> Ratio {
+        if self.hung_start.is_active() {
+            n.min(Ratio::new::<percent>(Self::HUNG_START_N_PERCENT))
+        } else {
+            n
+        }
+    }
+
+    /// Returns the EGT above the normal running EGT. It rises while the overtemperature
+    /// failure is active and returns to zero once the failure is cleared.
+    fn running_egt_excess(
+        &self,
+        context: &UpdateContext,
+        egt_excess: TemperatureInterval,
+    ) -> TemperatureInterval {
+        let change =
+            Self::EGT_OVERTEMPERATURE_RATE_DEGREES_PER_SECOND * context.delta_as_secs_f64();
+        let excess = egt_excess.get::<temperature_interval::degree_celsius>();
+
+        TemperatureInterval::new::<temperature_interval::degree_celsius>(
+            if self.egt_overtemperature.is_active() {
+                excess + change
+            } else {
+                (excess - change).max(0.)
+            },
+        )
+    }
+}
+impl SimulationElement for TurbineFailures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.hung_start.accept(visitor);
+        self.egt_overtemperature.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum TurbineSignal {
     StartOrContinue,
@@ -109,6 +200,10 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    oil_pressure_switch: OilPressureSwitch,
+    oil_system: OilSystem<C>,
+    operating_counters: OperatingCounters,
+    turbine_failures: TurbineFailures,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnit<T, U, C, N>
@@ -131,6 +226,10 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            oil_pressure_switch: OilPressureSwitch::new(),
+            oil_system: OilSystem::new(context),
+            operating_counters: OperatingCounters::new(context),
+            turbine_failures: TurbineFailures::new(),
         }
     }
 
@@ -151,11 +250,15 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.fuel_pressure_switch.update(has_fuel_remaining);
         self.ecb
             .update_fuel_pressure_switch_state(&self.fuel_pressure_switch);
+        self.oil_pressure_switch.update(self.ecb.n());
+        self.ecb
+            .update_oil_pressure_switch_state(&self.oil_pressure_switch);
         bleed_air_valve.update_open_amount::<ApuBleedAirValveSignal, Self>(self);
         self.ecb
             .update_bleed_air_valve_state(context, bleed_air_valve);
         self.air_intake_flap.update(context, &self.ecb);
-        self.ecb.update_air_intake_flap_state(&self.air_intake_flap);
+        self.ecb
+            .update_air_intake_flap_state(context, &self.air_intake_flap);
 
         if let Some(turbine) = self.turbine.take() {
             let updated_turbine = turbine.update(
@@ -163,13 +266,19 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
                 bleed_air_valve.is_open(),
                 apu_gen_is_used,
                 &self.ecb,
+                &self.turbine_failures,
             );
 
             self.ecb.update(context, updated_turbine.as_ref());
+            self.operating_counters
+                .update(context, &updated_turbine.state());
 
             self.turbine = Some(updated_turbine);
         }
 
+        self.oil_system.update(context, self.ecb.n());
+        self.ecb.update_oil_system_state(&self.oil_system);
+
         let emergency_shutdown = self.is_emergency_shutdown();
         for gen in &mut self.generators {
             gen.update(self.ecb.n(), emergency_shutdown);
@@ -267,6 +376,10 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
         self.start_motor.accept(visitor);
         self.air_intake_flap.accept(visitor);
         self.ecb.accept(visitor);
+        self.oil_pressure_switch.accept(visitor);
+        self.oil_system.accept(visitor);
+        self.operating_counters.accept(visitor);
+        self.turbine_failures.accept(visitor);
 
         visitor.visit(self);
     }
@@ -286,6 +399,7 @@ pub trait Turbine {
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
+        failures: &TurbineFailures,
     ) -> Box<dyn Turbine>;
     fn n(&self) -> Ratio;
     fn n2(&self) -> Ratio {
@@ -318,6 +432,9 @@ pub trait ApuConstants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool;
     const FUEL_LINE_ID: u8;
+    const OIL_TANK_CAPACITY_QUARTS: f64;
+    const OIL_LOW_LEVEL_QUARTS: f64;
+    const OIL_CONSUMPTION_QUARTS_PER_HOUR: f64;
 }
 
 pub struct AuxiliaryPowerUnitFireOverheadPanel {
@@ -416,6 +533,7 @@ mod tests {
         pressure::{bar, psi},
         ratio::percent,
         thermodynamic_temperature::degree_celsius,
+        volume::quart_liquid,
     };
 
     pub fn test_bed_with(
@@ -456,6 +574,7 @@ mod tests {
             _: bool,
             _: bool,
             _: &dyn ControllerSignal<TurbineSignal>,
+            _: &TurbineFailures,
         ) -> Box<dyn Turbine> {
             self
         }
@@ -842,6 +961,24 @@ mod tests {
             self
         }
 
+        fn oil_quantity_of(mut self, quantity: Volume) -> Self {
+            self.write_by_name(
+                "APU_OIL_CONSUMED_QUANTITY",
+                Volume::new::<quart_liquid>(C::OIL_TANK_CAPACITY_QUARTS) - quantity,
+            );
+            self
+        }
+
+        fn service_oil(mut self, is_pressed: bool) -> Self {
+            self.write_by_name("APU_OIL_SERVICE", is_pressed);
+            self
+        }
+
+        fn start_count_of(mut self, count: u32) -> Self {
+            self.write_by_name("APU_START_COUNT", count);
+            self
+        }
+
         pub fn power_demand(mut self, power: Power) -> Self {
             self.command(|a| a.set_power_demand(power));
             self
@@ -898,6 +1035,11 @@ mod tests {
             self
         }
 
+        fn failed(mut self, failure_type: FailureType) -> Self {
+            self.fail(failure_type);
+            self
+        }
+
         fn no_fuel_available(mut self) -> Self {
             self.command(|a| a.set_has_fuel_remaining(false));
             self
@@ -1208,6 +1350,26 @@ mod tests {
         fn apu_fuel_used(&mut self) -> Arinc429Word<Mass> {
             self.read_arinc429_by_name("APU_FUEL_USED")
         }
+
+        fn oil_quantity(&mut self) -> Arinc429Word<Volume> {
+            self.read_arinc429_by_name("APU_OIL_QUANTITY")
+        }
+
+        fn oil_temperature(&mut self) -> Arinc429Word<ThermodynamicTemperature> {
+            self.read_arinc429_by_name("APU_OIL_TEMPERATURE")
+        }
+
+        fn has_low_oil_level(&mut self) -> Arinc429Word<bool> {
+            self.read_arinc429_by_name("APU_LOW_OIL_LEVEL")
+        }
+
+        fn start_count(&mut self) -> u32 {
+            self.read_by_name("APU_START_COUNT")
+        }
+
+        fn operating_hours(&mut self) -> f64 {
+            self.read_by_name("APU_OPERATING_HOURS")
+        }
     }
     impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> TestBed
         for AuxiliaryPowerUnitTestBed<T, U, C, N>
@@ -3408,5 +3570,458 @@ mod tests {
                     < 1.
             );
         }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn hung_start_shuts_down_with_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuHungStart)
+                .and()
+                .starting_apu();
+
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+                assert!(test_bed.n().normal_value().unwrap().get::<percent>() <= 35.);
+            }
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn egt_overtemperature_shuts_down_running_apu<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            let egt_before_failure = test_bed.egt().normal_value().unwrap();
+
+            test_bed = test_bed
+                .failed(FailureType::ApuEgtOvertemperature)
+                .run(Duration::from_secs(5));
+
+            assert!(test_bed.egt().normal_value().unwrap() > egt_before_failure);
+            assert!(!test_bed.is_auto_shutdown());
+
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn low_oil_pressure_shuts_down_running_apu<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .failed(FailureType::ApuLowOilPressure)
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn low_oil_pressure_does_not_prevent_start<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuLowOilPressure)
+                .and()
+                .starting_apu()
+                .run(Duration::from_secs(10));
+
+            assert!(!test_bed.master_has_fault());
+            assert!(test_bed.n().normal_value().unwrap().get::<percent>() > 0.);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn stuck_air_intake_flap_prevents_start_and_causes_fault<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .failed(FailureType::ApuAirIntakeFlapStuck)
+                .and()
+                .master_on()
+                .start_on();
+
+            for _ in 0..30 {
+                test_bed = test_bed.run(Duration::from_secs(1));
+            }
+
+            assert!(!test_bed
+                .is_air_intake_flap_fully_open()
+                .normal_value()
+                .unwrap());
+            assert_about_eq!(test_bed.n().normal_value().unwrap().get::<percent>(), 0.);
+            assert!(test_bed.master_has_fault());
+            assert!(!test_bed.start_is_on());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn electronic_control_box_failure_shuts_down_running_apu<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .failed(FailureType::ApuElectronicControlBox)
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.n().is_failure_warning());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_consumes_oil<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            let initial_quantity = test_bed.oil_quantity().normal_value().unwrap();
+
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(60));
+            }
+
+            let quantity = test_bed.oil_quantity().normal_value().unwrap();
+            assert!(quantity < initial_quantity);
+            assert_about_eq!(
+                (initial_quantity - quantity).get::<quart_liquid>(),
+                C::OIL_CONSUMPTION_QUARTS_PER_HOUR,
+                0.01
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn shutdown_apu_does_not_consume_oil<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.master_on().run(Duration::from_secs(3_600));
+
+            assert_about_eq!(
+                test_bed
+                    .oil_quantity()
+                    .normal_value()
+                    .unwrap()
+                    .get::<quart_liquid>(),
+                C::OIL_TANK_CAPACITY_QUARTS
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn oil_consumption_continues_from_restored_quantity<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let restored_quantity = Volume::new::<quart_liquid>(C::OIL_TANK_CAPACITY_QUARTS - 1.);
+            let mut test_bed = bed_with.oil_quantity_of(restored_quantity).running_apu();
+
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(60));
+            }
+
+            assert_about_eq!(
+                test_bed
+                    .oil_quantity()
+                    .normal_value()
+                    .unwrap()
+                    .get::<quart_liquid>(),
+                C::OIL_TANK_CAPACITY_QUARTS - 1. - C::OIL_CONSUMPTION_QUARTS_PER_HOUR,
+                0.01
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn low_oil_level_is_indicated_without_shutting_down_the_apu<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+            assert!(!test_bed.has_low_oil_level().normal_value().unwrap());
+
+            test_bed = test_bed
+                .then_continue_with()
+                .oil_quantity_of(Volume::new::<quart_liquid>(C::OIL_LOW_LEVEL_QUARTS - 0.1))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.has_low_oil_level().normal_value().unwrap());
+            assert!(test_bed.apu_is_available());
+            assert!(!test_bed.master_has_fault());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn servicing_the_oil_refills_the_tank_and_clears_the_low_level<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .master_on()
+                .oil_quantity_of(Volume::new::<quart_liquid>(C::OIL_LOW_LEVEL_QUARTS - 0.1))
+                .run(Duration::from_secs(1));
+            assert!(test_bed.has_low_oil_level().normal_value().unwrap());
+
+            test_bed = test_bed
+                .then_continue_with()
+                .service_oil(true)
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.has_low_oil_level().normal_value().unwrap());
+            assert_about_eq!(
+                test_bed
+                    .oil_quantity()
+                    .normal_value()
+                    .unwrap()
+                    .get::<quart_liquid>(),
+                C::OIL_TANK_CAPACITY_QUARTS
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn holding_the_oil_service_does_not_refill_the_tank_again<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .master_on()
+                .service_oil(true)
+                .run(Duration::from_secs(1))
+                .then_continue_with()
+                .oil_quantity_of(Volume::new::<quart_liquid>(C::OIL_LOW_LEVEL_QUARTS - 0.1))
+                .run(Duration::from_secs(1));
+
+            assert!(test_bed.has_low_oil_level().normal_value().unwrap());
+
+            test_bed = test_bed
+                .then_continue_with()
+                .service_oil(false)
+                .run(Duration::from_secs(1))
+                .then_continue_with()
+                .service_oil(true)
+                .run(Duration::from_secs(1));
+
+            assert!(!test_bed.has_low_oil_level().normal_value().unwrap());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn oil_data_is_not_available_when_ecb_unpowered<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.master_off().run(Duration::from_secs(1));
+
+            assert!(test_bed.oil_quantity().is_failure_warning());
+            assert!(test_bed.oil_temperature().is_failure_warning());
+            assert!(test_bed.has_low_oil_level().is_failure_warning());
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn oil_is_at_ambient_temperature_when_apu_was_never_started<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let ambient = ThermodynamicTemperature::new::<degree_celsius>(-20.);
+            let mut test_bed = bed_with
+                .ambient_temperature(ambient)
+                .master_on()
+                .run(Duration::from_secs(1));
+
+            assert_about_eq!(
+                test_bed
+                    .oil_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>(),
+                -20.,
+                0.1
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_warms_up_oil<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let ambient = ThermodynamicTemperature::new::<degree_celsius>(15.);
+            let mut test_bed = bed_with
+                .ambient_temperature(ambient)
+                .master_on()
+                .run(Duration::from_secs(1_000));
+            assert_eq!(test_bed.oil_temperature().normal_value().unwrap(), ambient);
+
+            test_bed = test_bed.then_continue_with().running_apu();
+            for _ in 0..10 {
+                test_bed = test_bed.run(Duration::from_secs(60));
+            }
+
+            assert!(
+                test_bed
+                    .oil_temperature()
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree_celsius>()
+                    > 90.
+            );
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn apu_start_increments_start_count<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.running_apu();
+
+            assert_eq!(test_bed.start_count(), 1);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn start_count_continues_from_restored_value<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.start_count_of(125).running_apu();
+
+            assert_eq!(test_bed.start_count(), 126);
+        }
+
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn operating_hours_only_increase_while_apu_runs<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with.master_on().run(Duration::from_secs(3_600));
+            assert_about_eq!(test_bed.operating_hours(), 0.);
+
+            test_bed = test_bed.then_continue_with().running_apu();
+            let hours_when_available = test_bed.operating_hours();
+            for _ in 0..60 {
+                test_bed = test_bed.run(Duration::from_secs(60));
+            }
+
+            assert_about_eq!(test_bed.operating_hours() - hours_when_available, 1.);
+        }
     }
 }
@@ -0,0 +1,110 @@
+use std::marker::PhantomData;
+
+use uom::si::{
+    f64::*, ratio::ratio, thermodynamic_temperature::degree_celsius, volume::quart_liquid,
+};
+
+use crate::{
+    shared::calculate_towards_target_temperature,
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use super::ApuConstants;
+
+/// The APU lubrication system. Oil is consumed while the turbine rotates and the oil
+/// temperature follows the turbine speed, returning to ambient temperature after shutdown.
+/// The consumed oil quantity is read back from the simulator every update. This allows the
+/// aircraft to restore it from persistent storage, after which consumption continues.
+/// Servicing the APU refills the oil tank.
+pub(super) struct OilSystem<C: ApuConstants> {
+    consumed_quantity_id: VariableIdentifier,
+    service_id: VariableIdentifier,
+
+    consumed_quantity: Volume,
+    service_requested: bool,
+    service_is_pressed: bool,
+    temperature: ThermodynamicTemperature,
+    temperature_is_initialised: bool,
+
+    constants: PhantomData<C>,
+}
+impl<C: ApuConstants> OilSystem<C> {
+    const RUNNING_OIL_TEMPERATURE: f64 = 95.; // Deg C
+    const OIL_TEMPERATURE_COEFFICIENT: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        OilSystem {
+            consumed_quantity_id: context.get_identifier("APU_OIL_CONSUMED_QUANTITY".to_owned()),
+            service_id: context.get_identifier("APU_OIL_SERVICE".to_owned()),
+
+            consumed_quantity: Volume::default(),
+            service_requested: false,
+            service_is_pressed: false,
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            // The oil is soaked at ambient temperature on first update
+            temperature_is_initialised: false,
+
+            constants: PhantomData,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, n: Ratio) {
+        let n = n.get::<ratio>().clamp(0., 1.);
+
+        if self.service_requested {
+            self.consumed_quantity = Volume::default();
+        }
+
+        let consumed = C::OIL_CONSUMPTION_QUARTS_PER_HOUR * n * context.delta_as_secs_f64() / 3600.;
+        self.consumed_quantity = Volume::new::<quart_liquid>(
+            (self.consumed_quantity.get::<quart_liquid>() + consumed)
+                .min(C::OIL_TANK_CAPACITY_QUARTS),
+        );
+
+        if !self.temperature_is_initialised {
+            self.temperature = context.ambient_temperature();
+            self.temperature_is_initialised = true;
+        }
+
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        let target = ThermodynamicTemperature::new::<degree_celsius>(
+            ambient + (Self::RUNNING_OIL_TEMPERATURE - ambient) * n,
+        );
+        self.temperature = calculate_towards_target_temperature(
+            self.temperature,
+            target,
+            Self::OIL_TEMPERATURE_COEFFICIENT,
+            context.delta(),
+        );
+    }
+
+    pub fn quantity(&self) -> Volume {
+        Volume::new::<quart_liquid>(C::OIL_TANK_CAPACITY_QUARTS) - self.consumed_quantity
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// Indicates the oil quantity is low. The APU can still be operated,
+    /// but should be serviced before its next flight.
+    pub fn has_low_level(&self) -> bool {
+        self.quantity().get::<quart_liquid>() <= C::OIL_LOW_LEVEL_QUARTS
+    }
+}
+impl<C: ApuConstants> SimulationElement for OilSystem<C> {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.consumed_quantity = reader.read(&self.consumed_quantity_id);
+
+        let service_is_pressed: bool = reader.read(&self.service_id);
+        self.service_requested = service_is_pressed && !self.service_is_pressed;
+        self.service_is_pressed = service_is_pressed;
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.consumed_quantity_id, self.consumed_quantity);
+    }
+}
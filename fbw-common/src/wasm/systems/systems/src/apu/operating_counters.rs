@@ -0,0 +1,53 @@
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+    VariableIdentifier, Write,
+};
+
+use super::TurbineState;
+
+/// Start and operating hours counters of the APU, as used for maintenance purposes.
+/// The counters are read back from the simulator every update. This allows the aircraft
+/// to restore them from persistent storage, after which they continue counting.
+pub(super) struct OperatingCounters {
+    start_count_id: VariableIdentifier,
+    operating_hours_id: VariableIdentifier,
+
+    start_count: u32,
+    operating_hours: f64,
+    was_starting: bool,
+}
+impl OperatingCounters {
+    pub fn new(context: &mut InitContext) -> Self {
+        OperatingCounters {
+            start_count_id: context.get_identifier("APU_START_COUNT".to_owned()),
+            operating_hours_id: context.get_identifier("APU_OPERATING_HOURS".to_owned()),
+
+            start_count: 0,
+            operating_hours: 0.,
+            was_starting: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, turbine_state: &TurbineState) {
+        let is_starting = *turbine_state == TurbineState::Starting;
+        if is_starting && !self.was_starting {
+            self.start_count += 1;
+        }
+        self.was_starting = is_starting;
+
+        if *turbine_state != TurbineState::Shutdown {
+            self.operating_hours += context.delta_as_secs_f64() / 3600.;
+        }
+    }
+}
+impl SimulationElement for OperatingCounters {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.start_count = reader.read(&self.start_count_id);
+        self.operating_hours = reader.read(&self.operating_hours_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.start_count_id, self.start_count);
+        writer.write(&self.operating_hours_id, self.operating_hours);
+    }
+}
@@ -23,7 +23,10 @@ use crate::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
 };
 
-use super::{ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
+use super::{
+    ApuConstants, ApuGenerator, ApuStartMotor, Turbine, TurbineFailures, TurbineSignal,
+    TurbineState,
+};
 
 pub struct Pw980Constants;
 
@@ -34,6 +37,9 @@ impl ApuConstants for Pw980Constants {
     const AIR_INTAKE_FLAP_CLOSURE_PERCENT: f64 = 8.;
     const SHOULD_BE_AVAILABLE_DURING_SHUTDOWN: bool = false;
     const FUEL_LINE_ID: u8 = 141;
+    const OIL_TANK_CAPACITY_QUARTS: f64 = 9.;
+    const OIL_LOW_LEVEL_QUARTS: f64 = 6.;
+    const OIL_CONSUMPTION_QUARTS_PER_HOUR: f64 = 0.08;
 }
 
 pub struct ShutdownPw980Turbine {
@@ -57,6 +63,7 @@ impl Turbine for ShutdownPw980Turbine {
         _: bool,
         _: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
+        _: &TurbineFailures,
     ) -> Box<dyn Turbine> {
         self.egt = calculate_towards_ambient_egt(self.egt, context);
 
@@ -287,14 +294,15 @@ impl Turbine for Starting {
         _: bool,
         _: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
+        failures: &TurbineFailures,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
-        self.n2 = self.calculate_n2();
+        self.n2 = failures.starting_n(self.calculate_n2());
         if context.aircraft_preset_quick_mode() {
             self.n = Ratio::new::<percent>(100.);
             println!("apu/pw980.rs: Aircraft Preset Quick Mode is active, setting N to 100%");
         } else {
-            self.n = self.calculate_n();
+            self.n = failures.starting_n(self.calculate_n());
         };
         self.egt = self.calculate_egt(context);
 
@@ -471,6 +479,8 @@ struct Running {
     base_egt_deviation: TemperatureInterval,
     bleed_air_usage: BleedAirUsageEgtDelta,
     apu_gen_usage: ApuGenUsageEgtDelta,
+    // EGT above the normal running EGT, caused by an overtemperature failure.
+    egt_excess: TemperatureInterval,
     n2: Ratio,
     bleed_air_n2_delta: ApuBleedUsageN2Delta,
 }
@@ -486,6 +496,7 @@ impl Running {
             ),
             bleed_air_usage: BleedAirUsageEgtDelta::new(),
             apu_gen_usage: ApuGenUsageEgtDelta::new(),
+            egt_excess: TemperatureInterval::default(),
             n2: Ratio::default(),
             bleed_air_n2_delta: ApuBleedUsageN2Delta::new(),
         }
@@ -532,8 +543,11 @@ impl Turbine for Running {
         apu_bleed_is_used: bool,
         apu_gen_is_used: bool,
         controller: &dyn ControllerSignal<TurbineSignal>,
+        failures: &TurbineFailures,
     ) -> Box<dyn Turbine> {
-        self.egt = self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used);
+        self.egt_excess = failures.running_egt_excess(context, self.egt_excess);
+        self.egt =
+            self.calculate_egt(context, apu_gen_is_used, apu_bleed_is_used) + self.egt_excess;
         self.n2 = self.calculate_n2(context, apu_bleed_is_used);
 
         match controller.signal() {
@@ -750,6 +764,7 @@ impl Turbine for Stopping {
         _: bool,
         _: bool,
         _: &dyn ControllerSignal<TurbineSignal>,
+        _: &TurbineFailures,
     ) -> Box<dyn Turbine> {
         self.since += context.delta();
         if context.aircraft_preset_quick_mode() {
@@ -0,0 +1,701 @@
+use super::{
+    consumption::PowerConsumer, ElectricalBus, ElectricalElement, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, Electricity,
+};
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{ElectricalBusType, ElectricalBuses, PowerConsumptionReport},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{electric_current::ampere, electric_potential::volt, f64::*, power::watt};
+
+/// Represents a circuit breaker in an electrical power circuit.
+/// When closed a circuit breaker conducts the potential towards other targets.
+///
+/// The breaker trips when the current flowing through it exceeds its rating for too long.
+/// The higher the overcurrent, the sooner it trips. When its failure is active, the breaker
+/// trips immediately. A tripped breaker stays open until it is reset by setting the
+/// `ELEC_CB_{id}_RESET` variable. Only the transition of the variable to true resets the
+/// breaker, thus it has to be released before it can be reset again. Resetting has no
+/// effect while the failure is active.
+///
+/// Independent of tripping, the breaker can be pulled through the `ELEC_CB_{id}_IS_PULLED`
+/// variable, which opens it until it is pushed in again.
+pub struct CircuitBreaker {
+    id: &'static str,
+    identifier: ElectricalElementIdentifier,
+    is_closed_id: VariableIdentifier,
+    is_tripped_id: VariableIdentifier,
+    reset_id: VariableIdentifier,
+    is_pulled_id: VariableIdentifier,
+
+    rating: ElectricCurrent,
+    protected_bus: Option<ElectricalBusType>,
+    protected_bus_potential: ElectricPotential,
+    /// The accumulated heat caused by overcurrent, expressed as
+    /// the squared current ratio above the rating multiplied by seconds.
+    overcurrent_heat: f64,
+    is_tripped: bool,
+    reset_requested: bool,
+    reset_is_pressed: bool,
+    is_pulled: bool,
+    failure: Failure,
+}
+impl CircuitBreaker {
+    /// The time it takes a breaker to trip when twice its rated current flows through it.
+    const TRIP_TIME_AT_TWICE_THE_RATING_SECONDS: f64 = 5.;
+    const TRIP_HEAT: f64 = 3. * Self::TRIP_TIME_AT_TWICE_THE_RATING_SECONDS;
+
+    pub fn new(context: &mut InitContext, id: &'static str, rating: ElectricCurrent) -> Self {
+        Self {
+            id,
+            identifier: context.next_electrical_identifier(),
+            is_closed_id: context.get_identifier(format!("ELEC_CB_{}_IS_CLOSED", id)),
+            is_tripped_id: context.get_identifier(format!("ELEC_CB_{}_IS_TRIPPED", id)),
+            reset_id: context.get_identifier(format!("ELEC_CB_{}_RESET", id)),
+            is_pulled_id: context.get_identifier(format!("ELEC_CB_{}_IS_PULLED", id)),
+
+            rating,
+            protected_bus: None,
+            protected_bus_potential: ElectricPotential::new::<volt>(0.),
+            overcurrent_heat: 0.,
+            is_tripped: false,
+            reset_requested: false,
+            reset_is_pressed: false,
+            is_pulled: false,
+            failure: Failure::new(FailureType::CircuitBreaker(id)),
+        }
+    }
+
+    /// Protects the given bus against overcurrent. The current flowing through the
+    /// breaker is then derived from the power consumed from the bus, and the breaker
+    /// updates itself when the power consumption report is processed.
+    pub fn protecting(mut self, bus_type: ElectricalBusType) -> Self {
+        self.protected_bus = Some(bus_type);
+        self
+    }
+
+    /// Updates the breaker with the current flowing through it.
+    /// Breakers protecting a bus do not need to be updated explicitly.
+    pub fn update(&mut self, context: &UpdateContext, current: ElectricCurrent) {
+        if self.reset_requested && !self.failure.is_active() {
+            self.is_tripped = false;
+            self.overcurrent_heat = 0.;
+        }
+
+        if self.is_closed() {
+            let ratio = current.get::<ampere>().abs() / self.rating.get::<ampere>();
+            if ratio > 1. {
+                self.overcurrent_heat += (ratio * ratio - 1.) * context.delta_as_secs_f64();
+            } else {
+                // Cool down at the same rate as heating up at twice the rating.
+                self.overcurrent_heat =
+                    (self.overcurrent_heat - 3. * context.delta_as_secs_f64()).max(0.);
+            }
+        }
+
+        if self.failure.is_active() || self.overcurrent_heat >= Self::TRIP_HEAT {
+            self.is_tripped = true;
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        !self.is_tripped && !self.is_pulled
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.is_tripped
+    }
+
+    pub fn is_pulled(&self) -> bool {
+        self.is_pulled
+    }
+
+    pub fn rating(&self) -> ElectricCurrent {
+        self.rating
+    }
+}
+impl ElectricalElement for CircuitBreaker {
+    fn input_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn output_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn is_conductive(&self) -> bool {
+        self.is_closed()
+    }
+
+    fn network_name(&self) -> Option<String> {
+        Some(format!("CB_{}", self.id))
+    }
+}
+impl SimulationElement for CircuitBreaker {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let reset_is_pressed: bool = reader.read(&self.reset_id);
+        self.reset_requested = reset_is_pressed && !self.reset_is_pressed;
+        self.reset_is_pressed = reset_is_pressed;
+        self.is_pulled = reader.read(&self.is_pulled_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_closed_id, self.is_closed());
+        writer.write(&self.is_tripped_id, self.is_tripped());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        if let Some(bus_type) = self.protected_bus {
+            self.protected_bus_potential = buses.potential_of(bus_type).raw();
+        }
+    }
+
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        context: &UpdateContext,
+        report: &T,
+    ) {
+        if let Some(bus_type) = self.protected_bus {
+            let current = current_for(
+                report.total_consumption_from_bus(bus_type),
+                self.protected_bus_potential,
+            );
+            self.update(context, current);
+        }
+    }
+}
+
+/// Describes a circuit breaker in an aircraft's catalogue of circuit breakers.
+#[derive(Clone, Copy, Debug)]
+pub struct CircuitBreakerDefinition {
+    id: &'static str,
+    panel: &'static str,
+    powered_by: ElectricalBusType,
+    rating_amperes: f64,
+}
+impl CircuitBreakerDefinition {
+    pub const fn new(
+        id: &'static str,
+        panel: &'static str,
+        powered_by: ElectricalBusType,
+        rating_amperes: f64,
+    ) -> Self {
+        Self {
+            id,
+            panel,
+            powered_by,
+            rating_amperes,
+        }
+    }
+
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    /// The panel on which the circuit breaker is located.
+    pub fn panel(&self) -> &'static str {
+        self.panel
+    }
+
+    pub fn powered_by(&self) -> ElectricalBusType {
+        self.powered_by
+    }
+
+    /// The bus on the protected side of the circuit breaker.
+    /// Consumers behind the circuit breaker should be powered by this bus.
+    pub const fn bus_type(&self) -> ElectricalBusType {
+        ElectricalBusType::Sub(self.id)
+    }
+}
+
+/// Contains the circuit breakers of an aircraft's catalogue. Each circuit breaker
+/// is powered by the bus given in its definition, and in turn powers a bus of
+/// its own to which the equipment behind the circuit breaker connects.
+///
+/// The load of the equipment is represented by a power consumer, which is protected
+/// by the circuit breaker and consumes from the bus behind it.
+pub struct CircuitBreakerPanel {
+    definitions: Vec<CircuitBreakerDefinition>,
+    consumers: Vec<PowerConsumer>,
+    buses: Vec<ElectricalBus>,
+}
+impl CircuitBreakerPanel {
+    pub fn new(context: &mut InitContext, definitions: &[CircuitBreakerDefinition]) -> Self {
+        Self {
+            definitions: definitions.to_vec(),
+            consumers: definitions
+                .iter()
+                .map(|definition| {
+                    PowerConsumer::from(definition.bus_type()).with_circuit_breaker(
+                        CircuitBreaker::new(
+                            context,
+                            definition.id,
+                            ElectricCurrent::new::<ampere>(definition.rating_amperes),
+                        ),
+                    )
+                })
+                .collect(),
+            buses: definitions
+                .iter()
+                .map(|definition| ElectricalBus::new(context, definition.bus_type()))
+                .collect(),
+        }
+    }
+
+    /// Flows electricity through the circuit breakers. Call this after all buses
+    /// powering the circuit breakers received their potential.
+    pub fn update(&self, electricity: &mut Electricity) {
+        for ((definition, consumer), bus) in self
+            .definitions
+            .iter()
+            .zip(&self.consumers)
+            .zip(&self.buses)
+        {
+            if let Some(circuit_breaker) = consumer.circuit_breaker() {
+                electricity.flow_from_bus(definition.powered_by, circuit_breaker);
+                electricity.flow(circuit_breaker, bus);
+            }
+        }
+    }
+
+    /// Sets the power demanded by the equipment behind the given circuit breaker.
+    pub fn demand(&mut self, id: &str, power: Power) {
+        if let Some(consumer) = self.consumer_mut(id) {
+            consumer.demand(power);
+        }
+    }
+
+    pub fn circuit_breaker(&self, id: &str) -> Option<&CircuitBreaker> {
+        self.definitions
+            .iter()
+            .position(|definition| definition.id == id)
+            .and_then(|index| self.consumers[index].circuit_breaker())
+    }
+
+    fn consumer_mut(&mut self, id: &str) -> Option<&mut PowerConsumer> {
+        self.definitions
+            .iter()
+            .position(|definition| definition.id == id)
+            .map(|index| &mut self.consumers[index])
+    }
+
+    /// Returns the definitions of the circuit breakers located on the given panel.
+    pub fn definitions_on<'a>(
+        &'a self,
+        panel: &'a str,
+    ) -> impl Iterator<Item = &'a CircuitBreakerDefinition> + 'a {
+        self.definitions
+            .iter()
+            .filter(move |definition| definition.panel == panel)
+    }
+}
+impl SimulationElement for CircuitBreakerPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.consumers, visitor);
+        accept_iterable!(self.buses, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Returns the current which flows when consuming the given power at the given potential.
+pub(super) fn current_for(power: Power, potential: ElectricPotential) -> ElectricCurrent {
+    if potential > ElectricPotential::new::<volt>(0.) {
+        ElectricCurrent::new::<ampere>(power.get::<watt>() / potential.get::<volt>())
+    } else {
+        ElectricCurrent::new::<ampere>(0.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{ConsumePower, PotentialOrigin},
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use ntest::assert_about_eq;
+    use std::time::Duration;
+
+    struct CircuitBreakerTestAircraft {
+        power_source: TestElectricitySource,
+        circuit_breaker: CircuitBreaker,
+        bus: ElectricalBus,
+        demand: Power,
+    }
+    impl CircuitBreakerTestAircraft {
+        const BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("TEST");
+
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                power_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::TransformerRectifier(1),
+                ),
+                circuit_breaker: CircuitBreaker::new(
+                    context,
+                    "TEST",
+                    ElectricCurrent::new::<ampere>(10.),
+                )
+                .protecting(Self::BUS_TYPE),
+                bus: ElectricalBus::new(context, Self::BUS_TYPE),
+                demand: Power::new::<watt>(0.),
+            }
+        }
+
+        fn demand(&mut self, current: ElectricCurrent) {
+            // The test electricity source provides 28V.
+            self.demand = Power::new::<watt>(current.get::<ampere>() * 28.);
+        }
+
+        fn bus_is_powered(&self, electricity: &Electricity) -> bool {
+            electricity.is_powered(&self.bus)
+        }
+    }
+    impl Aircraft for CircuitBreakerTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.power_source);
+            electricity.flow(&self.power_source, &self.circuit_breaker);
+            electricity.flow(&self.circuit_breaker, &self.bus);
+        }
+    }
+    impl SimulationElement for CircuitBreakerTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.circuit_breaker.accept(visitor);
+            self.bus.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, power: &mut T) {
+            power.consume_from_bus(Self::BUS_TYPE, self.demand);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<CircuitBreakerTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(CircuitBreakerTestAircraft::new);
+        // Ensure the bus potential is known to the breaker.
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        test_bed
+    }
+
+    #[test]
+    fn starts_closed() {
+        let test_bed = test_bed();
+
+        assert!(test_bed.query(|a| a.circuit_breaker.is_closed()));
+        assert!(test_bed.query_elec(|a, elec| a.bus_is_powered(elec)));
+    }
+
+    #[test]
+    fn does_not_trip_at_rated_current() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(10.)));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(test_bed.query(|a| a.circuit_breaker.is_closed()));
+    }
+
+    #[test]
+    fn trips_after_some_time_at_twice_the_rated_current() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(20.)));
+        test_bed.run_with_delta(Duration::from_secs_f64(
+            CircuitBreaker::TRIP_TIME_AT_TWICE_THE_RATING_SECONDS * 0.9,
+        ));
+        assert!(test_bed.query(|a| a.circuit_breaker.is_closed()));
+
+        test_bed.run_with_delta(Duration::from_secs_f64(
+            CircuitBreaker::TRIP_TIME_AT_TWICE_THE_RATING_SECONDS * 0.2,
+        ));
+        assert!(test_bed.query(|a| a.circuit_breaker.is_tripped()));
+    }
+
+    #[test]
+    fn trips_sooner_at_higher_overcurrent() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(100.)));
+        test_bed.run_with_delta(Duration::from_millis(200));
+
+        assert!(test_bed.query(|a| a.circuit_breaker.is_tripped()));
+    }
+
+    #[test]
+    fn tripped_breaker_no_longer_powers_the_bus() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(100.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.query_elec(|a, elec| !a.bus_is_powered(elec)));
+    }
+
+    #[test]
+    fn tripped_breaker_stays_tripped_without_reset() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(100.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(0.)));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(test_bed.query(|a| a.circuit_breaker.is_tripped()));
+    }
+
+    #[test]
+    fn tripped_breaker_closes_when_reset() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(100.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(0.)));
+        test_bed.write_by_name("ELEC_CB_TEST_RESET", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.query(|a| a.circuit_breaker.is_closed()));
+    }
+
+    #[test]
+    fn trips_when_failed() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::CircuitBreaker("TEST"));
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.query(|a| a.circuit_breaker.is_tripped()));
+    }
+
+    #[test]
+    fn cannot_be_reset_while_failed() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::CircuitBreaker("TEST"));
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        test_bed.write_by_name("ELEC_CB_TEST_RESET", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+        assert!(test_bed.query(|a| a.circuit_breaker.is_tripped()));
+
+        test_bed.unfail(FailureType::CircuitBreaker("TEST"));
+        test_bed.run_with_delta(Duration::from_millis(1));
+        assert!(test_bed.query(|a| a.circuit_breaker.is_tripped()));
+
+        test_bed.write_by_name("ELEC_CB_TEST_RESET", false);
+        test_bed.run_with_delta(Duration::from_millis(1));
+        test_bed.write_by_name("ELEC_CB_TEST_RESET", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+        assert!(test_bed.query(|a| a.circuit_breaker.is_closed()));
+    }
+
+    #[test]
+    fn holding_reset_does_not_prevent_tripping() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ELEC_CB_TEST_RESET", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(100.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        test_bed.command(|a| a.demand(ElectricCurrent::new::<ampere>(0.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.circuit_breaker.is_tripped()));
+    }
+
+    #[test]
+    fn current_for_unpowered_potential_is_zero() {
+        assert_about_eq!(
+            current_for(Power::new::<watt>(100.), ElectricPotential::new::<volt>(0.))
+                .get::<ampere>(),
+            0.
+        );
+    }
+
+    #[test]
+    fn pulled_breaker_no_longer_powers_the_bus() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.query(|a| !a.circuit_breaker.is_closed()));
+        assert!(test_bed.query(|a| !a.circuit_breaker.is_tripped()));
+        assert!(test_bed.query_elec(|a, elec| !a.bus_is_powered(elec)));
+    }
+
+    #[test]
+    fn pushed_in_breaker_powers_the_bus_again() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", false);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.query_elec(|a, elec| a.bus_is_powered(elec)));
+    }
+
+    #[test]
+    fn writes_its_state() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("ELEC_CB_TEST_IS_CLOSED"));
+        assert!(test_bed.contains_variable_with_name("ELEC_CB_TEST_IS_TRIPPED"));
+    }
+}
+
+#[cfg(test)]
+mod panel_tests {
+    use super::*;
+    use crate::{
+        electrical::test::TestElectricitySource,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use std::time::Duration;
+
+    const CATALOGUE: [CircuitBreakerDefinition; 3] = [
+        CircuitBreakerDefinition::new("ONE", "1VU", ElectricalBusType::DirectCurrent(1), 5.),
+        CircuitBreakerDefinition::new("TWO", "1VU", ElectricalBusType::DirectCurrent(1), 5.),
+        CircuitBreakerDefinition::new("THREE", "2VU", ElectricalBusType::DirectCurrent(2), 5.),
+    ];
+
+    struct CircuitBreakerPanelTestAircraft {
+        power_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        panel: CircuitBreakerPanel,
+    }
+    impl CircuitBreakerPanelTestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                power_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::TransformerRectifier(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                panel: CircuitBreakerPanel::new(context, &CATALOGUE),
+            }
+        }
+
+        fn demand_behind_two(&mut self, power: Power) {
+            self.panel.demand("TWO", power);
+        }
+    }
+    impl Aircraft for CircuitBreakerPanelTestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.power_source);
+            electricity.flow(&self.power_source, &self.dc_1_bus);
+            electricity.flow(&self.power_source, &self.dc_2_bus);
+
+            self.panel.update(electricity);
+        }
+    }
+    impl SimulationElement for CircuitBreakerPanelTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.dc_1_bus.accept(visitor);
+            self.dc_2_bus.accept(visitor);
+            self.panel.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<CircuitBreakerPanelTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(CircuitBreakerPanelTestAircraft::new);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        test_bed
+    }
+
+    fn is_powered(test_bed: &SimulationTestBed<CircuitBreakerPanelTestAircraft>, id: &str) -> bool {
+        let bus_type = CATALOGUE.iter().find(|d| d.id() == id).unwrap().bus_type();
+
+        test_bed.query_elec(|_, elec| elec.bus_is_powered(bus_type))
+    }
+
+    #[test]
+    fn powers_the_bus_behind_each_circuit_breaker() {
+        let test_bed = test_bed();
+
+        assert!(is_powered(&test_bed, "ONE"));
+        assert!(is_powered(&test_bed, "TWO"));
+        assert!(is_powered(&test_bed, "THREE"));
+    }
+
+    #[test]
+    fn pulling_a_circuit_breaker_only_depowers_its_own_bus() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ELEC_CB_TWO_IS_PULLED", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(is_powered(&test_bed, "ONE"));
+        assert!(!is_powered(&test_bed, "TWO"));
+        assert!(is_powered(&test_bed, "THREE"));
+    }
+
+    #[test]
+    fn consumer_is_powered_again_after_its_tripped_circuit_breaker_is_reset() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand_behind_two(Power::new::<watt>(2800.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_millis(1));
+        assert!(!is_powered(&test_bed, "TWO"));
+
+        test_bed.command(|a| a.demand_behind_two(Power::new::<watt>(28.)));
+        test_bed.write_by_name("ELEC_CB_TWO_RESET", true);
+        test_bed.run_with_delta(Duration::from_millis(1));
+        test_bed.write_by_name("ELEC_CB_TWO_RESET", false);
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(is_powered(&test_bed, "TWO"));
+        assert!(test_bed.query(|a| a.panel.circuit_breaker("TWO").unwrap().is_closed()));
+    }
+
+    #[test]
+    fn circuit_breakers_can_be_found_by_id() {
+        let test_bed = test_bed();
+
+        assert!(test_bed.query(|a| a.panel.circuit_breaker("THREE").is_some()));
+        assert!(test_bed.query(|a| a.panel.circuit_breaker("FOUR").is_none()));
+    }
+
+    #[test]
+    fn lists_the_circuit_breakers_on_a_panel() {
+        let test_bed = test_bed();
+
+        assert_eq!(
+            test_bed.query(|a| a
+                .panel
+                .definitions_on("1VU")
+                .map(|d| d.id())
+                .collect::<Vec<_>>()),
+            vec!["ONE", "TWO"]
+        );
+    }
+}
@@ -16,7 +16,7 @@
 //! 6. The total load is passed to the various origins so that they can calculate their
 //!    load %, voltage, frequency and current.
 
-use super::ElectricalBusType;
+use super::{circuit_breaker::current_for, CircuitBreaker, ElectricalBusType};
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
     shared::{random_number, ConsumePower, ElectricalBuses, FwcFlightPhase},
@@ -26,13 +26,15 @@ use crate::{
 };
 use num_traits::FromPrimitive;
 use std::time::Duration;
-use uom::si::{f64::*, power::watt};
+use uom::si::{electric_potential::volt, f64::*, power::watt};
 
 /// A generic consumer of power.
 pub struct PowerConsumer {
     is_powered: bool,
     demand: Power,
     powered_by_bus: ElectricalBusType,
+    potential: ElectricPotential,
+    circuit_breaker: Option<CircuitBreaker>,
 }
 impl PowerConsumer {
     /// Create a power consumer which consumes power from the given bus type.
@@ -41,21 +43,64 @@ impl PowerConsumer {
             is_powered: Default::default(),
             demand: Power::new::<watt>(0.),
             powered_by_bus: bus_type,
+            potential: ElectricPotential::new::<volt>(0.),
+            circuit_breaker: None,
         }
     }
 
+    /// Protects the consumer with the given circuit breaker. The consumer is unpowered
+    /// while the breaker is tripped. The breaker trips when the consumer's demand
+    /// causes an overcurrent.
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
     /// Set the amount of power that is demanded by the consumer when powered.
     pub fn demand(&mut self, power: Power) {
         self.demand = power;
     }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    fn circuit_breaker_is_closed(&self) -> bool {
+        self.circuit_breaker
+            .as_ref()
+            .is_none_or(|circuit_breaker| circuit_breaker.is_closed())
+    }
 }
 impl SimulationElement for PowerConsumer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(circuit_breaker) = &mut self.circuit_breaker {
+            circuit_breaker.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = buses.is_powered(self.powered_by_bus);
+        self.is_powered = buses.is_powered(self.powered_by_bus) && self.circuit_breaker_is_closed();
+        self.potential = buses.potential_of(self.powered_by_bus).raw();
     }
 
-    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
-        consumption.consume_from_bus(self.powered_by_bus, self.demand);
+    fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
+        let demand = if self.circuit_breaker_is_closed() {
+            self.demand
+        } else {
+            Power::new::<watt>(0.)
+        };
+
+        consumption.consume_from_bus(self.powered_by_bus, demand);
+        // The breaker is updated while open as well, as it would otherwise never be reset.
+        if let Some(circuit_breaker) = &mut self.circuit_breaker {
+            circuit_breaker.update(context, current_for(demand, self.potential));
+        }
     }
 }
 
@@ -351,4 +396,126 @@ mod tests {
             assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
         }
     }
+
+    #[cfg(test)]
+    mod power_consumer_tests {
+        use super::*;
+        use crate::{
+            electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+            simulation::{
+                test::{SimulationTestBed, TestBed, WriteByName},
+                Aircraft, InitContext,
+            },
+        };
+        use uom::si::electric_current::ampere;
+
+        struct PowerConsumerTestAircraft {
+            electricity_source: TestElectricitySource,
+            bus: ElectricalBus,
+            consumer: PowerConsumer,
+            consumption: Power,
+        }
+        impl PowerConsumerTestAircraft {
+            fn new(context: &mut InitContext) -> Self {
+                Self {
+                    electricity_source: TestElectricitySource::powered(
+                        context,
+                        PotentialOrigin::TransformerRectifier(1),
+                    ),
+                    bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                    consumer: PowerConsumer::from(ElectricalBusType::DirectCurrent(1))
+                        .with_circuit_breaker(CircuitBreaker::new(
+                            context,
+                            "TEST",
+                            ElectricCurrent::new::<ampere>(5.),
+                        )),
+                    consumption: Power::new::<watt>(0.),
+                }
+            }
+
+            fn demand(&mut self, power: Power) {
+                self.consumer.demand(power);
+            }
+        }
+        impl Aircraft for PowerConsumerTestAircraft {
+            fn update_before_power_distribution(
+                &mut self,
+                _: &UpdateContext,
+                electricity: &mut Electricity,
+            ) {
+                electricity.supplied_by(&self.electricity_source);
+                electricity.flow(&self.electricity_source, &self.bus);
+            }
+        }
+        impl SimulationElement for PowerConsumerTestAircraft {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.bus.accept(visitor);
+                self.consumer.accept(visitor);
+
+                visitor.visit(self);
+            }
+
+            fn process_power_consumption_report<T: PowerConsumptionReport>(
+                &mut self,
+                _: &UpdateContext,
+                report: &T,
+            ) {
+                self.consumption =
+                    report.total_consumption_of(PotentialOrigin::TransformerRectifier(1));
+            }
+        }
+
+        #[test]
+        fn consumer_within_circuit_breaker_rating_is_powered_and_consumes() {
+            let mut test_bed = SimulationTestBed::new(PowerConsumerTestAircraft::new);
+            test_bed.command(|a| a.demand(Power::new::<watt>(100.)));
+            test_bed.run();
+            test_bed.run();
+
+            assert!(test_bed.query(|a| a.consumer.is_powered()));
+            assert!(test_bed.query(|a| a.consumption == Power::new::<watt>(100.)));
+        }
+
+        #[test]
+        fn consumer_causing_overcurrent_trips_its_circuit_breaker() {
+            let mut test_bed = SimulationTestBed::new(PowerConsumerTestAircraft::new);
+            test_bed.command(|a| a.demand(Power::new::<watt>(1000.)));
+            test_bed.run();
+            test_bed.run();
+            test_bed.run();
+
+            assert!(test_bed.query(|a| !a.consumer.is_powered()));
+            assert!(test_bed.query(|a| a.consumption == Power::new::<watt>(0.)));
+        }
+
+        #[test]
+        fn consumer_is_powered_again_after_its_tripped_circuit_breaker_is_reset() {
+            let mut test_bed = SimulationTestBed::new(PowerConsumerTestAircraft::new);
+            test_bed.command(|a| a.demand(Power::new::<watt>(1000.)));
+            test_bed.run();
+            test_bed.run();
+            test_bed.run();
+            assert!(test_bed.query(|a| !a.consumer.is_powered()));
+
+            test_bed.command(|a| a.demand(Power::new::<watt>(100.)));
+            test_bed.write_by_name("ELEC_CB_TEST_RESET", true);
+            test_bed.run();
+            test_bed.run();
+
+            assert!(test_bed.query(|a| a.consumer.is_powered()));
+            assert!(test_bed.query(|a| a.consumption == Power::new::<watt>(100.)));
+        }
+
+        #[test]
+        fn consumer_behind_a_pulled_circuit_breaker_is_unpowered() {
+            let mut test_bed = SimulationTestBed::new(PowerConsumerTestAircraft::new);
+            test_bed.command(|a| a.demand(Power::new::<watt>(100.)));
+            test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", true);
+            test_bed.run();
+            test_bed.run();
+
+            assert!(test_bed.query(|a| !a.consumer.is_powered()));
+            assert!(test_bed.query(|a| a.consumption == Power::new::<watt>(0.)));
+        }
+    }
 }
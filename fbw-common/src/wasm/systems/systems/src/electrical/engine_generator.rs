@@ -47,9 +47,13 @@ pub struct EngineGenerator<Drive: EngineGeneratorDrive> {
     output_potential: ElectricPotential,
     load: Ratio,
     time_above_threshold: Duration,
+    time_overloaded: Duration,
     failure: Failure,
 }
 impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
+    /// The time the load has to exceed 100% before the generator is considered overloaded.
+    const OVERLOAD_DETECTION_TIME: Duration = Duration::from_secs(5);
+
     pub fn new(
         context: &mut InitContext,
         number: usize,
@@ -68,6 +72,7 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
             output_potential: ElectricPotential::new::<volt>(0.),
             load: Ratio::new::<percent>(0.),
             time_above_threshold: INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            time_overloaded: Duration::ZERO,
             failure: Failure::new(FailureType::Generator(number)),
         }
     }
@@ -139,6 +144,13 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
     pub fn is_drive_connected(&self) -> bool {
         self.drive.is_connected()
     }
+
+    /// Indicates if the load has been above 100% for some time.
+    /// Use this to shed loads such as the galleys.
+    // TODO: move to GCU when implemented
+    pub fn is_overloaded(&self) -> bool {
+        self.time_overloaded >= Self::OVERLOAD_DETECTION_TIME
+    }
 }
 impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
     fn output_potential(&self) -> Potential {
@@ -204,7 +216,7 @@ impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
 
     fn process_power_consumption_report<T: PowerConsumptionReport>(
         &mut self,
-        _: &UpdateContext,
+        context: &UpdateContext,
         report: &T,
     ) {
         const POWERFACTOR: f64 = 0.8;
@@ -219,6 +231,12 @@ impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
             report.total_consumption_of(PotentialOrigin::EngineGenerator(self.number));
         let power_factor_correction = Ratio::new::<ratio>(POWERFACTOR);
         self.load = power_consumption * power_factor_correction / self.max_true_power;
+
+        self.time_overloaded = if self.load_normal() {
+            Duration::ZERO
+        } else {
+            (self.time_overloaded + context.delta()).min(Self::OVERLOAD_DETECTION_TIME)
+        };
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -943,6 +961,41 @@ mod tests {
             assert!(!test_bed.load_is_normal());
         }
 
+        #[test]
+        fn when_load_exceeds_maximum_briefly_not_overloaded() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>((90000. / 0.8) + 1.)));
+            test_bed.run();
+
+            assert!(!test_bed.query(|a| a.engine_gen.is_overloaded()));
+        }
+
+        #[test]
+        fn when_load_exceeds_maximum_for_some_time_overloaded() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>((90000. / 0.8) + 1.)));
+            test_bed.run_with_delta(Duration::from_secs(6));
+            test_bed.run();
+
+            assert!(test_bed.query(|a| a.engine_gen.is_overloaded()));
+        }
+
+        #[test]
+        fn when_load_returns_below_maximum_no_longer_overloaded() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>((90000. / 0.8) + 1.)));
+            test_bed.run_with_delta(Duration::from_secs(6));
+            test_bed.run();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(50000.)));
+            test_bed.run();
+
+            assert!(!test_bed.query(|a| a.engine_gen.is_overloaded()));
+        }
+
         #[test]
         fn output_within_normal_parameters_when_load_exceeds_maximum() {
             let mut test_bed = EngineGeneratorTestBed::with_running_engine();
@@ -3,10 +3,12 @@
 mod battery;
 mod battery_charge_limiter;
 mod battery_charge_rectifier_unit;
+mod circuit_breaker;
 pub mod consumption;
 mod emergency_generator;
 mod engine_generator;
 mod external_power_source;
+mod network;
 mod ram_air_turbine;
 mod static_inverter;
 mod transformer_rectifier;
@@ -33,12 +35,15 @@ use crate::{
 pub use battery::Battery;
 pub use battery_charge_limiter::BatteryChargeLimiter;
 pub use battery_charge_rectifier_unit::BatteryChargeRectifierUnit;
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerDefinition, CircuitBreakerPanel};
 pub use emergency_generator::EmergencyGenerator;
 pub use engine_generator::{
     EngineGenerator, IntegratedDriveGenerator, VariableFrequencyGenerator,
     INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
 };
 pub use external_power_source::ExternalPowerSource;
+use network::ElectricalNetwork;
+pub use network::ElectricalNetworkFormat;
 use rustc_hash::{FxHashMap, FxHashSet};
 pub use static_inverter::StaticInverter;
 pub use transformer_rectifier::TransformerRectifier;
@@ -65,23 +70,57 @@ pub trait BatteryPushButtons {
 /// When closed a contactor conducts the potential towards other targets.
 #[derive(Debug)]
 pub struct Contactor {
+    id: String,
     identifier: ElectricalElementIdentifier,
     closed_id: VariableIdentifier,
     closed: bool,
+    closing_time: Duration,
+    commanded_closed_for: Duration,
 }
 impl Contactor {
     pub fn new(context: &mut InitContext, id: &str) -> Contactor {
         Contactor {
+            id: id.to_owned(),
             identifier: context.next_electrical_identifier(),
             closed_id: context.get_identifier(format!("ELEC_CONTACTOR_{}_IS_CLOSED", id)),
             closed: false,
+            closing_time: Duration::ZERO,
+            commanded_closed_for: Duration::ZERO,
         }
     }
 
+    /// Sets the time it takes the contactor to close once it is commanded to close
+    /// through [`Self::close_after_closing_time_when`].
+    pub fn with_closing_time(mut self, closing_time: Duration) -> Self {
+        self.closing_time = closing_time;
+        // A contactor which is commanded closed when the simulation starts is
+        // considered to have been closed before it started.
+        self.commanded_closed_for = closing_time;
+        self
+    }
+
     pub fn close_when(&mut self, should_be_closed: bool) {
         self.closed = should_be_closed;
     }
 
+    /// Closes the contactor once it has been commanded to close for its closing time.
+    /// Opening happens immediately. Thus when the powering of a bus is transferred
+    /// from one contactor to another, the bus is briefly unpowered. This is the
+    /// interruption seen on the aircraft during a break power transfer.
+    pub fn close_after_closing_time_when(
+        &mut self,
+        context: &UpdateContext,
+        should_be_closed: bool,
+    ) {
+        if should_be_closed {
+            self.commanded_closed_for += context.delta();
+        } else {
+            self.commanded_closed_for = Duration::ZERO;
+        }
+
+        self.closed = should_be_closed && self.commanded_closed_for >= self.closing_time;
+    }
+
     pub fn is_open(&self) -> bool {
         !self.closed
     }
@@ -102,6 +141,10 @@ impl ElectricalElement for Contactor {
     fn is_conductive(&self) -> bool {
         self.closed
     }
+
+    fn network_name(&self) -> Option<String> {
+        Some(format!("CONTACTOR_{}", self.id))
+    }
 }
 impl SimulationElement for Contactor {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -328,6 +371,12 @@ pub trait ElectricalElement {
 
     /// Returns whether the element is currently capable of conducting electricity.
     fn is_conductive(&self) -> bool;
+
+    /// Returns the name under which the element appears in an exported electrical network.
+    /// Buses are named after their type, other elements without a name by their identifier.
+    fn network_name(&self) -> Option<String> {
+        None
+    }
 }
 
 pub trait ElectricitySource: ElectricalElement {
@@ -338,6 +387,23 @@ pub trait ElectricityTransformer: ElectricalElement {
     fn transform(&self, input: Ref<Potential>) -> Potential;
 }
 
+/// Refers to the output of a bus by its identifier only.
+struct BusOutput(ElectricalElementIdentifier);
+impl ElectricalElement for BusOutput {
+    fn input_identifier(&self) -> ElectricalElementIdentifier {
+        self.0
+    }
+
+    fn output_identifier(&self) -> ElectricalElementIdentifier {
+        self.0
+    }
+
+    fn is_conductive(&self) -> bool {
+        // Whether or not the bus itself conducts is handled when flowing into the bus.
+        true
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub struct ElectricalElementIdentifier(u32);
 impl ElectricalElementIdentifier {
@@ -364,6 +430,7 @@ pub struct Electricity {
     buses: FxHashMap<ElectricalBusType, ElectricalElementIdentifier>,
     potential: PotentialCollection,
     none_potential: RefCell<Potential>,
+    network: Option<ElectricalNetwork>,
 }
 impl Electricity {
     pub fn new() -> Self {
@@ -372,11 +439,35 @@ impl Electricity {
             buses: Default::default(),
             potential: PotentialCollection::new(),
             none_potential: RefCell::new(Potential::none()),
+            network: None,
         }
     }
 
     pub(super) fn pre_tick(&mut self) {
         self.potential.clear();
+        if let Some(network) = &mut self.network {
+            network.clear();
+        }
+    }
+
+    /// Enables or disables recording of the electrical network. When enabled, every
+    /// [flow](`Self::flow()`), [supply](`Self::supplied_by()`) and [transformation](`Self::transform_in()`)
+    /// of a tick is recorded, such that the network can be [exported](`Self::export_network()`).
+    /// Recording is disabled by default, as it is only needed for debugging and visualisation.
+    pub fn record_network(&mut self, enabled: bool) {
+        if !enabled {
+            self.network = None;
+        } else if self.network.is_none() {
+            self.network = Some(ElectricalNetwork::default());
+        }
+    }
+
+    /// Exports the electrical network as recorded during the last tick, including which
+    /// elements are powered and by which origins. Returns `None` when recording is disabled.
+    pub fn export_network(&self, format: ElectricalNetworkFormat) -> Option<String> {
+        self.network
+            .as_ref()
+            .map(|network| network.export(format, &self.buses, &self.potential))
     }
 
     /// Flows electricity from the given output element to the given input element as long
@@ -413,12 +504,29 @@ impl Electricity {
         from_output: &impl ElectricalElement,
         to_input: &impl ElectricalElement,
     ) {
+        if let Some(network) = &mut self.network {
+            network.record_flow(from_output, to_input);
+        }
+
         if from_output.is_conductive() && to_input.is_conductive() {
             self.potential
                 .flow(from_output.output_identifier(), to_input.input_identifier());
         }
     }
 
+    /// Flows electricity from the bus of the given type to the given input element.
+    /// Use this when the bus itself is out of reach, such as for the circuit breakers of a
+    /// [CircuitBreakerPanel] which are powered by buses throughout the electrical system.
+    pub fn flow_from_bus(
+        &mut self,
+        bus_type: ElectricalBusType,
+        to_input: &impl ElectricalElement,
+    ) {
+        if let Some(&identifier) = self.buses.get(&bus_type) {
+            self.flow(&BusOutput(identifier), to_input);
+        }
+    }
+
     /// Takes the output supplied by the given source of electricity, such that
     /// it can then [flow](`Self::flow()`) through the electrical system.
     /// ```rust
@@ -445,6 +553,10 @@ impl Electricity {
     /// electricity.flow(&generator, &contactor);
     /// ```
     pub fn supplied_by(&mut self, source: &impl ElectricitySource) {
+        if let Some(network) = &mut self.network {
+            network.record_source(source);
+        }
+
         let output_identifier = source.output_identifier();
         self.potential.supplied_by(
             output_identifier,
@@ -479,6 +591,10 @@ impl Electricity {
     /// electricity.flow(&tr, &dc_bus);
     /// ```
     pub fn transform_in(&mut self, transformer: &impl ElectricityTransformer) {
+        if let Some(network) = &mut self.network {
+            network.record_transformer(transformer);
+        }
+
         let output_identifier = transformer.output_identifier();
         let transformed_potential = match self.potential.get(transformer.input_identifier()) {
             Some(input_potential) => transformer
@@ -604,6 +720,13 @@ impl PowerConsumptionReport for Electricity {
         self.potential.total_consumption_of(origin)
     }
 
+    fn total_consumption_from_bus(&self, bus_type: ElectricalBusType) -> Power {
+        match self.buses.get(&bus_type) {
+            Some(identifier) => self.potential.total_consumption_from(*identifier),
+            None => Power::new::<watt>(0.),
+        }
+    }
+
     fn is_powered(&self, element: &impl ElectricalElement) -> bool {
         self.is_powered(element)
     }
@@ -827,18 +950,21 @@ impl Default for Potential {
 struct PotentialCollection {
     items: FxHashMap<ElectricalElementIdentifier, Rc<RefCell<Potential>>>,
     consumption_per_origin: FxHashMap<PotentialOrigin, Power>,
+    consumption_per_element: FxHashMap<ElectricalElementIdentifier, Power>,
 }
 impl PotentialCollection {
     fn new() -> Self {
         Self {
             items: Default::default(),
             consumption_per_origin: Default::default(),
+            consumption_per_element: Default::default(),
         }
     }
 
     fn clear(&mut self) {
         self.items.clear();
         self.consumption_per_origin.clear();
+        self.consumption_per_element.clear();
     }
 
     fn flow(
@@ -915,6 +1041,17 @@ impl PotentialCollection {
                 let y = self.consumption_per_origin.entry(*origin).or_default();
                 *y += power / potential.origin_count() as f64;
             }
+
+            if potential.is_powered() {
+                *self.consumption_per_element.entry(identifier).or_default() += power;
+            }
+        }
+    }
+
+    fn total_consumption_from(&self, identifier: ElectricalElementIdentifier) -> Power {
+        match self.consumption_per_element.get(&identifier) {
+            Some(power) => *power,
+            None => Power::new::<watt>(0.),
         }
     }
 
@@ -1145,8 +1282,11 @@ mod tests {
         struct ContactorTestAircraft {
             contactor: Contactor,
             power_source: TestElectricitySource,
+            commanded_closed: Option<bool>,
         }
         impl ContactorTestAircraft {
+            const CLOSING_TIME: Duration = Duration::from_millis(50);
+
             fn new_closed(context: &mut InitContext) -> Self {
                 Self::new(context, true)
             }
@@ -1164,9 +1304,21 @@ mod tests {
                         context,
                         PotentialOrigin::External,
                     ),
+                    commanded_closed: None,
                 }
             }
 
+            fn new_with_closing_time(context: &mut InitContext) -> Self {
+                let mut aircraft = Self::new(context, false);
+                aircraft.contactor = Contactor::new(context, "TEST_CLOSING_TIME")
+                    .with_closing_time(Self::CLOSING_TIME);
+                aircraft
+            }
+
+            fn command_contactor_closed(&mut self, closed: bool) {
+                self.commanded_closed = Some(closed);
+            }
+
             fn open_contactor(&mut self) {
                 self.contactor.close_when(false);
             }
@@ -1194,9 +1346,14 @@ mod tests {
         impl Aircraft for ContactorTestAircraft {
             fn update_before_power_distribution(
                 &mut self,
-                _: &UpdateContext,
+                context: &UpdateContext,
                 electricity: &mut Electricity,
             ) {
+                if let Some(closed) = self.commanded_closed {
+                    self.contactor
+                        .close_after_closing_time_when(context, closed);
+                }
+
                 electricity.supplied_by(&self.power_source);
                 electricity.flow(&self.power_source, &self.contactor);
             }
@@ -1277,6 +1434,56 @@ mod tests {
             assert!(test_bed.query_elec(|a, elec| a.contactor_is_powered(elec)));
         }
 
+        #[test]
+        fn contactor_with_closing_time_commanded_closed_from_the_start_is_closed() {
+            let mut test_bed = SimulationTestBed::new(ContactorTestAircraft::new_with_closing_time);
+            test_bed.command(|a| a.command_contactor_closed(true));
+            test_bed.run_with_delta(Duration::from_millis(1));
+
+            assert!(test_bed.query(|a| a.contactor_is_closed()));
+        }
+
+        #[test]
+        fn contactor_with_closing_time_opens_immediately() {
+            let mut test_bed = SimulationTestBed::new(ContactorTestAircraft::new_with_closing_time);
+            test_bed.command(|a| a.command_contactor_closed(true));
+            test_bed.run_with_delta(Duration::from_millis(1));
+
+            test_bed.command(|a| a.command_contactor_closed(false));
+            test_bed.run_with_delta(Duration::from_millis(1));
+
+            assert!(test_bed.query(|a| a.contactor_is_open()));
+        }
+
+        #[test]
+        fn contactor_with_closing_time_closes_once_commanded_closed_for_closing_time() {
+            let mut test_bed = SimulationTestBed::new(ContactorTestAircraft::new_with_closing_time);
+            test_bed.command(|a| a.command_contactor_closed(false));
+            test_bed.run_with_delta(Duration::from_millis(1));
+
+            test_bed.command(|a| a.command_contactor_closed(true));
+            test_bed.run_with_delta(ContactorTestAircraft::CLOSING_TIME / 2);
+            assert!(test_bed.query(|a| a.contactor_is_open()));
+
+            test_bed.run_with_delta(ContactorTestAircraft::CLOSING_TIME / 2);
+            assert!(test_bed.query(|a| a.contactor_is_closed()));
+        }
+
+        #[test]
+        fn contactor_with_closing_time_has_no_output_while_closing() {
+            let mut test_bed = SimulationTestBed::new(ContactorTestAircraft::new_with_closing_time);
+            test_bed.command(|a| a.provide_power());
+            test_bed.command(|a| a.command_contactor_closed(false));
+            test_bed.run_with_delta(Duration::from_millis(1));
+
+            test_bed.command(|a| a.command_contactor_closed(true));
+            test_bed.run_with_delta(Duration::from_millis(10));
+            assert!(test_bed.query_elec(|a, elec| !a.contactor_is_powered(elec)));
+
+            test_bed.run_with_delta(ContactorTestAircraft::CLOSING_TIME);
+            assert!(test_bed.query_elec(|a, elec| a.contactor_is_powered(elec)));
+        }
+
         #[test]
         fn writes_its_state() {
             let mut test_bed = SimulationTestBed::new(ContactorTestAircraft::new_open);
@@ -2117,5 +2324,93 @@ mod tests {
                 Power::new::<watt>(200.)
             );
         }
+
+        #[test]
+        fn network_is_not_exported_when_not_recorded() {
+            let mut electricity = Electricity::new();
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            electricity.supplied_by(&generator);
+
+            assert!(electricity
+                .export_network(ElectricalNetworkFormat::Json)
+                .is_none());
+        }
+
+        #[test]
+        fn exported_network_contains_powered_bus_with_its_origin() {
+            let mut electricity = Electricity::new();
+            electricity.record_network(true);
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            let bus = TestBus::new(&mut electricity, ElectricalBusType::AlternatingCurrent(1));
+
+            electricity.supplied_by(&generator);
+            electricity.flow(&generator, &bus);
+
+            assert_eq!(
+                electricity.export_network(ElectricalNetworkFormat::Json),
+                Some(
+                    "{\"nodes\":[\
+                    {\"id\":1,\"kind\":\"source\",\"name\":\"ELEMENT_1\",\"conductive\":true,\"powered\":true,\"origins\":[\"EngineGenerator(1)\"]},\
+                    {\"id\":2,\"kind\":\"bus\",\"name\":\"AC_1_BUS\",\"conductive\":true,\"powered\":true,\"origins\":[\"EngineGenerator(1)\"]}],\
+                    \"edges\":[{\"from\":1,\"to\":2,\"kind\":\"flow\",\"conductive\":true}]}"
+                        .to_owned()
+                )
+            );
+        }
+
+        #[test]
+        fn exported_network_contains_connections_which_do_not_conduct() {
+            let mut electricity = Electricity::new();
+            electricity.record_network(true);
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            let contactor = TestElectricalElement::new(&mut electricity).non_conductive();
+
+            electricity.supplied_by(&generator);
+            electricity.flow(&generator, &contactor);
+
+            let network = electricity
+                .export_network(ElectricalNetworkFormat::Dot)
+                .unwrap();
+
+            assert!(
+                network.contains("n2 [label=\"ELEMENT_2\\n(open)\", shape=ellipse, color=gray];")
+            );
+            assert!(network.contains("n1 -> n2 [style=dotted];"));
+        }
+
+        #[test]
+        fn exported_network_contains_transformations() {
+            let mut electricity = Electricity::new();
+            electricity.record_network(true);
+            let source = TestElectricalElement::new(&mut electricity).power();
+            let transformer = TestTransformer::new(&mut electricity);
+
+            electricity.supplied_by(&source);
+            electricity.flow(&source, &transformer);
+            electricity.transform_in(&transformer);
+
+            let network = electricity
+                .export_network(ElectricalNetworkFormat::Dot)
+                .unwrap();
+
+            assert!(network.contains("n1 -> n2 [style=solid];"));
+            assert!(network.contains("n2 -> n3 [style=bold];"));
+            assert!(network.contains("TransformerRectifier(1)"));
+        }
+
+        #[test]
+        fn recorded_network_is_cleared_before_each_tick() {
+            let mut electricity = Electricity::new();
+            electricity.record_network(true);
+            let generator = TestElectricalElement::new(&mut electricity).power();
+            electricity.supplied_by(&generator);
+
+            electricity.pre_tick();
+
+            assert_eq!(
+                electricity.export_network(ElectricalNetworkFormat::Json),
+                Some("{\"nodes\":[],\"edges\":[]}".to_owned())
+            );
+        }
     }
 }
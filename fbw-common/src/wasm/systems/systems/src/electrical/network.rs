@@ -0,0 +1,295 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::shared::ElectricalBusType;
+
+use super::{ElectricalElement, ElectricalElementIdentifier, PotentialCollection};
+
+/// The formats in which the electrical network can be exported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElectricalNetworkFormat {
+    /// A Graphviz digraph, which can be rendered using e.g. `dot -Tsvg`.
+    Dot,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NodeKind {
+    Bus,
+    Element,
+    Source,
+    TransformerInput,
+    TransformerOutput,
+}
+impl NodeKind {
+    fn name(&self) -> &'static str {
+        match self {
+            NodeKind::Bus => "bus",
+            NodeKind::Element => "element",
+            NodeKind::Source => "source",
+            NodeKind::TransformerInput => "transformer_input",
+            NodeKind::TransformerOutput => "transformer_output",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum EdgeKind {
+    Flow,
+    Transform,
+}
+impl EdgeKind {
+    fn name(&self) -> &'static str {
+        match self {
+            EdgeKind::Flow => "flow",
+            EdgeKind::Transform => "transform",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    kind: NodeKind,
+    name: Option<String>,
+    conductive: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Edge {
+    from: u32,
+    to: u32,
+    kind: EdgeKind,
+    conductive: bool,
+}
+
+/// Records the electrical network as it is built up during a single simulation tick,
+/// such that it can be exported once the tick completes.
+///
+/// Contactors which are open and failed buses are recorded as well, as their connections
+/// are attempted even though no electricity flows through them.
+#[derive(Debug, Default)]
+pub(super) struct ElectricalNetwork {
+    nodes: BTreeMap<u32, Node>,
+    edges: Vec<Edge>,
+    known_edges: FxHashSet<Edge>,
+}
+impl ElectricalNetwork {
+    pub(super) fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+        self.known_edges.clear();
+    }
+
+    pub(super) fn record_flow(
+        &mut self,
+        from_output: &impl ElectricalElement,
+        to_input: &impl ElectricalElement,
+    ) {
+        let from = self.record_element(from_output, from_output.output_identifier());
+        let to = self.record_element(to_input, to_input.input_identifier());
+
+        self.record_edge(Edge {
+            from,
+            to,
+            kind: EdgeKind::Flow,
+            conductive: from_output.is_conductive() && to_input.is_conductive(),
+        });
+    }
+
+    pub(super) fn record_source(&mut self, source: &impl ElectricalElement) {
+        let identifier = self.record_element(source, source.output_identifier());
+        self.mark(identifier, NodeKind::Source);
+    }
+
+    pub(super) fn record_transformer(&mut self, transformer: &impl ElectricalElement) {
+        let input = self.record_element(transformer, transformer.input_identifier());
+        let output = self.record_element(transformer, transformer.output_identifier());
+        self.mark(input, NodeKind::TransformerInput);
+        self.mark(output, NodeKind::TransformerOutput);
+
+        self.record_edge(Edge {
+            from: input,
+            to: output,
+            kind: EdgeKind::Transform,
+            conductive: transformer.is_conductive(),
+        });
+    }
+
+    fn record_element(
+        &mut self,
+        element: &impl ElectricalElement,
+        identifier: ElectricalElementIdentifier,
+    ) -> u32 {
+        self.nodes.entry(identifier.0).or_insert_with(|| Node {
+            kind: NodeKind::Element,
+            name: element.network_name(),
+            conductive: element.is_conductive(),
+        });
+
+        identifier.0
+    }
+
+    fn mark(&mut self, identifier: u32, kind: NodeKind) {
+        if let Some(node) = self.nodes.get_mut(&identifier) {
+            node.kind = kind;
+        }
+    }
+
+    fn record_edge(&mut self, edge: Edge) {
+        if self.known_edges.insert(edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    pub(super) fn export(
+        &self,
+        format: ElectricalNetworkFormat,
+        buses: &FxHashMap<ElectricalBusType, ElectricalElementIdentifier>,
+        potential: &PotentialCollection,
+    ) -> String {
+        let bus_types: FxHashMap<u32, ElectricalBusType> = buses
+            .iter()
+            .map(|(bus_type, identifier)| (identifier.0, *bus_type))
+            .collect();
+
+        let nodes: Vec<ExportedNode> = self
+            .nodes
+            .iter()
+            .map(|(&identifier, node)| {
+                let (powered, mut origins) =
+                    match potential.get(ElectricalElementIdentifier(identifier)) {
+                        Some(potential) => (
+                            potential.is_powered(),
+                            potential.origins().map(|o| o.to_string()).collect(),
+                        ),
+                        None => (false, Vec::new()),
+                    };
+                // Potential origins are kept in a hash set, sort them for a stable output.
+                origins.sort();
+
+                let bus_type = bus_types.get(&identifier);
+                ExportedNode {
+                    identifier,
+                    kind: if bus_type.is_some() {
+                        NodeKind::Bus
+                    } else {
+                        node.kind
+                    },
+                    name: match (bus_type, &node.name) {
+                        (Some(bus_type), _) => format!("{}_BUS", bus_type),
+                        (None, Some(name)) => name.clone(),
+                        (None, None) => format!("ELEMENT_{}", identifier),
+                    },
+                    conductive: node.conductive,
+                    powered,
+                    origins,
+                }
+            })
+            .collect();
+
+        match format {
+            ElectricalNetworkFormat::Dot => self.to_dot(&nodes),
+            ElectricalNetworkFormat::Json => self.to_json(&nodes),
+        }
+    }
+
+    fn to_dot(&self, nodes: &[ExportedNode]) -> String {
+        let mut output = String::from("digraph electrical_network {\n");
+        for node in nodes {
+            let shape = match node.kind {
+                NodeKind::Bus => "box",
+                NodeKind::Source => "doublecircle",
+                NodeKind::TransformerInput | NodeKind::TransformerOutput => "trapezium",
+                NodeKind::Element => "ellipse",
+            };
+            let mut label = escape(&node.name);
+            if !node.conductive {
+                label += "\\n(open)";
+            }
+            if !node.origins.is_empty() {
+                label += &format!("\\n{}", escape(&node.origins.join(", ")));
+            }
+
+            let _ = writeln!(
+                output,
+                "  n{} [label=\"{}\", shape={}, color={}];",
+                node.identifier,
+                label,
+                shape,
+                if node.powered { "green" } else { "gray" }
+            );
+        }
+
+        for edge in &self.edges {
+            let _ = writeln!(
+                output,
+                "  n{} -> n{} [style={}];",
+                edge.from,
+                edge.to,
+                match (edge.kind, edge.conductive) {
+                    (_, false) => "dotted",
+                    (EdgeKind::Flow, true) => "solid",
+                    (EdgeKind::Transform, true) => "bold",
+                }
+            );
+        }
+
+        output.push_str("}\n");
+        output
+    }
+
+    fn to_json(&self, nodes: &[ExportedNode]) -> String {
+        let nodes: Vec<String> = nodes
+            .iter()
+            .map(|node| {
+                format!(
+                    "{{\"id\":{},\"kind\":\"{}\",\"name\":\"{}\",\"conductive\":{},\"powered\":{},\"origins\":[{}]}}",
+                    node.identifier,
+                    node.kind.name(),
+                    escape(&node.name),
+                    node.conductive,
+                    node.powered,
+                    node.origins
+                        .iter()
+                        .map(|origin| format!("\"{}\"", escape(origin)))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                )
+            })
+            .collect();
+
+        let edges: Vec<String> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                format!(
+                    "{{\"from\":{},\"to\":{},\"kind\":\"{}\",\"conductive\":{}}}",
+                    edge.from,
+                    edge.to,
+                    edge.kind.name(),
+                    edge.conductive
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"nodes\":[{}],\"edges\":[{}]}}",
+            nodes.join(","),
+            edges.join(",")
+        )
+    }
+}
+
+struct ExportedNode {
+    identifier: u32,
+    kind: NodeKind,
+    name: String,
+    conductive: bool,
+    powered: bool,
+    origins: Vec<String>,
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -2,10 +2,12 @@ use crate::air_conditioning::{
     acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, VcmId, ZoneType,
 };
 use crate::air_conditioning::{FdacId, OcsmId};
+use crate::hydraulic::flap_slat::{SecondarySurfaceSide, SecondarySurfaceType};
 use crate::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, BleedLeakZone, ElectricalBusType,
+    FireDetectionLoopID, FireDetectionZone, FlightControlSurfaceId, GearActuatorId, HydraulicColor,
+    HydraulicSectionId, LgciuId, PneumaticValveId, ProximityDetectorId,
 };
 use crate::simulation::SimulationElement;
 use rustc_hash::FxHashSet;
@@ -44,13 +46,29 @@ pub enum FailureType {
     TransformerRectifier(usize),
     StaticInverter,
     ElectricalBus(ElectricalBusType),
+    CircuitBreaker(&'static str),
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    Smoke(FireDetectionZone),
+    SmokeDetector(FireDetectionLoopID, FireDetectionZone),
+    // ATA27
+    FlapSlatTransmissionShaftBreak(SecondarySurfaceType, SecondarySurfaceSide),
+    FlapSlatSkew(SecondarySurfaceType, SecondarySurfaceSide),
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
+    HydraulicSectionLeak(HydraulicColor, HydraulicSectionId),
+    PumpCaseDrainLeak(HydraulicColor, usize),
+    PtuLeak(HydraulicColor),
+    FlightControlActuatorLeak(FlightControlSurfaceId, usize),
+    FlightControlActuatorJam(FlightControlSurfaceId, usize),
+    FlightControlActuatorRunaway(FlightControlSurfaceId, usize),
+    FlightControlSurfaceDisconnect(FlightControlSurfaceId),
+    ThsJam,
+    ThsElectricMotorRunaway(usize),
+    ThsHydraulicMotor(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
     // ATA32
@@ -58,13 +76,34 @@ pub enum FailureType {
     LgciuInternalError(LgciuId),
     GearProxSensorDamage(ProximityDetectorId),
     GearActuatorJammed(GearActuatorId),
+    GearDoorActuatorFault(GearActuatorId),
+    GearUplockJammed(GearActuatorId),
+    GearDownlockFailure(GearActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    AntiSkid,
+    BrakeSeized(usize),
+    BrakeReleased(usize),
+    BrakeTemperatureProbe(usize),
+    FlatTyre(usize),
     // ATA34
     RadioAltimeter(usize),
     RadioAntennaInterrupted(usize),
     RadioAntennaDirectCoupling(usize),
     EnhancedGroundProximityWarningSystemComputer,
+    // ATA36
+    BleedDuctLeak(BleedLeakZone),
+    BleedLeakDetectionLoop(BleedLeakZone, FireDetectionLoopID),
+    PneumaticValveStuckOpen(PneumaticValveId),
+    PneumaticValveStuckClosed(PneumaticValveId),
+    PneumaticValveStuckInPosition(PneumaticValveId),
+    PneumaticValveInternalLeak(PneumaticValveId),
+    // ATA49
+    ApuHungStart,
+    ApuEgtOvertemperature,
+    ApuLowOilPressure,
+    ApuAirIntakeFlapStuck,
+    ApuElectronicControlBox,
 }
 
 pub struct Failure {
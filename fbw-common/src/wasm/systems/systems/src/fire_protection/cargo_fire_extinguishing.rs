@@ -0,0 +1,339 @@
+use uom::si::{f64::*, pressure::psi};
+
+use crate::{
+    overhead::MomentaryPushButton,
+    shared::{ElectricalBusType, ElectricalBuses, FireDetectionZone},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CargoFireBottleState {
+    Full,
+    HighRateDischarge,
+    MeteredDischarge,
+    Empty,
+}
+
+/// Cargo fire extinguishing bottle. When the squib is fired, a high-rate discharge quickly
+/// builds up the agent concentration in the compartment. The remaining agent is then
+/// metered into the compartment to keep the concentration for a longer period.
+pub struct CargoFireBottle {
+    bottle_discharged_id: VariableIdentifier,
+    bottle_pressure_id: VariableIdentifier,
+
+    state: CargoFireBottleState,
+    pressure: Pressure,
+
+    powered_by: [ElectricalBusType; 2],
+    is_powered: bool,
+}
+impl CargoFireBottle {
+    const NOMINAL_PRESSURE_PSI: f64 = 600.;
+    const HIGH_RATE_DISCHARGE_TIME_CONSTANT_SECONDS: f64 = 1.;
+    const METERED_DISCHARGE_START_PRESSURE_PSI: f64 = 150.;
+    const METERED_DISCHARGE_DURATION_SECONDS: f64 = 3600.;
+
+    fn new(context: &mut InitContext, id: &str, powered_by: [ElectricalBusType; 2]) -> Self {
+        Self {
+            bottle_discharged_id: context
+                .get_identifier(format!("CARGO_FIRE_BOTTLE_{}_IS_DISCHARGED", id)),
+            bottle_pressure_id: context
+                .get_identifier(format!("CARGO_FIRE_BOTTLE_{}_PRESSURE", id)),
+
+            state: CargoFireBottleState::Full,
+            pressure: Pressure::new::<psi>(Self::NOMINAL_PRESSURE_PSI),
+
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, should_discharge: bool) {
+        if self.state == CargoFireBottleState::Full && should_discharge && self.is_powered {
+            self.state = CargoFireBottleState::HighRateDischarge;
+        }
+
+        match self.state {
+            CargoFireBottleState::HighRateDischarge => {
+                self.pressure = Pressure::new::<psi>(
+                    (self.pressure.get::<psi>()
+                        * (-context.delta_as_secs_f64()
+                            / Self::HIGH_RATE_DISCHARGE_TIME_CONSTANT_SECONDS)
+                            .exp())
+                    .max(Self::METERED_DISCHARGE_START_PRESSURE_PSI),
+                );
+            }
+            CargoFireBottleState::MeteredDischarge => {
+                let metered_flow_psi_per_second = Self::METERED_DISCHARGE_START_PRESSURE_PSI
+                    / Self::METERED_DISCHARGE_DURATION_SECONDS;
+                self.pressure = Pressure::new::<psi>(
+                    (self.pressure.get::<psi>()
+                        - metered_flow_psi_per_second * context.delta_as_secs_f64())
+                    .max(0.),
+                );
+            }
+            CargoFireBottleState::Full | CargoFireBottleState::Empty => {}
+        }
+
+        self.state = match self.state {
+            CargoFireBottleState::HighRateDischarge
+                if self.pressure.get::<psi>() <= Self::METERED_DISCHARGE_START_PRESSURE_PSI =>
+            {
+                CargoFireBottleState::MeteredDischarge
+            }
+            CargoFireBottleState::MeteredDischarge if self.pressure.get::<psi>() <= 0. => {
+                CargoFireBottleState::Empty
+            }
+            state => state,
+        };
+    }
+
+    fn is_discharged(&self) -> bool {
+        self.state != CargoFireBottleState::Full
+    }
+
+    fn is_discharging(&self) -> bool {
+        matches!(
+            self.state,
+            CargoFireBottleState::HighRateDischarge | CargoFireBottleState::MeteredDischarge
+        )
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+}
+impl SimulationElement for CargoFireBottle {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.bottle_discharged_id, self.is_discharged());
+        writer.write(&self.bottle_pressure_id, self.pressure);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = self.powered_by.iter().any(|&p| buses.is_powered(p));
+    }
+}
+
+/// A cargo fire bottle shared between the cargo compartments. Each compartment has its own
+/// DISCH pushbutton, which fires the squib directing the agent into that compartment.
+/// Once discharged, the bottle cannot be redirected into another compartment.
+pub struct CargoFireExtinguishingSystem<const N: usize> {
+    bottle: CargoFireBottle,
+    discharge_pbs: [MomentaryPushButton; N],
+
+    discharged_into: Option<usize>,
+}
+impl<const N: usize> CargoFireExtinguishingSystem<N> {
+    pub fn new(
+        context: &mut InitContext,
+        cargo_compartments: [FireDetectionZone; N],
+        powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self {
+            bottle: CargoFireBottle::new(context, "1", powered_by),
+            discharge_pbs: cargo_compartments.map(|zone| {
+                MomentaryPushButton::new(context, &format!("CARGO_FIRE_AGENT_{}", zone))
+            }),
+
+            discharged_into: None,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        if self.discharged_into.is_none() && self.bottle.is_powered {
+            self.discharged_into = self.discharge_pbs.iter().position(|pb| pb.is_pressed());
+        }
+
+        self.bottle.update(context, self.discharged_into.is_some());
+    }
+
+    /// Indicates, for each compartment, whether agent is currently being discharged into it.
+    pub fn agent_is_present(&self) -> [bool; N] {
+        let mut agent_is_present = [false; N];
+        if let Some(index) = self.discharged_into {
+            agent_is_present[index] = self.bottle.is_discharging();
+        }
+        agent_is_present
+    }
+
+    pub fn bottle_is_discharged(&self) -> bool {
+        self.bottle.is_discharged()
+    }
+
+    pub fn bottle_pressure(&self) -> Pressure {
+        self.bottle.pressure()
+    }
+}
+impl<const N: usize> SimulationElement for CargoFireExtinguishingSystem<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.bottle.accept(visitor);
+        accept_iterable!(self.discharge_pbs, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use ntest::assert_about_eq;
+    use std::time::Duration;
+
+    struct TestAircraft {
+        cargo_fire_extinguishing: CargoFireExtinguishingSystem<2>,
+
+        powered_source: TestElectricitySource,
+        dc_bat_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                cargo_fire_extinguishing: CargoFireExtinguishingSystem::new(
+                    context,
+                    [FireDetectionZone::ForwardCargo, FireDetectionZone::AftCargo],
+                    [
+                        ElectricalBusType::DirectCurrentBattery,
+                        ElectricalBusType::DirectCurrentEssential,
+                    ],
+                ),
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_bat_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_bat_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.cargo_fire_extinguishing.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.cargo_fire_extinguishing.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run();
+        test_bed
+    }
+
+    fn bottle_pressure(test_bed: &SimulationTestBed<TestAircraft>) -> f64 {
+        test_bed.query(|a| a.cargo_fire_extinguishing.bottle_pressure().get::<psi>())
+    }
+
+    #[test]
+    fn bottle_is_full_before_discharge() {
+        let mut test_bed = test_bed();
+
+        let discharged: bool = test_bed.read_by_name("CARGO_FIRE_BOTTLE_1_IS_DISCHARGED");
+        assert!(!discharged);
+        assert_about_eq!(bottle_pressure(&test_bed), 600.);
+        assert_eq!(
+            test_bed.query(|a| a.cargo_fire_extinguishing.agent_is_present()),
+            [false, false]
+        );
+    }
+
+    #[test]
+    fn discharge_pb_directs_agent_into_selected_compartment() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_CARGO_FIRE_AGENT_AFT_CARGO_IS_PRESSED", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.cargo_fire_extinguishing.bottle_is_discharged()));
+        assert_eq!(
+            test_bed.query(|a| a.cargo_fire_extinguishing.agent_is_present()),
+            [false, true]
+        );
+    }
+
+    #[test]
+    fn high_rate_discharge_is_followed_by_metered_discharge() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_CARGO_FIRE_AGENT_FWD_CARGO_IS_PRESSED", true);
+        test_bed.run_with_delta(Duration::from_millis(100));
+        test_bed.write_by_name("OVHD_CARGO_FIRE_AGENT_FWD_CARGO_IS_PRESSED", false);
+
+        for _ in 0..5 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        // High rate discharge is over, metered discharge keeps the agent flowing
+        let pressure_after_high_rate = bottle_pressure(&test_bed);
+        assert!(pressure_after_high_rate < 150.);
+        assert!(pressure_after_high_rate > 140.);
+        assert_eq!(
+            test_bed.query(|a| a.cargo_fire_extinguishing.agent_is_present()),
+            [true, false]
+        );
+
+        for _ in 0..30 {
+            test_bed.run_with_delta(Duration::from_secs(60));
+        }
+
+        assert!(bottle_pressure(&test_bed) < pressure_after_high_rate);
+        assert!(bottle_pressure(&test_bed) > 0.);
+
+        for _ in 0..31 {
+            test_bed.run_with_delta(Duration::from_secs(60));
+        }
+
+        assert_about_eq!(bottle_pressure(&test_bed), 0.);
+        assert_eq!(
+            test_bed.query(|a| a.cargo_fire_extinguishing.agent_is_present()),
+            [false, false]
+        );
+    }
+
+    #[test]
+    fn bottle_cannot_be_redirected_once_discharged() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("OVHD_CARGO_FIRE_AGENT_FWD_CARGO_IS_PRESSED", true);
+        test_bed.run();
+        test_bed.write_by_name("OVHD_CARGO_FIRE_AGENT_FWD_CARGO_IS_PRESSED", false);
+        test_bed.write_by_name("OVHD_CARGO_FIRE_AGENT_AFT_CARGO_IS_PRESSED", true);
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.cargo_fire_extinguishing.agent_is_present()),
+            [true, false]
+        );
+    }
+
+    #[test]
+    fn bottle_does_not_discharge_when_unpowered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.powered_source.unpower());
+        test_bed.write_by_name("OVHD_CARGO_FIRE_AGENT_FWD_CARGO_IS_PRESSED", true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.cargo_fire_extinguishing.bottle_is_discharged()));
+    }
+}
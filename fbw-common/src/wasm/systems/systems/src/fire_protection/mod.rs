@@ -0,0 +1,718 @@
+pub mod cargo_fire_extinguishing;
+pub mod smoke_detection;
+
+use std::time::Duration;
+
+use uom::si::{f64::*, pressure::psi};
+
+use crate::{
+    failures::{Failure, FailureType},
+    overhead::MomentaryPushButton,
+    shared::{
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, FireDetectionLoopID,
+        FireDetectionZone, LgciuWeightOnWheels,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+/// The Fire Detection Unit (FDU) monitors two detection loops (A and B) in every fire zone.
+/// A fire is detected when both loops detect it, or when one loop detects it while the other
+/// one has failed. If both loops of a zone fail within a short interval, it is considered as a
+/// burn through and a fire is detected as well.
+pub struct FireDetectionUnit<const N: usize> {
+    fire_detection_loops: [FireDetectionLoop<N>; 2],
+
+    fire_detected_id: [VariableIdentifier; N],
+
+    fire_detected: [bool; N],
+    fire_detection_zones: [FireDetectionZone; N],
+    interval_between_loop_failures: [Duration; N],
+    apu_fire_on_ground: bool,
+    should_extinguish_apu_fire: DelayedTrueLogicGate,
+}
+impl<const N: usize> FireDetectionUnit<N> {
+    const DELAY_APU_FIRE_EXTINGUISHING: Duration = Duration::from_secs(10);
+    const MAX_INTERVAL_BETWEEN_LOOP_FAILURES_FOR_FIRE: Duration = Duration::from_secs(5);
+
+    pub fn new(
+        context: &mut InitContext,
+        fire_detection_zones: [FireDetectionZone; N],
+        loops_powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self {
+            fire_detection_loops: [
+                FireDetectionLoop::new(
+                    context,
+                    FireDetectionLoopID::A,
+                    &fire_detection_zones,
+                    loops_powered_by[0],
+                ),
+                FireDetectionLoop::new(
+                    context,
+                    FireDetectionLoopID::B,
+                    &fire_detection_zones,
+                    loops_powered_by[1],
+                ),
+            ],
+
+            fire_detected_id: fire_detection_zones.map(|zone| Self::init_identifier(context, zone)),
+
+            fire_detected: [false; N],
+            fire_detection_zones,
+            interval_between_loop_failures: [Duration::ZERO; N],
+            apu_fire_on_ground: false,
+            should_extinguish_apu_fire: DelayedTrueLogicGate::new(
+                Self::DELAY_APU_FIRE_EXTINGUISHING,
+            ),
+        }
+    }
+
+    fn init_identifier(
+        context: &mut InitContext,
+        zone_id: FireDetectionZone,
+    ) -> VariableIdentifier {
+        if matches!(zone_id, FireDetectionZone::Engine(_)) {
+            context.get_identifier(format!("FIRE_DETECTED_ENG{}", zone_id))
+        } else {
+            context.get_identifier(format!("FIRE_DETECTED_{}", zone_id))
+        }
+    }
+
+    /// `fire_test_pushbuttons_pressed` holds the state of the fire test for each zone,
+    /// in the same order as the fire detection zones given at construction.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        fire_test_pushbuttons_pressed: [bool; N],
+        lgciu: [&impl LgciuWeightOnWheels; 2],
+    ) {
+        self.interval_between_loop_failures = self.calculate_interval_between_failures(context);
+
+        self.fire_detected = self.fire_detection_determination(fire_test_pushbuttons_pressed);
+
+        self.fire_detection_loops
+            .iter_mut()
+            .for_each(|l| l.update_was_powered());
+
+        // If a fire is detected in the APU while the aircraft is on the ground, the extinguishing system is automatically activated after a delay
+        self.apu_fire_on_ground = self
+            .zone_index(FireDetectionZone::Apu)
+            .is_some_and(|index| {
+                self.fire_detected[index] && !fire_test_pushbuttons_pressed[index]
+            })
+            && lgciu.iter().all(|a| a.left_and_right_gear_compressed(true));
+        self.should_extinguish_apu_fire
+            .update(context, self.apu_fire_on_ground);
+    }
+
+    fn fire_detection_determination(&self, fire_test_pb: [bool; N]) -> [bool; N] {
+        let mut fire_detected = [false; N];
+        for (((&zone, &interval_between_loop_failures), &fire_test_pb), fire_detected) in self
+            .fire_detection_zones
+            .iter()
+            .zip(&self.interval_between_loop_failures)
+            .zip(&fire_test_pb)
+            .zip(&mut fire_detected)
+        {
+            *fire_detected = (self.fire_detection_loops[0]
+                .fire_detected_in_loop(zone, fire_test_pb)
+                && self.fire_detection_loops[1].fire_detected_in_loop(zone, fire_test_pb))
+                || (self
+                    .fire_detection_loops
+                    .iter()
+                    .any(|l| l.fire_detected_in_loop(zone, fire_test_pb))
+                    && self
+                        .fire_detection_loops
+                        .iter()
+                        .any(|l| l.loop_has_failed(zone)))
+                || (self
+                    .fire_detection_loops
+                    .iter()
+                    .all(|l| l.loop_has_failed(zone))
+                    && interval_between_loop_failures
+                        < Self::MAX_INTERVAL_BETWEEN_LOOP_FAILURES_FOR_FIRE
+                    && zone != FireDetectionZone::Mlg);
+        }
+        fire_detected
+    }
+
+    fn calculate_interval_between_failures(&self, context: &UpdateContext) -> [Duration; N] {
+        let mut interval = [Duration::ZERO; N];
+        for ((&zone, &interval_between_loop_failures), interval) in self
+            .fire_detection_zones
+            .iter()
+            .zip(&self.interval_between_loop_failures)
+            .zip(&mut interval)
+        {
+            *interval = if self
+                .fire_detection_loops
+                .iter()
+                .all(|l| !l.loop_has_failed(zone))
+            {
+                Duration::ZERO
+            } else if self
+                .fire_detection_loops
+                .iter()
+                .all(|l| l.loop_has_failed(zone))
+            {
+                interval_between_loop_failures
+            } else {
+                interval_between_loop_failures + context.delta()
+            }
+        }
+        interval
+    }
+
+    fn zone_index(&self, zone: FireDetectionZone) -> Option<usize> {
+        self.fire_detection_zones.iter().position(|&z| z == zone)
+    }
+
+    pub fn fire_detected(&self, zone: FireDetectionZone) -> bool {
+        self.zone_index(zone)
+            .is_some_and(|index| self.fire_detected[index])
+    }
+
+    pub fn loop_has_failed(&self, loop_id: FireDetectionLoopID, zone: FireDetectionZone) -> bool {
+        let fire_detection_loop = match loop_id {
+            FireDetectionLoopID::A => &self.fire_detection_loops[0],
+            FireDetectionLoopID::B => &self.fire_detection_loops[1],
+        };
+
+        fire_detection_loop.loop_has_failed(zone)
+    }
+
+    pub fn should_extinguish_apu_fire(&self) -> bool {
+        self.should_extinguish_apu_fire.output()
+    }
+
+    pub fn apu_fire_on_ground(&self) -> bool {
+        self.apu_fire_on_ground
+    }
+}
+impl<const N: usize> SimulationElement for FireDetectionUnit<N> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, fire_detected) in self.fire_detected_id.iter().zip(self.fire_detected) {
+            writer.write(id, fire_detected);
+        }
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.fire_detection_loops, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct FireDetectionLoop<const N: usize> {
+    loop_id: FireDetectionLoopID,
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    was_powered_before: bool,
+    failures: [Failure; N],
+
+    fire_detectors: [FireDetector; N],
+}
+impl<const N: usize> FireDetectionLoop<N> {
+    fn new(
+        context: &mut InitContext,
+        loop_id: FireDetectionLoopID,
+        fire_detection_zones: &[FireDetectionZone; N],
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            loop_id,
+            powered_by,
+            is_powered: false,
+            was_powered_before: false,
+            failures: fire_detection_zones
+                .map(|zone| Failure::new(FailureType::FireDetectionLoop(loop_id, zone))),
+
+            fire_detectors: fire_detection_zones.map(|zone| FireDetector::new(context, zone)),
+        }
+    }
+
+    fn failure(&self, fire_detection_zone: FireDetectionZone) -> &Failure {
+        self.failures
+            .iter()
+            .find(|&f| {
+                f.failure_type()
+                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
+            })
+            .unwrap()
+    }
+
+    fn fire_detected_in_loop(
+        &self,
+        fire_detection_zone: FireDetectionZone,
+        fire_test_pushbutton_is_pressed: bool,
+    ) -> bool {
+        !self.failure(fire_detection_zone).is_active()
+            && self.is_powered
+            && (self
+                .fire_detectors
+                .iter()
+                .find(|detector| fire_detection_zone == detector.zone_id())
+                .unwrap()
+                .fire_detected()
+                || fire_test_pushbutton_is_pressed)
+    }
+
+    fn loop_has_failed(&self, fire_detection_zone: FireDetectionZone) -> bool {
+        self.failure(fire_detection_zone).is_active()
+            || (!self.is_powered && self.was_powered_before)
+    }
+
+    /// This is to avoid a fire detection on initial load
+    fn update_was_powered(&mut self) {
+        self.was_powered_before = self.is_powered
+    }
+}
+impl<const N: usize> SimulationElement for FireDetectionLoop<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.failures, visitor);
+        accept_iterable!(self.fire_detectors, visitor);
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+// Electro-pneumatic fire detectors. There are multiple detectors in 3 fire zones per engine, one per pylon, one in the APU and one in the MLG
+// For simplicity here we simulate just one detection zone per engine, when we have deep engine simulation we can modify this accordingly
+struct FireDetector {
+    zone_id: FireDetectionZone,
+
+    fire_detection_id: VariableIdentifier,
+    fire_detected: bool,
+}
+impl FireDetector {
+    const ENGINE_ON_FIRE: &'static str = "ENG ON FIRE:";
+
+    fn new(context: &mut InitContext, fire_zone_id: FireDetectionZone) -> Self {
+        Self {
+            zone_id: fire_zone_id,
+
+            fire_detection_id: Self::init_identifier(context, fire_zone_id),
+            fire_detected: false,
+        }
+    }
+
+    fn init_identifier(
+        context: &mut InitContext,
+        zone_id: FireDetectionZone,
+    ) -> VariableIdentifier {
+        if matches!(zone_id, FireDetectionZone::Engine(_)) {
+            context.get_identifier(format!("{}{}", Self::ENGINE_ON_FIRE, zone_id))
+        } else {
+            context.get_identifier(format!("{}_ON_FIRE", zone_id))
+        }
+    }
+
+    fn zone_id(&self) -> FireDetectionZone {
+        self.zone_id
+    }
+
+    fn fire_detected(&self) -> bool {
+        self.fire_detected
+    }
+}
+impl SimulationElement for FireDetector {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.fire_detected = reader.read(&self.fire_detection_id);
+    }
+}
+
+/// This struct represents the physical bottle of Halon 1301, and its possible states of armed, disarmed, full and empty
+/// There are two squibs per bottle, but for simplicity we simulate a "single" squib with two possible power sources
+/// Once the squib is fired, the bottle pressure quickly drops as the agent is discharged into the fire zone.
+pub struct ExtinguishingAgentBottle {
+    squib_armed_id: VariableIdentifier,
+    bottle_discharged_id: VariableIdentifier,
+    bottle_pressure_id: VariableIdentifier,
+    agent_pb: MomentaryPushButton,
+
+    squib_is_armed: bool,
+    bottle_is_discharged: bool,
+    system_test: bool,
+    pressure: Pressure,
+
+    timer: Duration,
+    powered_by: [ElectricalBusType; 2],
+    is_powered: bool,
+}
+impl ExtinguishingAgentBottle {
+    const SQUIB_FIRING_DELAY: Duration = Duration::from_secs(1);
+    const NOMINAL_PRESSURE_PSI: f64 = 600.;
+    const DISCHARGE_TIME_CONSTANT_SECONDS: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext, id: &str, powered_by: [ElectricalBusType; 2]) -> Self {
+        Self {
+            squib_armed_id: context.get_identifier(format!("FIRE_SQUIB_{}_IS_ARMED", id)),
+            bottle_discharged_id: context
+                .get_identifier(format!("FIRE_SQUIB_{}_IS_DISCHARGED", id)),
+            bottle_pressure_id: context.get_identifier(format!("FIRE_BOTTLE_{}_PRESSURE", id)),
+            agent_pb: MomentaryPushButton::new(context, &format!("FIRE_AGENT_{}", id)),
+
+            squib_is_armed: false,
+            bottle_is_discharged: false,
+            system_test: false,
+            pressure: Pressure::new::<psi>(Self::NOMINAL_PRESSURE_PSI),
+
+            timer: Duration::ZERO,
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_button_is_pressed: bool,
+        fire_test_pushbutton_is_pressed: bool,
+        should_extinguish_fire: Option<bool>,
+    ) {
+        self.system_test = fire_test_pushbutton_is_pressed && self.is_powered;
+        self.squib_is_armed = self.is_powered && engine_fire_push_button_is_pressed;
+        if self.is_powered
+            && ((self.squib_is_armed || should_extinguish_fire.unwrap_or(false))
+                && self.timer >= Self::SQUIB_FIRING_DELAY)
+        {
+            // Once the bottle is discharged, it can't be recharged
+            self.bottle_is_discharged = true
+        } else if self.is_powered
+            && (((self.squib_is_armed)
+                && (self.agent_pb.is_pressed() || self.timer > Duration::ZERO))
+                || should_extinguish_fire.unwrap_or(false))
+            && self.timer <= Self::SQUIB_FIRING_DELAY
+        {
+            self.timer += context.delta()
+        } else {
+            self.timer = Duration::ZERO
+        };
+
+        self.update_pressure(context);
+    }
+
+    fn update_pressure(&mut self, context: &UpdateContext) {
+        if self.bottle_is_discharged {
+            self.pressure *=
+                (-context.delta_as_secs_f64() / Self::DISCHARGE_TIME_CONSTANT_SECONDS).exp();
+        }
+    }
+
+    pub fn bottle_discharge(&self) -> bool {
+        self.bottle_is_discharged
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+}
+impl SimulationElement for ExtinguishingAgentBottle {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.squib_armed_id,
+            self.squib_is_armed || self.system_test,
+        );
+        writer.write(
+            &self.bottle_discharged_id,
+            self.bottle_is_discharged || self.system_test,
+        );
+        writer.write(&self.bottle_pressure_id, self.pressure);
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.agent_pb.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = self.powered_by.iter().any(|&p| buses.is_powered(p));
+    }
+}
+
+/// Small module that sets each zone on fire when the failure is triggered. This is independent to the system implementation.
+pub struct SetOnFireModule<const N: usize> {
+    fire_id: [VariableIdentifier; N],
+
+    fire: [Failure; N],
+    should_set_zone_on_fire: [bool; N],
+    should_extinguish_zone: [bool; N],
+    // We use this to avoid having a previously discharged bottle extinguish a fire
+    bottles_already_discharged: [usize; N],
+    // We use this to know when to cancel the fire command when the failure is resolved
+    was_on_fire: [bool; N],
+}
+impl<const N: usize> SetOnFireModule<N> {
+    pub fn new(context: &mut InitContext, fire_detection_zones: [FireDetectionZone; N]) -> Self {
+        Self {
+            fire_id: fire_detection_zones.map(|zone| Self::init_identifier(context, zone)),
+
+            fire: fire_detection_zones.map(|zone| Failure::new(FailureType::SetOnFire(zone))),
+            should_set_zone_on_fire: [false; N],
+            should_extinguish_zone: [false; N],
+            bottles_already_discharged: [0; N],
+            was_on_fire: [false; N],
+        }
+    }
+
+    fn init_identifier(
+        context: &mut InitContext,
+        zone_id: FireDetectionZone,
+    ) -> VariableIdentifier {
+        if matches!(zone_id, FireDetectionZone::Engine(_)) {
+            context.get_identifier(format!("ENG_{}_ON_FIRE", zone_id))
+        } else {
+            context.get_identifier(format!("{}_ON_FIRE", zone_id))
+        }
+    }
+
+    /// `bottles_discharged` holds the number of bottles discharged into each zone, in the same
+    /// order as the fire detection zones given at construction.
+    pub fn update(&mut self, bottles_discharged: [usize; N]) {
+        for id in 0..N {
+            self.should_set_zone_on_fire[id] = self.fire[id].is_active()
+                && !self.should_set_zone_on_fire[id]
+                && !self.was_on_fire[id]
+        }
+
+        self.should_extinguish_zone = self.zone_extinguishing_determination(bottles_discharged);
+        self.bottles_already_discharged = bottles_discharged;
+        self.was_on_fire = self.fire.each_ref().map(|f| f.is_active());
+    }
+
+    /// We check any "new" bottle discharges and then add a random factor on whether it should extinguish a fire
+    /// We also use this function to "extinguish" a fire if the user deselects the failure
+    fn zone_extinguishing_determination(&self, bottles_discharged: [usize; N]) -> [bool; N] {
+        let mut should_extinguish_zone = [false; N];
+        for (id, should_extinguish_zone) in should_extinguish_zone.iter_mut().enumerate() {
+            *should_extinguish_zone =
+                (bottles_discharged[id] > self.bottles_already_discharged[id] && rand::random())
+                    || (self.was_on_fire[id] && !self.fire[id].is_active());
+        }
+        should_extinguish_zone
+    }
+}
+impl<const N: usize> SimulationElement for SetOnFireModule<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.fire, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, zone) in self.fire_id.iter().enumerate() {
+            if self.should_set_zone_on_fire[id] {
+                writer.write(zone, true)
+            } else if self.should_extinguish_zone[id] {
+                writer.write(zone, false)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use ntest::assert_about_eq;
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    struct TestAircraft {
+        fire_detection_unit: FireDetectionUnit<2>,
+        bottle: ExtinguishingAgentBottle,
+        lgciu: TestLgciu,
+
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+
+        fire_test: bool,
+        fire_pb_released: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                fire_detection_unit: FireDetectionUnit::new(
+                    context,
+                    [FireDetectionZone::Engine(1), FireDetectionZone::Apu],
+                    [
+                        ElectricalBusType::DirectCurrent(1),
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                ),
+                bottle: ExtinguishingAgentBottle::new(
+                    context,
+                    "1_ENG_1",
+                    [
+                        ElectricalBusType::DirectCurrent(1),
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                ),
+                lgciu: TestLgciu { compressed: true },
+
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+
+                fire_test: false,
+                fire_pb_released: false,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_1_bus);
+            electricity.flow(&self.powered_source, &self.dc_2_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fire_detection_unit.update(
+                context,
+                [self.fire_test; 2],
+                [&self.lgciu, &self.lgciu],
+            );
+            self.bottle
+                .update(context, self.fire_pb_released, self.fire_test, None);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.fire_detection_unit.accept(visitor);
+            self.bottle.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run();
+        test_bed
+    }
+
+    #[test]
+    fn detects_fire_when_both_loops_detect_it() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ENG ON FIRE:1", true);
+        test_bed.run();
+
+        let fire_detected: bool = test_bed.read_by_name("FIRE_DETECTED_ENG1");
+        assert!(fire_detected);
+        assert!(test_bed.query(|a| a
+            .fire_detection_unit
+            .fire_detected(FireDetectionZone::Engine(1))));
+        assert!(!test_bed.query(|a| a.fire_detection_unit.fire_detected(FireDetectionZone::Apu)));
+    }
+
+    #[test]
+    fn detects_fire_with_a_single_loop_when_other_loop_failed() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::FireDetectionLoop(
+            FireDetectionLoopID::B,
+            FireDetectionZone::Engine(1),
+        ));
+        test_bed.run_with_delta(Duration::from_secs(10));
+        test_bed.write_by_name("ENG ON FIRE:1", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a
+            .fire_detection_unit
+            .loop_has_failed(FireDetectionLoopID::B, FireDetectionZone::Engine(1))));
+        assert!(test_bed.query(|a| a
+            .fire_detection_unit
+            .fire_detected(FireDetectionZone::Engine(1))));
+    }
+
+    #[test]
+    fn apu_fire_on_ground_requests_extinguishing_after_delay() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("APU_ON_FIRE", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.fire_detection_unit.apu_fire_on_ground()));
+        assert!(!test_bed.query(|a| a.fire_detection_unit.should_extinguish_apu_fire()));
+
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.fire_detection_unit.should_extinguish_apu_fire()));
+    }
+
+    #[test]
+    fn bottle_is_full_before_discharge() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let pressure: f64 = test_bed.read_by_name("FIRE_BOTTLE_1_ENG_1_PRESSURE");
+        assert_about_eq!(pressure, 600.);
+    }
+
+    #[test]
+    fn bottle_pressure_drops_after_discharge() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.fire_pb_released = true);
+        test_bed.write_by_name("OVHD_FIRE_AGENT_1_ENG_1_IS_PRESSED", true);
+        test_bed.run_with_delta(Duration::from_millis(600));
+        test_bed.write_by_name("OVHD_FIRE_AGENT_1_ENG_1_IS_PRESSED", false);
+        test_bed.run_with_delta(Duration::from_millis(600));
+        test_bed.run_with_delta(Duration::from_millis(600));
+
+        assert!(test_bed.query(|a| a.bottle.bottle_discharge()));
+
+        for _ in 0..5 {
+            test_bed.run_with_delta(Duration::from_millis(500));
+        }
+
+        assert!(test_bed.query(|a| a.bottle.pressure().get::<psi>()) < 10.);
+    }
+}
@@ -0,0 +1,412 @@
+use std::time::Duration;
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, FireDetectionLoopID,
+        FireDetectionZone, SmokeDetection,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+/// The Smoke Detection Control Unit (SDCU) monitors two smoke detectors (loop A and B) in each
+/// of the cargo compartments, lavatories and avionics bay. Each loop is handled by its own SDCU
+/// channel. Smoke is detected when both detectors of a zone detect it, or when one detector
+/// detects it while the other one has failed.
+pub struct SmokeDetectionControlUnit<const N: usize> {
+    smoke_detectors: [[SmokeDetector; 2]; N],
+
+    smoke_detected_id: [VariableIdentifier; N],
+    detector_fault_id: [[VariableIdentifier; 2]; N],
+
+    smoke_detected: [bool; N],
+    fire_detection_zones: [FireDetectionZone; N],
+
+    channels_powered_by: [ElectricalBusType; 2],
+    channel_is_powered: [bool; 2],
+}
+impl<const N: usize> SmokeDetectionControlUnit<N> {
+    pub fn new(
+        context: &mut InitContext,
+        fire_detection_zones: [FireDetectionZone; N],
+        channels_powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self {
+            smoke_detectors: fire_detection_zones.map(|zone| {
+                [
+                    SmokeDetector::new(context, FireDetectionLoopID::A, zone),
+                    SmokeDetector::new(context, FireDetectionLoopID::B, zone),
+                ]
+            }),
+
+            smoke_detected_id: fire_detection_zones
+                .map(|zone| context.get_identifier(format!("SMOKE_DETECTED_{}", zone))),
+            detector_fault_id: fire_detection_zones.map(|zone| {
+                [
+                    context.get_identifier(format!("SMOKE_{}_DETECTOR_A_FAULT", zone)),
+                    context.get_identifier(format!("SMOKE_{}_DETECTOR_B_FAULT", zone)),
+                ]
+            }),
+
+            smoke_detected: [false; N],
+            fire_detection_zones,
+
+            channels_powered_by,
+            channel_is_powered: [false; 2],
+        }
+    }
+
+    /// `smoke_test_pushbuttons_pressed` holds the state of the smoke test for each zone,
+    /// in the same order as the fire detection zones given at construction.
+    pub fn update(&mut self, smoke_test_pushbuttons_pressed: [bool; N]) {
+        for ((detectors, smoke_detected), &test_is_pressed) in self
+            .smoke_detectors
+            .iter()
+            .zip(self.smoke_detected.iter_mut())
+            .zip(&smoke_test_pushbuttons_pressed)
+        {
+            let detected = |channel: usize| {
+                self.channel_is_powered[channel]
+                    && detectors[channel].smoke_detected(test_is_pressed)
+            };
+            let has_fault = |channel: usize| {
+                !self.channel_is_powered[channel] || detectors[channel].has_failed()
+            };
+
+            *smoke_detected = (detected(0) && detected(1))
+                || ((detected(0) || detected(1)) && (has_fault(0) || has_fault(1)));
+        }
+    }
+
+    fn zone_index(&self, zone: FireDetectionZone) -> Option<usize> {
+        self.fire_detection_zones.iter().position(|&z| z == zone)
+    }
+
+    pub fn detector_has_failed(
+        &self,
+        loop_id: FireDetectionLoopID,
+        zone: FireDetectionZone,
+    ) -> bool {
+        let channel = match loop_id {
+            FireDetectionLoopID::A => 0,
+            FireDetectionLoopID::B => 1,
+        };
+
+        self.zone_index(zone).is_some_and(|index| {
+            !self.channel_is_powered[channel] || self.smoke_detectors[index][channel].has_failed()
+        })
+    }
+}
+impl<const N: usize> SmokeDetection for SmokeDetectionControlUnit<N> {
+    fn smoke_detected(&self, zone: FireDetectionZone) -> bool {
+        self.zone_index(zone)
+            .is_some_and(|index| self.smoke_detected[index])
+    }
+}
+
+impl<const N: usize> SimulationElement for SmokeDetectionControlUnit<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for detectors in self.smoke_detectors.iter_mut() {
+            accept_iterable!(detectors, visitor);
+        }
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for ((id, fault_ids), &zone) in self
+            .smoke_detected_id
+            .iter()
+            .zip(&self.detector_fault_id)
+            .zip(&self.fire_detection_zones)
+        {
+            writer.write(id, self.smoke_detected(zone));
+            writer.write(
+                &fault_ids[0],
+                self.detector_has_failed(FireDetectionLoopID::A, zone),
+            );
+            writer.write(
+                &fault_ids[1],
+                self.detector_has_failed(FireDetectionLoopID::B, zone),
+            );
+        }
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.channel_is_powered = self.channels_powered_by.map(|bus| buses.is_powered(bus));
+    }
+}
+
+/// Optical smoke detector, reading the smoke present in its zone
+struct SmokeDetector {
+    smoke_present_id: VariableIdentifier,
+    smoke_present: bool,
+
+    failure: Failure,
+}
+impl SmokeDetector {
+    fn new(
+        context: &mut InitContext,
+        loop_id: FireDetectionLoopID,
+        zone: FireDetectionZone,
+    ) -> Self {
+        Self {
+            smoke_present_id: context.get_identifier(format!("{}_SMOKE", zone)),
+            smoke_present: false,
+
+            failure: Failure::new(FailureType::SmokeDetector(loop_id, zone)),
+        }
+    }
+
+    fn smoke_detected(&self, test_is_pressed: bool) -> bool {
+        !self.failure.is_active() && (self.smoke_present || test_is_pressed)
+    }
+
+    fn has_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+}
+impl SimulationElement for SmokeDetector {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.smoke_present = reader.read(&self.smoke_present_id);
+    }
+}
+
+/// Small module that generates smoke in each zone when the failure is triggered. This is independent to the system implementation.
+/// When a fire suppression agent is present in the zone long enough, the fire is considered suppressed and the smoke clears.
+pub struct SmokeGenerationModule<const N: usize> {
+    smoke_id: [VariableIdentifier; N],
+
+    smoke: [Failure; N],
+    suppression: [DelayedTrueLogicGate; N],
+    is_suppressed: [bool; N],
+}
+impl<const N: usize> SmokeGenerationModule<N> {
+    const SUPPRESSION_DELAY: Duration = Duration::from_secs(30);
+
+    pub fn new(context: &mut InitContext, fire_detection_zones: [FireDetectionZone; N]) -> Self {
+        Self {
+            smoke_id: fire_detection_zones
+                .map(|zone| context.get_identifier(format!("{}_SMOKE", zone))),
+
+            smoke: fire_detection_zones.map(|zone| Failure::new(FailureType::Smoke(zone))),
+            suppression: [(); N].map(|_| DelayedTrueLogicGate::new(Self::SUPPRESSION_DELAY)),
+            is_suppressed: [false; N],
+        }
+    }
+
+    /// `agent_is_present` holds whether a fire suppression agent is present in each zone, in the
+    /// same order as the fire detection zones given at construction.
+    pub fn update(&mut self, context: &UpdateContext, agent_is_present: [bool; N]) {
+        for (((smoke, suppression), is_suppressed), agent_is_present) in self
+            .smoke
+            .iter()
+            .zip(self.suppression.iter_mut())
+            .zip(self.is_suppressed.iter_mut())
+            .zip(agent_is_present)
+        {
+            suppression.update(context, smoke.is_active() && agent_is_present);
+
+            // The suppression is kept until the failure is resolved
+            *is_suppressed = smoke.is_active() && (*is_suppressed || suppression.output());
+        }
+    }
+
+    fn smoke_is_present(&self, index: usize) -> bool {
+        self.smoke[index].is_active() && !self.is_suppressed[index]
+    }
+}
+impl<const N: usize> SimulationElement for SmokeGenerationModule<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.smoke, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (index, id) in self.smoke_id.iter().enumerate() {
+            writer.write(id, self.smoke_is_present(index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    const ZONES: [FireDetectionZone; 2] =
+        [FireDetectionZone::ForwardCargo, FireDetectionZone::Lavatory];
+
+    struct TestAircraft {
+        sdcu: SmokeDetectionControlUnit<2>,
+        smoke_generation: SmokeGenerationModule<2>,
+
+        powered_source_1: TestElectricitySource,
+        powered_source_2: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+
+        agent_is_present: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                sdcu: SmokeDetectionControlUnit::new(
+                    context,
+                    ZONES,
+                    [
+                        ElectricalBusType::DirectCurrent(1),
+                        ElectricalBusType::DirectCurrent(2),
+                    ],
+                ),
+                smoke_generation: SmokeGenerationModule::new(context, ZONES),
+
+                powered_source_1: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                powered_source_2: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(2),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+
+                agent_is_present: false,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source_1);
+            electricity.supplied_by(&self.powered_source_2);
+            electricity.flow(&self.powered_source_1, &self.dc_1_bus);
+            electricity.flow(&self.powered_source_2, &self.dc_2_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.sdcu.update([false; 2]);
+            self.smoke_generation
+                .update(context, [self.agent_is_present, false]);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.sdcu.accept(visitor);
+            self.smoke_generation.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn no_smoke_detected_by_default() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let smoke_detected: bool = test_bed.read_by_name("SMOKE_DETECTED_FWD_CARGO");
+        assert!(!smoke_detected);
+    }
+
+    #[test]
+    fn smoke_is_detected_when_both_detectors_detect_it() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FWD_CARGO_SMOKE", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.sdcu.smoke_detected(FireDetectionZone::ForwardCargo)));
+        assert!(!test_bed.query(|a| a.sdcu.smoke_detected(FireDetectionZone::Lavatory)));
+    }
+
+    #[test]
+    fn smoke_is_detected_by_a_single_detector_when_other_failed() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::SmokeDetector(
+            FireDetectionLoopID::A,
+            FireDetectionZone::Lavatory,
+        ));
+        test_bed.write_by_name("LAVATORY_SMOKE", true);
+        test_bed.run();
+
+        let detector_fault: bool = test_bed.read_by_name("SMOKE_LAVATORY_DETECTOR_A_FAULT");
+        assert!(detector_fault);
+        assert!(test_bed.query(|a| a.sdcu.smoke_detected(FireDetectionZone::Lavatory)));
+    }
+
+    #[test]
+    fn smoke_is_detected_by_a_single_channel_when_other_unpowered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.powered_source_2.unpower());
+        test_bed.write_by_name("FWD_CARGO_SMOKE", true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a
+            .sdcu
+            .detector_has_failed(FireDetectionLoopID::B, FireDetectionZone::ForwardCargo)));
+        assert!(test_bed.query(|a| a.sdcu.smoke_detected(FireDetectionZone::ForwardCargo)));
+    }
+
+    #[test]
+    fn smoke_is_not_detected_when_unpowered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.powered_source_1.unpower());
+        test_bed.command(|a| a.powered_source_2.unpower());
+        test_bed.write_by_name("FWD_CARGO_SMOKE", true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.sdcu.smoke_detected(FireDetectionZone::ForwardCargo)));
+    }
+
+    #[test]
+    fn smoke_failure_generates_smoke() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::Smoke(FireDetectionZone::ForwardCargo));
+        test_bed.run();
+
+        let smoke: bool = test_bed.read_by_name("FWD_CARGO_SMOKE");
+        assert!(smoke);
+    }
+
+    #[test]
+    fn smoke_clears_once_agent_present_long_enough() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::Smoke(FireDetectionZone::ForwardCargo));
+        test_bed.command(|a| a.agent_is_present = true);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        let smoke: bool = test_bed.read_by_name("FWD_CARGO_SMOKE");
+        assert!(smoke);
+
+        test_bed.run_with_delta(Duration::from_secs(25));
+        test_bed.command(|a| a.agent_is_present = false);
+        test_bed.run();
+
+        let smoke: bool = test_bed.read_by_name("FWD_CARGO_SMOKE");
+        assert!(!smoke);
+    }
+}
@@ -0,0 +1,729 @@
+use std::time::Duration;
+use uom::si::{f64::*, ratio::ratio, velocity::knot};
+
+use crate::simulation::{
+    InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+    SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
+};
+
+/// An aircraft system a ground service hands over to while performing, such as boarding or
+/// refuelling. The service is complete once the process has stopped running.
+pub trait GroundServiceProcess {
+    fn start(&mut self);
+    fn stop(&mut self);
+    fn is_running(&self) -> bool;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroundServiceState {
+    Idle = 0,
+    Requested = 1,
+    Connecting = 2,
+    Performing = 3,
+    Disconnecting = 4,
+    Completed = 5,
+}
+read_write_enum!(GroundServiceState);
+impl From<f64> for GroundServiceState {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => GroundServiceState::Idle,
+            1 => GroundServiceState::Requested,
+            2 => GroundServiceState::Connecting,
+            3 => GroundServiceState::Performing,
+            4 => GroundServiceState::Disconnecting,
+            5 => GroundServiceState::Completed,
+            i => panic!("Cannot convert from {} to GroundServiceState.", i),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroundServiceId {
+    Stairs,
+    Jetway,
+    Catering,
+    Cleaning,
+    FuelTruck,
+    PotableWater,
+    Boarding,
+}
+impl GroundServiceId {
+    fn name(&self) -> &'static str {
+        match self {
+            GroundServiceId::Stairs => "STAIRS",
+            GroundServiceId::Jetway => "JETWAY",
+            GroundServiceId::Catering => "CATERING",
+            GroundServiceId::Cleaning => "CLEANING",
+            GroundServiceId::FuelTruck => "FUEL_TRUCK",
+            GroundServiceId::PotableWater => "POTABLE_WATER",
+            GroundServiceId::Boarding => "BOARDING",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroundServicePrerequisite {
+    /// The door at the given index of the aircraft's ground service doors is open
+    DoorOpen(usize),
+    /// The given service is connected to the aircraft
+    Connected(GroundServiceId),
+    /// Either the stairs or the jetway are connected to the aircraft
+    PassengerAccess,
+}
+
+pub struct GroundServiceInfo<'a> {
+    pub id: GroundServiceId,
+    pub connection_time: Duration,
+    /// Time the service takes once connected. Services without a service time stay connected
+    /// until they are no longer requested, or until their process has stopped.
+    pub service_time: Option<Duration>,
+    pub disconnection_time: Duration,
+    pub prerequisites: &'a [GroundServicePrerequisite],
+}
+
+pub struct GroundServiceDoor {
+    open_ratio_id: VariableIdentifier,
+    open_ratio: Ratio,
+}
+impl GroundServiceDoor {
+    const OPEN_THRESHOLD: f64 = 0.9;
+
+    pub fn new(context: &mut InitContext, open_ratio_variable: &str) -> Self {
+        Self {
+            open_ratio_id: context.get_identifier(open_ratio_variable.to_owned()),
+            open_ratio: Ratio::default(),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open_ratio.get::<ratio>() > Self::OPEN_THRESHOLD
+    }
+}
+impl SimulationElement for GroundServiceDoor {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.open_ratio = reader.read(&self.open_ratio_id);
+    }
+}
+
+struct GroundService {
+    request_id: VariableIdentifier,
+    state_id: VariableIdentifier,
+
+    id: GroundServiceId,
+    connection_time: Duration,
+    service_time: Option<Duration>,
+    disconnection_time: Duration,
+    prerequisites: Vec<GroundServicePrerequisite>,
+
+    is_requested: bool,
+    state: GroundServiceState,
+    time_in_state: Duration,
+}
+impl GroundService {
+    fn new(context: &mut InitContext, info: &GroundServiceInfo) -> Self {
+        Self {
+            request_id: context.get_identifier(format!("GND_SVC_{}_REQUESTED", info.id.name())),
+            state_id: context.get_identifier(format!("GND_SVC_{}_STATE", info.id.name())),
+
+            id: info.id,
+            connection_time: info.connection_time,
+            service_time: info.service_time,
+            disconnection_time: info.disconnection_time,
+            prerequisites: info.prerequisites.to_vec(),
+
+            is_requested: false,
+            state: GroundServiceState::Idle,
+            time_in_state: Duration::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        prerequisites_met: bool,
+        process: Option<&mut dyn GroundServiceProcess>,
+    ) {
+        self.time_in_state += context.delta();
+
+        let new_state = match self.state {
+            GroundServiceState::Idle if self.is_requested => GroundServiceState::Requested,
+            GroundServiceState::Requested if !self.is_requested => GroundServiceState::Idle,
+            GroundServiceState::Requested if prerequisites_met => GroundServiceState::Connecting,
+            GroundServiceState::Connecting if !self.is_requested => {
+                GroundServiceState::Disconnecting
+            }
+            GroundServiceState::Connecting if self.time_in_state >= self.connection_time => {
+                GroundServiceState::Performing
+            }
+            GroundServiceState::Performing if self.is_performed(process.as_deref()) => {
+                GroundServiceState::Disconnecting
+            }
+            GroundServiceState::Disconnecting if self.time_in_state >= self.disconnection_time => {
+                if self.is_requested {
+                    GroundServiceState::Completed
+                } else {
+                    GroundServiceState::Idle
+                }
+            }
+            GroundServiceState::Completed if !self.is_requested => GroundServiceState::Idle,
+            state => state,
+        };
+
+        if new_state != self.state {
+            if let Some(process) = process {
+                match new_state {
+                    GroundServiceState::Performing => process.start(),
+                    GroundServiceState::Disconnecting if process.is_running() => process.stop(),
+                    _ => {}
+                }
+            }
+
+            self.state = new_state;
+            self.time_in_state = Duration::default();
+        }
+    }
+
+    fn is_performed(&self, process: Option<&dyn GroundServiceProcess>) -> bool {
+        // The process is only started when entering the performing state, so it can only be
+        // considered as stopped from the following update onwards.
+        let process_stopped = process.is_some_and(|process| {
+            self.time_in_state > Duration::default() && !process.is_running()
+        });
+        let service_time_elapsed = self
+            .service_time
+            .is_some_and(|service_time| self.time_in_state >= service_time);
+
+        !self.is_requested || process_stopped || service_time_elapsed
+    }
+
+    fn is_connected(&self) -> bool {
+        self.state == GroundServiceState::Performing
+    }
+}
+impl SimulationElement for GroundService {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_requested = reader.read(&self.request_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.state_id, self.state);
+    }
+}
+
+/// Turnaround services of the aircraft while parked at the gate. Each service is requested
+/// through its own variable, waits for its prerequisites, connects, performs and disconnects
+/// again. Boarding and refuelling are handed over to the corresponding aircraft system while the
+/// service is performing, which makes them independent from GSX.
+pub struct GroundServices<const D: usize, const S: usize> {
+    doors: [GroundServiceDoor; D],
+    services: [GroundService; S],
+}
+impl<const D: usize, const S: usize> GroundServices<D, S> {
+    // Vehicles are only allowed to approach a parked aircraft
+    const MAX_GROUND_SPEED_KNOTS: f64 = 1.;
+
+    pub fn new(
+        context: &mut InitContext,
+        doors: [GroundServiceDoor; D],
+        services: [GroundServiceInfo; S],
+    ) -> Self {
+        Self {
+            doors,
+            services: services.map(|info| GroundService::new(context, &info)),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        boarding: &mut impl GroundServiceProcess,
+        refuel: &mut impl GroundServiceProcess,
+    ) {
+        let is_parked = context.is_on_ground()
+            && context.ground_speed().abs() < Velocity::new::<knot>(Self::MAX_GROUND_SPEED_KNOTS);
+        let prerequisites_met: [bool; S] = std::array::from_fn(|index| {
+            is_parked
+                && self.services[index]
+                    .prerequisites
+                    .iter()
+                    .all(|prerequisite| self.is_prerequisite_met(*prerequisite))
+        });
+
+        for (service, prerequisites_met) in self.services.iter_mut().zip(prerequisites_met) {
+            let process: Option<&mut dyn GroundServiceProcess> = match service.id {
+                GroundServiceId::Boarding => Some(&mut *boarding),
+                GroundServiceId::FuelTruck => Some(&mut *refuel),
+                _ => None,
+            };
+
+            service.update(context, prerequisites_met, process);
+        }
+    }
+
+    fn is_prerequisite_met(&self, prerequisite: GroundServicePrerequisite) -> bool {
+        match prerequisite {
+            GroundServicePrerequisite::DoorOpen(index) => self.doors[index].is_open(),
+            GroundServicePrerequisite::Connected(id) => self.is_connected(id),
+            GroundServicePrerequisite::PassengerAccess => {
+                self.is_connected(GroundServiceId::Stairs)
+                    || self.is_connected(GroundServiceId::Jetway)
+            }
+        }
+    }
+
+    pub fn state(&self, id: GroundServiceId) -> GroundServiceState {
+        self.services
+            .iter()
+            .find(|service| service.id == id)
+            .map_or(GroundServiceState::Idle, |service| service.state)
+    }
+
+    pub fn is_connected(&self, id: GroundServiceId) -> bool {
+        self.services
+            .iter()
+            .any(|service| service.id == id && service.is_connected())
+    }
+}
+impl<const D: usize, const S: usize> SimulationElement for GroundServices<D, S> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.doors, visitor);
+        accept_iterable!(self.services, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+    use ntest::assert_about_eq;
+    use uom::si::ratio::percent;
+
+    struct TestProcess {
+        is_running: bool,
+        start_count: usize,
+    }
+    impl TestProcess {
+        fn new() -> Self {
+            Self {
+                is_running: false,
+                start_count: 0,
+            }
+        }
+
+        fn finish(&mut self) {
+            self.is_running = false;
+        }
+    }
+    impl GroundServiceProcess for TestProcess {
+        fn start(&mut self) {
+            self.is_running = true;
+            self.start_count += 1;
+        }
+
+        fn stop(&mut self) {
+            self.is_running = false;
+        }
+
+        fn is_running(&self) -> bool {
+            self.is_running
+        }
+    }
+
+    const PASSENGER_DOOR: usize = 0;
+    const SERVICE_DOOR: usize = 1;
+    const CARGO_DOOR: usize = 2;
+
+    struct TestAircraft {
+        ground_services: GroundServices<3, 7>,
+        boarding: TestProcess,
+        refuel: TestProcess,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let doors = [
+                GroundServiceDoor::new(context, "PASSENGER_DOOR_OPEN"),
+                GroundServiceDoor::new(context, "SERVICE_DOOR_OPEN"),
+                GroundServiceDoor::new(context, "CARGO_DOOR_OPEN"),
+            ];
+            let services = [
+                GroundServiceInfo {
+                    id: GroundServiceId::Stairs,
+                    connection_time: Duration::from_secs(30),
+                    service_time: None,
+                    disconnection_time: Duration::from_secs(30),
+                    prerequisites: &[],
+                },
+                GroundServiceInfo {
+                    id: GroundServiceId::Jetway,
+                    connection_time: Duration::from_secs(60),
+                    service_time: None,
+                    disconnection_time: Duration::from_secs(60),
+                    prerequisites: &[],
+                },
+                GroundServiceInfo {
+                    id: GroundServiceId::Catering,
+                    connection_time: Duration::from_secs(120),
+                    service_time: Some(Duration::from_secs(600)),
+                    disconnection_time: Duration::from_secs(60),
+                    prerequisites: &[GroundServicePrerequisite::DoorOpen(SERVICE_DOOR)],
+                },
+                GroundServiceInfo {
+                    id: GroundServiceId::Cleaning,
+                    connection_time: Duration::from_secs(60),
+                    service_time: Some(Duration::from_secs(900)),
+                    disconnection_time: Duration::from_secs(60),
+                    prerequisites: &[
+                        GroundServicePrerequisite::PassengerAccess,
+                        GroundServicePrerequisite::DoorOpen(PASSENGER_DOOR),
+                    ],
+                },
+                GroundServiceInfo {
+                    id: GroundServiceId::FuelTruck,
+                    connection_time: Duration::from_secs(90),
+                    service_time: None,
+                    disconnection_time: Duration::from_secs(90),
+                    prerequisites: &[],
+                },
+                GroundServiceInfo {
+                    id: GroundServiceId::PotableWater,
+                    connection_time: Duration::from_secs(60),
+                    service_time: Some(Duration::from_secs(300)),
+                    disconnection_time: Duration::from_secs(60),
+                    prerequisites: &[],
+                },
+                GroundServiceInfo {
+                    id: GroundServiceId::Boarding,
+                    connection_time: Duration::from_secs(10),
+                    service_time: None,
+                    disconnection_time: Duration::from_secs(10),
+                    prerequisites: &[
+                        GroundServicePrerequisite::PassengerAccess,
+                        GroundServicePrerequisite::DoorOpen(PASSENGER_DOOR),
+                        GroundServicePrerequisite::DoorOpen(CARGO_DOOR),
+                    ],
+                },
+            ];
+
+            Self {
+                ground_services: GroundServices::new(context, doors, services),
+                boarding: TestProcess::new(),
+                refuel: TestProcess::new(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.ground_services
+                .update(context, &mut self.boarding, &mut self.refuel);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ground_services.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(true);
+
+        test_bed
+    }
+
+    fn request(test_bed: &mut SimulationTestBed<TestAircraft>, id: GroundServiceId, on: bool) {
+        test_bed.write_by_name(&format!("GND_SVC_{}_REQUESTED", id.name()), on);
+    }
+
+    fn open_door(test_bed: &mut SimulationTestBed<TestAircraft>, name: &str) {
+        test_bed.write_by_name(name, Ratio::new::<percent>(100.));
+    }
+
+    fn state(
+        test_bed: &mut SimulationTestBed<TestAircraft>,
+        id: GroundServiceId,
+    ) -> GroundServiceState {
+        test_bed.read_by_name(&format!("GND_SVC_{}_STATE", id.name()))
+    }
+
+    fn connect_stairs(test_bed: &mut SimulationTestBed<TestAircraft>) {
+        request(test_bed, GroundServiceId::Stairs, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(30));
+    }
+
+    #[test]
+    fn services_are_idle_when_not_requested() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Catering),
+            GroundServiceState::Idle
+        );
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Boarding),
+            GroundServiceState::Idle
+        );
+    }
+
+    #[test]
+    fn service_goes_through_its_timed_sequence() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundServiceId::PotableWater, true);
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Requested
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Connecting
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(59));
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Connecting
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Performing
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(300));
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Disconnecting
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(60));
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Completed
+        );
+    }
+
+    #[test]
+    fn completed_service_returns_to_idle_once_no_longer_requested() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundServiceId::PotableWater, true);
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(100));
+        }
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Completed
+        );
+
+        request(&mut test_bed, GroundServiceId::PotableWater, false);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Idle
+        );
+    }
+
+    #[test]
+    fn service_waits_for_its_door_to_be_open() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundServiceId::Catering, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(300));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Catering),
+            GroundServiceState::Requested
+        );
+
+        open_door(&mut test_bed, "SERVICE_DOOR_OPEN");
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Catering),
+            GroundServiceState::Connecting
+        );
+    }
+
+    #[test]
+    fn service_does_not_connect_while_aircraft_is_moving() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("GPS GROUND SPEED", Velocity::new::<knot>(5.));
+        request(&mut test_bed, GroundServiceId::PotableWater, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::PotableWater),
+            GroundServiceState::Requested
+        );
+    }
+
+    #[test]
+    fn cleaning_requires_stairs_or_jetway() {
+        let mut test_bed = test_bed();
+        open_door(&mut test_bed, "PASSENGER_DOOR_OPEN");
+        request(&mut test_bed, GroundServiceId::Cleaning, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(100));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Cleaning),
+            GroundServiceState::Requested
+        );
+
+        connect_stairs(&mut test_bed);
+        assert!(test_bed.query(|a| a.ground_services.is_connected(GroundServiceId::Stairs)));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Cleaning),
+            GroundServiceState::Connecting
+        );
+    }
+
+    #[test]
+    fn stairs_stay_connected_until_released() {
+        let mut test_bed = test_bed();
+        connect_stairs(&mut test_bed);
+        test_bed.run_with_delta(Duration::from_secs(3600));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Stairs),
+            GroundServiceState::Performing
+        );
+
+        request(&mut test_bed, GroundServiceId::Stairs, false);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Stairs),
+            GroundServiceState::Disconnecting
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(30));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Stairs),
+            GroundServiceState::Idle
+        );
+    }
+
+    #[test]
+    fn boarding_requires_cargo_door_open() {
+        let mut test_bed = test_bed();
+        open_door(&mut test_bed, "PASSENGER_DOOR_OPEN");
+        connect_stairs(&mut test_bed);
+        request(&mut test_bed, GroundServiceId::Boarding, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Boarding),
+            GroundServiceState::Requested
+        );
+        assert!(!test_bed.query(|a| a.boarding.is_running()));
+
+        open_door(&mut test_bed, "CARGO_DOOR_OPEN");
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::Boarding),
+            GroundServiceState::Performing
+        );
+        assert!(test_bed.query(|a| a.boarding.is_running()));
+    }
+
+    #[test]
+    fn refuel_is_started_once_fuel_truck_connected_and_completes_with_refuel() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundServiceId::FuelTruck, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(!test_bed.query(|a| a.refuel.is_running()));
+
+        test_bed.run_with_delta(Duration::from_secs(30));
+
+        assert!(test_bed.query(|a| a.refuel.is_running()));
+        assert_eq!(test_bed.query(|a| a.refuel.start_count), 1);
+
+        test_bed.run_with_delta(Duration::from_secs(600));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::FuelTruck),
+            GroundServiceState::Performing
+        );
+
+        test_bed.command(|a| a.refuel.finish());
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::FuelTruck),
+            GroundServiceState::Disconnecting
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(90));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::FuelTruck),
+            GroundServiceState::Completed
+        );
+        assert_eq!(test_bed.query(|a| a.refuel.start_count), 1);
+    }
+
+    #[test]
+    fn cancelling_service_stops_its_process() {
+        let mut test_bed = test_bed();
+        request(&mut test_bed, GroundServiceId::FuelTruck, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(90));
+
+        assert!(test_bed.query(|a| a.refuel.is_running()));
+
+        request(&mut test_bed, GroundServiceId::FuelTruck, false);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!test_bed.query(|a| a.refuel.is_running()));
+
+        test_bed.run_with_delta(Duration::from_secs(90));
+
+        assert_eq!(
+            state(&mut test_bed, GroundServiceId::FuelTruck),
+            GroundServiceState::Idle
+        );
+    }
+
+    #[test]
+    fn door_is_open_above_threshold() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("SERVICE_DOOR_OPEN", Ratio::new::<percent>(95.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.ground_services.doors[SERVICE_DOOR].is_open()));
+        assert_about_eq!(
+            test_bed.query(|a| a.ground_services.doors[SERVICE_DOOR]
+                .open_ratio
+                .get::<ratio>()),
+            0.95
+        );
+    }
+}
@@ -0,0 +1,534 @@
+use crate::{
+    failures::{Failure, FailureType},
+    simulation::{SimulationElement, SimulationElementVisitor, SurfaceTypeMsfs, UpdateContext},
+};
+
+use std::time::Duration;
+
+use uom::si::{f64::*, length::millimeter, ratio::ratio, velocity::knot};
+
+/// Side of the aircraft of an anti-skid channel. Each side has its own brake pressure output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AntiSkidSide {
+    Left = 0,
+    Right = 1,
+}
+
+/// Anti-skid function of a braking and steering computer.
+///
+/// Each of the `N` braked wheels of a side has its own channel, which compares the wheel speed
+/// with a reference speed and releases brake pressure when the wheel slips too much. It also
+/// releases the brakes when a wheel is locked, and after touchdown until the wheels spun up.
+///
+/// The simulator doesn't make the wheels slip, thus the slip of each wheel is modelled here from
+/// the braking demand and the friction the runway surface provides.
+pub struct AntiSkid<const N: usize> {
+    failure: Failure,
+
+    channels: [[AntiSkidChannel; N]; 2],
+    reference_speed: Velocity,
+    friction_limit: f64,
+
+    is_selected: bool,
+    main_gear_compressed: bool,
+    touchdown_protection_armed: bool,
+    time_on_ground: Duration,
+}
+impl<const N: usize> AntiSkid<N> {
+    /// Wheel speed at which the touchdown protection ends, unless the wheels already turn at
+    /// the reference speed
+    const TOUCHDOWN_SPIN_UP_SPEED_KNOT: f64 = 72.;
+    /// Time after touchdown after which the touchdown protection ends
+    const TOUCHDOWN_PROTECTION_DURATION: Duration = Duration::from_secs(5);
+
+    /// Braking ratio at which a wet paved surface starts to skid
+    const WET_FRICTION_LIMIT: f64 = 0.6;
+    /// Precipitation rate above which a paved surface is considered wet
+    const WET_PRECIPITATION_RATE_MILLIMETER: f64 = 0.1;
+
+    pub fn new() -> Self {
+        Self {
+            failure: Failure::new(FailureType::AntiSkid),
+
+            channels: [[AntiSkidChannel::default(); N]; 2],
+            reference_speed: Velocity::default(),
+            friction_limit: 1.,
+
+            is_selected: true,
+            main_gear_compressed: false,
+            touchdown_protection_armed: false,
+            time_on_ground: Duration::default(),
+        }
+    }
+
+    /// Updates the anti-skid
+    /// ## Parameters
+    /// `is_selected` - the anti-skid is switched on
+    /// `main_gear_compressed` - the main gear is on ground
+    /// `wheel_speeds` - the speed of each braked wheel on the left and right side
+    /// `demands` - the brake demands on the left and right side before modulation
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_selected: bool,
+        main_gear_compressed: bool,
+        wheel_speeds: [[Velocity; N]; 2],
+        demands: [Ratio; 2],
+    ) {
+        self.is_selected = is_selected;
+        self.main_gear_compressed = main_gear_compressed;
+        self.reference_speed = context.ground_speed().abs();
+        self.friction_limit = Self::friction_limit(context);
+
+        self.update_touchdown_protection(context, main_gear_compressed, wheel_speeds);
+
+        let is_monitored = main_gear_compressed
+            && self.reference_speed.get::<knot>() > AntiSkidChannel::MIN_REFERENCE_SPEED_KNOT;
+        let is_active = self.is_active();
+        let reference_speed = self.reference_speed;
+        let friction_limit = self.friction_limit;
+        for ((side_channels, side_wheel_speeds), demand) in
+            self.channels.iter_mut().zip(wheel_speeds).zip(demands)
+        {
+            for (channel, wheel_speed) in side_channels.iter_mut().zip(side_wheel_speeds) {
+                if is_monitored {
+                    channel.update(
+                        context,
+                        is_active,
+                        reference_speed,
+                        friction_limit,
+                        wheel_speed,
+                        demand,
+                    );
+                } else {
+                    channel.reset();
+                }
+            }
+        }
+    }
+
+    fn update_touchdown_protection(
+        &mut self,
+        context: &UpdateContext,
+        main_gear_compressed: bool,
+        wheel_speeds: [[Velocity; N]; 2],
+    ) {
+        if !main_gear_compressed {
+            self.touchdown_protection_armed = true;
+            self.time_on_ground = Duration::default();
+        } else {
+            self.time_on_ground += context.delta();
+
+            let spin_up_speed = Velocity::new::<knot>(Self::TOUCHDOWN_SPIN_UP_SPEED_KNOT)
+                .min(self.reference_speed * AntiSkidChannel::LOCKED_WHEEL_SPEED_RATIO);
+            let wheels_spun_up = wheel_speeds
+                .iter()
+                .flatten()
+                .all(|&speed| speed >= spin_up_speed);
+            if wheels_spun_up || self.time_on_ground > Self::TOUCHDOWN_PROTECTION_DURATION {
+                self.touchdown_protection_armed = false;
+            }
+        }
+    }
+
+    /// The braking ratio above which the wheels start to skid on the current surface.
+    fn friction_limit(context: &UpdateContext) -> f64 {
+        let is_raining = context.precipitation_rate().get::<millimeter>()
+            > Self::WET_PRECIPITATION_RATE_MILLIMETER;
+
+        match context.surface_type() {
+            SurfaceTypeMsfs::Ice => 0.15,
+            SurfaceTypeMsfs::Water => 0.1,
+            SurfaceTypeMsfs::Snow => 0.35,
+            SurfaceTypeMsfs::Grass
+            | SurfaceTypeMsfs::GrassBumpy
+            | SurfaceTypeMsfs::ShortGrass
+            | SurfaceTypeMsfs::LongGrass
+            | SurfaceTypeMsfs::HardTurf
+            | SurfaceTypeMsfs::Forest
+            | SurfaceTypeMsfs::Dirt
+            | SurfaceTypeMsfs::Sand => {
+                if is_raining {
+                    0.3
+                } else {
+                    0.45
+                }
+            }
+            _ => {
+                if is_raining {
+                    Self::WET_FRICTION_LIMIT
+                } else {
+                    1.
+                }
+            }
+        }
+    }
+
+    pub fn is_operative(&self) -> bool {
+        !self.failure.is_active()
+    }
+
+    /// The anti-skid modulates the brake pressure
+    pub fn is_active(&self) -> bool {
+        self.is_selected && self.is_operative()
+    }
+
+    /// The touchdown protection is armed in flight, and releases the brakes once the main gear
+    /// touches down until the wheels spun up.
+    pub fn touchdown_protection_is_active(&self) -> bool {
+        self.is_active() && self.main_gear_compressed && self.touchdown_protection_armed
+    }
+
+    /// Whether any wheel of the side is locked.
+    pub fn wheel_is_locked(&self, side: AntiSkidSide) -> bool {
+        self.channels[side as usize]
+            .iter()
+            .any(|channel| channel.is_locked(self.reference_speed))
+    }
+
+    /// Modulates the given brake demand of a side.
+    /// The simulator brakes each side as a whole, thus the demand is reduced by the average
+    /// release of the wheels of the side.
+    pub fn modulated_demand(&self, side: AntiSkidSide, demand: Ratio) -> Ratio {
+        if !self.is_active() {
+            demand
+        } else if self.touchdown_protection_is_active() {
+            Ratio::default()
+        } else {
+            let release = self.channels[side as usize]
+                .iter()
+                .map(|channel| channel.release)
+                .sum::<f64>()
+                / N as f64;
+
+            demand * (1. - release)
+        }
+    }
+
+    /// The maximum braking force ratio the wheels of a side can transmit to the runway.
+    /// Locked wheels slide and transmit less force than rolling ones.
+    pub fn braking_force_limit(&self, side: AntiSkidSide) -> f64 {
+        let wheels_friction_ratio = self.channels[side as usize]
+            .iter()
+            .map(|channel| {
+                if channel.is_locked(self.reference_speed) {
+                    AntiSkidChannel::LOCKED_WHEEL_FRICTION_RATIO
+                } else {
+                    1.
+                }
+            })
+            .sum::<f64>()
+            / N as f64;
+
+        wheels_friction_ratio * self.friction_limit
+    }
+}
+impl<const N: usize> SimulationElement for AntiSkid<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+impl<const N: usize> Default for AntiSkid<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct AntiSkidChannel {
+    /// Modelled slip of the wheels, 0 when rolling freely and 1 when locked
+    slip: f64,
+    /// Ratio of the brake demand released by the anti-skid
+    release: f64,
+}
+impl AntiSkidChannel {
+    /// Wheel slip above which brake pressure is released
+    const TARGET_SLIP_RATIO: f64 = 0.13;
+    /// A wheel slower than this ratio of the reference speed is considered locked
+    const LOCKED_WHEEL_SPEED_RATIO: f64 = 0.5;
+    /// Reference speed below which slip isn't monitored anymore
+    const MIN_REFERENCE_SPEED_KNOT: f64 = 10.;
+    /// Rate at which the slip builds up per unit of demand above the friction limit, per second
+    const SLIP_BUILD_UP_RATE: f64 = 8.;
+    /// Rate at which the slip recovers when braking below the friction limit, per second
+    const SLIP_RECOVERY_RATE: f64 = 3.;
+    /// Rate at which the anti-skid releases brake pressure, per second
+    const RELEASE_RATE: f64 = 6.;
+    /// Rate at which the anti-skid reapplies brake pressure, per second
+    const REAPPLY_RATE: f64 = 1.5;
+    /// Friction of a sliding tyre relative to the friction of a rolling tyre
+    const LOCKED_WHEEL_FRICTION_RATIO: f64 = 0.7;
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_active: bool,
+        reference_speed: Velocity,
+        friction_limit: f64,
+        measured_wheel_speed: Velocity,
+        demand: Ratio,
+    ) {
+        let delta = context.delta_as_secs_f64();
+
+        let applied_demand = if is_active {
+            demand.get::<ratio>() * (1. - self.release)
+        } else {
+            demand.get::<ratio>()
+        };
+
+        if applied_demand > friction_limit {
+            self.slip += (applied_demand - friction_limit) / friction_limit
+                * Self::SLIP_BUILD_UP_RATE
+                * delta;
+        } else {
+            self.slip -= Self::SLIP_RECOVERY_RATE * delta;
+        }
+        self.slip = self.slip.clamp(0., 1.);
+
+        if !is_active {
+            self.release = 0.;
+            return;
+        }
+
+        let wheel_speed = measured_wheel_speed.min(reference_speed) * (1. - self.slip);
+        let measured_slip = 1. - (wheel_speed / reference_speed).get::<ratio>();
+
+        if wheel_speed < reference_speed * Self::LOCKED_WHEEL_SPEED_RATIO {
+            // Locked wheel protection
+            self.release = 1.;
+        } else if measured_slip > Self::TARGET_SLIP_RATIO {
+            self.release += Self::RELEASE_RATE * delta;
+        } else {
+            self.release -= Self::REAPPLY_RATE * delta;
+        }
+        self.release = self.release.clamp(0., 1.);
+    }
+
+    fn reset(&mut self) {
+        self.slip = 0.;
+        self.release = 0.;
+    }
+
+    fn is_locked(&self, reference_speed: Velocity) -> bool {
+        reference_speed.get::<knot>() > Self::MIN_REFERENCE_SPEED_KNOT
+            && 1. - self.slip < Self::LOCKED_WHEEL_SPEED_RATIO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft, InitContext,
+    };
+
+    struct TestAircraft {
+        anti_skid: AntiSkid<2>,
+        is_selected: bool,
+        main_gear_compressed: bool,
+        wheel_speeds: [[Velocity; 2]; 2],
+        demand: Ratio,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                anti_skid: AntiSkid::new(),
+                is_selected: true,
+                main_gear_compressed: true,
+                wheel_speeds: [[Velocity::new::<knot>(100.); 2]; 2],
+                demand: Ratio::default(),
+            }
+        }
+
+        fn set_demand(&mut self, demand: Ratio) {
+            self.demand = demand;
+        }
+
+        fn set_main_gear_compressed(&mut self, compressed: bool) {
+            self.main_gear_compressed = compressed;
+        }
+
+        fn set_anti_skid_selected(&mut self, is_selected: bool) {
+            self.is_selected = is_selected;
+        }
+
+        fn set_wheel_speed(&mut self, wheel_speed: Velocity) {
+            self.wheel_speeds = [[wheel_speed; 2]; 2];
+        }
+
+        fn set_left_wheel_speed(&mut self, index: usize, wheel_speed: Velocity) {
+            self.wheel_speeds[AntiSkidSide::Left as usize][index] = wheel_speed;
+        }
+
+        fn left_modulated_demand(&self) -> Ratio {
+            self.anti_skid
+                .modulated_demand(AntiSkidSide::Left, self.demand)
+        }
+
+        fn right_modulated_demand(&self) -> Ratio {
+            self.anti_skid
+                .modulated_demand(AntiSkidSide::Right, self.demand)
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.anti_skid.update(
+                context,
+                self.is_selected,
+                self.main_gear_compressed,
+                self.wheel_speeds,
+                [self.demand; 2],
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.anti_skid.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_on(surface: SurfaceTypeMsfs) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_on_ground(true);
+        test_bed.write_by_name("GPS GROUND SPEED", 100.);
+        test_bed.write_by_name("SURFACE TYPE", surface as u32 as f64);
+
+        test_bed
+    }
+
+    fn brake_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        test_bed.command(|a| a.set_demand(Ratio::new::<ratio>(1.)));
+        let steps = duration.as_millis() / 50;
+        for _ in 0..steps {
+            test_bed.run_with_delta(Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn full_braking_on_dry_runway_is_not_modulated() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Concrete);
+        brake_for(&mut test_bed, Duration::from_secs(2));
+
+        assert_eq!(
+            test_bed.query(|a| a.left_modulated_demand().get::<ratio>()),
+            1.
+        );
+        assert!(!test_bed.query(|a| a.anti_skid.wheel_is_locked(AntiSkidSide::Left)));
+    }
+
+    #[test]
+    fn full_braking_on_icy_runway_is_modulated_without_locking_wheels() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Ice);
+        brake_for(&mut test_bed, Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.left_modulated_demand().get::<ratio>()) < 0.5);
+        assert!(!test_bed.query(|a| a.anti_skid.wheel_is_locked(AntiSkidSide::Left)));
+    }
+
+    #[test]
+    fn full_braking_on_icy_runway_without_anti_skid_locks_wheels() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Ice);
+        test_bed.command(|a| a.set_anti_skid_selected(false));
+        brake_for(&mut test_bed, Duration::from_secs(5));
+
+        assert_eq!(
+            test_bed.query(|a| a.left_modulated_demand().get::<ratio>()),
+            1.
+        );
+        assert!(test_bed.query(|a| a.anti_skid.wheel_is_locked(AntiSkidSide::Left)));
+        assert!(test_bed.query(|a| a.anti_skid.braking_force_limit(AntiSkidSide::Left)) < 0.15);
+    }
+
+    #[test]
+    fn failed_anti_skid_does_not_modulate() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Ice);
+        test_bed.fail(FailureType::AntiSkid);
+        brake_for(&mut test_bed, Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.anti_skid.is_operative()));
+        assert!(test_bed.query(|a| a.anti_skid.wheel_is_locked(AntiSkidSide::Left)));
+    }
+
+    #[test]
+    fn locked_wheel_releases_the_brakes() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Concrete);
+        test_bed.command(|a| a.set_wheel_speed(Velocity::new::<knot>(20.)));
+        brake_for(&mut test_bed, Duration::from_millis(100));
+
+        assert_eq!(
+            test_bed.query(|a| a.left_modulated_demand().get::<ratio>()),
+            0.
+        );
+    }
+
+    #[test]
+    fn single_locked_wheel_only_releases_its_own_brake() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Concrete);
+        test_bed.command(|a| a.set_left_wheel_speed(0, Velocity::new::<knot>(20.)));
+        brake_for(&mut test_bed, Duration::from_millis(100));
+
+        assert_eq!(
+            test_bed.query(|a| a.left_modulated_demand().get::<ratio>()),
+            0.5
+        );
+        assert_eq!(
+            test_bed.query(|a| a.right_modulated_demand().get::<ratio>()),
+            1.
+        );
+    }
+
+    #[test]
+    fn brakes_are_not_released_in_flight() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Concrete);
+        test_bed.command(|a| a.set_main_gear_compressed(false));
+        test_bed.command(|a| a.set_wheel_speed(Velocity::default()));
+        brake_for(&mut test_bed, Duration::from_millis(100));
+
+        assert!(!test_bed.query(|a| a.anti_skid.touchdown_protection_is_active()));
+        assert_eq!(
+            test_bed.query(|a| a.left_modulated_demand().get::<ratio>()),
+            1.
+        );
+    }
+
+    #[test]
+    fn touchdown_protection_releases_brakes_until_wheels_spun_up() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Concrete);
+        test_bed.command(|a| a.set_main_gear_compressed(false));
+        test_bed.command(|a| a.set_wheel_speed(Velocity::default()));
+        brake_for(&mut test_bed, Duration::from_millis(100));
+
+        test_bed.command(|a| a.set_main_gear_compressed(true));
+        brake_for(&mut test_bed, Duration::from_millis(100));
+        assert!(test_bed.query(|a| a.anti_skid.touchdown_protection_is_active()));
+        assert_eq!(
+            test_bed.query(|a| a.left_modulated_demand().get::<ratio>()),
+            0.
+        );
+
+        test_bed.command(|a| a.set_wheel_speed(Velocity::new::<knot>(100.)));
+        brake_for(&mut test_bed, Duration::from_millis(100));
+        assert!(!test_bed.query(|a| a.anti_skid.touchdown_protection_is_active()));
+    }
+
+    #[test]
+    fn touchdown_protection_ends_after_some_time_on_ground() {
+        let mut test_bed = test_bed_on(SurfaceTypeMsfs::Concrete);
+        test_bed.command(|a| a.set_main_gear_compressed(false));
+        brake_for(&mut test_bed, Duration::from_millis(100));
+
+        // Aquaplaning wheels don't spin up
+        test_bed.command(|a| a.set_main_gear_compressed(true));
+        test_bed.command(|a| a.set_wheel_speed(Velocity::default()));
+        brake_for(&mut test_bed, Duration::from_secs(2));
+        assert!(test_bed.query(|a| a.anti_skid.touchdown_protection_is_active()));
+
+        brake_for(&mut test_bed, Duration::from_secs(4));
+        assert!(!test_bed.query(|a| a.anti_skid.touchdown_protection_is_active()));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    shared::{ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
+    failures::{Failure, FailureType},
+    landing_gear::{Tyre, TyreProperties},
+    shared::{interpolation, ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write,
@@ -9,14 +11,16 @@ use std::f64::consts::PI;
 use uom::si::{
     angular_velocity::revolution_per_minute,
     area::square_meter,
-    energy::joule,
+    energy::{joule, megajoule},
     f64::{
-        AngularVelocity, Area, Energy, HeatCapacity, Length, Mass, Power, Pressure,
-        SpecificHeatCapacity, TemperatureInterval, ThermodynamicTemperature,
+        AngularVelocity, Area, Energy, HeatCapacity, Length, Mass, Power, Pressure, Ratio,
+        SpecificHeatCapacity, TemperatureInterval, ThermodynamicTemperature, Velocity,
     },
     length::meter,
     mass_density::kilogram_per_cubic_meter,
     power::watt,
+    pressure::psi,
+    ratio::{percent, ratio},
     specific_heat_capacity::joule_per_kilogram_kelvin,
     temperature_interval::kelvin,
     thermodynamic_temperature::{self, degree_celsius},
@@ -29,6 +33,7 @@ pub struct BrakeAssembly<const N: usize> {
     brakes: [Brake; N],
     brake_probes: [BrakeProbe; N],
     brake_fans: Option<[BrakeFan; N]>,
+    tyres: [Tyre; N],
 }
 impl<const N: usize> BrakeAssembly<N> {
     /// Creates a new brake assembly
@@ -42,7 +47,10 @@ impl<const N: usize> BrakeAssembly<N> {
         brake_fan_bus: Option<ElectricalBusType>,
     ) -> Self {
         let brakes = indices.map(|index| Brake::new(context, index));
-        let brake_probes = sensors_powered_by.map(BrakeProbe::new);
+        let mut sensors_powered_by = sensors_powered_by.into_iter();
+        let brake_probes =
+            indices.map(|index| BrakeProbe::new(index, sensors_powered_by.next().unwrap()));
+        let tyres = indices.map(|index| Tyre::new(context, index));
         let brake_fans = brake_fan_bus.map(|bus| {
             brakes
                 .iter()
@@ -57,6 +65,7 @@ impl<const N: usize> BrakeAssembly<N> {
             brakes,
             brake_probes,
             brake_fans,
+            tyres,
         }
     }
 
@@ -64,6 +73,7 @@ impl<const N: usize> BrakeAssembly<N> {
         &mut self,
         context: &UpdateContext,
         brake_properties: &BrakeProperties,
+        tyre_properties: &TyreProperties,
         actuator_pressure: Pressure,
         brake_fan_should_be_on: bool,
         gear_extended_phys: bool,
@@ -102,6 +112,33 @@ impl<const N: usize> BrakeAssembly<N> {
             );
             brake_probe.update(context, brake, brake_fan_is_running)
         }
+
+        for (tyre, brake) in self.tyres.iter_mut().zip(&self.brakes) {
+            tyre.update(
+                context,
+                tyre_properties,
+                brake.temperature(),
+                self.wheel_speed,
+            );
+        }
+    }
+
+    pub fn tyres(&self) -> &[Tyre; N] {
+        &self.tyres
+    }
+
+    /// The surface speed of each tyre of the assembly.
+    pub fn tyre_speeds(&self) -> [Velocity; N] {
+        self.tyres.each_ref().map(|tyre| tyre.speed())
+    }
+
+    /// The rolling drag added by deflated tyres, as a ratio of maximum braking force.
+    pub fn tyres_rolling_drag(&self) -> f64 {
+        self.tyres
+            .iter()
+            .map(|tyre| tyre.rolling_drag())
+            .sum::<f64>()
+            / N as f64
     }
 
     pub fn brake_temperature_sensors(
@@ -110,6 +147,25 @@ impl<const N: usize> BrakeAssembly<N> {
         &self.brake_probes
     }
 
+    /// The braking efficiency of the operative brakes, averaged over all brakes of the assembly.
+    /// Seized and released brakes don't contribute to it.
+    pub fn braking_efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            self.brakes
+                .iter()
+                .map(|brake| brake.efficiency().get::<ratio>())
+                .sum::<f64>()
+                / N as f64,
+        )
+    }
+
+    /// The ratio of brakes in the assembly which are seized and thus brake at full force.
+    pub fn seized_brakes_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            self.brakes.iter().filter(|brake| brake.is_seized()).count() as f64 / N as f64,
+        )
+    }
+
     pub fn any_brake_fan_running(&self) -> bool {
         self.brake_fans
             .iter()
@@ -122,6 +178,7 @@ impl<const N: usize> SimulationElement for BrakeAssembly<N> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.brakes, visitor);
         accept_iterable!(self.brake_probes, visitor);
+        accept_iterable!(self.tyres, visitor);
         if let Some(brake_fans) = &mut self.brake_fans {
             accept_iterable!(brake_fans, visitor);
         }
@@ -134,6 +191,64 @@ impl<const N: usize> SimulationElement for BrakeAssembly<N> {
     }
 }
 
+/// Summarises the condition of the brakes and tyres on one side of the aircraft,
+/// which corrects the braking force commanded through the brake pressure.
+#[derive(Clone, Copy, Debug)]
+pub struct WheelsCondition {
+    braking_efficiency: f64,
+    seized_brakes: f64,
+    tyres_rolling_drag: f64,
+    braking_force_limit: f64,
+}
+impl WheelsCondition {
+    pub fn from_assemblies<const N: usize>(assemblies: &[&BrakeAssembly<N>]) -> Self {
+        let count = assemblies.len() as f64;
+        Self {
+            braking_efficiency: assemblies
+                .iter()
+                .map(|assembly| assembly.braking_efficiency().get::<ratio>())
+                .sum::<f64>()
+                / count,
+            seized_brakes: assemblies
+                .iter()
+                .map(|assembly| assembly.seized_brakes_ratio().get::<ratio>())
+                .sum::<f64>()
+                / count,
+            tyres_rolling_drag: assemblies
+                .iter()
+                .map(|assembly| assembly.tyres_rolling_drag())
+                .sum::<f64>()
+                / count,
+            braking_force_limit: 1.,
+        }
+    }
+
+    /// Limits the braking force ratio to what the runway surface can take, as given by the anti-skid.
+    pub fn with_braking_force_limit(mut self, braking_force_limit: f64) -> Self {
+        self.braking_force_limit = braking_force_limit;
+        self
+    }
+
+    /// Corrects a braking force ratio for faded, seized and released brakes, deflated tyres
+    /// and the runway friction.
+    pub fn correct_braking_force(&self, braking_force: f64) -> f64 {
+        ((braking_force * self.braking_efficiency + self.seized_brakes)
+            .min(self.braking_force_limit)
+            + self.tyres_rolling_drag)
+            .clamp(0., 1.)
+    }
+}
+impl Default for WheelsCondition {
+    fn default() -> Self {
+        Self {
+            braking_efficiency: 1.,
+            seized_brakes: 0.,
+            tyres_rolling_drag: 0.,
+            braking_force_limit: 1.,
+        }
+    }
+}
+
 pub struct BrakeProperties {
     brake_radius: Length,
     surface_area: Area,
@@ -169,10 +284,24 @@ impl BrakeProperties {
 }
 
 /// Simulates a carbon brake (C/C composite)
+///
+/// The brake wears with the energy it absorbs, which is indicated by its wear pin.
+/// The wear is read back from the simulator every update, so it can be restored from persistent storage.
+/// At high temperatures its efficiency fades.
 struct Brake {
     temperature_id: VariableIdentifier,
+    wear_id: VariableIdentifier,
+    energy_id: VariableIdentifier,
+
     temperature: ThermodynamicTemperature,
     initialized: bool,
+    wear: Ratio,
+    /// The energy absorbed since the wheel last started rolling
+    energy_since_roll_start: Energy,
+    was_rolling: bool,
+
+    seized_failure: Failure,
+    released_failure: Failure,
 }
 impl Brake {
     // Sources for values:
@@ -189,12 +318,28 @@ impl Brake {
     const BRAKE_EMISSIVITY: f64 = 0.71;
     /// Stefan-Boltzmann constant, W/(m^2*K^4)
     const BOLTZMANN_CONSTANT: f64 = 5.670374419e-8;
+    /// Energy a brake absorbs over its lifetime until the wear pin is flush, MJ
+    const LIFETIME_ENERGY_MEGAJOULE: f64 = 30000.;
+    /// Actuator pressure a seized brake is stuck at, psi
+    const SEIZED_ACTUATOR_PRESSURE_PSI: f64 = 3000.;
+
+    const FADE_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: [f64; 5] = [0., 600., 800., 1000., 1200.];
+    const FADE_EFFICIENCY_RATIO: [f64; 5] = [1., 1., 0.9, 0.75, 0.6];
 
     fn new(context: &mut InitContext, index: usize) -> Self {
         Self {
             temperature_id: context.get_identifier(format!("BRAKE_TEMPERATURE_{index}")),
+            wear_id: context.get_identifier(format!("BRAKE_WEAR_{index}")),
+            energy_id: context.get_identifier(format!("BRAKE_ENERGY_{index}")),
+
             temperature: ThermodynamicTemperature::default(),
             initialized: false,
+            wear: Ratio::default(),
+            energy_since_roll_start: Energy::default(),
+            was_rolling: false,
+
+            seized_failure: Failure::new(FailureType::BrakeSeized(index)),
+            released_failure: Failure::new(FailureType::BrakeReleased(index)),
         }
     }
 
@@ -213,12 +358,21 @@ impl Brake {
         }
 
         // Heat up process
+        let actuator_pressure = if self.is_seized() {
+            Pressure::new::<psi>(Self::SEIZED_ACTUATOR_PRESSURE_PSI)
+        } else if self.is_released() {
+            Pressure::default()
+        } else {
+            actuator_pressure
+        };
         let actuator_area = Area::new::<square_meter>(Self::BRAKE_ACTUATOR_AREA);
         let force = actuator_pressure * actuator_area;
         let energy = force * passed_length;
         let delta = energy / brake_properties.heat_capacity();
         self.temperature += delta;
 
+        self.update_wear(passed_length, energy);
+
         // Cool down process
         let radiated_energy = self.calculate_radiated_energy(context, brake_properties);
         let brake_fan_coefficient = if brake_fan_on {
@@ -246,6 +400,19 @@ impl Brake {
         self.temperature -= energy / brake_properties.heat_capacity();
     }
 
+    fn update_wear(&mut self, passed_length: Length, energy: Energy) {
+        let is_rolling = passed_length > Length::default();
+        if is_rolling && !self.was_rolling {
+            self.energy_since_roll_start = Energy::default();
+        }
+        self.was_rolling = is_rolling;
+
+        self.energy_since_roll_start += energy;
+        self.wear = (self.wear
+            + Ratio::new::<ratio>(energy.get::<megajoule>() / Self::LIFETIME_ENERGY_MEGAJOULE))
+        .min(Ratio::new::<ratio>(1.));
+    }
+
     fn calculate_radiated_energy(
         &self,
         context: &UpdateContext,
@@ -289,10 +456,48 @@ impl Brake {
     fn temperature(&self) -> ThermodynamicTemperature {
         self.temperature
     }
+
+    fn is_seized(&self) -> bool {
+        self.seized_failure.is_active()
+    }
+
+    fn is_released(&self) -> bool {
+        self.released_failure.is_active()
+    }
+
+    /// The braking efficiency, which fades at high temperatures.
+    /// Seized and released brakes have no efficiency as they don't respond to pressure.
+    fn efficiency(&self) -> Ratio {
+        if self.is_seized() || self.is_released() {
+            Ratio::default()
+        } else {
+            Ratio::new::<ratio>(interpolation(
+                &Self::FADE_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS,
+                &Self::FADE_EFFICIENCY_RATIO,
+                self.temperature.get::<degree_celsius>(),
+            ))
+        }
+    }
 }
 impl SimulationElement for Brake {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.seized_failure.accept(visitor);
+        self.released_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.wear = Ratio::new::<percent>(reader.read(&self.wear_id));
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.wear_id, self.wear.get::<percent>());
+        writer.write(
+            &self.energy_id,
+            self.energy_since_roll_start.get::<megajoule>(),
+        );
     }
 }
 
@@ -339,16 +544,18 @@ struct BrakeProbe {
     initialised: bool,
     powered_by: ElectricalBusType,
     is_powered: bool,
+    failure: Failure,
 }
 impl BrakeProbe {
     const THERMAL_INERTIA: f64 = 0.003;
 
-    fn new(powered_by: ElectricalBusType) -> Self {
+    fn new(index: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             temperature: ThermodynamicTemperature::default(),
             initialised: false,
             powered_by,
             is_powered: false,
+            failure: Failure::new(FailureType::BrakeTemperatureProbe(index)),
         }
     }
 
@@ -376,10 +583,16 @@ impl BrakeProbe {
 }
 impl ControllerSignal<ThermodynamicTemperature> for BrakeProbe {
     fn signal(&self) -> Option<ThermodynamicTemperature> {
-        self.is_powered.then_some(self.temperature)
+        (self.is_powered && !self.failure.is_active()).then_some(self.temperature)
     }
 }
 impl SimulationElement for BrakeProbe {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.is_powered = buses.is_powered(self.powered_by);
     }
@@ -425,8 +638,10 @@ mod tests {
     use std::time::Duration;
 
     use super::*;
+    use crate::electrical::{test::TestElectricitySource, ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
     use crate::simulation::{
-        test::{ReadByName, SimulationTestBed, TestBed},
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
         Aircraft,
     };
     use uom::si::{
@@ -570,9 +785,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn braking_wears_the_brake() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| a.set_actuator_pressure(Pressure::new::<psi>(1000.0)));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(1.)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let wear: f64 = test_bed.read_by_name("BRAKE_WEAR_0");
+        assert!(wear > 0.);
+    }
+
+    #[test]
+    fn restored_wear_keeps_increasing() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("BRAKE_WEAR_0", 40.);
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| a.set_actuator_pressure(Pressure::new::<psi>(1000.0)));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(1.)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let wear: f64 = test_bed.read_by_name("BRAKE_WEAR_0");
+        assert!(wear > 40.);
+    }
+
+    #[test]
+    fn absorbed_energy_is_kept_once_stopped_and_reset_when_rolling_again() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+
+        test_bed.command(|a| a.set_actuator_pressure(Pressure::new::<psi>(3000.0)));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(1.)));
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_millis(10));
+        }
+
+        test_bed.command(|a| a.set_passed_length(Length::default()));
+        test_bed.run_with_delta(Duration::from_millis(10));
+        let energy_after_stop: f64 = test_bed.read_by_name("BRAKE_ENERGY_0");
+        assert!(energy_after_stop > 0.);
+
+        test_bed.run_with_delta(Duration::from_secs(10));
+        let energy: f64 = test_bed.read_by_name("BRAKE_ENERGY_0");
+        assert_eq!(energy, energy_after_stop);
+
+        test_bed.command(|a| a.set_actuator_pressure(Pressure::default()));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(1.)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+        let energy: f64 = test_bed.read_by_name("BRAKE_ENERGY_0");
+        assert_eq!(energy, 0.);
+    }
+
+    #[test]
+    fn hot_brake_fades() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+        assert_eq!(test_bed.query(|a| a.brake.efficiency().get::<ratio>()), 1.);
+
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(1000.))
+        });
+
+        assert!(test_bed.query(|a| a.brake.efficiency().get::<ratio>()) < 1.);
+    }
+
+    #[test]
+    fn seized_brake_heats_up_without_pressure() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+        let initial_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("BRAKE_TEMPERATURE_0");
+
+        test_bed.fail(FailureType::BrakeSeized(0));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(0.01)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let brake_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("BRAKE_TEMPERATURE_0");
+        assert!(brake_temperature > initial_temperature);
+        assert_eq!(test_bed.query(|a| a.brake.efficiency().get::<ratio>()), 0.);
+    }
+
+    #[test]
+    fn released_brake_does_not_heat_up_under_pressure() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_without_delta();
+        let initial_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("BRAKE_TEMPERATURE_0");
+
+        test_bed.fail(FailureType::BrakeReleased(0));
+        test_bed.command(|a| a.set_actuator_pressure(Pressure::new::<psi>(3000.0)));
+        test_bed.command(|a| a.set_passed_length(Length::new::<meter>(0.01)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let brake_temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("BRAKE_TEMPERATURE_0");
+        assert!(brake_temperature <= initial_temperature);
+        assert_eq!(test_bed.query(|a| a.brake.efficiency().get::<ratio>()), 0.);
+    }
+
+    #[test]
+    fn unpowered_brake_probe_provides_no_temperature() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_with_delta(Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.brake_probe.signal().is_some()));
+
+        test_bed.command(|a| a.unpower_brake_probe());
+        test_bed.run_with_delta(Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.brake_probe.signal().is_none()));
+    }
+
+    #[test]
+    fn failed_brake_probe_provides_no_temperature() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_with_delta(Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.brake_probe.signal().is_some()));
+
+        test_bed.fail(FailureType::BrakeTemperatureProbe(0));
+        test_bed.run_with_delta(Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.brake_probe.signal().is_none()));
+    }
+
+    #[test]
+    fn wheels_condition_corrects_braking_force() {
+        let condition = WheelsCondition {
+            braking_efficiency: 0.5,
+            seized_brakes: 0.25,
+            tyres_rolling_drag: 0.,
+            braking_force_limit: 1.,
+        };
+
+        assert_eq!(condition.correct_braking_force(0.), 0.25);
+        assert_eq!(condition.correct_braking_force(1.), 0.75);
+
+        let condition = condition.with_braking_force_limit(0.3);
+        assert_eq!(condition.correct_braking_force(1.), 0.3);
+    }
+
     struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
         brake_properties: BrakeProperties,
         brake: Brake,
+        brake_probe: BrakeProbe,
         passed_length: Length,
         actuator_pressure: Pressure,
         brake_fan_on: bool,
@@ -581,12 +939,18 @@ mod tests {
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::TransformerRectifier(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
                 brake_properties: BrakeProperties::new(
                     Length::new::<meter>(0.3),
                     Length::new::<meter>(0.05),
                     Mass::new::<kilogram>(8.0),
                 ),
                 brake: Brake::new(context, 0),
+                brake_probe: BrakeProbe::new(0, ElectricalBusType::DirectCurrent(1)),
                 passed_length: Length::default(),
                 actuator_pressure: Pressure::default(),
                 brake_fan_on: false,
@@ -611,8 +975,21 @@ mod tests {
             self.brake.temperature = temperature;
             println!("{:?}", self.brake.temperature);
         }
+
+        fn unpower_brake_probe(&mut self) {
+            self.electricity_source.unpower();
+        }
     }
     impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.dc_1_bus);
+        }
+
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
             self.brake.update(
                 context,
@@ -622,11 +999,14 @@ mod tests {
                 self.brake_fan_on,
                 self.gear_extended_phys,
             );
+            self.brake_probe
+                .update(context, &self.brake, self.brake_fan_on);
         }
     }
     impl SimulationElement for TestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.brake.accept(visitor);
+            self.brake_probe.accept(visitor);
 
             visitor.visit(self);
         }
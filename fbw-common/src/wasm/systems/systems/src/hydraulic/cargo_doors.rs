@@ -254,6 +254,8 @@ impl CargoDoor {
     ) {
         self.aerodynamic_model
             .update_body(context, self.hydraulic_assembly.body());
+        self.hydraulic_assembly
+            .set_fluid_viscosity_flow_ratios([current_pressure.fluid_viscosity_flow_ratio()]);
         self.hydraulic_assembly.update(
             context,
             std::slice::from_ref(cargo_door_controller),
@@ -431,6 +431,10 @@ mod tests {
         fn is_pressure_switch_pressurised(&self) -> bool {
             self.pressure.get::<psi>() > 1700.
         }
+
+        fn fluid_viscosity_flow_ratio(&self) -> Ratio {
+            Ratio::new::<ratio>(1.)
+        }
     }
 
     struct TestLgciuSensors {
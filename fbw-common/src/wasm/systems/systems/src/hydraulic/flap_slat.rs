@@ -1,5 +1,6 @@
 use super::hydraulic_motor::FlapSlatHydraulicMotor;
 use super::linear_actuator::Actuator;
+use crate::failures::{Failure, FailureType};
 use crate::shared::Clamp;
 use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, AverageExt, PositionPickoffUnit, SectionPressure,
@@ -22,7 +23,7 @@ use uom::ConstZero;
 use std::fmt;
 use std::time::Duration;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum SecondarySurfaceSide {
     Left,
     Right,
@@ -36,7 +37,7 @@ impl fmt::Display for SecondarySurfaceSide {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum SecondarySurfaceType {
     Flaps,
     Slats,
@@ -65,6 +66,18 @@ pub enum SolenoidStatus {
 pub trait ValveBlock {
     fn get_pob_status(&self) -> SolenoidStatus;
     fn get_command_status(&self) -> Option<ChannelCommand>;
+
+    /// Wing Tip Brakes (WTB) solenoid. When energised, the brakes at both wing tips lock
+    /// the transmission and the surfaces can't move anymore.
+    fn get_wtb_status(&self) -> SolenoidStatus;
+}
+
+/// Asymmetry Position Pickoff Units (APPU) at each wing tip, plus the surface skew sensors.
+/// Unlike the FPPU mounted on the PCU, these see a transmission failure between the PCU and the surfaces.
+pub trait AsymmetryPositionPickoffUnits {
+    fn left_angle(&self) -> Angle;
+    fn right_angle(&self) -> Angle;
+    fn is_skew_detected(&self) -> bool;
 }
 
 pub struct SecondarySurface {
@@ -73,11 +86,19 @@ pub struct SecondarySurface {
 
     surface_position_overall_id: VariableIdentifier,
     surface_angle_overall_id: VariableIdentifier,
+    appu_angle_id: VariableIdentifier,
 
     surface_positions: Vec<Ratio>,
     surface_angles: Vec<Angle>,
+
+    transmission_angle: Angle,
+
+    shaft_break_failure: Failure,
+    skew_failure: Failure,
 }
 impl SecondarySurface {
+    const SKEW_DETECTION_THRESHOLD_DEGREE: f64 = 1.;
+
     pub fn new(
         context: &mut InitContext,
         side: SecondarySurfaceSide,
@@ -95,6 +116,7 @@ impl SecondarySurface {
         let surface_position_overall_id =
             context.get_identifier(format!("{side}_{surface}_POSITION_PERCENT"));
         let surface_angle_overall_id = context.get_identifier(format!("{side}_{surface}_ANGLE"));
+        let appu_angle_id = context.get_identifier(format!("{side}_{surface}_APPU_ANGLE"));
 
         Self {
             surface_position_ids,
@@ -103,21 +125,76 @@ impl SecondarySurface {
             surface_angles,
             surface_position_overall_id,
             surface_angle_overall_id,
+            appu_angle_id,
+            transmission_angle: Angle::ZERO,
+            shaft_break_failure: Failure::new(FailureType::FlapSlatTransmissionShaftBreak(
+                surface, side,
+            )),
+            skew_failure: Failure::new(FailureType::FlapSlatSkew(surface, side)),
         }
     }
 
-    // No failures are considered. All surfaces have the same deflection.
+    /// Drives this wing transmission from the PCU output angle. After a shaft break, the
+    /// transmission stops where it is and this side is no longer driven by the PCU.
+    fn update_transmission(&mut self, pcu_output_angle: Angle) {
+        if !self.shaft_break_failure.is_active() {
+            self.transmission_angle = pcu_output_angle;
+        }
+    }
+
+    // A skewed surface has its most outboard panel stuck while the others keep following the transmission.
     fn update(&mut self, position: Ratio, angle: Angle) {
-        for surface_position in &mut self.surface_positions {
+        let number_of_driven_panels =
+            if self.skew_failure.is_active() && self.surface_positions.len() > 1 {
+                self.surface_positions.len() - 1
+            } else {
+                self.surface_positions.len()
+            };
+
+        for surface_position in self
+            .surface_positions
+            .iter_mut()
+            .take(number_of_driven_panels)
+        {
             *surface_position = position;
         }
-        for surface_angle in &mut self.surface_angles {
+        for surface_angle in self.surface_angles.iter_mut().take(number_of_driven_panels) {
             *surface_angle = angle;
         }
     }
+
+    fn is_skew_detected(&self) -> bool {
+        let max_angle = self
+            .surface_angles
+            .iter()
+            .copied()
+            .reduce(Angle::max)
+            .unwrap_or_default();
+        let min_angle = self
+            .surface_angles
+            .iter()
+            .copied()
+            .reduce(Angle::min)
+            .unwrap_or_default();
+
+        max_angle - min_angle > Angle::new::<degree>(Self::SKEW_DETECTION_THRESHOLD_DEGREE)
+    }
+}
+impl PositionPickoffUnit for SecondarySurface {
+    fn angle(&self) -> Angle {
+        self.transmission_angle
+    }
 }
 impl SimulationElement for SecondarySurface {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.shaft_break_failure.accept(visitor);
+        self.skew_failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.appu_angle_id, self.transmission_angle.get::<degree>());
+
         for (id, position) in self
             .surface_position_ids
             .iter()
@@ -254,6 +331,8 @@ impl FlapSlatAssembly {
 
         self.update_motors_flow(context);
 
+        self.update_transmissions();
+
         self.update_position_ratios();
 
         self.update_surface_variables();
@@ -268,6 +347,14 @@ impl FlapSlatAssembly {
         let max_speed = self.max_speed().get::<radian_per_second>();
         let time_delta = context.delta_as_secs_f64();
 
+        let wtb_engaged = sfcc_1_request.get_wtb_status() == SolenoidStatus::Energised
+            || sfcc_2_request.get_wtb_status() == SolenoidStatus::Energised;
+
+        if wtb_engaged {
+            self.speed = AngularVelocity::ZERO;
+            return;
+        }
+
         let sfcc_1_pob = sfcc_1_request.get_pob_status();
         let sfcc_2_pob = sfcc_2_request.get_pob_status();
 
@@ -458,30 +545,40 @@ impl FlapSlatAssembly {
         self.left_motor.update_flow(context);
     }
 
+    fn update_transmissions(&mut self) {
+        let pcu_output_angle = self.position_feedback();
+
+        self.left_surfaces.update_transmission(pcu_output_angle);
+        self.right_surfaces.update_transmission(pcu_output_angle);
+    }
+
     fn update_position_ratios(&mut self) {
-        self.left_position = Ratio::new::<ratio>(
-            interpolation(
-                &self.synchro_gear_breakpoints,
-                &self.final_surface_angle_carac,
-                self.position_feedback().get::<degree>(),
-            ) / interpolation(
-                &self.synchro_gear_breakpoints,
-                &self.final_surface_angle_carac,
-                self.max_synchro_gear_position.get::<degree>(),
-            ),
-        );
+        let max_surface_angle =
+            self.surface_angle_from_synchro_angle(self.max_synchro_gear_position);
 
-        // TODO update when left and right are simulated separatly
-        self.right_position = self.left_position;
+        self.left_position =
+            self.surface_angle_from_synchro_angle(self.left_surfaces.angle()) / max_surface_angle;
+        self.right_position =
+            self.surface_angle_from_synchro_angle(self.right_surfaces.angle()) / max_surface_angle;
     }
 
     fn update_surface_variables(&mut self) {
-        let surface_position = self.flap_surface_angle();
+        let left_surface_angle = self.surface_angle_from_synchro_angle(self.left_surfaces.angle());
+        let right_surface_angle =
+            self.surface_angle_from_synchro_angle(self.right_surfaces.angle());
 
         self.left_surfaces
-            .update(self.left_position, surface_position);
+            .update(self.left_position, left_surface_angle);
         self.right_surfaces
-            .update(self.right_position, surface_position);
+            .update(self.right_position, right_surface_angle);
+    }
+
+    fn surface_angle_from_synchro_angle(&self, synchro_angle: Angle) -> Angle {
+        Angle::new::<degree>(interpolation(
+            &self.synchro_gear_breakpoints,
+            &self.final_surface_angle_carac,
+            synchro_angle.get::<degree>(),
+        ))
     }
 
     pub fn position_feedback(&self) -> Angle {
@@ -508,13 +605,10 @@ impl FlapSlatAssembly {
         self.current_max_speed.output()
     }
 
+    #[cfg(test)]
     /// Gets flap surface angle from current Feedback Position Pickup Unit (FPPU) position
     fn flap_surface_angle(&self) -> Angle {
-        Angle::new::<degree>(interpolation(
-            &self.synchro_gear_breakpoints,
-            &self.final_surface_angle_carac,
-            self.position_feedback().get::<degree>(),
-        ))
+        self.surface_angle_from_synchro_angle(self.position_feedback())
     }
 
     #[cfg(test)]
@@ -574,10 +668,14 @@ impl SimulationElement for FlapSlatAssembly {
         writer.write(&self.fppu_id, self.position_feedback().get::<degree>());
         writer.write(&self.ippu_id, self.position_feedback().get::<degree>());
 
-        let flaps_fppu_percent =
-            (self.position_feedback() / self.max_synchro_gear_position).get::<percent>();
-        writer.write(&self.animation_left_id, flaps_fppu_percent);
-        writer.write(&self.animation_right_id, flaps_fppu_percent);
+        writer.write(
+            &self.animation_left_id,
+            (self.left_surfaces.angle() / self.max_synchro_gear_position).get::<percent>(),
+        );
+        writer.write(
+            &self.animation_right_id,
+            (self.right_surfaces.angle() / self.max_synchro_gear_position).get::<percent>(),
+        );
 
         writer.write(&self.is_moving_id, self.is_surface_moving());
     }
@@ -587,6 +685,19 @@ impl PositionPickoffUnit for FlapSlatAssembly {
         self.position_feedback()
     }
 }
+impl AsymmetryPositionPickoffUnits for FlapSlatAssembly {
+    fn left_angle(&self) -> Angle {
+        self.left_surfaces.angle()
+    }
+
+    fn right_angle(&self) -> Angle {
+        self.right_surfaces.angle()
+    }
+
+    fn is_skew_detected(&self) -> bool {
+        self.left_surfaces.is_skew_detected() || self.right_surfaces.is_skew_detected()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -599,7 +710,9 @@ mod tests {
     use uom::ConstZero;
 
     use crate::assert_gt_lt;
+    use crate::failures::FailureType;
     use crate::shared::update_iterator::MaxStepLoop;
+    use ntest::assert_about_eq;
 
     use crate::simulation::{
         test::{ReadByName, SimulationTestBed, TestBed},
@@ -633,12 +746,17 @@ mod tests {
         fn is_pressure_switch_pressurised(&self) -> bool {
             self.pressure.get::<psi>() > 1700.
         }
+
+        fn fluid_viscosity_flow_ratio(&self) -> Ratio {
+            Ratio::new::<ratio>(1.)
+        }
     }
 
     #[derive(Default)]
     struct TestSFCC {
         motor_angle_request: Option<Angle>,
         position_feedback: Angle,
+        wtb_engaged: bool,
     }
     impl TestSFCC {
         const POSITIONING_THRESHOLD_DEGREE: f64 = 6.69;
@@ -652,6 +770,10 @@ mod tests {
             self.motor_angle_request = flap_fppu_from_surface_angle(request_sfcc1);
         }
 
+        fn set_wtb_engaged(&mut self, wtb_engaged: bool) {
+            self.wtb_engaged = wtb_engaged;
+        }
+
         fn in_positioning_threshold_range(
             synchro_angle_request: Angle,
             synchro_angle_feedback: Angle,
@@ -694,6 +816,14 @@ mod tests {
                 Some(ChannelCommand::Retract)
             }
         }
+
+        fn get_wtb_status(&self) -> SolenoidStatus {
+            if self.wtb_engaged {
+                SolenoidStatus::Energised
+            } else {
+                SolenoidStatus::DeEnergised
+            }
+        }
     }
 
     struct TestAircraft {
@@ -849,6 +979,26 @@ mod tests {
             self
         }
 
+        fn set_wtb_engaged(mut self, wtb_engaged: bool) -> Self {
+            self.command(|a| {
+                a.sfcc1.set_wtb_engaged(wtb_engaged);
+                a.sfcc2.set_wtb_engaged(wtb_engaged);
+            });
+            self
+        }
+
+        fn left_appu_angle(&self) -> Angle {
+            self.query(|a| a.flaps_slats.left_angle())
+        }
+
+        fn right_appu_angle(&self) -> Angle {
+            self.query(|a| a.flaps_slats.right_angle())
+        }
+
+        fn is_skew_detected(&self) -> bool {
+            self.query(|a| a.flaps_slats.is_skew_detected())
+        }
+
         fn set_individual_hyd_pressure(
             mut self,
             left_pressure: Pressure,
@@ -1365,18 +1515,108 @@ mod tests {
         }
     }
 
+    #[test]
+    fn flap_slat_assembly_appus_follow_fppu_without_failure() {
+        let mut test_bed = test_bed();
+
+        test_bed = test_bed
+            .set_angle_request(Some(Angle::new::<degree>(20.)))
+            .set_hyd_pressure(Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI))
+            .run_waiting_for(Duration::from_millis(10000));
+
+        assert!(test_bed.synchro_position() > Angle::new::<degree>(100.));
+        assert_eq!(test_bed.left_appu_angle(), test_bed.synchro_position());
+        assert_eq!(test_bed.right_appu_angle(), test_bed.synchro_position());
+        assert!(!test_bed.is_skew_detected());
+
+        let appu_angle: f64 = test_bed.read_by_name("LEFT_FLAPS_APPU_ANGLE");
+        assert_about_eq!(appu_angle, test_bed.synchro_position().get::<degree>());
+    }
+
+    #[test]
+    fn flap_slat_assembly_shaft_break_creates_asymmetry() {
+        let mut test_bed = test_bed();
+
+        test_bed = test_bed
+            .set_angle_request(Some(Angle::new::<degree>(10.)))
+            .set_hyd_pressure(Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI))
+            .run_waiting_for(Duration::from_millis(10000));
+
+        let broken_side_angle = test_bed.left_appu_angle();
+
+        test_bed.fail(FailureType::FlapSlatTransmissionShaftBreak(
+            SecondarySurfaceType::Flaps,
+            SecondarySurfaceSide::Left,
+        ));
+        test_bed = test_bed
+            .set_angle_request(Some(Angle::new::<degree>(30.)))
+            .run_waiting_for(Duration::from_millis(10000));
+
+        assert_eq!(test_bed.left_appu_angle(), broken_side_angle);
+        assert_eq!(test_bed.right_appu_angle(), test_bed.synchro_position());
+        assert!(
+            test_bed.right_appu_angle() - test_bed.left_appu_angle() > Angle::new::<degree>(50.)
+        );
+
+        let left_flaps_angle: f64 = test_bed.read_by_name("LEFT_FLAPS_ANGLE");
+        let right_flaps_angle: f64 = test_bed.read_by_name("RIGHT_FLAPS_ANGLE");
+        assert!(right_flaps_angle - left_flaps_angle > 10.);
+    }
+
+    #[test]
+    fn flap_slat_assembly_skew_leaves_outboard_panel_behind() {
+        let mut test_bed = test_bed();
+
+        test_bed.fail(FailureType::FlapSlatSkew(
+            SecondarySurfaceType::Flaps,
+            SecondarySurfaceSide::Right,
+        ));
+        test_bed = test_bed
+            .set_angle_request(Some(Angle::new::<degree>(20.)))
+            .set_hyd_pressure(Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI))
+            .run_waiting_for(Duration::from_millis(10000));
+
+        let inboard_angle: f64 = test_bed.read_by_name("RIGHT_FLAPS_1_ANGLE");
+        let outboard_angle: f64 = test_bed.read_by_name("RIGHT_FLAPS_2_ANGLE");
+        assert!(inboard_angle > 19.);
+        assert!(outboard_angle < 1.);
+
+        assert!(test_bed.is_skew_detected());
+        assert_eq!(test_bed.left_appu_angle(), test_bed.right_appu_angle());
+    }
+
+    #[test]
+    fn flap_slat_assembly_stops_with_wtb_engaged() {
+        let mut test_bed = test_bed();
+
+        test_bed = test_bed
+            .set_angle_request(Some(Angle::new::<degree>(40.)))
+            .set_hyd_pressure(Pressure::new::<psi>(MAX_CIRCUIT_PRESSURE_PSI))
+            .run_waiting_for(Duration::from_millis(5000));
+
+        let locked_position = test_bed.synchro_position();
+        assert!(locked_position > Angle::ZERO);
+
+        test_bed = test_bed
+            .set_wtb_engaged(true)
+            .run_waiting_for(Duration::from_millis(5000));
+
+        assert_eq!(test_bed.synchro_position(), locked_position);
+        assert_eq!(test_bed.flap_slat_speed(), AngularVelocity::ZERO);
+    }
+
     fn flap_system(context: &mut InitContext, max_speed: AngularVelocity) -> FlapSlatAssembly {
         let left_flaps = SecondarySurface::new(
             context,
             SecondarySurfaceSide::Left,
             SecondarySurfaceType::Flaps,
-            1,
+            2,
         );
         let right_flaps = SecondarySurface::new(
             context,
             SecondarySurfaceSide::Right,
             SecondarySurfaceType::Flaps,
-            1,
+            2,
         );
 
         FlapSlatAssembly::new(
@@ -188,6 +188,18 @@ impl HydraulicGearSystem {
 
         let current_pressure = self.hydraulic_supply.gear_system_manifold_pressure();
 
+        let fluid_viscosity_flow_ratio = main_hydraulic_circuit.fluid_viscosity_flow_ratio();
+        for assembly in [
+            &mut self.nose_door_assembly,
+            &mut self.left_door_assembly,
+            &mut self.right_door_assembly,
+            &mut self.nose_gear_assembly,
+            &mut self.left_gear_assembly,
+            &mut self.right_gear_assembly,
+        ] {
+            assembly.set_fluid_viscosity_flow_ratio(fluid_viscosity_flow_ratio);
+        }
+
         self.nose_door_assembly.update(
             context,
             lgciu_controller,
@@ -442,6 +454,10 @@ struct GearSystemComponentAssembly {
     hydraulic_uplock: HydraulicLock,
     hydraulic_downlock: Option<HydraulicLock>,
 
+    uplock_jammed_failure: Failure,
+    downlock_failure: Option<Failure>,
+    downlock_failed_to_engage: bool,
+
     aerodynamic_model: AerodynamicModel,
 }
 impl GearSystemComponentAssembly {
@@ -499,6 +515,13 @@ impl GearSystemComponentAssembly {
             } else {
                 None
             },
+            uplock_jammed_failure: Failure::new(FailureType::GearUplockJammed(id)),
+            downlock_failure: if has_hydraulic_downlock {
+                Some(Failure::new(FailureType::GearDownlockFailure(id)))
+            } else {
+                None
+            },
+            downlock_failed_to_engage: false,
             aerodynamic_model,
         };
 
@@ -507,6 +530,11 @@ impl GearSystemComponentAssembly {
         obj
     }
 
+    fn set_fluid_viscosity_flow_ratio(&mut self, flow_ratio: Ratio) {
+        self.hydraulic_assembly
+            .set_fluid_viscosity_flow_ratios([flow_ratio]);
+    }
+
     fn update(
         &mut self,
         context: &UpdateContext,
@@ -558,8 +586,10 @@ impl GearSystemComponentAssembly {
             }
         };
 
+        // A jammed uplock cannot be released hydraulically anymore, only the gravity extension
+        // mechanical release can free it
         self.hydraulic_uplock.update(
-            should_hydraulically_open,
+            should_hydraulically_open && !self.uplock_jammed_failure.is_active(),
             should_mechanically_open,
             current_pressure,
         );
@@ -571,14 +601,41 @@ impl GearSystemComponentAssembly {
             should_lock_down = hyd_lock.is_locked_or_ready_to_latch();
         }
 
+        self.update_downlock_engagement(should_hydraulically_open, current_pressure);
+
         self.hydraulic_controller.update(
             should_mechanically_open || should_hydraulically_open,
+            should_mechanically_open,
             self.hydraulic_uplock.is_locked_or_ready_to_latch(),
-            should_lock_down,
+            should_lock_down && !self.downlock_failed_to_engage,
             self.position_normalized(),
         );
     }
 
+    // With a failed downlock, the lock springs can no longer engage the lock stays on their own.
+    // The gear then only locks down if the actuator pushes it into the lock under pressure. A gear
+    // reaching its extended position without hydraulic assistance (gravity extension) stays unlocked.
+    // A gear that is already locked down stays locked.
+    fn update_downlock_engagement(
+        &mut self,
+        should_hydraulically_open: bool,
+        current_pressure: Pressure,
+    ) {
+        let downlock_failed = self
+            .downlock_failure
+            .as_ref()
+            .is_some_and(|failure| failure.is_active());
+
+        let is_pressurised_extension = should_hydraulically_open
+            && current_pressure.get::<psi>() > HydraulicLock::UNLOCK_MIN_PRESS_PSI;
+
+        if !downlock_failed || is_pressurised_extension {
+            self.downlock_failed_to_engage = false;
+        } else if self.position_normalized().get::<ratio>() < 0.5 {
+            self.downlock_failed_to_engage = true;
+        }
+    }
+
     fn position_normalized(&self) -> Ratio {
         if !self.is_inverted_control {
             self.hydraulic_assembly.position_normalized()
@@ -596,7 +653,9 @@ impl GearSystemComponentAssembly {
     }
 
     fn is_sensor_fully_opened(&self, lgciu_id: LgciuId) -> bool {
-        self.fully_opened_proximity_detectors[lgciu_id as usize].proximity_detected()
+        // Downlock sensors target the lock stays: a gear fully extended but not locked is not detected
+        !self.downlock_failed_to_engage
+            && self.fully_opened_proximity_detectors[lgciu_id as usize].proximity_detected()
     }
 
     #[cfg(test)]
@@ -614,6 +673,10 @@ impl SimulationElement for GearSystemComponentAssembly {
         accept_iterable!(self.uplock_proximity_detectors, visitor);
         accept_iterable!(self.fully_opened_proximity_detectors, visitor);
         self.hydraulic_controller.accept(visitor);
+        self.uplock_jammed_failure.accept(visitor);
+        if let Some(failure) = &mut self.downlock_failure {
+            failure.accept(visitor);
+        }
 
         visitor.visit(self);
     }
@@ -632,6 +695,9 @@ struct GearSystemComponentHydraulicController {
     jamming_position: Ratio,
     jamming_is_effective: bool,
 
+    door_actuator_failure: Option<Failure>,
+    is_mechanically_released: bool,
+
     soft_downlock_is_active: bool,
 }
 impl GearSystemComponentHydraulicController {
@@ -646,6 +712,13 @@ impl GearSystemComponentHydraulicController {
             jammed_actuator_failure: Failure::new(FailureType::GearActuatorJammed(id)),
             jamming_position: Ratio::new::<ratio>(random_from_range(0., 1.)),
             jamming_is_effective: false,
+            door_actuator_failure: match GearSysComponentId::from(id) {
+                GearSysComponentId::Door => {
+                    Some(Failure::new(FailureType::GearDoorActuatorFault(id)))
+                }
+                GearSysComponentId::Gear => None,
+            },
+            is_mechanically_released: false,
             soft_downlock_is_active: false,
         }
     }
@@ -654,11 +727,13 @@ impl GearSystemComponentHydraulicController {
     fn update(
         &mut self,
         should_open: bool,
+        is_mechanically_released: bool,
         should_uplock: bool,
         should_downlock: bool,
         actual_position: Ratio,
     ) {
         self.actual_position = actual_position;
+        self.is_mechanically_released = is_mechanically_released;
 
         self.requested_position = if should_open {
             Ratio::new::<ratio>(1.1)
@@ -698,6 +773,12 @@ impl GearSystemComponentHydraulicController {
         }
     }
 
+    fn door_actuator_is_faulty(&self) -> bool {
+        self.door_actuator_failure
+            .as_ref()
+            .is_some_and(|failure| failure.is_active())
+    }
+
     fn update_soft_downlock(&mut self) {
         if self.is_soft_downlock {
             if (!self.is_inverted_control
@@ -728,6 +809,16 @@ impl HydraulicAssemblyController for GearSystemComponentHydraulicController {
             return LinearActuatorMode::ClosedValves;
         }
 
+        // A faulty door actuator keeps its valves closed so the door cannot be moved hydraulically.
+        // Gravity extension mechanically releases the door, the actuator then only damps its fall.
+        if self.door_actuator_is_faulty() {
+            return if self.is_mechanically_released {
+                LinearActuatorMode::ClosedCircuitDamping
+            } else {
+                LinearActuatorMode::ClosedValves
+            };
+        }
+
         if self.soft_downlock_is_active {
             LinearActuatorMode::ClosedValves
         } else {
@@ -760,6 +851,9 @@ impl ElectroHydrostaticPowered for GearSystemComponentHydraulicController {}
 impl SimulationElement for GearSystemComponentHydraulicController {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.jammed_actuator_failure.accept(visitor);
+        if let Some(failure) = &mut self.door_actuator_failure {
+            failure.accept(visitor);
+        }
 
         visitor.visit(self);
     }
@@ -12,6 +12,7 @@ use uom::si::{
     power::watt,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     torque::newton_meter,
     velocity::meter_per_second,
     volume::{cubic_meter, gallon},
@@ -19,10 +20,11 @@ use uom::si::{
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         interpolation, low_pass_filter::LowPassFilter, pid::PidController,
         random_from_normal_distribution, random_from_range, ConsumePower, ElectricalBusType,
-        ElectricalBuses,
+        ElectricalBuses, FlightControlSurfaceId,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -67,6 +69,10 @@ struct VariableSpeedPump {
     should_activate_electrical_mode: bool,
     powered_by: ElectricalBusType,
     consumed_power: Power,
+
+    motor_temperature: ThermodynamicTemperature,
+    motor_temperature_is_initialised: bool,
+    is_overheated: bool,
 }
 impl VariableSpeedPump {
     // Coefficient to convert hyd power in elec power 1.1 means we lose 10% efficiency from the pump
@@ -79,6 +85,13 @@ impl VariableSpeedPump {
 
     const LOW_PASS_RPM_TRANSIENT_TIME_CONSTANT: Duration = Duration::from_millis(100);
 
+    // Motor thermal model: heat capacity of the motor and its cooling to ambient air.
+    // With those values the motor stabilises around 30°C above ambient at static consumption
+    const MOTOR_HEAT_CAPACITY_JOULE_PER_CELSIUS: f64 = 2000.;
+    const MOTOR_COOLING_WATT_PER_CELSIUS: f64 = 5.;
+    const MOTOR_OVERHEAT_TEMPERATURE_DEGC: f64 = 170.;
+    const MOTOR_OVERHEAT_RESET_TEMPERATURE_DEGC: f64 = 120.;
+
     pub fn new(powered_by: ElectricalBusType) -> Self {
         Self {
             speed: LowPassFilter::<AngularVelocity>::new(
@@ -88,6 +101,10 @@ impl VariableSpeedPump {
             should_activate_electrical_mode: false,
             powered_by,
             consumed_power: Power::default(),
+
+            motor_temperature: ThermodynamicTemperature::default(),
+            motor_temperature_is_initialised: false,
+            is_overheated: false,
         }
     }
 
@@ -96,9 +113,9 @@ impl VariableSpeedPump {
         context: &UpdateContext,
         actuator_flow: VolumeRate,
         actuator_pressure: Pressure,
-        controller: &impl ElectroHydrostaticPowered,
+        should_activate_electrical_mode: bool,
     ) {
-        self.should_activate_electrical_mode = controller.should_activate_electrical_mode();
+        self.should_activate_electrical_mode = should_activate_electrical_mode;
 
         let new_speed = if self.is_active() {
             AngularVelocity::new::<revolution_per_minute>(
@@ -111,9 +128,42 @@ impl VariableSpeedPump {
         };
 
         self.update_power_consumed(actuator_flow, actuator_pressure);
+        self.update_motor_temperature(context);
         self.speed.update(context.delta(), new_speed);
     }
 
+    fn update_motor_temperature(&mut self, context: &UpdateContext) {
+        // The motor is soaked to ambient temperature when the simulation starts
+        if !self.motor_temperature_is_initialised {
+            self.motor_temperature = context.ambient_temperature();
+            self.motor_temperature_is_initialised = true;
+        }
+
+        // Fluid power is throttled back into heat within the closed loop so all consumed power
+        // ends up heating the motor pump unit
+        let heat_power_watt = self.consumed_power.get::<watt>();
+
+        let temperature_above_ambient_degc = self.motor_temperature.get::<degree_celsius>()
+            - context.ambient_temperature().get::<degree_celsius>();
+
+        let temperature_rate_degc_per_s = (heat_power_watt
+            - temperature_above_ambient_degc * Self::MOTOR_COOLING_WATT_PER_CELSIUS)
+            / Self::MOTOR_HEAT_CAPACITY_JOULE_PER_CELSIUS;
+
+        self.motor_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            self.motor_temperature.get::<degree_celsius>()
+                + temperature_rate_degc_per_s * context.delta_as_secs_f64(),
+        );
+
+        if self.motor_temperature.get::<degree_celsius>() > Self::MOTOR_OVERHEAT_TEMPERATURE_DEGC {
+            self.is_overheated = true;
+        } else if self.motor_temperature.get::<degree_celsius>()
+            < Self::MOTOR_OVERHEAT_RESET_TEMPERATURE_DEGC
+        {
+            self.is_overheated = false;
+        }
+    }
+
     fn update_power_consumed(&mut self, actuator_flow: VolumeRate, actuator_pressure: Pressure) {
         let current_fluid_power = Power::new::<watt>(
             actuator_pressure.get::<pascal>() * actuator_flow.get::<cubic_meter_per_second>(),
@@ -128,7 +178,7 @@ impl VariableSpeedPump {
     }
 
     fn is_active(&self) -> bool {
-        self.should_activate_electrical_mode && self.is_powered
+        self.should_activate_electrical_mode && self.is_powered && !self.is_overheated
     }
 
     fn max_available_pressure(&self, accumulator_pressure: Pressure) -> Pressure {
@@ -138,6 +188,16 @@ impl VariableSpeedPump {
             Pressure::default()
         }
     }
+
+    fn is_overheated(&self) -> bool {
+        self.is_overheated
+    }
+
+    #[cfg(test)]
+    fn set_motor_temperature(&mut self, temperature: ThermodynamicTemperature) {
+        self.motor_temperature = temperature;
+        self.motor_temperature_is_initialised = true;
+    }
 }
 impl SimulationElement for VariableSpeedPump {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -152,10 +212,11 @@ impl Debug for VariableSpeedPump {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "\nEHA pump => is powered {:?} / Is active? {:?} / Rpm {:0}",
+            "\nEHA pump => is powered {:?} / Is active? {:?} / Rpm {:0} / Motor temp {:.0}",
             self.is_powered,
             self.is_active(),
-            self.speed.output().get::<revolution_per_minute>()
+            self.speed.output().get::<revolution_per_minute>(),
+            self.motor_temperature.get::<degree_celsius>()
         )
     }
 }
@@ -263,6 +324,12 @@ pub trait ElectroHydrostaticPowered {
     fn should_activate_electrical_mode(&self) -> bool {
         false
     }
+
+    /// Allows an electrical backup hydraulic actuator to switch by itself to electrical mode
+    /// when its aircraft hydraulic supply is lost.
+    fn should_switch_to_electrical_mode_on_hydraulic_loss(&self) -> bool {
+        false
+    }
 }
 
 #[derive(PartialEq, Eq, Copy, Clone)]
@@ -271,19 +338,33 @@ pub enum ElectroHydrostaticActuatorType {
     ElectricalBackupHydraulicActuator, // Can run either in electric backup mode or from aircraft hydraulic pressure
 }
 
+/// Electrical backup of an actuator: a motor driven variable speed pump circulating fluid
+/// from a local low pressure accumulator acting as the actuator's own reservoir.
+///
+/// An electrical backup hydraulic actuator (EBHA) runs from aircraft hydraulics and
+/// switches to its electrical backup either on command or, if its controller allows it,
+/// automatically when the hydraulic supply pressure is lost.
 #[derive(PartialEq, Copy, Clone)]
 pub struct ElectroHydrostaticBackup {
     accumulator: LowPressureAccumulator,
     pump: VariableSpeedPump,
 
     backup_type: ElectroHydrostaticActuatorType,
+
+    hydraulic_supply_lost: bool,
+    electrical_mode_requested: bool,
 }
 impl ElectroHydrostaticBackup {
+    const HYDRAULIC_SUPPLY_LOST_PRESSURE_PSI: f64 = 1750.;
+    const HYDRAULIC_SUPPLY_RECOVERED_PRESSURE_PSI: f64 = 2500.;
+
     pub fn new(powered_by: ElectricalBusType, backup_type: ElectroHydrostaticActuatorType) -> Self {
         Self {
             accumulator: LowPressureAccumulator::new(),
             pump: VariableSpeedPump::new(powered_by),
             backup_type,
+            hydraulic_supply_lost: false,
+            electrical_mode_requested: false,
         }
     }
 
@@ -295,16 +376,35 @@ impl ElectroHydrostaticBackup {
         current_actuator_flow: VolumeRate,
         current_actuator_pressure: Pressure,
     ) {
+        self.update_hydraulic_supply_state(current_pressure);
+
+        self.electrical_mode_requested = controller.should_activate_electrical_mode()
+            || (self.can_move_using_aircraft_hydraulic_pressure()
+                && self.hydraulic_supply_lost
+                && controller.should_switch_to_electrical_mode_on_hydraulic_loss());
+
         self.accumulator
             .update(context, current_pressure, controller);
         self.pump.update(
             context,
             current_actuator_flow,
             current_actuator_pressure,
-            controller,
+            self.electrical_mode_requested,
         );
     }
 
+    fn update_hydraulic_supply_state(&mut self, current_pressure: Pressure) {
+        if current_pressure.get::<psi>() < Self::HYDRAULIC_SUPPLY_LOST_PRESSURE_PSI {
+            self.hydraulic_supply_lost = true;
+        } else if current_pressure.get::<psi>() > Self::HYDRAULIC_SUPPLY_RECOVERED_PRESSURE_PSI {
+            self.hydraulic_supply_lost = false;
+        }
+    }
+
+    fn is_electrical_mode_requested(&self) -> bool {
+        self.electrical_mode_requested
+    }
+
     fn max_available_pressure(&self) -> Pressure {
         self.pump
             .max_available_pressure(self.accumulator.pressure())
@@ -321,6 +421,10 @@ impl ElectroHydrostaticBackup {
     fn is_electrical_mode_active(&self) -> bool {
         self.pump.is_active()
     }
+
+    fn is_motor_overheated(&self) -> bool {
+        self.pump.is_overheated()
+    }
 }
 impl SimulationElement for ElectroHydrostaticBackup {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -346,7 +450,9 @@ impl Debug for ElectroHydrostaticBackup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "\nEHA system => Acc pressure {:?} / Max pressure {:.0} Pump {:?}",
+            "\nEHA system => Hyd supply lost {:?} / Motor overheat {:?} / Acc pressure {:?} / Max pressure {:.0} Pump {:?}",
+            self.hydraulic_supply_lost,
+            self.is_motor_overheated(),
             self.accumulator_pressure().get::<psi>(),
             self.max_available_pressure().get::<psi>(),
             self.pump
@@ -415,6 +521,8 @@ struct CoreHydraulicForce {
     has_flow_restriction: bool,
     max_working_pressure: Pressure,
 
+    fluid_viscosity_flow_ratio: Ratio,
+
     locks_position_in_closed_mode: bool,
     is_soft_locked: bool,
     soft_lock_velocity: (AngularVelocity, AngularVelocity),
@@ -515,12 +623,18 @@ impl CoreHydraulicForce {
             has_flow_restriction,
             max_working_pressure,
 
+            fluid_viscosity_flow_ratio: Ratio::new::<ratio>(1.),
+
             locks_position_in_closed_mode,
             is_soft_locked: locks_position_in_closed_mode,
             soft_lock_velocity: soft_lock_velocity.unwrap_or_default(),
         }
     }
 
+    fn set_fluid_viscosity_flow_ratio(&mut self, flow_ratio: Ratio) {
+        self.fluid_viscosity_flow_ratio = flow_ratio;
+    }
+
     fn update_force(
         &mut self,
         context: &UpdateContext,
@@ -849,7 +963,9 @@ impl CoreHydraulicForce {
             1.
         };
 
-        let pressure_corrected_openloop_target = open_loop_flow_target * pressure_correction_factor;
+        let pressure_corrected_openloop_target = open_loop_flow_target
+            * pressure_correction_factor
+            * self.fluid_viscosity_flow_ratio.get::<ratio>();
         self.pid_controller
             .change_setpoint(pressure_corrected_openloop_target.get::<gallon_per_second>());
 
@@ -963,6 +1079,11 @@ pub struct LinearActuator {
     total_volume_to_actuator: Volume,
     total_volume_to_reservoir: Volume,
 
+    external_leak_flow: VolumeRate,
+    total_volume_leaked: Volume,
+
+    is_runaway: bool,
+
     requested_position: Ratio,
 
     core_hydraulics: CoreHydraulicForce,
@@ -970,6 +1091,9 @@ pub struct LinearActuator {
     electro_hydrostatic_backup: Option<ElectroHydrostaticBackup>,
 }
 impl LinearActuator {
+    // Pressure at which external leak flow is specified
+    const EXTERNAL_LEAK_NOMINAL_PRESSURE_PSI: f64 = 3000.;
+
     pub fn new(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
@@ -1060,6 +1184,11 @@ impl LinearActuator {
             total_volume_to_actuator: Volume::new::<gallon>(0.),
             total_volume_to_reservoir: Volume::new::<gallon>(0.),
 
+            external_leak_flow: VolumeRate::default(),
+            total_volume_leaked: Volume::default(),
+
+            is_runaway: false,
+
             requested_position: Ratio::new::<ratio>(0.),
 
             core_hydraulics: CoreHydraulicForce::new(
@@ -1113,23 +1242,28 @@ impl LinearActuator {
                 eha.can_move_using_aircraft_hydraulic_pressure();
         }
 
-        let internal_actuator_pressure = if controller.should_activate_electrical_mode()
-            && self.electro_hydrostatic_backup.is_some()
+        let internal_actuator_pressure = if let Some(ehba) = self
+            .electro_hydrostatic_backup
+            .filter(|eha| eha.is_electrical_mode_requested())
         {
-            match self.electro_hydrostatic_backup {
-                Some(ehba) => ehba.max_available_pressure(),
-                None => unreachable!(),
-            }
+            ehba.max_available_pressure()
         } else if can_move_using_aircraft_hydraulic_pressure {
             current_input_pressure
         } else {
             Pressure::default()
         };
 
+        // A runaway servo valve drives the actuator regardless of what its controller demands
+        let requested_mode = if self.is_runaway {
+            LinearActuatorMode::PositionControl
+        } else {
+            controller.requested_mode()
+        };
+
         self.core_hydraulics.update_force(
             context,
             self.requested_position,
-            controller.requested_mode(),
+            requested_mode,
             self.position_normalized,
             internal_actuator_pressure,
             self.signed_flow,
@@ -1198,6 +1332,30 @@ impl LinearActuator {
         }
     }
 
+    fn set_runaway(&mut self, is_runaway: bool) {
+        self.is_runaway = is_runaway;
+    }
+
+    /// Sets the ratio of nominal flow the actuator can get depending on its circuit fluid viscosity
+    pub fn set_fluid_viscosity_flow_ratio(&mut self, flow_ratio: Ratio) {
+        self.core_hydraulics
+            .set_fluid_viscosity_flow_ratio(flow_ratio);
+    }
+
+    /// Sets the flow lost overboard at nominal pressure, zero if actuator is not leaking
+    pub fn set_external_leak(&mut self, leak_flow: VolumeRate) {
+        self.external_leak_flow = leak_flow;
+    }
+
+    fn update_external_leak(&mut self, context: &UpdateContext, current_input_pressure: Pressure) {
+        let pressure_ratio = ((current_input_pressure - Pressure::new::<psi>(14.7))
+            / Pressure::new::<psi>(Self::EXTERNAL_LEAK_NOMINAL_PRESSURE_PSI))
+        .max(Ratio::default());
+
+        self.total_volume_leaked +=
+            self.external_leak_flow * context.delta_as_time() * pressure_ratio;
+    }
+
     fn eha_backup_is_active(&self) -> bool {
         self.electro_hydrostatic_backup
             .is_some_and(|eha| eha.is_electrical_mode_active())
@@ -1236,7 +1394,7 @@ impl Actuator for LinearActuator {
             eha_volume_used = eha.used_volume();
         }
 
-        self.total_volume_to_actuator + eha_volume_used
+        self.total_volume_to_actuator + eha_volume_used + self.total_volume_leaked
     }
 
     fn reservoir_return(&self) -> Volume {
@@ -1246,6 +1404,7 @@ impl Actuator for LinearActuator {
     fn reset_volumes(&mut self) {
         self.total_volume_to_reservoir = Volume::new::<gallon>(0.);
         self.total_volume_to_actuator = Volume::new::<gallon>(0.);
+        self.total_volume_leaked = Volume::new::<gallon>(0.);
 
         if let Some(eha) = self.electro_hydrostatic_backup.as_mut() {
             eha.reset_volumes();
@@ -1331,6 +1490,10 @@ pub fn get_more_restrictive_soft_lock_velocities(
 pub struct HydraulicLinearActuatorAssembly<const N: usize> {
     linear_actuators: [LinearActuator; N],
     rigid_body: LinearActuatedRigidBodyOnHingeAxis,
+
+    actuators_jammed: [bool; N],
+    jammed_position: Option<Ratio>,
+    is_disconnected: bool,
 }
 impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
     pub fn new(
@@ -1340,6 +1503,10 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         Self {
             linear_actuators,
             rigid_body,
+
+            actuators_jammed: [false; N],
+            jammed_position: None,
+            is_disconnected: false,
         }
     }
 
@@ -1348,6 +1515,36 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         &mut self.linear_actuators[index]
     }
 
+    pub fn set_actuator_external_leak(&mut self, index: usize, leak_flow: VolumeRate) {
+        assert!(index < N);
+        self.linear_actuators[index].set_external_leak(leak_flow);
+    }
+
+    /// Cold and viscous fluid reduces the flow each actuator gets from its circuit
+    pub fn set_fluid_viscosity_flow_ratios(&mut self, flow_ratios: [Ratio; N]) {
+        for (actuator, flow_ratio) in self.linear_actuators.iter_mut().zip(flow_ratios) {
+            actuator.set_fluid_viscosity_flow_ratio(flow_ratio);
+        }
+    }
+
+    /// A jammed actuator holds the body at the position it had when jam occurred
+    pub fn set_actuator_jammed(&mut self, index: usize, is_jammed: bool) {
+        assert!(index < N);
+        self.actuators_jammed[index] = is_jammed;
+    }
+
+    /// A runaway actuator drives the body to its full extension position
+    pub fn set_actuator_runaway(&mut self, index: usize, is_runaway: bool) {
+        assert!(index < N);
+        self.linear_actuators[index].set_runaway(is_runaway);
+    }
+
+    /// A disconnected body is not held by its actuators anymore and only moves from
+    /// aerodynamic and inertial forces
+    pub fn set_disconnected(&mut self, is_disconnected: bool) {
+        self.is_disconnected = is_disconnected;
+    }
+
     pub fn body(&mut self) -> &mut impl AerodynamicBody {
         &mut self.rigid_body
     }
@@ -1365,14 +1562,29 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         current_pressure: [Pressure; N],
     ) {
         for (index, actuator) in self.linear_actuators.iter_mut().enumerate() {
-            actuator.set_position_target(
+            actuator.set_position_target(if actuator.is_runaway {
+                Ratio::new::<ratio>(1.)
+            } else {
                 self.rigid_body
                     .linear_actuator_pos_normalized_from_angular_position_normalized(
                         assembly_controllers[index].requested_position(),
-                    ),
-            );
+                    )
+            });
+        }
+
+        for (index, actuator) in self.linear_actuators.iter_mut().enumerate() {
+            actuator.update_external_leak(context, current_pressure[index]);
         }
 
+        if self.is_disconnected {
+            self.rigid_body.unlock();
+            self.rigid_body.soft_unlock();
+            self.rigid_body.update(context);
+
+            return;
+        }
+
+        self.update_jammed_position();
         self.update_hard_lock_mechanism(assembly_controllers);
 
         if !self.rigid_body.is_locked() {
@@ -1397,10 +1609,24 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         }
     }
 
+    fn update_jammed_position(&mut self) {
+        if !self.actuators_jammed.iter().any(|&is_jammed| is_jammed) {
+            self.jammed_position = None;
+        } else if self.jammed_position.is_none() {
+            self.jammed_position = Some(self.rigid_body.position_normalized());
+        }
+    }
+
     fn update_hard_lock_mechanism(
         &mut self,
         assembly_controllers: &[impl HydraulicAssemblyController],
     ) {
+        if let Some(jammed_position) = self.jammed_position {
+            self.rigid_body.lock_at_position_normalized(jammed_position);
+
+            return;
+        }
+
         // The first controller requesting a lock locks the body
         let mut no_lock = true;
         for controller in assembly_controllers {
@@ -1528,6 +1754,62 @@ impl Debug for HydraulicLinearActuatorAssembly<3> {
     }
 }
 
+/// Failures of a flight control surface and of each of its N actuators.
+/// Actuator indexes are the ones used by the owning hydraulic assembly.
+pub struct FlightControlSurfaceFailures<const N: usize> {
+    actuator_leaks: [Failure; N],
+    actuator_jams: [Failure; N],
+    actuator_runaways: [Failure; N],
+    disconnect: Failure,
+}
+impl<const N: usize> FlightControlSurfaceFailures<N> {
+    // External leak flow of a single actuator at nominal pressure when failed
+    const ACTUATOR_EXTERNAL_LEAK_GAL_P_S: f64 = 0.03;
+
+    pub fn new(surface_id: FlightControlSurfaceId) -> Self {
+        Self {
+            actuator_leaks: std::array::from_fn(|index| {
+                Failure::new(FailureType::FlightControlActuatorLeak(surface_id, index))
+            }),
+            actuator_jams: std::array::from_fn(|index| {
+                Failure::new(FailureType::FlightControlActuatorJam(surface_id, index))
+            }),
+            actuator_runaways: std::array::from_fn(|index| {
+                Failure::new(FailureType::FlightControlActuatorRunaway(surface_id, index))
+            }),
+            disconnect: Failure::new(FailureType::FlightControlSurfaceDisconnect(surface_id)),
+        }
+    }
+
+    pub fn apply_to(&self, hydraulic_assembly: &mut HydraulicLinearActuatorAssembly<N>) {
+        for index in 0..N {
+            hydraulic_assembly.set_actuator_external_leak(
+                index,
+                if self.actuator_leaks[index].is_active() {
+                    VolumeRate::new::<gallon_per_second>(Self::ACTUATOR_EXTERNAL_LEAK_GAL_P_S)
+                } else {
+                    VolumeRate::default()
+                },
+            );
+            hydraulic_assembly.set_actuator_jammed(index, self.actuator_jams[index].is_active());
+            hydraulic_assembly
+                .set_actuator_runaway(index, self.actuator_runaways[index].is_active());
+        }
+
+        hydraulic_assembly.set_disconnected(self.disconnect.is_active());
+    }
+}
+impl<const N: usize> SimulationElement for FlightControlSurfaceFailures<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.actuator_leaks, visitor);
+        accept_iterable!(self.actuator_jams, visitor);
+        accept_iterable!(self.actuator_runaways, visitor);
+        self.disconnect.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 /// Represent any physical object able to rotate on a hinge axis.
 /// It can be a gear, elevator, cargo door, etc. Only one rotation degree of freedom is handled.
 /// An linear actuator or multiple linear actuators can apply forces to its control arm.
@@ -2011,6 +2293,7 @@ mod tests {
 
         should_activate_elec_backup: bool,
         should_activate_elec_backup_refill: bool,
+        should_switch_elec_backup_on_hydraulic_loss: bool,
     }
     impl TestHydraulicAssemblyController {
         fn new() -> Self {
@@ -2025,6 +2308,7 @@ mod tests {
 
                 should_activate_elec_backup: false,
                 should_activate_elec_backup_refill: false,
+                should_switch_elec_backup_on_hydraulic_loss: false,
             }
         }
 
@@ -2057,6 +2341,10 @@ mod tests {
         fn set_elec_backup_refill(&mut self, is_on: bool) {
             self.should_activate_elec_backup_refill = is_on;
         }
+
+        fn set_elec_backup_switchover(&mut self, is_allowed: bool) {
+            self.should_switch_elec_backup_on_hydraulic_loss = is_allowed;
+        }
     }
     impl HydraulicAssemblyController for TestHydraulicAssemblyController {
         fn requested_mode(&self) -> LinearActuatorMode {
@@ -2092,6 +2380,10 @@ mod tests {
         fn should_open_refill_valve(&self) -> bool {
             self.should_activate_elec_backup_refill
         }
+
+        fn should_switch_to_electrical_mode_on_hydraulic_loss(&self) -> bool {
+            self.should_switch_elec_backup_on_hydraulic_loss
+        }
     }
 
     struct TestAerodynamicModel {
@@ -2192,6 +2484,39 @@ mod tests {
             self.controllers[actuator_id].set_elec_backup_refill(is_active);
         }
 
+        fn command_electro_backup_switchover(&mut self, is_allowed: bool, actuator_id: usize) {
+            assert!(actuator_id < N);
+            self.controllers[actuator_id].set_elec_backup_switchover(is_allowed);
+        }
+
+        fn command_eha_motor_temperature(
+            &mut self,
+            temperature: ThermodynamicTemperature,
+            actuator_id: usize,
+        ) {
+            assert!(actuator_id < N);
+            if let Some(eha) = self.hydraulic_assembly.linear_actuators[actuator_id]
+                .electro_hydrostatic_backup
+                .as_mut()
+            {
+                eha.pump.set_motor_temperature(temperature);
+            }
+        }
+
+        fn eha_motor_temperature(&self, actuator_id: usize) -> ThermodynamicTemperature {
+            self.hydraulic_assembly.linear_actuators[actuator_id]
+                .electro_hydrostatic_backup
+                .map_or(ThermodynamicTemperature::default(), |eha| {
+                    eha.pump.motor_temperature
+                })
+        }
+
+        fn eha_motor_is_overheated(&self, actuator_id: usize) -> bool {
+            self.hydraulic_assembly.linear_actuators[actuator_id]
+                .electro_hydrostatic_backup
+                .is_some_and(|eha| eha.is_motor_overheated())
+        }
+
         fn accumulator_pressure(&self, actuator_id: usize) -> Pressure {
             if let Some(eha) =
                 self.hydraulic_assembly.linear_actuators[actuator_id].electro_hydrostatic_backup
@@ -3920,6 +4245,105 @@ mod tests {
         assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.8));
     }
 
+    #[test]
+    fn spoiler_electro_hydrostatic_switches_to_electrical_mode_on_hydraulic_loss() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        // Accumulator is charged from aircraft hydraulics before the supply is lost
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(3000.)]));
+        test_bed.command(|a| a.command_electro_backup_refill(true, 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(5.));
+
+        test_bed.command(|a| a.command_electro_backup_refill(false, 0));
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(0.)]));
+
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.01));
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| a.command_electro_backup_switchover(true, 0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.8));
+
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.8));
+    }
+
+    #[test]
+    fn spoiler_electro_hydrostatic_does_not_switch_to_electrical_mode_with_hydraulic_pressure() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(3000.)]));
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| a.command_electro_backup_switchover(true, 0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.8));
+
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.95));
+        assert!(
+            test_bed
+                .query(|a| a.current_power_consumption())
+                .get::<watt>()
+                < 100.
+        );
+    }
+
+    #[test]
+    fn electro_hydrostatic_motor_starts_at_ambient_temperature() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(40.));
+
+        test_bed.run_with_delta(Duration::from_secs_f64(0.1));
+
+        assert!(
+            (test_bed
+                .query(|a| a.eha_motor_temperature(0))
+                .get::<degree_celsius>()
+                - 40.)
+                .abs()
+                < 0.1
+        );
+    }
+
+    #[test]
+    fn electro_hydrostatic_overheated_motor_cannot_move_until_cooled_down() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = spoiler_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(0.)]));
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| {
+            a.command_eha_motor_temperature(
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+                0,
+            )
+        });
+        test_bed.command(|a| a.command_electro_backup(true, 0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.), 0));
+        test_bed.run_with_delta(Duration::from_secs_f64(0.8));
+
+        assert!(test_bed.query(|a| a.eha_motor_is_overheated(0)));
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.01));
+
+        test_bed.run_with_delta(Duration::from_secs_f64(400.));
+
+        assert!(!test_bed.query(|a| a.eha_motor_is_overheated(0)));
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.8));
+    }
+
     #[test]
     fn electro_hydrostatic_accumulator_pressure_increase_when_refilled() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -1,6 +1,7 @@
 use self::brake_circuit::BrakeAccumulatorCharacteristics;
 use self::linear_actuator::Actuator;
 use crate::failures::{Failure, FailureType};
+use crate::fuel::FUEL_GALLONS_TO_KG;
 use crate::hydraulic::{
     electrical_pump_physics::ElectricalPumpPhysics, pumps::PumpCharacteristics,
 };
@@ -11,7 +12,8 @@ use crate::physics::{GravityEffect, WobblePhysics};
 use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, random_from_normal_distribution,
     random_from_range, AirbusElectricPumpId, AirbusEngineDrivenPumpId, DelayedTrueLogicGate,
-    ElectricalBusType, ElectricalBuses, HydraulicColor, RamAirTurbineController, SectionPressure,
+    ElectricalBusType, ElectricalBuses, HydraulicColor, HydraulicSectionId,
+    RamAirTurbineController, SectionPressure,
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -25,16 +27,21 @@ use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     f64::*,
     length::meter,
+    mass::kilogram,
+    power::watt,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     torque::{newton_meter, pound_force_inch},
     volume::{cubic_inch, cubic_meter, gallon},
-    volume_rate::{gallon_per_minute, gallon_per_second},
+    volume_rate::{cubic_meter_per_second, gallon_per_minute, gallon_per_second},
 };
 
 pub mod aerodynamic_model;
+pub mod anti_skid;
 pub mod brake;
 pub mod brake_circuit;
+pub mod brake_to_vacate;
 pub mod bypass_pin;
 pub mod cargo_doors;
 pub mod command_sensor_unit;
@@ -84,9 +91,20 @@ pub trait PressureSource {
     fn displacement(&self) -> Volume;
 }
 
+/// Hydraulic fluid of a circuit.
+///
+/// Fluid temperature integrates all the hydraulic power used in the circuit, as every pumped
+/// watt ends up as heat through pump losses, actuator work and internal leakage.
+/// It is cooled by ambient air and by the heat exchanger located in a fuel tank when fluid
+/// flows through it. Cold fluid is more viscous, which reduces what pumps can draw from reservoir
+/// and slows down actuators.
 pub struct Fluid {
     current_bulk: Pressure,
     heat_state: HeatingProperties,
+
+    temperature: ThermodynamicTemperature,
+    temperature_is_initialised: bool,
+    heat_capacity_joule_per_celsius: f64,
 }
 impl Fluid {
     const HEATING_TIME_CONSTANT_MEAN_S: f64 = 40.;
@@ -95,9 +113,33 @@ impl Fluid {
     const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
     const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
 
-    pub fn new(bulk: Pressure) -> Self {
+    const DENSITY_KG_PER_CUBIC_METER: f64 = 1000.;
+    const SPECIFIC_HEAT_JOULE_PER_KG_CELSIUS: f64 = 1700.;
+    // Pipes, pumps and actuators bodies heat up with the fluid
+    const HARDWARE_HEAT_CAPACITY_FACTOR: f64 = 2.;
+
+    const AMBIENT_COOLING_WATT_PER_CELSIUS: f64 = 40.;
+
+    // Heat generated by an overheating pump or ptu on top of the hydraulic power
+    const OVERHEATING_SOURCE_HEAT_WATT: f64 = 15000.;
+
+    const WARM_START_TEMPERATURE_DEGC: f64 = 40.;
+
+    const VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGC: [f64; 5] = [-55., -40., -25., -10., 0.];
+    const VISCOSITY_FLOW_RATIO_MAP: [f64; 5] = [0.4, 0.6, 0.8, 0.95, 1.];
+
+    pub fn new(bulk: Pressure, total_volume: Volume, starts_warm: bool) -> Self {
         Self {
             current_bulk: bulk,
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::WARM_START_TEMPERATURE_DEGC,
+            ),
+            // Cold starts are cold soaked to ambient temperature on first update
+            temperature_is_initialised: starts_warm,
+            heat_capacity_joule_per_celsius: total_volume.get::<cubic_meter>()
+                * Self::DENSITY_KG_PER_CUBIC_METER
+                * Self::SPECIFIC_HEAT_JOULE_PER_KG_CELSIUS
+                * Self::HARDWARE_HEAT_CAPACITY_FACTOR,
             heat_state: HeatingProperties::new(
                 Duration::from_secs_f64(
                     random_from_normal_distribution(
@@ -116,8 +158,68 @@ impl Fluid {
         self.current_bulk
     }
 
-    fn update(&mut self, context: &UpdateContext, is_heating: bool) {
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// Ratio of nominal flow that can go through pumps and actuators depending on fluid viscosity
+    pub fn viscosity_flow_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGC,
+            &Self::VISCOSITY_FLOW_RATIO_MAP,
+            self.temperature.get::<degree_celsius>(),
+        ))
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_heating: bool,
+        hydraulic_power: Power,
+        heat_exchanger: Option<(&mut HeatExchanger, VolumeRate)>,
+    ) {
         self.heat_state.update(context, is_heating);
+
+        self.update_temperature(context, is_heating, hydraulic_power, heat_exchanger);
+    }
+
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        is_heating: bool,
+        hydraulic_power: Power,
+        heat_exchanger: Option<(&mut HeatExchanger, VolumeRate)>,
+    ) {
+        if !self.temperature_is_initialised {
+            self.temperature = context.ambient_temperature();
+            self.temperature_is_initialised = true;
+        }
+
+        let heating_power_watt = hydraulic_power.get::<watt>().max(0.)
+            + if is_heating {
+                Self::OVERHEATING_SOURCE_HEAT_WATT
+            } else {
+                0.
+            };
+
+        let temperature_above_ambient_degc = self.temperature.get::<degree_celsius>()
+            - context.ambient_temperature().get::<degree_celsius>();
+
+        let mut cooling_power_watt =
+            temperature_above_ambient_degc * Self::AMBIENT_COOLING_WATT_PER_CELSIUS;
+
+        if let Some((heat_exchanger, flow)) = heat_exchanger {
+            let heat_exchanger_power = heat_exchanger.cooling_power(self.temperature, flow);
+            heat_exchanger.update(context, heat_exchanger_power);
+
+            cooling_power_watt += heat_exchanger_power.get::<watt>();
+        }
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            self.temperature.get::<degree_celsius>()
+                + (heating_power_watt - cooling_power_watt) * context.delta_as_secs_f64()
+                    / self.heat_capacity_joule_per_celsius,
+        );
     }
 }
 impl HeatingElement for Fluid {
@@ -130,6 +232,93 @@ impl HeatingElement for Fluid {
     }
 }
 
+/// Fuel cooled heat exchanger of a hydraulic circuit, immersed at the bottom of a fuel tank.
+///
+/// Heat taken from the hydraulic fluid warms up the fuel around it, which otherwise slowly
+/// follows ambient temperature. It stops cooling the fluid once the tank fuel level uncovers it.
+pub struct HeatExchanger {
+    fuel_tank_quantity_id: VariableIdentifier,
+    fuel_quantity: Mass,
+
+    fuel_temperature: ThermodynamicTemperature,
+    fuel_temperature_is_initialised: bool,
+}
+impl HeatExchanger {
+    const COOLING_WATT_PER_CELSIUS: f64 = 60.;
+    // Flow through the heat exchanger from which it reaches full cooling efficiency
+    const NOMINAL_FLOW_GPM: f64 = 2.;
+
+    // Fuel quantity under which the heat exchanger is not fully immersed anymore
+    const FULLY_COVERED_FUEL_QUANTITY_KG: f64 = 200.;
+    const FUEL_SPECIFIC_HEAT_JOULE_PER_KG_CELSIUS: f64 = 2000.;
+    const FUEL_AMBIENT_TIME_CONSTANT: Duration = Duration::from_secs(60 * 60);
+
+    pub fn new(context: &mut InitContext, fuel_tank_quantity_id: &str) -> Self {
+        Self {
+            fuel_tank_quantity_id: context.get_identifier(fuel_tank_quantity_id.to_owned()),
+            fuel_quantity: Mass::default(),
+            fuel_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            fuel_temperature_is_initialised: false,
+        }
+    }
+
+    pub fn fuel_temperature(&self) -> ThermodynamicTemperature {
+        self.fuel_temperature
+    }
+
+    fn fuel_coverage(&self) -> f64 {
+        (self.fuel_quantity.get::<kilogram>() / Self::FULLY_COVERED_FUEL_QUANTITY_KG).clamp(0., 1.)
+    }
+
+    fn cooling_power(
+        &self,
+        fluid_temperature: ThermodynamicTemperature,
+        flow: VolumeRate,
+    ) -> Power {
+        let flow_efficiency =
+            (flow.get::<gallon_per_minute>() / Self::NOMINAL_FLOW_GPM).clamp(0., 1.);
+
+        Power::new::<watt>(
+            (fluid_temperature.get::<degree_celsius>()
+                - self.fuel_temperature.get::<degree_celsius>())
+                * flow_efficiency
+                * self.fuel_coverage()
+                * Self::COOLING_WATT_PER_CELSIUS,
+        )
+    }
+
+    fn update(&mut self, context: &UpdateContext, heat_to_fuel: Power) {
+        let ambient_temperature_degc = context.ambient_temperature().get::<degree_celsius>();
+
+        if !self.fuel_temperature_is_initialised {
+            self.fuel_temperature = context.ambient_temperature();
+            self.fuel_temperature_is_initialised = true;
+        }
+
+        let mut fuel_temperature_degc = self.fuel_temperature.get::<degree_celsius>();
+
+        if self.fuel_quantity > Mass::default() {
+            fuel_temperature_degc += heat_to_fuel.get::<watt>() * context.delta_as_secs_f64()
+                / (self.fuel_quantity.get::<kilogram>()
+                    * Self::FUEL_SPECIFIC_HEAT_JOULE_PER_KG_CELSIUS);
+        }
+
+        fuel_temperature_degc += (ambient_temperature_degc - fuel_temperature_degc)
+            * (1.
+                - (-context.delta_as_secs_f64() / Self::FUEL_AMBIENT_TIME_CONSTANT.as_secs_f64())
+                    .exp());
+
+        self.fuel_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(fuel_temperature_degc);
+    }
+}
+impl SimulationElement for HeatExchanger {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let fuel_quantity_gallons: f64 = reader.read(&self.fuel_tank_quantity_id);
+        self.fuel_quantity = Mass::new::<kilogram>(fuel_quantity_gallons * FUEL_GALLONS_TO_KG);
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum PressureSwitchState {
     Pressurised,
@@ -963,6 +1152,8 @@ impl Accumulator {
 /// Each pump section has its own pressure, and so does system section.
 /// Flow is distributed from pump sections to system section according to regulation state and pressure difference.
 pub struct HydraulicCircuit {
+    fluid_temperature_id: VariableIdentifier,
+
     pump_sections: Vec<Section>,
     system_section: Section,
     auxiliary_section: Option<Section>,
@@ -973,6 +1164,7 @@ pub struct HydraulicCircuit {
     pump_section_routed_to_auxiliary_section: Vec<bool>,
 
     fluid: Fluid,
+    heat_exchanger: Option<HeatExchanger>,
     reservoir: Reservoir,
 
     circuit_target_pressure: Pressure,
@@ -987,8 +1179,17 @@ impl HydraulicCircuit {
     const SYSTEM_SECTION_STATIC_LEAK_GAL_P_S: f64 = 0.03;
     const AUX_SECTION_STATIC_LEAK_GAL_P_S: f64 = 0.001;
 
+    // External leak rates at nominal circuit pressure when the related failure is active
+    const SECTION_EXTERNAL_LEAK_GAL_P_S: f64 = 0.05;
+    const PUMP_CASE_DRAIN_EXTERNAL_LEAK_GAL_P_S: f64 = 0.02;
+    const PTU_EXTERNAL_LEAK_GAL_P_S: f64 = 0.03;
+
     const FLUID_BULK_MODULUS_PASCAL: f64 = 1450000000.0;
 
+    // Pumps hydraulic power to fluid heat, accounting for pump internal losses
+    const PUMP_EFFICIENCY: f64 = 0.85;
+    const PTU_ROTATING_HEAT_WATT: f64 = 3000.;
+
     // TODO firevalves are actually powered by a sub-bus (401PP DC ESS)
     const DEFAULT_FIRE_VALVE_POWERING_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentEssential;
@@ -1018,6 +1219,7 @@ impl HydraulicCircuit {
         priority_valve: PriorityValve,
         system_accumulator_precharge: Pressure,
         system_accumulator_volume: Volume,
+        heat_exchanger_fuel_tank_quantity_id: Option<&str>,
     ) -> Self {
         assert!(number_of_pump_sections > 0);
 
@@ -1034,7 +1236,7 @@ impl HydraulicCircuit {
                 Self::DEFAULT_FIRE_VALVE_POWERING_BUS,
             ));
 
-            pump_sections.push(Section::new(
+            let mut pump_section = Section::new(
                 context,
                 id,
                 "PUMP",
@@ -1052,7 +1254,16 @@ impl HydraulicCircuit {
                 false,
                 None,
                 None,
+            );
+            pump_section.add_external_leak(ExternalLeak::new(
+                FailureType::HydraulicSectionLeak(id, HydraulicSectionId::Pump(pump_id)),
+                VolumeRate::new::<gallon_per_second>(Self::SECTION_EXTERNAL_LEAK_GAL_P_S),
+            ));
+            pump_section.add_external_leak(ExternalLeak::new(
+                FailureType::PumpCaseDrainLeak(id, pump_id),
+                VolumeRate::new::<gallon_per_second>(Self::PUMP_CASE_DRAIN_EXTERNAL_LEAK_GAL_P_S),
             ));
+            pump_sections.push(pump_section);
 
             pump_to_system_check_valves.push(CheckValve::new());
 
@@ -1063,58 +1274,85 @@ impl HydraulicCircuit {
             - Volume::new::<gallon>(Self::PUMP_SECTION_MAX_VOLUME_GAL)
                 * number_of_pump_sections as f64;
 
-        Self {
-            pump_sections,
-            system_section: Section::new(
+        let mut system_section = Section::new(
+            context,
+            id,
+            "SYSTEM",
+            1,
+            VolumeRate::new::<gallon_per_second>(Self::SYSTEM_SECTION_STATIC_LEAK_GAL_P_S),
+            system_section_volume * priming_volume,
+            system_section_volume,
+            Some(Accumulator::new_system_accumulator(
+                system_accumulator_precharge,
+                system_accumulator_volume,
+                Volume::new::<gallon>(0.),
+                circuit_target_pressure,
+            )),
+            system_pressure_switch_lo_hyst,
+            system_pressure_switch_hi_hyst,
+            None,
+            connected_to_ptu_left_side,
+            connected_to_ptu_right_side,
+            Some(LeakMeasurementValve::new(
+                Self::DEFAULT_LEAK_MEASUREMENT_VALVE_POWERING_BUS,
+            )),
+            Some(priority_valve),
+        );
+        system_section.add_external_leak(ExternalLeak::new(
+            FailureType::HydraulicSectionLeak(id, HydraulicSectionId::System),
+            VolumeRate::new::<gallon_per_second>(Self::SECTION_EXTERNAL_LEAK_GAL_P_S),
+        ));
+        if connected_to_ptu_left_side || connected_to_ptu_right_side {
+            system_section.add_external_leak(ExternalLeak::new(
+                FailureType::PtuLeak(id),
+                VolumeRate::new::<gallon_per_second>(Self::PTU_EXTERNAL_LEAK_GAL_P_S),
+            ));
+        }
+
+        let auxiliary_section = if has_auxiliary_section {
+            let mut auxiliary_section = Section::new(
                 context,
                 id,
-                "SYSTEM",
+                "AUXILIARY",
                 1,
-                VolumeRate::new::<gallon_per_second>(Self::SYSTEM_SECTION_STATIC_LEAK_GAL_P_S),
-                system_section_volume * priming_volume,
-                system_section_volume,
-                Some(Accumulator::new_system_accumulator(
-                    system_accumulator_precharge,
-                    system_accumulator_volume,
-                    Volume::new::<gallon>(0.),
-                    circuit_target_pressure,
-                )),
+                VolumeRate::new::<gallon_per_second>(Self::AUX_SECTION_STATIC_LEAK_GAL_P_S),
+                system_section_volume
+                    * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO
+                    * priming_volume,
+                system_section_volume * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO,
+                None,
                 system_pressure_switch_lo_hyst,
                 system_pressure_switch_hi_hyst,
                 None,
-                connected_to_ptu_left_side,
-                connected_to_ptu_right_side,
-                Some(LeakMeasurementValve::new(
-                    Self::DEFAULT_LEAK_MEASUREMENT_VALVE_POWERING_BUS,
-                )),
-                Some(priority_valve),
-            ),
-            auxiliary_section: if has_auxiliary_section {
-                Some(Section::new(
-                    context,
-                    id,
-                    "AUXILIARY",
-                    1,
-                    VolumeRate::new::<gallon_per_second>(Self::AUX_SECTION_STATIC_LEAK_GAL_P_S),
-                    system_section_volume
-                        * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO
-                        * priming_volume,
-                    system_section_volume * Self::AUXILIARY_TO_SYSTEM_SECTION_SIZE_RATIO,
-                    None,
-                    system_pressure_switch_lo_hyst,
-                    system_pressure_switch_hi_hyst,
-                    None,
-                    false,
-                    false,
-                    None,
-                    None,
-                ))
-            } else {
-                None
-            },
+                false,
+                false,
+                None,
+                None,
+            );
+            auxiliary_section.add_external_leak(ExternalLeak::new(
+                FailureType::HydraulicSectionLeak(id, HydraulicSectionId::Auxiliary),
+                VolumeRate::new::<gallon_per_second>(Self::SECTION_EXTERNAL_LEAK_GAL_P_S),
+            ));
+            Some(auxiliary_section)
+        } else {
+            None
+        };
+
+        Self {
+            fluid_temperature_id: context.get_identifier(format!("HYD_{}_FLUID_TEMPERATURE", id)),
+
+            pump_sections,
+            system_section,
+            auxiliary_section,
             pump_sections_check_valves: pump_to_system_check_valves,
             pump_section_routed_to_auxiliary_section: pump_section_to_auxiliary,
-            fluid: Fluid::new(Pressure::new::<pascal>(Self::FLUID_BULK_MODULUS_PASCAL)),
+            fluid: Fluid::new(
+                Pressure::new::<pascal>(Self::FLUID_BULK_MODULUS_PASCAL),
+                high_pressure_max_volume + reservoir.max_capacity,
+                context.has_engines_running(),
+            ),
+            heat_exchanger: heat_exchanger_fuel_tank_quantity_id
+                .map(|fuel_tank_quantity_id| HeatExchanger::new(context, fuel_tank_quantity_id)),
             reservoir,
             circuit_target_pressure,
         }
@@ -1146,32 +1384,18 @@ impl HydraulicCircuit {
         controller: &impl HydraulicCircuitController,
         reservoir_pressure: Pressure,
     ) {
-        let mut any_pump_is_overheating = false;
-        for pump in main_section_pumps.iter() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        if let Some(pump) = system_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        if let Some(pump) = auxiliary_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        let ptu_overheats_fluid = ptu.is_some_and(|p| p.is_overheating() && p.is_rotating());
-
-        self.fluid
-            .update(context, ptu_overheats_fluid || any_pump_is_overheating);
+        self.update_fluid(
+            context,
+            main_section_pumps,
+            &system_section_pump,
+            &auxiliary_section_pump,
+            ptu,
+        );
 
         self.reservoir
             .update(context, reservoir_pressure, &self.fluid);
+        self.reservoir
+            .set_suction_flow_ratio(self.fluid.viscosity_flow_ratio());
 
         self.update_shutoff_valves(controller);
         self.update_leak_measurement_valves(context, controller);
@@ -1212,6 +1436,65 @@ impl HydraulicCircuit {
         self.update_final_delta_vol_and_pressure(context);
     }
 
+    fn update_fluid(
+        &mut self,
+        context: &UpdateContext,
+        main_section_pumps: &[&mut dyn HeatingPressureSource],
+        system_section_pump: &Option<&mut impl HeatingPressureSource>,
+        auxiliary_section_pump: &Option<&mut impl HeatingPressureSource>,
+        ptu: Option<&PowerTransferUnit>,
+    ) {
+        let mut any_pump_is_overheating = false;
+        let mut total_flow = VolumeRate::default();
+        let mut hydraulic_power_watt = 0.;
+
+        let mut add_pump = |pump: &dyn HeatingPressureSource, section: &Section| {
+            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
+                any_pump_is_overheating = true;
+            }
+
+            total_flow += pump.flow();
+            hydraulic_power_watt += section.pressure().get::<pascal>()
+                * pump.flow().get::<cubic_meter_per_second>()
+                / Self::PUMP_EFFICIENCY;
+        };
+
+        for (pump, section) in main_section_pumps.iter().zip(&self.pump_sections) {
+            add_pump(&**pump, section);
+        }
+
+        if let Some(pump) = system_section_pump.as_ref() {
+            add_pump(&**pump, &self.system_section);
+        }
+
+        if let (Some(pump), Some(section)) = (
+            auxiliary_section_pump.as_ref(),
+            self.auxiliary_section.as_ref(),
+        ) {
+            add_pump(&**pump, section);
+        }
+
+        let ptu_is_rotating = ptu.is_some_and(|p| p.is_rotating());
+        let ptu_overheats_fluid = ptu.is_some_and(|p| p.is_overheating() && p.is_rotating());
+
+        if ptu_is_rotating {
+            hydraulic_power_watt += Self::PTU_ROTATING_HEAT_WATT;
+        }
+
+        self.fluid.update(
+            context,
+            ptu_overheats_fluid || any_pump_is_overheating,
+            Power::new::<watt>(hydraulic_power_watt),
+            self.heat_exchanger
+                .as_mut()
+                .map(|heat_exchanger| (heat_exchanger, total_flow)),
+        );
+    }
+
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.fluid.temperature()
+    }
+
     fn update_delta_vol_from_valves(&mut self) {
         for (pump_index, section) in self.pump_sections.iter_mut().enumerate() {
             section.update_downstream_delta_vol(&self.pump_sections_check_valves[pump_index]);
@@ -1470,6 +1753,10 @@ impl SimulationElement for HydraulicCircuit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.reservoir.accept(visitor);
 
+        if let Some(heat_exchanger) = self.heat_exchanger.as_mut() {
+            heat_exchanger.accept(visitor);
+        }
+
         for section in &mut self.pump_sections {
             section.accept(visitor);
         }
@@ -1482,6 +1769,10 @@ impl SimulationElement for HydraulicCircuit {
 
         visitor.visit(self);
     }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.fluid_temperature_id, self.fluid.temperature());
+    }
 }
 impl HydraulicPressureSensors for HydraulicCircuit {
     fn pump_section_switch_pressurised(&self, pump_index: usize) -> bool {
@@ -1532,6 +1823,10 @@ pub struct Section {
 
     total_actuator_consumed_volume: Volume,
     total_actuator_returned_volume: Volume,
+
+    external_leaks: Vec<ExternalLeak>,
+
+    fluid_viscosity_flow_ratio: Ratio,
 }
 impl Section {
     pub fn new(
@@ -1587,9 +1882,17 @@ impl Section {
 
             total_actuator_consumed_volume: Volume::new::<gallon>(0.),
             total_actuator_returned_volume: Volume::new::<gallon>(0.),
+
+            external_leaks: Vec::new(),
+
+            fluid_viscosity_flow_ratio: Ratio::new::<ratio>(1.),
         }
     }
 
+    fn add_external_leak(&mut self, leak: ExternalLeak) {
+        self.external_leaks.push(leak);
+    }
+
     /// Gives the exact volume of fluid needed to get to any target_press pressure
     fn volume_to_reach_target(&self, target_press: Pressure, fluid: &Fluid) -> Volume {
         (target_press - self.current_pressure) * (self.max_high_press_volume) / fluid.bulk_mod()
@@ -1658,6 +1961,12 @@ impl Section {
 
         reservoir.add_return_volume(static_leak);
 
+        // External leaks are lost overboard so they never come back to reservoir
+        for leak in &self.external_leaks {
+            delta_volume_flow_pass -=
+                leak.leaked_volume(context, self.current_pressure, target_pressure);
+        }
+
         if let Some(accumulator) = &mut self.accumulator {
             accumulator.update(
                 context,
@@ -1736,6 +2045,7 @@ impl Section {
         self.current_volume += final_delta_volume;
 
         self.update_pressure(context, fluid);
+        self.fluid_viscosity_flow_ratio = fluid.viscosity_flow_ratio();
 
         self.current_flow = final_delta_volume / context.delta_as_time();
 
@@ -1820,6 +2130,8 @@ impl SimulationElement for Section {
             leak_meas_valve.accept(visitor);
         }
 
+        accept_iterable!(self.external_leaks, visitor);
+
         visitor.visit(self);
     }
 
@@ -1856,6 +2168,47 @@ impl SectionPressure for Section {
     fn is_pressure_switch_pressurised(&self) -> bool {
         self.pressure_switch_state() == PressureSwitchState::Pressurised
     }
+
+    fn fluid_viscosity_flow_ratio(&self) -> Ratio {
+        self.fluid_viscosity_flow_ratio
+    }
+}
+
+/// A failure-driven leak of fluid out of the circuit at a given location. Leak rate is
+/// given at circuit target pressure and scales with the pressure seen at the leak location.
+struct ExternalLeak {
+    failure: Failure,
+    flow_at_target_pressure: VolumeRate,
+}
+impl ExternalLeak {
+    fn new(failure_type: FailureType, flow_at_target_pressure: VolumeRate) -> Self {
+        Self {
+            failure: Failure::new(failure_type),
+            flow_at_target_pressure,
+        }
+    }
+
+    fn leaked_volume(
+        &self,
+        context: &UpdateContext,
+        pressure: Pressure,
+        target_pressure: Pressure,
+    ) -> Volume {
+        if self.failure.is_active() {
+            self.flow_at_target_pressure
+                * context.delta_as_time()
+                * ((pressure - Pressure::new::<psi>(14.7)) / target_pressure).max(Ratio::default())
+        } else {
+            Volume::default()
+        }
+    }
+}
+impl SimulationElement for ExternalLeak {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
 }
 
 pub struct FireValve {
@@ -2241,6 +2594,8 @@ pub struct Reservoir {
 
     total_return_flow: VolumeRate,
     total_return_volume: Volume,
+
+    suction_flow_ratio: Ratio,
 }
 impl Reservoir {
     const MIN_USABLE_VOLUME_GAL: f64 = 0.2;
@@ -2296,6 +2651,8 @@ impl Reservoir {
             ),
             total_return_flow: VolumeRate::default(),
             total_return_volume: Volume::default(),
+
+            suction_flow_ratio: Ratio::new::<ratio>(1.),
         }
     }
 
@@ -2361,16 +2718,21 @@ impl Reservoir {
         volume_taken
     }
 
+    /// Viscous fluid limits the flow pumps can draw through suction lines
+    fn set_suction_flow_ratio(&mut self, suction_flow_ratio: Ratio) {
+        self.suction_flow_ratio = suction_flow_ratio;
+    }
+
     // Try to take flow from reservoir. Will return only what's currently available
     fn try_take_flow(&mut self, context: &UpdateContext, flow: VolumeRate) -> VolumeRate {
-        let desired_volume = flow * context.delta_as_time();
+        let desired_volume = flow * self.suction_flow_ratio * context.delta_as_time();
         let volume_taken = self.try_take_volume(desired_volume);
         volume_taken / context.delta_as_time()
     }
 
     // What's current flow available
     fn request_flow_availability(&self, context: &UpdateContext, flow: VolumeRate) -> VolumeRate {
-        let desired_volume = flow * context.delta_as_time();
+        let desired_volume = flow * self.suction_flow_ratio * context.delta_as_time();
         self.fluid_level_reachable_by_pumps().min(desired_volume) / context.delta_as_time()
     }
 
@@ -3233,7 +3595,7 @@ impl SimulationElement for HydraulicValve {
 #[cfg(test)]
 mod tests {
     use crate::simulation::test::{
-        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+        ElementCtorFn, ReadByName, SimulationTestBed, TestAircraft, TestBed, WriteByName,
     };
     use crate::simulation::InitContext;
     use ntest::assert_about_eq;
@@ -3262,6 +3624,89 @@ mod tests {
 
     impl SimulationElement for PriorityValve {}
 
+    struct TestFluidCircuit {
+        fluid: Fluid,
+        heat_exchanger: Option<HeatExchanger>,
+
+        hydraulic_power: Power,
+        heat_exchanger_flow: VolumeRate,
+    }
+    impl TestFluidCircuit {
+        const FUEL_TANK_QUANTITY: &'static str = "FUEL TANK LEFT MAIN QUANTITY";
+
+        fn new(context: &mut InitContext, starts_warm: bool, has_heat_exchanger: bool) -> Self {
+            Self {
+                fluid: Fluid::new(
+                    Pressure::new::<pascal>(HydraulicCircuit::FLUID_BULK_MODULUS_PASCAL),
+                    Volume::new::<gallon>(15.),
+                    starts_warm,
+                ),
+                heat_exchanger: has_heat_exchanger
+                    .then(|| HeatExchanger::new(context, Self::FUEL_TANK_QUANTITY)),
+                hydraulic_power: Power::default(),
+                heat_exchanger_flow: VolumeRate::default(),
+            }
+        }
+
+        fn set_hydraulic_power(&mut self, hydraulic_power: Power) {
+            self.hydraulic_power = hydraulic_power;
+        }
+
+        fn set_heat_exchanger_flow(&mut self, flow: VolumeRate) {
+            self.heat_exchanger_flow = flow;
+        }
+
+        fn fuel_temperature(&self) -> ThermodynamicTemperature {
+            self.heat_exchanger.as_ref().unwrap().fuel_temperature()
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            let heat_exchanger_flow = self.heat_exchanger_flow;
+            self.fluid.update(
+                context,
+                false,
+                self.hydraulic_power,
+                self.heat_exchanger
+                    .as_mut()
+                    .map(|heat_exchanger| (heat_exchanger, heat_exchanger_flow)),
+            );
+        }
+    }
+    impl SimulationElement for TestFluidCircuit {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            if let Some(heat_exchanger) = self.heat_exchanger.as_mut() {
+                heat_exchanger.accept(visitor);
+            }
+
+            visitor.visit(self);
+        }
+    }
+
+    fn fluid_test_bed(
+        starts_warm: bool,
+        has_heat_exchanger: bool,
+        ambient_temperature_degc: f64,
+    ) -> SimulationTestBed<TestAircraft<TestFluidCircuit>> {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            TestFluidCircuit::new(context, starts_warm, has_heat_exchanger)
+        }));
+        test_bed.set_update_after_power_distribution(|circuit, context| circuit.update(context));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+            ambient_temperature_degc,
+        ));
+
+        test_bed
+    }
+
+    fn run_for(
+        test_bed: &mut SimulationTestBed<TestAircraft<TestFluidCircuit>>,
+        duration: Duration,
+    ) {
+        for _ in 0..duration.as_secs() {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+    }
+
     #[test]
     fn section_writes_its_state() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -3298,6 +3743,119 @@ mod tests {
         assert!(!test_bed.contains_variable_with_name("HYD_GREEN_PUMP_3_FIRE_VALVE_OPENED"));
     }
 
+    #[test]
+    fn cold_started_fluid_is_soaked_at_ambient_temperature() {
+        let mut test_bed = fluid_test_bed(false, false, -30.);
+
+        test_bed.run();
+
+        assert_about_eq!(
+            test_bed
+                .query_element(|c| c.fluid.temperature())
+                .get::<degree_celsius>(),
+            -30.,
+            0.1
+        );
+    }
+
+    #[test]
+    fn cold_fluid_reduces_flow_ratio() {
+        let mut test_bed = fluid_test_bed(false, false, -30.);
+
+        test_bed.run();
+
+        let flow_ratio = test_bed.query_element(|c| c.fluid.viscosity_flow_ratio());
+        assert!(flow_ratio.get::<ratio>() > 0.6 && flow_ratio.get::<ratio>() < 0.8);
+    }
+
+    #[test]
+    fn warm_fluid_has_nominal_flow_ratio() {
+        let mut test_bed = fluid_test_bed(true, false, -30.);
+
+        test_bed.run();
+
+        assert_about_eq!(
+            test_bed
+                .query_element(|c| c.fluid.viscosity_flow_ratio())
+                .get::<ratio>(),
+            1.
+        );
+    }
+
+    #[test]
+    fn hydraulic_power_warms_up_fluid() {
+        let mut test_bed = fluid_test_bed(false, false, 15.);
+        test_bed.command_element(|c| c.set_hydraulic_power(Power::new::<watt>(10000.)));
+
+        run_for(&mut test_bed, Duration::from_secs(10 * 60));
+
+        assert!(
+            test_bed
+                .query_element(|c| c.fluid.temperature())
+                .get::<degree_celsius>()
+                > 30.
+        );
+    }
+
+    #[test]
+    fn heat_exchanger_does_not_cool_fluid_without_fuel() {
+        let mut test_bed_with_fuel = fluid_test_bed(true, true, 15.);
+        let mut test_bed_without_fuel = fluid_test_bed(true, true, 15.);
+
+        test_bed_with_fuel.write_by_name(TestFluidCircuit::FUEL_TANK_QUANTITY, 1000.);
+        test_bed_without_fuel.write_by_name(TestFluidCircuit::FUEL_TANK_QUANTITY, 0.);
+        for test_bed in [&mut test_bed_with_fuel, &mut test_bed_without_fuel] {
+            test_bed.command_element(|c| {
+                c.set_heat_exchanger_flow(VolumeRate::new::<gallon_per_minute>(5.))
+            });
+            run_for(test_bed, Duration::from_secs(10 * 60));
+        }
+
+        let temperature_with_fuel = test_bed_with_fuel.query_element(|c| c.fluid.temperature());
+        let temperature_without_fuel =
+            test_bed_without_fuel.query_element(|c| c.fluid.temperature());
+
+        assert!(
+            temperature_with_fuel.get::<degree_celsius>() + 2.
+                < temperature_without_fuel.get::<degree_celsius>()
+        );
+    }
+
+    #[test]
+    fn heat_exchanger_warms_up_surrounding_fuel() {
+        let mut test_bed = fluid_test_bed(true, true, 15.);
+        test_bed.write_by_name(TestFluidCircuit::FUEL_TANK_QUANTITY, 100.);
+        test_bed.command_element(|c| {
+            c.set_hydraulic_power(Power::new::<watt>(10000.));
+            c.set_heat_exchanger_flow(VolumeRate::new::<gallon_per_minute>(5.));
+        });
+
+        run_for(&mut test_bed, Duration::from_secs(20 * 60));
+
+        assert!(
+            test_bed
+                .query_element(|c| c.fuel_temperature())
+                .get::<degree_celsius>()
+                > 17.
+        );
+    }
+
+    #[test]
+    fn hot_fluid_is_not_reported_overheating_without_overheating_source() {
+        let mut test_bed = fluid_test_bed(true, false, 15.);
+        test_bed.command_element(|c| c.set_hydraulic_power(Power::new::<watt>(20000.)));
+
+        run_for(&mut test_bed, Duration::from_secs(30 * 60));
+
+        assert!(
+            test_bed
+                .query_element(|c| c.fluid.temperature())
+                .get::<degree_celsius>()
+                > 120.
+        );
+        assert!(!test_bed.query_element(|c| c.fluid.is_overheating()));
+    }
+
     #[test]
     fn reservoir_gives_desired_flow() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
@@ -3732,6 +4290,7 @@ mod tests {
             priority_valve,
             Pressure::new::<psi>(1885.),
             Volume::new::<gallon>(0.264),
+            None,
         )
     }
 
@@ -1,19 +1,76 @@
-use uom::si::{f64::*, length::meter};
+use uom::si::{
+    angle::{degree, radian},
+    angular_velocity::degree_per_second,
+    f64::*,
+    length::meter,
+    velocity::{knot, meter_per_second},
+};
 
 use crate::{
     shared::{
         low_pass_filter::LowPassFilter, steering_angle_from_plane_yaw_rate, DelayedFalseLogicGate,
     },
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, UpdateContext, VariableIdentifier,
+        InitContext, Read, Reader, SimulationElement, SimulatorReader, SimulatorWriter,
+        UpdateContext, VariableIdentifier, Write, Writer,
     },
 };
 use std::time::Duration;
 
 use super::nose_steering::Pushback;
 
+/// How the native tug is attached to the nose gear.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TugAttachment {
+    None = 0,
+    Towbar = 1,
+    Towbarless = 2,
+}
+impl TugAttachment {
+    fn max_speed(&self) -> Velocity {
+        match self {
+            TugAttachment::None => Velocity::default(),
+            TugAttachment::Towbar => Velocity::new::<knot>(5.),
+            TugAttachment::Towbarless => Velocity::new::<knot>(8.),
+        }
+    }
+
+    // A towbar has some play at both ends so the nose wheel lags behind the tug heading,
+    // while a towbarless tug clamps the nose wheel in its cradle.
+    fn steering_time_constant(&self) -> Duration {
+        match self {
+            TugAttachment::None => PushbackTug::STEERING_ANGLE_FILTER_TIME_CONSTANT,
+            TugAttachment::Towbar => Duration::from_millis(1500),
+            TugAttachment::Towbarless => Duration::from_millis(500),
+        }
+    }
+}
+
+read_write_enum!(TugAttachment);
+
+impl From<f64> for TugAttachment {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => TugAttachment::None,
+            1 => TugAttachment::Towbar,
+            2 => TugAttachment::Towbarless,
+            // The attachment is written by ground handling add-ons, thus an unknown
+            // value is treated as a detached tug instead of aborting the simulation.
+            _ => TugAttachment::None,
+        }
+    }
+}
+
 pub struct PushbackTug {
     state_id: VariableIdentifier,
+    attachment_id: VariableIdentifier,
+    speed_command_id: VariableIdentifier,
+    turn_command_id: VariableIdentifier,
+    native_pin_inserted_id: VariableIdentifier,
+    tug_speed_id: VariableIdentifier,
+    tug_yaw_rate_id: VariableIdentifier,
+    torque_link_overload_id: VariableIdentifier,
+    torque_link_reset_id: VariableIdentifier,
 
     steering_angle: LowPassFilter<Angle>,
 
@@ -25,6 +82,18 @@ pub struct PushbackTug {
     // 4 = might be finishing pushback, to confirm
     state: f64,
     nose_wheel_steering_pin_inserted: DelayedFalseLogicGate,
+
+    attachment: TugAttachment,
+    // Both commands are in [-1;1]. Negative speed pushes the aircraft backwards,
+    // negative turn steers the nose wheel to the left.
+    speed_command: f64,
+    turn_command: f64,
+    native_pin_inserted: bool,
+    tug_speed: LowPassFilter<Velocity>,
+    tug_yaw_rate: AngularVelocity,
+    torque_link_overload: bool,
+    torque_link_reset_requested: bool,
+    torque_link_reset_is_pressed: bool,
 }
 impl PushbackTug {
     pub const DURATION_AFTER_WHICH_NWS_PIN_IS_REMOVED_AFTER_PUSHBACK: Duration =
@@ -34,9 +103,29 @@ impl PushbackTug {
 
     const STEERING_ANGLE_FILTER_TIME_CONSTANT: Duration = Duration::from_millis(800);
 
+    const WHEEL_DISTANCE_TO_ROTATION_CENTER_METERS: f64 = 25.;
+
+    const TUG_SPEED_FILTER_TIME_CONSTANT: Duration = Duration::from_secs(2);
+    const MAX_TUG_STEERING_ANGLE_DEGREES: f64 = 75.;
+
+    // Difference between the tug heading and the nose wheel above which the tug is fighting the
+    // nose wheel steering actuator and damages the torque links
+    const TORQUE_LINK_OVERLOAD_ANGLE_DEGREES: f64 = 10.;
+
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             state_id: context.get_identifier("PUSHBACK STATE".to_owned()),
+            attachment_id: context.get_identifier("PUSHBACK_TUG_ATTACHMENT".to_owned()),
+            speed_command_id: context.get_identifier("PUSHBACK_TUG_SPEED_COMMAND".to_owned()),
+            turn_command_id: context.get_identifier("PUSHBACK_TUG_TURN_COMMAND".to_owned()),
+            native_pin_inserted_id: context
+                .get_identifier("PUSHBACK_TUG_NWS_PIN_INSERTED".to_owned()),
+            tug_speed_id: context.get_identifier("PUSHBACK_TUG_SPEED".to_owned()),
+            tug_yaw_rate_id: context.get_identifier("PUSHBACK_TUG_YAW_RATE".to_owned()),
+            torque_link_overload_id: context
+                .get_identifier("PUSHBACK_TUG_TORQUE_LINK_OVERLOAD".to_owned()),
+            torque_link_reset_id: context
+                .get_identifier("PUSHBACK_TUG_TORQUE_LINK_RESET".to_owned()),
 
             steering_angle: LowPassFilter::new(Self::STEERING_ANGLE_FILTER_TIME_CONSTANT),
 
@@ -44,6 +133,16 @@ impl PushbackTug {
             nose_wheel_steering_pin_inserted: DelayedFalseLogicGate::new(
                 Self::DURATION_AFTER_WHICH_NWS_PIN_IS_REMOVED_AFTER_PUSHBACK,
             ),
+
+            attachment: TugAttachment::None,
+            speed_command: 0.,
+            turn_command: 0.,
+            native_pin_inserted: false,
+            tug_speed: LowPassFilter::new(Self::TUG_SPEED_FILTER_TIME_CONSTANT),
+            tug_yaw_rate: AngularVelocity::default(),
+            torque_link_overload: false,
+            torque_link_reset_requested: false,
+            torque_link_reset_is_pressed: false,
         }
     }
 
@@ -52,26 +151,94 @@ impl PushbackTug {
             let new_angle = if context.local_velocity().to_ms_vector()[2].abs() < 0.05 {
                 self.steering_angle.output()
             } else {
-                steering_angle_from_plane_yaw_rate(context, Length::new::<meter>(25.))
+                steering_angle_from_plane_yaw_rate(
+                    context,
+                    Length::new::<meter>(Self::WHEEL_DISTANCE_TO_ROTATION_CENTER_METERS),
+                )
             };
             self.steering_angle.update(context.delta(), new_angle);
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
+    fn update_native_tug(&mut self, context: &UpdateContext, nose_wheel_position: Angle) {
+        self.tug_speed.update(
+            context.delta(),
+            self.attachment.max_speed() * self.speed_command.clamp(-1., 1.),
+        );
+
+        self.steering_angle
+            .set_time_constant(self.attachment.steering_time_constant());
+        self.steering_angle
+            .update(context.delta(), self.tug_steering_angle());
+
+        // Latched, also after the tug is detached, as the torque links must be inspected
+        // before flight. Maintenance resets it once inspected.
+        self.torque_link_overload = self.torque_link_overload
+            || (!self.native_pin_inserted
+                && (self.steering_angle.output() - nose_wheel_position).abs()
+                    > Angle::new::<degree>(Self::TORQUE_LINK_OVERLOAD_ANGLE_DEGREES));
+
+        // The tug moves the nose wheel along its heading, which turns the aircraft
+        // around its main gear.
+        self.tug_yaw_rate = AngularVelocity::new::<degree_per_second>(
+            (self.tug_speed.output().get::<meter_per_second>()
+                * nose_wheel_position.get::<radian>().sin()
+                / Self::WHEEL_DISTANCE_TO_ROTATION_CENTER_METERS)
+                .to_degrees(),
+        );
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, nose_wheel_position: Angle) {
         self.nose_wheel_steering_pin_inserted
             .update(context, self.is_pushing());
 
-        self.update_pushback_angle(context);
+        if self.torque_link_reset_requested {
+            self.torque_link_overload = false;
+        }
+
+        if self.is_native_tug_attached() {
+            self.update_native_tug(context, nose_wheel_position);
+        } else {
+            self.tug_speed.reset(Velocity::default());
+            self.tug_yaw_rate = AngularVelocity::default();
+            self.steering_angle
+                .set_time_constant(Self::STEERING_ANGLE_FILTER_TIME_CONSTANT);
+
+            self.update_pushback_angle(context);
+        }
     }
 
     fn is_pushing(&self) -> bool {
         (self.state - PushbackTug::STATE_NO_PUSHBACK).abs() > f64::EPSILON
     }
+
+    fn is_native_tug_attached(&self) -> bool {
+        self.attachment != TugAttachment::None
+    }
+
+    fn tug_steering_angle(&self) -> Angle {
+        Angle::new::<degree>(
+            Self::MAX_TUG_STEERING_ANGLE_DEGREES * self.turn_command.clamp(-1., 1.),
+        )
+    }
+
+    /// The speed of the native tug, negative when pushing back.
+    pub fn tug_speed(&self) -> Velocity {
+        self.tug_speed.output()
+    }
+
+    /// The yaw rate the native tug imposes on the aircraft, positive to the right.
+    pub fn tug_yaw_rate(&self) -> AngularVelocity {
+        self.tug_yaw_rate
+    }
+
+    pub fn is_torque_link_overloaded(&self) -> bool {
+        self.torque_link_overload
+    }
 }
 impl Pushback for PushbackTug {
     fn is_nose_wheel_steering_pin_inserted(&self) -> bool {
-        self.nose_wheel_steering_pin_inserted.output()
+        self.nose_wheel_steering_pin_inserted.output() || self.native_pin_inserted
     }
 
     fn steering_angle(&self) -> Angle {
@@ -81,5 +248,253 @@ impl Pushback for PushbackTug {
 impl SimulationElement for PushbackTug {
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.state = reader.read(&self.state_id);
+        self.attachment = reader.read(&self.attachment_id);
+        self.speed_command = reader.read(&self.speed_command_id);
+        self.turn_command = reader.read(&self.turn_command_id);
+        self.native_pin_inserted = reader.read(&self.native_pin_inserted_id);
+        // Only the transition to true resets, so a held reset cannot hide a new overload
+        let torque_link_reset_is_pressed: bool = reader.read(&self.torque_link_reset_id);
+        self.torque_link_reset_requested =
+            torque_link_reset_is_pressed && !self.torque_link_reset_is_pressed;
+        self.torque_link_reset_is_pressed = torque_link_reset_is_pressed;
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.tug_speed_id, self.tug_speed());
+        writer.write(
+            &self.tug_yaw_rate_id,
+            self.tug_yaw_rate().get::<degree_per_second>(),
+        );
+        writer.write(&self.torque_link_overload_id, self.torque_link_overload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+
+    struct TestAircraft {
+        tug: PushbackTug,
+        nose_wheel_position: Angle,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                tug: PushbackTug::new(context),
+                nose_wheel_position: Angle::default(),
+            }
+        }
+
+        fn set_nose_wheel_position(&mut self, position: Angle) {
+            self.nose_wheel_position = position;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tug.update(context, self.nose_wheel_position);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tug.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_with_tug(attachment: TugAttachment) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("PUSHBACK STATE", PushbackTug::STATE_NO_PUSHBACK);
+        test_bed.write_by_name("PUSHBACK_TUG_ATTACHMENT", attachment);
+        test_bed.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", true);
+
+        test_bed
+    }
+
+    fn tug_speed(test_bed: &mut SimulationTestBed<TestAircraft>) -> Velocity {
+        test_bed.read_by_name("PUSHBACK_TUG_SPEED")
+    }
+
+    fn steering_angle(test_bed: &SimulationTestBed<TestAircraft>) -> Angle {
+        test_bed.query(|a| a.tug.steering_angle())
+    }
+
+    #[test]
+    fn no_tug_speed_when_not_attached() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::None);
+        test_bed.write_by_name("PUSHBACK_TUG_SPEED_COMMAND", -1.);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert_eq!(tug_speed(&mut test_bed), Velocity::default());
+    }
+
+    #[test]
+    fn towbarless_tug_pushes_faster_than_towbar_tug() {
+        let mut towbar_test_bed = test_bed_with_tug(TugAttachment::Towbar);
+        towbar_test_bed.write_by_name("PUSHBACK_TUG_SPEED_COMMAND", -1.);
+        towbar_test_bed.run_with_delta(Duration::from_secs(20));
+
+        let mut towbarless_test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        towbarless_test_bed.write_by_name("PUSHBACK_TUG_SPEED_COMMAND", -1.);
+        towbarless_test_bed.run_with_delta(Duration::from_secs(20));
+
+        let towbar_speed = tug_speed(&mut towbar_test_bed);
+        let towbarless_speed = tug_speed(&mut towbarless_test_bed);
+
+        assert!(towbar_speed < Velocity::new::<knot>(-4.9));
+        assert!(towbarless_speed < towbar_speed);
+        assert!(towbarless_speed > Velocity::new::<knot>(-8.1));
+    }
+
+    #[test]
+    fn tug_speed_builds_up_progressively() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_SPEED_COMMAND", 1.);
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(tug_speed(&mut test_bed) > Velocity::default());
+        assert!(tug_speed(&mut test_bed) < Velocity::new::<knot>(4.));
+    }
+
+    #[test]
+    fn pushing_back_with_nose_wheel_turned_right_yaws_aircraft_left() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_SPEED_COMMAND", -1.);
+        test_bed.command(|a| a.set_nose_wheel_position(Angle::new::<degree>(30.)));
+        test_bed.run_with_delta(Duration::from_secs(20));
+
+        let yaw_rate: f64 = test_bed.read_by_name("PUSHBACK_TUG_YAW_RATE");
+        assert!(yaw_rate < 0.);
+    }
+
+    #[test]
+    fn no_yaw_rate_with_nose_wheel_centered() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_SPEED_COMMAND", -1.);
+        test_bed.run_with_delta(Duration::from_secs(20));
+
+        let yaw_rate: f64 = test_bed.read_by_name("PUSHBACK_TUG_YAW_RATE");
+        assert!(yaw_rate.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn unknown_attachment_is_treated_as_detached_tug() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_ATTACHMENT", 7.);
+        test_bed.write_by_name("PUSHBACK_TUG_SPEED_COMMAND", -1.);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert_eq!(tug_speed(&mut test_bed), Velocity::default());
+        assert!(!test_bed.query(|a| a.tug.is_native_tug_attached()));
+    }
+
+    #[test]
+    fn nose_wheel_follows_turn_command_with_pin_inserted() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbar);
+        test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", -0.5);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.tug.is_nose_wheel_steering_pin_inserted()));
+        assert!(
+            (steering_angle(&test_bed) - Angle::new::<degree>(-37.5)).abs()
+                < Angle::new::<degree>(0.5)
+        );
+        assert!(!test_bed.query(|a| a.tug.is_torque_link_overloaded()));
+    }
+
+    #[test]
+    fn towbarless_tug_steers_faster_than_towbar_tug() {
+        let mut towbar_test_bed = test_bed_with_tug(TugAttachment::Towbar);
+        towbar_test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 1.);
+        towbar_test_bed.run_with_delta(Duration::from_secs(1));
+
+        let mut towbarless_test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        towbarless_test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 1.);
+        towbarless_test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(steering_angle(&towbarless_test_bed) > steering_angle(&towbar_test_bed));
+    }
+
+    #[test]
+    fn torque_link_overload_when_turning_without_pin() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", false);
+        test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 0.5);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.tug.is_torque_link_overloaded()));
+
+        let is_overload_written: bool = test_bed.read_by_name("PUSHBACK_TUG_TORQUE_LINK_OVERLOAD");
+        assert!(is_overload_written);
+    }
+
+    #[test]
+    fn no_torque_link_overload_when_nose_wheel_follows_tug_without_pin() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", false);
+        test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 0.5);
+        test_bed.command(|a| a.set_nose_wheel_position(Angle::new::<degree>(37.5)));
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.tug.is_torque_link_overloaded()));
+    }
+
+    #[test]
+    fn torque_link_overload_latched_after_tug_detached() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", false);
+        test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 0.5);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 0.);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.tug.is_torque_link_overloaded()));
+
+        test_bed.write_by_name("PUSHBACK_TUG_ATTACHMENT", TugAttachment::None);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.tug.is_torque_link_overloaded()));
+    }
+
+    #[test]
+    fn torque_link_overload_cleared_by_maintenance_reset() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", false);
+        test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 0.5);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        test_bed.write_by_name("PUSHBACK_TUG_ATTACHMENT", TugAttachment::None);
+        test_bed.write_by_name("PUSHBACK_TUG_TORQUE_LINK_RESET", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!test_bed.query(|a| a.tug.is_torque_link_overloaded()));
+    }
+
+    #[test]
+    fn holding_torque_link_reset_does_not_hide_a_new_overload() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::Towbarless);
+        test_bed.write_by_name("PUSHBACK_TUG_TORQUE_LINK_RESET", true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        test_bed.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", false);
+        test_bed.write_by_name("PUSHBACK_TUG_TURN_COMMAND", 0.5);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.tug.is_torque_link_overloaded()));
+    }
+
+    #[test]
+    fn legacy_pushback_state_still_inserts_pin() {
+        let mut test_bed = test_bed_with_tug(TugAttachment::None);
+        test_bed.write_by_name("PUSHBACK_TUG_NWS_PIN_INSERTED", false);
+        test_bed.write_by_name("PUSHBACK STATE", 0.);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.tug.is_nose_wheel_steering_pin_inserted()));
     }
 }
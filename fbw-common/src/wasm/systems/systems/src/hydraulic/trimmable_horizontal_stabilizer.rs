@@ -2,12 +2,14 @@ use uom::si::{
     angle::{degree, radian},
     angular_velocity::{radian_per_second, revolution_per_minute},
     f64::*,
+    force::newton,
     pressure::psi,
     ratio::ratio,
     volume::{cubic_inch, gallon},
     volume_rate::gallon_per_minute,
 };
 
+use crate::failures::{Failure, FailureType};
 use crate::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
     VariableIdentifier, Write,
@@ -15,7 +17,7 @@ use crate::simulation::{
 
 use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, ElectricalBusType, ElectricalBuses,
-    TrimmableHorizontalStabilizer,
+    HydraulicColor, TrimmableHorizontalStabilizer,
 };
 
 use super::linear_actuator::Actuator;
@@ -24,6 +26,7 @@ use std::time::Duration;
 
 struct TrimWheels {
     position_percent_id: VariableIdentifier,
+    angle_id: VariableIdentifier,
 
     position: Angle,
     trim_actuator_over_trim_wheel_ratio: Ratio,
@@ -40,6 +43,7 @@ impl TrimWheels {
     ) -> Self {
         Self {
             position_percent_id: context.get_identifier("HYD_TRIM_WHEEL_PERCENT".to_owned()),
+            angle_id: context.get_identifier("HYD_TRIM_WHEEL_ANGLE".to_owned()),
 
             position: Angle::default(),
             trim_actuator_over_trim_wheel_ratio,
@@ -64,6 +68,7 @@ impl SimulationElement for TrimWheels {
             &self.position_percent_id,
             self.position_normalized().get::<ratio>() * 100.,
         );
+        writer.write(&self.angle_id, self.position.get::<degree>());
     }
 }
 
@@ -141,6 +146,7 @@ struct ElectricDriveMotor {
     motor: DriveMotor,
 
     is_powered: bool,
+    runaway_failure: Failure,
 
     powered_by_bus_array: Vec<ElectricalBusType>,
     powered_by_bus: ElectricalTrimMotorElecBus,
@@ -149,6 +155,7 @@ impl ElectricDriveMotor {
     /// Creates an electric motor driving the trim input system.
     /// Power bus provided can contain only one main bus, or one main bus plus a secondary standby bus
     fn new(
+        number: usize,
         max_speed: AngularVelocity,
 
         speed_error_breakpoint: [f64; 7],
@@ -162,6 +169,7 @@ impl ElectricDriveMotor {
         Self {
             motor: DriveMotor::new(max_speed, speed_error_breakpoint, speed_regulation_coef_map),
             is_powered: true,
+            runaway_failure: Failure::new(FailureType::ThsElectricMotorRunaway(number)),
 
             powered_by_bus_array,
 
@@ -171,7 +179,8 @@ impl ElectricDriveMotor {
     }
 
     fn set_active_state(&mut self, is_active: bool) {
-        self.motor.set_active_state(is_active && self.is_powered);
+        self.motor
+            .set_active_state((is_active || self.is_runaway()) && self.is_powered);
     }
 
     fn update(
@@ -184,6 +193,11 @@ impl ElectricDriveMotor {
             .update(context, measured_position, requested_position);
     }
 
+    /// A runaway motor ignores its controller and keeps driving as long as it is powered
+    fn is_runaway(&self) -> bool {
+        self.runaway_failure.is_active()
+    }
+
     fn speed(&self) -> AngularVelocity {
         self.motor.speed()
     }
@@ -199,6 +213,11 @@ impl ElectricDriveMotor {
     }
 }
 impl SimulationElement for ElectricDriveMotor {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.runaway_failure.accept(visitor);
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         let bus_selected_index: usize =
             (self.powered_by_bus as usize).min(self.powered_by_bus_array.len() - 1);
@@ -342,10 +361,12 @@ pub trait ManualPitchTrimController {
 
 struct PitchTrimActuator {
     manual_override_id: VariableIdentifier,
+    manual_override_force_id: VariableIdentifier,
 
     electric_motors: [ElectricDriveMotor; 3],
     electric_clutches: [ElectricMotorClutch; 3],
     manual_override_active: bool,
+    manual_override_force: Force,
 
     position: Angle,
     speed: AngularVelocity,
@@ -362,6 +383,12 @@ impl PitchTrimActuator {
 
     const MIN_ELEC_MOTOR_SPEED_FOR_MANUAL_OVERRIDE_DETECTION_RPM: f64 = 10.;
 
+    // Force felt on the trim wheel rim when moving it manually: base mechanism friction, plus the slip
+    // force of each engaged electric motor clutch, or the full blocking force when the THS can't follow
+    const TRIM_WHEEL_FRICTION_FORCE_NEWTON: f64 = 20.;
+    const ELEC_CLUTCH_OVERRIDE_FORCE_NEWTON: f64 = 150.;
+    const TRIM_WHEEL_BLOCKED_FORCE_NEWTON: f64 = 500.;
+
     fn new(
         context: &mut InitContext,
         min_actuator_angle: Angle,
@@ -371,9 +398,12 @@ impl PitchTrimActuator {
     ) -> Self {
         Self {
             manual_override_id: context.get_identifier("HYD_THS_TRIM_MANUAL_OVERRIDE".to_owned()),
+            manual_override_force_id: context
+                .get_identifier("HYD_THS_TRIM_MANUAL_OVERRIDE_FORCE".to_owned()),
 
             electric_motors: [
                 ElectricDriveMotor::new(
+                    1,
                     max_elec_motor_speed,
                     Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
                     Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
@@ -383,12 +413,14 @@ impl PitchTrimActuator {
                     ],
                 ),
                 ElectricDriveMotor::new(
+                    2,
                     max_elec_motor_speed,
                     Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
                     Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
                     vec![ElectricalBusType::DirectCurrentEssential],
                 ),
                 ElectricDriveMotor::new(
+                    3,
                     max_elec_motor_speed,
                     Self::ELECTRIC_MOTOR_POSITION_ERROR_BREAKPOINT,
                     Self::ELECTRIC_MOTOR_SPEED_REGULATION_COEF_MAP,
@@ -397,6 +429,7 @@ impl PitchTrimActuator {
             ],
             electric_clutches: [ElectricMotorClutch::default(); 3],
             manual_override_active: false,
+            manual_override_force: Force::default(),
 
             position: Angle::default(),
             speed: AngularVelocity::default(),
@@ -450,13 +483,39 @@ impl PitchTrimActuator {
             self.manual_override_active = false
         }
 
-        if ths_hydraulic_assembly.is_at_max_down_spool_valve
+        let is_blocked_by_spool_valve = ths_hydraulic_assembly.is_at_max_down_spool_valve
             && self.speed.get::<radian_per_second>() < 0.
             || ths_hydraulic_assembly.is_at_max_up_spool_valve
-                && self.speed.get::<radian_per_second>() > 0.
-        {
+                && self.speed.get::<radian_per_second>() > 0.;
+
+        if is_blocked_by_spool_valve {
             self.speed = AngularVelocity::default();
         }
+
+        self.update_manual_override_force(manual_controller, is_blocked_by_spool_valve);
+    }
+
+    fn update_manual_override_force(
+        &mut self,
+        manual_controller: &impl ManualPitchTrimController,
+        is_blocked_by_spool_valve: bool,
+    ) {
+        self.manual_override_force = if !manual_controller.is_manually_moved() {
+            Force::default()
+        } else if is_blocked_by_spool_valve {
+            Force::new::<newton>(Self::TRIM_WHEEL_BLOCKED_FORCE_NEWTON)
+        } else {
+            let engaged_clutches = self
+                .electric_clutches
+                .iter()
+                .filter(|clutch| clutch.is_clutch_engaged())
+                .count();
+
+            Force::new::<newton>(
+                Self::TRIM_WHEEL_FRICTION_FORCE_NEWTON
+                    + engaged_clutches as f64 * Self::ELEC_CLUTCH_OVERRIDE_FORCE_NEWTON,
+            )
+        };
     }
 
     fn elec_motor_drive_total_speed(&self) -> AngularVelocity {
@@ -475,7 +534,10 @@ impl PitchTrimActuator {
     fn update_clutches_state(&mut self, controller: &impl PitchTrimActuatorController) {
         for (clutch_index, clutch) in self.electric_clutches.iter_mut().enumerate() {
             clutch.set_is_powered(self.electric_motors[clutch_index].is_powered());
-            clutch.set_energized(controller.energised_motor()[clutch_index]);
+            clutch.set_energized(
+                controller.energised_motor()[clutch_index]
+                    || self.electric_motors[clutch_index].is_runaway(),
+            );
         }
     }
 
@@ -491,10 +553,14 @@ impl PitchTrimActuator {
             let trim_actuator_normalized_position_request = ths_hydraulic_assembly
                 .normalized_position_from_ths_deflection(controller.commanded_position());
 
-            let final_trim_actuator_position_request = trim_actuator_normalized_position_request
-                .get::<ratio>()
-                * (self.max_actuator_angle - self.min_actuator_angle)
-                + self.min_actuator_angle;
+            // A runaway motor drives towards the full nose up stop whatever the commanded position
+            let final_trim_actuator_position_request = if motor.is_runaway() {
+                self.max_actuator_angle
+            } else {
+                trim_actuator_normalized_position_request.get::<ratio>()
+                    * (self.max_actuator_angle - self.min_actuator_angle)
+                    + self.min_actuator_angle
+            };
 
             motor.update(context, self.position, final_trim_actuator_position_request);
         }
@@ -519,6 +585,10 @@ impl SimulationElement for PitchTrimActuator {
 
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.manual_override_id, self.manual_override_active);
+        writer.write(
+            &self.manual_override_force_id,
+            self.manual_override_force.get::<newton>(),
+        );
     }
 }
 
@@ -677,6 +747,9 @@ pub struct TrimmableHorizontalStabilizerActuator {
     deflection_id: VariableIdentifier,
     hydraulic_motors: [HydraulicDriveMotor; 2],
 
+    jam_failure: Failure,
+    hydraulic_motor_failures: [Failure; 2],
+
     speed: AngularVelocity,
     actual_deflection: Angle,
 
@@ -714,6 +787,13 @@ impl TrimmableHorizontalStabilizerActuator {
                 Self::HYDRAULIC_MOTOR_SPEED_REGULATION_COEF_MAP,
             ); 2],
 
+            jam_failure: Failure::new(FailureType::ThsJam),
+            // Hydraulic motors are in green->yellow order
+            hydraulic_motor_failures: [
+                Failure::new(FailureType::ThsHydraulicMotor(HydraulicColor::Green)),
+                Failure::new(FailureType::ThsHydraulicMotor(HydraulicColor::Yellow)),
+            ],
+
             speed: AngularVelocity::default(),
             actual_deflection: Angle::default(),
             min_deflection,
@@ -737,15 +817,20 @@ impl TrimmableHorizontalStabilizerActuator {
         // So, we just use the first controller position.
         self.update_spool_valve_lock_position(controllers[0].requested_position());
 
-        for ((motor, controller), pressure) in self
+        for (((motor, controller), pressure), failure) in self
             .hydraulic_motors
             .iter_mut()
             .zip(controllers)
             .zip(pressures)
+            .zip(&self.hydraulic_motor_failures)
         {
+            // A failed motor is held by its pressure off brake, and a jammed ballscrew stalls both
+            // motors. The no-back holds the THS in place once no motor is driving anymore.
             motor.update(
                 context,
-                controller.motor_should_activate(),
+                controller.motor_should_activate()
+                    && !failure.is_active()
+                    && !self.jam_failure.is_active(),
                 self.actual_deflection,
                 controller.requested_position(),
                 pressure,
@@ -803,6 +888,12 @@ impl TrimmableHorizontalStabilizerActuator {
     }
 }
 impl SimulationElement for TrimmableHorizontalStabilizerActuator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jam_failure.accept(visitor);
+        accept_iterable!(self.hydraulic_motor_failures, visitor);
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.deflection_id, self.actual_deflection.get::<degree>());
     }
@@ -975,6 +1066,10 @@ mod tests {
         fn set_no_elec_power(&mut self) {
             self.is_elec_powered = false;
         }
+
+        fn hydraulic_motor_speed(&self, motor_idx: usize) -> AngularVelocity {
+            self.trim_assembly.ths_hydraulics.hydraulic_motors[motor_idx].speed()
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_before_power_distribution(
@@ -1154,5 +1249,128 @@ mod tests {
 
         let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
         assert!(deflection.get::<degree>() > 5.);
+
+        let trim_wheel_angle: Angle = test_bed.read_by_name("HYD_TRIM_WHEEL_ANGLE");
+        assert!(trim_wheel_angle.get::<degree>() > 360.);
+
+        let override_force_newton: f64 =
+            test_bed.read_by_name("HYD_THS_TRIM_MANUAL_OVERRIDE_FORCE");
+        assert!(override_force_newton > 0.);
+        assert!(override_force_newton < PitchTrimActuator::ELEC_CLUTCH_OVERRIDE_FORCE_NEWTON);
+    }
+
+    #[test]
+    fn jammed_ths_holds_position_and_blocks_trim_wheel() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(5.), 0));
+        test_bed.run_with_delta(Duration::from_millis(10000));
+
+        let deflection_before_jam: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection_before_jam.get::<degree>() > 4.9);
+
+        test_bed.fail(FailureType::ThsJam);
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(13.), 0));
+        test_bed.run_with_delta(Duration::from_millis(10000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!((deflection - deflection_before_jam).abs() < Angle::new::<degree>(0.1));
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.command(|a| a.set_manual_trim_input(true));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!((deflection - deflection_before_jam).abs() < Angle::new::<degree>(0.1));
+
+        let override_force_newton: f64 =
+            test_bed.read_by_name("HYD_THS_TRIM_MANUAL_OVERRIDE_FORCE");
+        assert!(override_force_newton >= PitchTrimActuator::TRIM_WHEEL_BLOCKED_FORCE_NEWTON);
+    }
+
+    #[test]
+    fn runaway_elec_motor_drives_ths_to_full_nose_up() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.fail(FailureType::ThsElectricMotorRunaway(1));
+        test_bed.run_with_delta(Duration::from_millis(20000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection.get::<degree>() > 13.45);
+    }
+
+    #[test]
+    fn runaway_elec_motor_stops_without_elec_power() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.command(|a| a.set_no_elec_power());
+        test_bed.fail(FailureType::ThsElectricMotorRunaway(1));
+        test_bed.run_with_delta(Duration::from_millis(20000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection.get::<degree>().abs() < 0.1);
+    }
+
+    #[test]
+    fn runaway_elec_motor_can_be_overridden_with_trim_wheel() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_no_elec_input());
+        test_bed.fail(FailureType::ThsElectricMotorRunaway(2));
+        test_bed.run_with_delta(Duration::from_millis(5000));
+
+        let deflection_before_override: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection_before_override.get::<degree>() > 2.);
+
+        test_bed.command(|a| a.set_manual_trim_input(false));
+        test_bed.run_with_delta(Duration::from_millis(3000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection < deflection_before_override);
+
+        let man_override: f64 = test_bed.read_by_name("HYD_THS_TRIM_MANUAL_OVERRIDE");
+        assert!(man_override >= 0.5);
+
+        let override_force_newton: f64 =
+            test_bed.read_by_name("HYD_THS_TRIM_MANUAL_OVERRIDE_FORCE");
+        assert!(override_force_newton >= PitchTrimActuator::ELEC_CLUTCH_OVERRIDE_FORCE_NEWTON);
+    }
+
+    #[test]
+    fn ths_is_driven_by_remaining_hydraulic_motor_with_one_motor_failed() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ThsHydraulicMotor(HydraulicColor::Green));
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(13.), 0));
+        test_bed.run_with_delta(Duration::from_millis(2000));
+
+        assert!(test_bed.query(|a| a.hydraulic_motor_speed(0)) == AngularVelocity::default());
+        assert!(
+            test_bed
+                .query(|a| a.hydraulic_motor_speed(1))
+                .get::<revolution_per_minute>()
+                > 100.
+        );
+
+        test_bed.run_with_delta(Duration::from_millis(20000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection.get::<degree>() > 12.9);
+        assert!(deflection.get::<degree>() < 13.1);
+    }
+
+    #[test]
+    fn ths_is_held_with_both_hydraulic_motors_failed() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.fail(FailureType::ThsHydraulicMotor(HydraulicColor::Green));
+        test_bed.fail(FailureType::ThsHydraulicMotor(HydraulicColor::Yellow));
+        test_bed.command(|a| a.set_elec_trim_demand(Angle::new::<degree>(13.), 0));
+        test_bed.run_with_delta(Duration::from_millis(10000));
+
+        let deflection: Angle = test_bed.read_by_name("HYD_FINAL_THS_DEFLECTION");
+        assert!(deflection.get::<degree>().abs() < 0.1);
     }
 }
@@ -1,3 +1,6 @@
+mod tyre;
+pub use tyre::{Tyre, TyreProperties};
+
 use std::time::Duration;
 
 use crate::{
@@ -1131,6 +1134,16 @@ impl LandingGearControlInterfaceUnit {
         self.transition_duration = Duration::default();
     }
 
+    /// Gear or doors did not reach the position commanded by the gear lever in time. This is
+    /// typically the result of a failed door actuator, a jammed uplock or a downlock that could
+    /// not engage.
+    fn has_control_fault(&self) -> bool {
+        matches!(
+            self.gear_system_state(),
+            GearSystemState::Extending | GearSystemState::Retracting
+        ) && self.transition_duration > Self::MAX_TRANSITION_DURATION
+    }
+
     pub fn gear_system_state(&self) -> GearSystemState {
         self.gear_system_control.state()
     }
@@ -1202,7 +1215,7 @@ impl LandingGearControlInterfaceUnit {
                     && self.sensor_inputs.right_gear_down_and_locked,
             );
             // Control fault (before version 4D, control fault + proximity sensor disagree)
-            word.set_bit(29, false);
+            word.set_bit(29, self.has_control_fault());
 
             word
         }
@@ -2046,6 +2059,24 @@ mod tests {
         assert!(test_bed.query(|a| a.lgcius.lgciu1().status) == LgciuStatus::Ok);
     }
 
+    #[test]
+    fn lgciu_reports_control_fault_if_gear_not_up_in_30s() {
+        let mut test_bed = test_bed_in_flight_with()
+            .in_flight()
+            .set_gear_handle_down()
+            .run_one_tick();
+
+        test_bed = test_bed.set_gear_handle_up().run_one_tick();
+
+        test_bed.fail_hyd_pressure();
+
+        test_bed.run_with_delta(Duration::from_secs(28));
+        assert!(!test_bed.query(|a| a.lgcius.lgciu2().discrete_word_2().get_bit(29)));
+
+        test_bed.run_with_delta(Duration::from_secs(3));
+        assert!(test_bed.query(|a| a.lgcius.lgciu2().discrete_word_2().get_bit(29)));
+    }
+
     #[test]
     fn tilting_gear_does_not_tilt_when_no_pitch_on_ground() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(test_tilting_gear_left))
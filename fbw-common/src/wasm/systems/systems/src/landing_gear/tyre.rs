@@ -0,0 +1,511 @@
+use crate::{
+    failures::{Failure, FailureType},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    angular_velocity::radian_per_second,
+    f64::*,
+    force::newton,
+    length::meter,
+    mass::kilogram,
+    pressure::psi,
+    ratio::ratio,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::meter_per_second,
+};
+
+pub struct TyreProperties {
+    rated_pressure: Pressure,
+    rated_load: Force,
+    speed_rating: Velocity,
+    radius: Length,
+    share_of_aircraft_weight: Ratio,
+}
+impl TyreProperties {
+    /// Creates the properties of a tyre
+    /// ## Parameters
+    /// `rated_pressure` - the nominal inflation pressure of a cold tyre
+    /// `rated_load` - the maximum static load the tyre is rated for
+    /// `speed_rating` - the maximum ground speed the tyre is rated for
+    /// `share_of_aircraft_weight` - the part of the aircraft weight carried by a single tyre
+    pub fn new(
+        rated_pressure: Pressure,
+        rated_load: Force,
+        speed_rating: Velocity,
+        radius: Length,
+        share_of_aircraft_weight: Ratio,
+    ) -> Self {
+        Self {
+            rated_pressure,
+            rated_load,
+            speed_rating,
+            radius,
+            share_of_aircraft_weight,
+        }
+    }
+}
+
+/// Simulates an aircraft tyre which is heated by its adjacent brake.
+///
+/// The tyre pressure follows the gas temperature. When the wheel gets too hot, the fusible
+/// plugs melt and the tyre deflates, preventing it from bursting. A tyre rolling faster than
+/// its speed rating or carrying an excessive load bursts.
+///
+/// A burst or deflated tyre stays so until it is replaced, either by setting the reset variable or
+/// by clearing the flat tyre failure.
+pub struct Tyre {
+    pressure_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
+    reset_id: VariableIdentifier,
+
+    /// The absolute pressure of the tyre gas at the reference temperature.
+    reference_pressure: Pressure,
+    reference_temperature: ThermodynamicTemperature,
+    temperature: ThermodynamicTemperature,
+    ambient_pressure: Pressure,
+    rated_pressure: Pressure,
+    speed: Velocity,
+
+    fusible_plugs_melted: bool,
+    is_burst: bool,
+    initialized: bool,
+    reset_requested: bool,
+    reset_is_pressed: bool,
+    flat_failure: Failure,
+    was_flat: bool,
+}
+impl Tyre {
+    /// Temperature at which the fusible plugs in the wheel melt
+    const FUSIBLE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS: f64 = 177.;
+    /// Time constant of the heat transfer from the brake towards the tyre
+    const BRAKE_HEAT_TRANSFER_TIME_CONSTANT_SECONDS: f64 = 900.;
+    /// Time constant of the tyre cooling down to ambient temperature
+    const AMBIENT_COOLING_TIME_CONSTANT_SECONDS: f64 = 2400.;
+    /// Time constant of the tyre deflating through melted fusible plugs
+    const FUSIBLE_PLUG_DEFLATION_TIME_CONSTANT_SECONDS: f64 = 30.;
+    /// Time constant of a flat tyre deflating
+    const FLAT_TYRE_DEFLATION_TIME_CONSTANT_SECONDS: f64 = 10.;
+    /// Load above which a tyre bursts, relative to its rated load
+    const BURST_LOAD_RATIO: f64 = 2.5;
+    /// Pressure below which a tyre starts adding rolling drag, relative to its rated pressure
+    const DEFLATED_PRESSURE_RATIO: f64 = 0.8;
+    /// Additional rolling drag of a fully deflated tyre, as a ratio of maximum braking force
+    const DEFLATED_ROLLING_DRAG: f64 = 0.05;
+
+    const GRAVITY_ACCELERATION: f64 = 9.80665;
+
+    pub fn new(context: &mut InitContext, index: usize) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("TYRE_PRESSURE_{index}")),
+            temperature_id: context.get_identifier(format!("TYRE_TEMPERATURE_{index}")),
+            reset_id: context.get_identifier(format!("TYRE_RESET_{index}")),
+
+            reference_pressure: Pressure::default(),
+            reference_temperature: ThermodynamicTemperature::default(),
+            temperature: ThermodynamicTemperature::default(),
+            ambient_pressure: Pressure::default(),
+            rated_pressure: Pressure::default(),
+            speed: Velocity::default(),
+
+            fusible_plugs_melted: false,
+            is_burst: false,
+            initialized: false,
+            reset_requested: false,
+            reset_is_pressed: false,
+            flat_failure: Failure::new(FailureType::FlatTyre(index)),
+            was_flat: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        properties: &TyreProperties,
+        brake_temperature: ThermodynamicTemperature,
+        wheel_speed: AngularVelocity,
+    ) {
+        self.ambient_pressure = context.ambient_pressure();
+        self.rated_pressure = properties.rated_pressure;
+
+        let is_flat = self.flat_failure.is_active();
+        if self.reset_requested || (self.was_flat && !is_flat) {
+            self.replace();
+        }
+        self.was_flat = is_flat;
+
+        if !self.initialized {
+            self.temperature = context.ambient_temperature();
+            self.reference_temperature = self.temperature;
+            self.reference_pressure = properties.rated_pressure + self.ambient_pressure;
+            self.initialized = true;
+        }
+
+        self.update_temperature(context, brake_temperature);
+
+        if self.temperature.get::<degree_celsius>()
+            > Self::FUSIBLE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS
+        {
+            self.fusible_plugs_melted = true;
+        }
+
+        self.speed = Velocity::new::<meter_per_second>(
+            wheel_speed.get::<radian_per_second>().abs() * properties.radius.get::<meter>(),
+        );
+        if self.speed > properties.speed_rating
+            || Self::load(context, properties) > properties.rated_load * Self::BURST_LOAD_RATIO
+        {
+            self.is_burst = true;
+        }
+
+        if self.is_burst {
+            self.reference_pressure = self.deflated_reference_pressure();
+        } else if is_flat {
+            self.deflate(context, Self::FLAT_TYRE_DEFLATION_TIME_CONSTANT_SECONDS);
+        } else if self.fusible_plugs_melted {
+            self.deflate(context, Self::FUSIBLE_PLUG_DEFLATION_TIME_CONSTANT_SECONDS);
+        }
+    }
+
+    /// Fits a new cold tyre inflated to its rated pressure.
+    fn replace(&mut self) {
+        self.fusible_plugs_melted = false;
+        self.is_burst = false;
+        self.initialized = false;
+    }
+
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        brake_temperature: ThermodynamicTemperature,
+    ) {
+        let brake_heating = (brake_temperature.get::<kelvin>() - self.temperature.get::<kelvin>())
+            * Self::rate(context, Self::BRAKE_HEAT_TRANSFER_TIME_CONSTANT_SECONDS);
+        let ambient_cooling = (self.temperature.get::<kelvin>()
+            - context.ambient_temperature().get::<kelvin>())
+            * Self::rate(context, Self::AMBIENT_COOLING_TIME_CONSTANT_SECONDS);
+
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.temperature.get::<kelvin>() + brake_heating - ambient_cooling,
+        );
+    }
+
+    fn deflate(&mut self, context: &UpdateContext, time_constant_seconds: f64) {
+        self.reference_pressure -= (self.reference_pressure - self.deflated_reference_pressure())
+            * Self::rate(context, time_constant_seconds);
+    }
+
+    /// The reference pressure at which the tyre gas is at ambient pressure at its current temperature.
+    fn deflated_reference_pressure(&self) -> Pressure {
+        self.ambient_pressure
+            * (self.reference_temperature.get::<kelvin>() / self.temperature.get::<kelvin>())
+    }
+
+    fn rate(context: &UpdateContext, time_constant_seconds: f64) -> f64 {
+        1. - (-context.delta_as_secs_f64() / time_constant_seconds).exp()
+    }
+
+    /// Estimates the vertical load on the tyre from the aircraft weight and vertical acceleration.
+    fn load(context: &UpdateContext, properties: &TyreProperties) -> Force {
+        if !context.is_on_ground() {
+            return Force::default();
+        }
+
+        let load_factor = 1.
+            + context.vert_accel().get::<meter_per_second_squared>() / Self::GRAVITY_ACCELERATION;
+
+        Force::new::<newton>(
+            context.total_weight().get::<kilogram>()
+                * Self::GRAVITY_ACCELERATION
+                * load_factor.max(0.)
+                * properties.share_of_aircraft_weight.get::<ratio>(),
+        )
+    }
+
+    /// The gauge pressure of the tyre.
+    pub fn pressure(&self) -> Pressure {
+        (self.reference_pressure
+            * (self.temperature.get::<kelvin>() / self.reference_temperature.get::<kelvin>())
+            - self.ambient_pressure)
+            .max(Pressure::default())
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// The ground speed of the tyre surface.
+    pub fn speed(&self) -> Velocity {
+        self.speed
+    }
+
+    pub fn is_burst(&self) -> bool {
+        self.is_burst
+    }
+
+    pub fn fusible_plugs_melted(&self) -> bool {
+        self.fusible_plugs_melted
+    }
+
+    /// The rolling drag added by the tyre being deflated, as a ratio of maximum braking force.
+    pub fn rolling_drag(&self) -> f64 {
+        if !self.initialized {
+            return 0.;
+        }
+
+        let deflated_pressure = self.rated_pressure.get::<psi>() * Self::DEFLATED_PRESSURE_RATIO;
+        let deflation =
+            ((deflated_pressure - self.pressure().get::<psi>()) / deflated_pressure).clamp(0., 1.);
+
+        deflation * Self::DEFLATED_ROLLING_DRAG
+    }
+}
+impl SimulationElement for Tyre {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.flat_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let reset_is_pressed: bool = reader.read(&self.reset_id);
+        self.reset_requested = reset_is_pressed && !self.reset_is_pressed;
+        self.reset_is_pressed = reset_is_pressed;
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.pressure_id, self.pressure());
+        writer.write(&self.temperature_id, self.temperature);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+    use std::time::Duration;
+    use uom::si::{angular_velocity::revolution_per_minute, velocity::knot};
+
+    struct TestAircraft {
+        tyre_properties: TyreProperties,
+        tyre: Tyre,
+        brake_temperature: ThermodynamicTemperature,
+        wheel_speed: AngularVelocity,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                tyre_properties: TyreProperties::new(
+                    Pressure::new::<psi>(200.),
+                    Force::new::<newton>(200000.),
+                    Velocity::new::<knot>(195.),
+                    Length::new::<meter>(0.58),
+                    Ratio::new::<ratio>(0.23),
+                ),
+                tyre: Tyre::new(context, 1),
+                brake_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+                wheel_speed: AngularVelocity::default(),
+            }
+        }
+
+        fn set_brake_temperature(&mut self, temperature: ThermodynamicTemperature) {
+            self.brake_temperature = temperature;
+        }
+
+        fn set_wheel_speed(&mut self, wheel_speed: AngularVelocity) {
+            self.wheel_speed = wheel_speed;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tyre.update(
+                context,
+                &self.tyre_properties,
+                self.brake_temperature,
+                self.wheel_speed,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tyre.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed
+    }
+
+    fn tyre_pressure(test_bed: &mut SimulationTestBed<TestAircraft>) -> Pressure {
+        test_bed.read_by_name("TYRE_PRESSURE_1")
+    }
+
+    #[test]
+    fn cold_tyre_is_at_rated_pressure() {
+        let mut test_bed = test_bed();
+
+        assert!((tyre_pressure(&mut test_bed).get::<psi>() - 200.).abs() < 0.1);
+    }
+
+    #[test]
+    fn hot_brake_heats_up_the_tyre_and_increases_its_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(150.))
+        });
+        test_bed.run_with_delta(Duration::from_secs(300));
+
+        assert!(test_bed.query(|a| a.tyre.temperature().get::<degree_celsius>()) > 30.);
+        assert!(tyre_pressure(&mut test_bed).get::<psi>() > 205.);
+        assert!(!test_bed.query(|a| a.tyre.fusible_plugs_melted()));
+    }
+
+    #[test]
+    fn overheated_tyre_deflates_through_its_fusible_plugs() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(800.))
+        });
+        for _ in 0..20 {
+            test_bed.run_with_delta(Duration::from_secs(30));
+        }
+
+        assert!(test_bed.query(|a| a.tyre.fusible_plugs_melted()));
+        assert!(tyre_pressure(&mut test_bed).get::<psi>() < 1.);
+        assert!(!test_bed.query(|a| a.tyre.is_burst()));
+    }
+
+    #[test]
+    fn tyre_bursts_above_its_speed_rating() {
+        let mut test_bed = test_bed();
+        // 220 knots with a 0.58m radius.
+        test_bed
+            .command(|a| a.set_wheel_speed(AngularVelocity::new::<revolution_per_minute>(1865.)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.query(|a| a.tyre.is_burst()));
+        assert!(tyre_pressure(&mut test_bed).get::<psi>() < 1.);
+    }
+
+    #[test]
+    fn tyre_does_not_burst_below_its_speed_rating() {
+        let mut test_bed = test_bed();
+        // 150 knots with a 0.58m radius.
+        test_bed
+            .command(|a| a.set_wheel_speed(AngularVelocity::new::<revolution_per_minute>(1270.)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(!test_bed.query(|a| a.tyre.is_burst()));
+    }
+
+    #[test]
+    fn flat_tyre_failure_deflates_the_tyre() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::FlatTyre(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(tyre_pressure(&mut test_bed).get::<psi>() < 1.);
+    }
+
+    #[test]
+    fn deflated_tyre_adds_rolling_drag() {
+        let mut test_bed = test_bed();
+        assert_eq!(test_bed.query(|a| a.tyre.rolling_drag()), 0.);
+
+        test_bed.fail(FailureType::FlatTyre(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(test_bed.query(|a| a.tyre.rolling_drag()) > 0.);
+    }
+
+    #[test]
+    fn clearing_flat_tyre_failure_reinflates_the_tyre() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::FlatTyre(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+        assert!(tyre_pressure(&mut test_bed).get::<psi>() < 1.);
+
+        test_bed.unfail(FailureType::FlatTyre(1));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!((tyre_pressure(&mut test_bed).get::<psi>() - 200.).abs() < 0.1);
+        assert_eq!(test_bed.query(|a| a.tyre.rolling_drag()), 0.);
+    }
+
+    #[test]
+    fn reset_replaces_a_burst_tyre() {
+        let mut test_bed = test_bed();
+        test_bed
+            .command(|a| a.set_wheel_speed(AngularVelocity::new::<revolution_per_minute>(1865.)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.tyre.is_burst()));
+
+        test_bed.command(|a| a.set_wheel_speed(AngularVelocity::default()));
+        test_bed.write_by_name("TYRE_RESET_1", true);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(!test_bed.query(|a| a.tyre.is_burst()));
+        assert!((tyre_pressure(&mut test_bed).get::<psi>() - 200.).abs() < 0.1);
+    }
+
+    #[test]
+    fn reset_replaces_a_tyre_with_melted_fusible_plugs() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(800.))
+        });
+        for _ in 0..20 {
+            test_bed.run_with_delta(Duration::from_secs(30));
+        }
+        assert!(test_bed.query(|a| a.tyre.fusible_plugs_melted()));
+
+        test_bed.command(|a| {
+            a.set_brake_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.))
+        });
+        test_bed.write_by_name("TYRE_RESET_1", true);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(!test_bed.query(|a| a.tyre.fusible_plugs_melted()));
+        assert!((tyre_pressure(&mut test_bed).get::<psi>() - 200.).abs() < 0.1);
+    }
+
+    #[test]
+    fn holding_the_reset_does_not_prevent_a_new_burst() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("TYRE_RESET_1", true);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed
+            .command(|a| a.set_wheel_speed(AngularVelocity::new::<revolution_per_minute>(1865.)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed.command(|a| a.set_wheel_speed(AngularVelocity::default()));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.query(|a| a.tyre.is_burst()));
+        assert!(tyre_pressure(&mut test_bed).get::<psi>() < 1.);
+    }
+
+    #[test]
+    fn holding_the_reset_does_not_prevent_the_flat_tyre_failure() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("TYRE_RESET_1", true);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.fail(FailureType::FlatTyre(1));
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        assert!(tyre_pressure(&mut test_bed).get::<psi>() < 1.);
+    }
+}
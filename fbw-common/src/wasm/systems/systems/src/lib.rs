@@ -15,7 +15,9 @@ pub mod electrical;
 pub mod engine;
 pub mod enhanced_gpwc;
 pub mod failures;
+pub mod fire_protection;
 pub mod fuel;
+pub mod ground_services;
 pub mod hydraulic;
 pub mod icing_state;
 pub mod indicating_recording;
@@ -2,6 +2,7 @@ use std::{cell::Cell, rc::Rc, time::Duration};
 use uom::si::{f64::Ratio, ratio::percent};
 
 use crate::{
+    ground_services::GroundServiceProcess,
     shared::random_from_range,
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -993,6 +994,21 @@ impl<const P: usize, const G: usize, const C: usize> PayloadManager<P, G, C> {
         }
     }
 }
+impl<const P: usize, const G: usize, const C: usize> GroundServiceProcess
+    for PayloadManager<P, G, C>
+{
+    fn start(&mut self) {
+        self.boarding_inputs.start_boarding();
+    }
+
+    fn stop(&mut self) {
+        self.emit_stop_boarding();
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_boarding_allowed()
+    }
+}
 impl<const P: usize, const G: usize, const C: usize> SimulationElement for PayloadManager<P, G, C> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.boarding_inputs.accept(visitor);
@@ -1047,6 +1063,10 @@ impl BoardingInputs {
         self.board_rate
     }
 
+    pub fn start_boarding(&mut self) {
+        self.is_boarding = true;
+    }
+
     pub fn stop_boarding(&mut self) {
         self.is_boarding = false;
     }
@@ -0,0 +1,408 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{calculate_towards_target_temperature, BleedLeakZone, FireDetectionLoopID},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use uom::si::{f64::*, pressure::psi, thermodynamic_temperature::degree_celsius};
+
+/// The area surrounding a section of the hot air ducts. When the duct leaks, the escaping bleed
+/// air heats up the area depending on how much the duct is still pressurised. Once the duct is
+/// isolated, the area slowly cools down towards ambient temperature.
+pub struct BleedDuctArea {
+    leak_failure: Failure,
+    temperature: ThermodynamicTemperature,
+}
+impl BleedDuctArea {
+    const HEATING_RATE_DEGREES_C_PER_SECOND: f64 = 20.;
+    const COOLING_RATE_DEGREES_C_PER_SECOND: f64 = 2.;
+
+    // Gauge pressure at which the leak blows at full strength onto the surrounding structure
+    const FULL_LEAK_DUCT_PRESSURE_PSIG: f64 = 20.;
+    const LEAK_TEMPERATURE_RATIO: f64 = 0.9;
+
+    pub fn new(zone: BleedLeakZone) -> Self {
+        Self {
+            leak_failure: Failure::new(FailureType::BleedDuctLeak(zone)),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        duct_pressure: Pressure,
+        duct_temperature: ThermodynamicTemperature,
+    ) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+
+        let target_temperature = if self.leak_failure.is_active() {
+            let leak_strength = ((duct_pressure - context.ambient_pressure()).get::<psi>()
+                / Self::FULL_LEAK_DUCT_PRESSURE_PSIG)
+                .clamp(0., 1.);
+
+            ambient_temperature
+                + (duct_temperature.get::<degree_celsius>() - ambient_temperature).max(0.)
+                    * Self::LEAK_TEMPERATURE_RATIO
+                    * leak_strength
+        } else {
+            ambient_temperature
+        };
+        let target_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(target_temperature);
+
+        self.temperature = calculate_towards_target_temperature(
+            self.temperature,
+            target_temperature,
+            if target_temperature > self.temperature {
+                Self::HEATING_RATE_DEGREES_C_PER_SECOND
+            } else {
+                Self::COOLING_RATE_DEGREES_C_PER_SECOND
+            },
+            context.delta(),
+        );
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+}
+impl SimulationElement for BleedDuctArea {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.leak_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A sensing element running along the ducts, triggering as soon as the temperature of any of
+/// the areas it passes through exceeds its alarm temperature.
+struct OverheatDetectionLoop {
+    failure: Failure,
+    alarm_temperature: ThermodynamicTemperature,
+    is_triggered: bool,
+}
+impl OverheatDetectionLoop {
+    fn new(
+        zone: BleedLeakZone,
+        loop_id: FireDetectionLoopID,
+        alarm_temperature: ThermodynamicTemperature,
+    ) -> Self {
+        Self {
+            failure: Failure::new(FailureType::BleedLeakDetectionLoop(zone, loop_id)),
+            alarm_temperature,
+            is_triggered: false,
+        }
+    }
+
+    fn update(&mut self, areas: &[&BleedDuctArea]) {
+        self.is_triggered = !self.failure.is_active()
+            && areas
+                .iter()
+                .any(|area| area.temperature() > self.alarm_temperature);
+    }
+
+    fn is_triggered(&self) -> bool {
+        self.is_triggered
+    }
+
+    fn has_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+}
+impl SimulationElement for OverheatDetectionLoop {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The leak detection logic of a bleed monitoring computer for one zone, monitoring one loop or
+/// a pair of loops (A and B). With a pair of loops, a leak is detected when both loops trigger,
+/// or when one loop triggers while the other one has failed.
+/// A detected leak is memorised by the computer until it loses power.
+pub struct BleedLeakDetector<const N: usize> {
+    leak_detected_id: VariableIdentifier,
+    loop_fault_id: [VariableIdentifier; N],
+
+    loops: [OverheatDetectionLoop; N],
+    leak_detected: bool,
+}
+impl<const N: usize> BleedLeakDetector<N> {
+    const LOOP_IDS: [FireDetectionLoopID; 2] = [FireDetectionLoopID::A, FireDetectionLoopID::B];
+
+    pub fn new(
+        context: &mut InitContext,
+        zone: BleedLeakZone,
+        alarm_temperature: ThermodynamicTemperature,
+    ) -> Self {
+        debug_assert!(N == 1 || N == 2);
+
+        let loop_names = ["A", "B"];
+
+        Self {
+            leak_detected_id: context.get_identifier(format!("PNEU_{}_LEAK_DETECTED", zone)),
+            loop_fault_id: std::array::from_fn(|index| {
+                context.get_identifier(format!(
+                    "PNEU_{}_LEAK_DETECTION_LOOP_{}_FAULT",
+                    zone, loop_names[index]
+                ))
+            }),
+
+            loops: std::array::from_fn(|index| {
+                OverheatDetectionLoop::new(zone, Self::LOOP_IDS[index], alarm_temperature)
+            }),
+            leak_detected: false,
+        }
+    }
+
+    pub fn update(&mut self, areas: &[&BleedDuctArea], is_powered: bool) {
+        self.loops.iter_mut().for_each(|l| l.update(areas));
+
+        let any_loop_triggered = self.loops.iter().any(|l| l.is_triggered());
+        let all_operative_loops_triggered = self
+            .loops
+            .iter()
+            .all(|l| l.is_triggered() || l.has_failed());
+
+        self.leak_detected = is_powered
+            && (self.leak_detected || (any_loop_triggered && all_operative_loops_triggered));
+    }
+
+    pub fn leak_detected(&self) -> bool {
+        self.leak_detected
+    }
+
+    pub fn has_loop_fault(&self) -> bool {
+        self.loops.iter().any(|l| l.has_failed())
+    }
+}
+impl<const N: usize> SimulationElement for BleedLeakDetector<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.loops, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.leak_detected_id, self.leak_detected);
+
+        for (id, detection_loop) in self.loop_fault_id.iter().zip(&self.loops) {
+            writer.write(id, detection_loop.has_failed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed},
+        Aircraft,
+    };
+    use std::time::Duration;
+
+    struct TestAircraft {
+        wing_area: BleedDuctArea,
+        pack_bay_area: BleedDuctArea,
+        detector: BleedLeakDetector<2>,
+
+        duct_pressure: Pressure,
+        duct_temperature: ThermodynamicTemperature,
+        is_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                wing_area: BleedDuctArea::new(BleedLeakZone::Wing(1)),
+                pack_bay_area: BleedDuctArea::new(BleedLeakZone::PackBay(1)),
+                detector: BleedLeakDetector::new(
+                    context,
+                    BleedLeakZone::Wing(1),
+                    ThermodynamicTemperature::new::<degree_celsius>(124.),
+                ),
+
+                duct_pressure: Pressure::new::<psi>(14.7),
+                duct_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+                is_powered: true,
+            }
+        }
+
+        fn pressurise_duct(&mut self) {
+            self.duct_pressure = Pressure::new::<psi>(14.7 + 42.);
+            self.duct_temperature = ThermodynamicTemperature::new::<degree_celsius>(200.);
+        }
+
+        fn depressurise_duct(&mut self) {
+            self.duct_pressure = Pressure::new::<psi>(14.7);
+        }
+
+        fn set_powered(&mut self, is_powered: bool) {
+            self.is_powered = is_powered;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.wing_area
+                .update(context, self.duct_pressure, self.duct_temperature);
+            self.pack_bay_area
+                .update(context, self.duct_pressure, self.duct_temperature);
+
+            self.detector
+                .update(&[&self.wing_area, &self.pack_bay_area], self.is_powered);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.wing_area.accept(visitor);
+            self.pack_bay_area.accept(visitor);
+            self.detector.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.pressurise_duct());
+
+        test_bed
+    }
+
+    fn leak_detected(test_bed: &mut SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.read_by_name("PNEU_WING_1_LEAK_DETECTED")
+    }
+
+    #[test]
+    fn no_leak_detected_without_failure() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(30));
+
+        assert!(!leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn leak_detected_when_pressurised_duct_leaks() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!leak_detected(&mut test_bed));
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        assert!(leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn leak_in_another_area_along_the_loops_is_detected() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::PackBay(1)));
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        assert!(leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn leak_of_depressurised_duct_is_not_detected() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.depressurise_duct());
+        test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+
+        for _ in 0..30 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        assert!(!leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn leak_still_detected_with_one_failed_loop() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedLeakDetectionLoop(
+            BleedLeakZone::Wing(1),
+            FireDetectionLoopID::A,
+        ));
+        test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        let loop_a_fault: bool = test_bed.read_by_name("PNEU_WING_1_LEAK_DETECTION_LOOP_A_FAULT");
+        let loop_b_fault: bool = test_bed.read_by_name("PNEU_WING_1_LEAK_DETECTION_LOOP_B_FAULT");
+
+        assert!(leak_detected(&mut test_bed));
+        assert!(loop_a_fault);
+        assert!(!loop_b_fault);
+    }
+
+    #[test]
+    fn leak_not_detected_with_both_loops_failed() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedLeakDetectionLoop(
+            BleedLeakZone::Wing(1),
+            FireDetectionLoopID::A,
+        ));
+        test_bed.fail(FailureType::BleedLeakDetectionLoop(
+            BleedLeakZone::Wing(1),
+            FireDetectionLoopID::B,
+        ));
+        test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        assert!(!leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn detected_leak_is_memorised_after_isolation() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        test_bed.command(|a| a.depressurise_duct());
+
+        for _ in 0..120 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        assert!(leak_detected(&mut test_bed));
+    }
+
+    #[test]
+    fn detected_leak_is_reset_by_power_loss() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        test_bed.unfail(FailureType::BleedDuctLeak(BleedLeakZone::Wing(1)));
+        test_bed.command(|a| a.set_powered(false));
+
+        for _ in 0..120 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        test_bed.command(|a| a.set_powered(true));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(!leak_detected(&mut test_bed));
+    }
+}
@@ -21,6 +21,7 @@ use uom::si::{
     volume::cubic_meter,
 };
 
+pub mod leak_detection;
 pub mod valve;
 
 pub trait PneumaticValveSignal {
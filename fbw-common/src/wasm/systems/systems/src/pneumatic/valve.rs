@@ -1,8 +1,12 @@
 use std::f64::consts::PI;
 
 use crate::{
+    failures::{Failure, FailureType},
     pneumatic::{Solenoid, SolenoidSignal},
-    shared::{interpolation, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve},
+    shared::{
+        interpolation, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve,
+        PneumaticValveId,
+    },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
@@ -16,10 +20,107 @@ use uom::si::{
 
 use super::{ControllablePneumaticValve, PneumaticContainer, PneumaticValveSignal};
 
+/// Mechanical faults which can be injected into a valve. A stuck valve no longer follows
+/// its control signal or the pressure acting on it, and its position feedback reports where
+/// the valve actually is. An internally leaking valve lets some air pass even when closed,
+/// which its position feedback does not show.
+pub struct PneumaticValveFailures {
+    stuck_open: Failure,
+    stuck_closed: Failure,
+    stuck_in_position: Failure,
+    internal_leak: Failure,
+    stuck_open_amount: Option<Ratio>,
+}
+impl PneumaticValveFailures {
+    const INTERNAL_LEAK_OPEN_AMOUNT: f64 = 0.05;
+
+    pub fn new(id: PneumaticValveId) -> Self {
+        Self {
+            stuck_open: Failure::new(FailureType::PneumaticValveStuckOpen(id)),
+            stuck_closed: Failure::new(FailureType::PneumaticValveStuckClosed(id)),
+            stuck_in_position: Failure::new(FailureType::PneumaticValveStuckInPosition(id)),
+            internal_leak: Failure::new(FailureType::PneumaticValveInternalLeak(id)),
+            stuck_open_amount: None,
+        }
+    }
+
+    /// Returns the position the valve takes when it is driven from `current_open_amount`
+    /// towards `commanded_open_amount`.
+    pub fn open_amount(
+        &mut self,
+        current_open_amount: Ratio,
+        commanded_open_amount: Ratio,
+    ) -> Ratio {
+        if !self.stuck_in_position.is_active() {
+            self.stuck_open_amount = None;
+        }
+
+        if self.stuck_closed.is_active() {
+            Ratio::new::<ratio>(0.)
+        } else if self.stuck_open.is_active() {
+            Ratio::new::<ratio>(1.)
+        } else if self.stuck_in_position.is_active() {
+            *self.stuck_open_amount.get_or_insert(current_open_amount)
+        } else {
+            commanded_open_amount
+        }
+    }
+
+    /// Returns the opening through which air flows for a valve at the given position.
+    pub fn transfer_open_amount(&self, open_amount: Ratio) -> Ratio {
+        if self.internal_leak.is_active() {
+            open_amount.max(Ratio::new::<ratio>(Self::INTERNAL_LEAK_OPEN_AMOUNT))
+        } else {
+            open_amount
+        }
+    }
+}
+impl SimulationElement for PneumaticValveFailures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.stuck_open.accept(visitor);
+        self.stuck_closed.accept(visitor);
+        self.stuck_in_position.accept(visitor);
+        self.internal_leak.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+fn open_amount_with_failures(
+    failures: &mut Option<PneumaticValveFailures>,
+    current_open_amount: Ratio,
+    commanded_open_amount: Ratio,
+) -> Ratio {
+    match failures {
+        Some(failures) => failures.open_amount(current_open_amount, commanded_open_amount),
+        None => commanded_open_amount,
+    }
+}
+
+fn transfer_open_amount_with_failures(
+    failures: &Option<PneumaticValveFailures>,
+    open_amount: Ratio,
+) -> Ratio {
+    match failures {
+        Some(failures) => failures.transfer_open_amount(open_amount),
+        None => open_amount,
+    }
+}
+
+fn accept_failures<T: SimulationElementVisitor>(
+    failures: &mut Option<PneumaticValveFailures>,
+    visitor: &mut T,
+) {
+    if let Some(failures) = failures {
+        failures.accept(visitor);
+    }
+}
+
 /// A valve only controlled by the physical forces due to the pressure gradient. This does not accept any signals.
 pub struct PurelyPneumaticValve {
     open_amount: Ratio,
     connector: PneumaticContainerConnector,
+    failures: Option<PneumaticValveFailures>,
 }
 impl PurelyPneumaticValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
@@ -28,9 +129,15 @@ impl PurelyPneumaticValve {
         Self {
             open_amount: Ratio::new::<ratio>(0.),
             connector: PneumaticContainerConnector::new(),
+            failures: None,
         }
     }
 
+    pub fn with_failures(mut self, id: PneumaticValveId) -> Self {
+        self.failures = Some(PneumaticValveFailures::new(id));
+        self
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
@@ -42,16 +149,23 @@ impl PurelyPneumaticValve {
         );
 
         self.connector
-            .with_transfer_speed_factor(self.open_amount)
+            .with_transfer_speed_factor(transfer_open_amount_with_failures(
+                &self.failures,
+                self.open_amount,
+            ))
             .update_move_fluid(context, container_one, container_two);
     }
 
     fn set_open_amount_from_pressure_difference(&mut self, pressure_difference: Pressure) {
-        self.open_amount = Ratio::new::<ratio>(
-            2. / PI
-                * (pressure_difference.get::<psi>() * Self::SPRING_CHARACTERISTIC)
-                    .atan()
-                    .max(0.),
+        self.open_amount = open_amount_with_failures(
+            &mut self.failures,
+            self.open_amount,
+            Ratio::new::<ratio>(
+                2. / PI
+                    * (pressure_difference.get::<psi>() * Self::SPRING_CHARACTERISTIC)
+                        .atan()
+                        .max(0.),
+            ),
         );
     }
 
@@ -68,6 +182,13 @@ impl PneumaticValve for PurelyPneumaticValve {
         self.open_amount.get::<percent>() > 0.
     }
 }
+impl SimulationElement for PurelyPneumaticValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_failures(&mut self.failures, visitor);
+
+        visitor.visit(self);
+    }
+}
 impl Default for PurelyPneumaticValve {
     fn default() -> Self {
         Self::new()
@@ -116,6 +237,7 @@ pub struct SolenoidValve<const N: usize> {
     characteristics: PneumaticValveCharacteristics<N>,
     solenoid: Solenoid,
     open_amount: Ratio,
+    failures: Option<PneumaticValveFailures>,
 }
 impl<const N: usize> SolenoidValve<N> {
     pub fn new(
@@ -127,9 +249,15 @@ impl<const N: usize> SolenoidValve<N> {
             characteristics,
             solenoid: Solenoid::new(powered_by),
             open_amount: Ratio::default(),
+            failures: None,
         }
     }
 
+    pub fn with_failures(mut self, id: PneumaticValveId) -> Self {
+        self.failures = Some(PneumaticValveFailures::new(id));
+        self
+    }
+
     pub fn update_solenoid(&mut self, controller: &impl ControllerSignal<SolenoidSignal>) {
         self.solenoid.update(controller);
     }
@@ -152,14 +280,21 @@ impl<const N: usize> SolenoidValve<N> {
         let current_open_amount = self.open_amount.get::<ratio>();
         let open_amount_change = context.delta_as_secs_f64() * self.characteristics.valve_speed;
 
-        self.open_amount = Ratio::new::<ratio>(if target_open_amount > current_open_amount {
-            target_open_amount.min(current_open_amount + open_amount_change)
-        } else {
-            target_open_amount.max(current_open_amount - open_amount_change)
-        });
+        self.open_amount = open_amount_with_failures(
+            &mut self.failures,
+            self.open_amount,
+            Ratio::new::<ratio>(if target_open_amount > current_open_amount {
+                target_open_amount.min(current_open_amount + open_amount_change)
+            } else {
+                target_open_amount.max(current_open_amount - open_amount_change)
+            }),
+        );
 
         self.connector
-            .with_transfer_speed_factor(self.open_amount)
+            .with_transfer_speed_factor(transfer_open_amount_with_failures(
+                &self.failures,
+                self.open_amount,
+            ))
             .update_move_fluid(context, upstream, downstream);
     }
 
@@ -179,6 +314,7 @@ impl<const N: usize> PneumaticValve for SolenoidValve<N> {
 impl<const N: usize> SimulationElement for SolenoidValve<N> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.solenoid.accept(visitor);
+        accept_failures(&mut self.failures, visitor);
 
         visitor.visit(self);
     }
@@ -189,6 +325,7 @@ pub struct ElectroPneumaticValve {
     connector: PneumaticContainerConnector,
     is_powered: bool,
     powered_by: ElectricalBusType,
+    failures: Option<PneumaticValveFailures>,
 }
 impl ElectroPneumaticValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
@@ -199,9 +336,15 @@ impl ElectroPneumaticValve {
             connector: PneumaticContainerConnector::new(),
             is_powered: false,
             powered_by,
+            failures: None,
         }
     }
 
+    pub fn with_failures(mut self, id: PneumaticValveId) -> Self {
+        self.failures = Some(PneumaticValveFailures::new(id));
+        self
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
@@ -212,19 +355,29 @@ impl ElectroPneumaticValve {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
+        } else {
+            self.open_amount =
+                open_amount_with_failures(&mut self.failures, self.open_amount, self.open_amount);
         }
 
         self.connector
-            .with_transfer_speed_factor(self.open_amount)
+            .with_transfer_speed_factor(transfer_open_amount_with_failures(
+                &self.failures,
+                self.open_amount,
+            ))
             .update_move_fluid(context, container_one, container_two);
     }
 
     fn set_open_amount_from_pressure_difference(&mut self, pressure_difference: Pressure) {
-        self.open_amount = Ratio::new::<ratio>(
-            2. / PI
-                * (pressure_difference.get::<psi>() * Self::SPRING_CHARACTERISTIC)
-                    .atan()
-                    .max(0.),
+        self.open_amount = open_amount_with_failures(
+            &mut self.failures,
+            self.open_amount,
+            Ratio::new::<ratio>(
+                2. / PI
+                    * (pressure_difference.get::<psi>() * Self::SPRING_CHARACTERISTIC)
+                        .atan()
+                        .max(0.),
+            ),
         );
     }
 
@@ -252,13 +405,19 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
     ) {
         if self.is_powered {
             if let Some(signal) = controller.signal() {
-                self.open_amount = signal.target_open_amount();
+                self.open_amount = open_amount_with_failures(
+                    &mut self.failures,
+                    self.open_amount,
+                    signal.target_open_amount(),
+                );
             }
         }
     }
 }
 impl SimulationElement for ElectroPneumaticValve {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_failures(&mut self.failures, visitor);
+
         visitor.visit(self);
     }
 
@@ -345,6 +504,7 @@ pub trait FullyClosed {
 pub struct DefaultValve {
     open_amount: Ratio,
     connector: PneumaticContainerConnector,
+    failures: Option<PneumaticValveFailures>,
 }
 impl PneumaticValve for DefaultValve {
     fn is_open(&self) -> bool {
@@ -356,6 +516,7 @@ impl DefaultValve {
         Self {
             open_amount,
             connector: PneumaticContainerConnector::new(),
+            failures: None,
         }
     }
 
@@ -367,6 +528,11 @@ impl DefaultValve {
         DefaultValve::new(Ratio::new::<ratio>(1.))
     }
 
+    pub fn with_failures(mut self, id: PneumaticValveId) -> Self {
+        self.failures = Some(PneumaticValveFailures::new(id));
+        self
+    }
+
     pub fn open_amount(&self) -> Ratio {
         self.open_amount
     }
@@ -377,8 +543,14 @@ impl DefaultValve {
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
+        self.open_amount =
+            open_amount_with_failures(&mut self.failures, self.open_amount, self.open_amount);
+
         self.connector
-            .with_transfer_speed_factor(self.open_amount)
+            .with_transfer_speed_factor(transfer_open_amount_with_failures(
+                &self.failures,
+                self.open_amount,
+            ))
             .update_move_fluid(context, container_one, container_two);
     }
 
@@ -389,8 +561,14 @@ impl DefaultValve {
         container_two: &mut impl PneumaticContainer,
         transfer_speed: f64,
     ) {
+        self.open_amount =
+            open_amount_with_failures(&mut self.failures, self.open_amount, self.open_amount);
+
         self.connector
-            .with_transfer_speed_factor(self.open_amount)
+            .with_transfer_speed_factor(transfer_open_amount_with_failures(
+                &self.failures,
+                self.open_amount,
+            ))
             .update_move_fluid_with_transfer_speed(
                 context,
                 container_one,
@@ -409,12 +587,18 @@ impl ControllablePneumaticValve for DefaultValve {
         controller: &U,
     ) {
         if let Some(signal) = controller.signal() {
-            self.open_amount = signal.target_open_amount();
+            self.open_amount = open_amount_with_failures(
+                &mut self.failures,
+                self.open_amount,
+                signal.target_open_amount(),
+            );
         }
     }
 }
 impl SimulationElement for DefaultValve {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_failures(&mut self.failures, visitor);
+
         visitor.visit(self);
     }
 }
@@ -612,7 +796,10 @@ mod tests {
         electrical::Electricity,
         pneumatic::{DefaultValve, PneumaticContainer, PneumaticPipe},
         shared::{ControllerSignal, InternationalStandardAtmosphere, MachNumber},
-        simulation::{test::TestVariableRegistry, InitContext},
+        simulation::{
+            test::{SimulationTestBed, TestAircraft, TestBed, TestVariableRegistry},
+            InitContext,
+        },
     };
 
     use std::time::Duration;
@@ -906,6 +1093,168 @@ mod tests {
         assert_eq!(valve.open_amount(), Ratio::new::<ratio>(0.));
     }
 
+    fn failed_default_valve_test_bed(
+        open_amount: Ratio,
+        failure_type: FailureType,
+    ) -> SimulationTestBed<TestAircraft<DefaultValve>> {
+        let mut test_bed = SimulationTestBed::from(
+            DefaultValve::new_closed().with_failures(PneumaticValveId::CrossBleed(1)),
+        );
+        test_bed.command_element(|valve| {
+            valve.update_open_amount(&TestValveController::new(open_amount))
+        });
+        test_bed.fail(failure_type);
+        test_bed.run();
+
+        test_bed
+    }
+
+    #[test]
+    fn stuck_closed_valve_does_not_open_when_commanded() {
+        let mut test_bed = failed_default_valve_test_bed(
+            Ratio::new::<ratio>(1.),
+            FailureType::PneumaticValveStuckClosed(PneumaticValveId::CrossBleed(1)),
+        );
+
+        test_bed.command_element(|valve| {
+            valve.update_open_amount(&TestValveController::new(Ratio::new::<ratio>(1.)))
+        });
+
+        assert!(test_bed.query_element(|valve| !valve.is_open()));
+    }
+
+    #[test]
+    fn stuck_open_valve_does_not_close_when_commanded() {
+        let mut test_bed = failed_default_valve_test_bed(
+            Ratio::new::<ratio>(0.),
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::CrossBleed(1)),
+        );
+
+        test_bed.command_element(|valve| {
+            valve.update_open_amount(&TestValveController::new(Ratio::new::<ratio>(0.)))
+        });
+
+        assert!(test_bed.query_element(|valve| valve.open_amount() == Ratio::new::<ratio>(1.)));
+    }
+
+    #[test]
+    fn valve_stuck_in_position_keeps_its_position() {
+        let mut test_bed = failed_default_valve_test_bed(
+            Ratio::new::<ratio>(0.3),
+            FailureType::PneumaticValveStuckInPosition(PneumaticValveId::CrossBleed(1)),
+        );
+
+        test_bed.command_element(|valve| {
+            valve.update_open_amount(&TestValveController::new(Ratio::new::<ratio>(1.)))
+        });
+        assert_about_eq!(
+            test_bed.query_element(|valve| valve.open_amount().get::<ratio>()),
+            0.3
+        );
+
+        test_bed.command_element(|valve| {
+            valve.update_open_amount(&TestValveController::new(Ratio::new::<ratio>(0.)))
+        });
+        assert_about_eq!(
+            test_bed.query_element(|valve| valve.open_amount().get::<ratio>()),
+            0.3
+        );
+    }
+
+    #[test]
+    fn valve_follows_command_again_once_failure_is_cleared() {
+        let mut test_bed = failed_default_valve_test_bed(
+            Ratio::new::<ratio>(0.),
+            FailureType::PneumaticValveStuckOpen(PneumaticValveId::CrossBleed(1)),
+        );
+
+        test_bed.unfail(FailureType::PneumaticValveStuckOpen(
+            PneumaticValveId::CrossBleed(1),
+        ));
+        test_bed.run();
+        test_bed.command_element(|valve| {
+            valve.update_open_amount(&TestValveController::new(Ratio::new::<ratio>(0.)))
+        });
+
+        assert!(test_bed.query_element(|valve| !valve.is_open()));
+    }
+
+    #[test]
+    fn internally_leaking_valve_moves_fluid_while_reporting_closed() {
+        let mut test_bed = failed_default_valve_test_bed(
+            Ratio::new::<ratio>(0.),
+            FailureType::PneumaticValveInternalLeak(PneumaticValveId::CrossBleed(1)),
+        );
+
+        let mut container_one = quick_container(1., 30., 15.);
+        let mut container_two = quick_container(1., 14.7, 15.);
+        let context = context(Duration::from_millis(100), Length::new::<foot>(0.));
+
+        test_bed.command_element(|valve| {
+            valve.update_move_fluid(&context, &mut container_one, &mut container_two)
+        });
+
+        assert!(test_bed.query_element(|valve| !valve.is_open()));
+        assert!(
+            test_bed.query_element(|valve| valve.fluid_flow().get::<kilogram_per_second>() > 0.)
+        );
+        assert!(container_two.pressure() > Pressure::new::<psi>(14.7));
+    }
+
+    #[test]
+    fn stuck_open_electropneumatic_valve_stays_open_without_pressure() {
+        let mut test_bed = SimulationTestBed::from(
+            ElectroPneumaticValve::new(ElectricalBusType::DirectCurrent(2))
+                .with_failures(PneumaticValveId::PressureRegulating(1)),
+        );
+        test_bed.fail(FailureType::PneumaticValveStuckOpen(
+            PneumaticValveId::PressureRegulating(1),
+        ));
+        test_bed.run();
+
+        let mut container_one = quick_container(1., 14., 15.);
+        let mut container_two = quick_container(1., 14., 15.);
+        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+
+        test_bed.command_element(|valve| {
+            valve.update_move_fluid(&context, &mut container_one, &mut container_two)
+        });
+
+        assert!(test_bed.query_element(|valve| !valve.is_powered()));
+        assert!(test_bed.query_element(|valve| valve.is_open()));
+    }
+
+    #[test]
+    fn stuck_closed_solenoid_valve_does_not_open_with_energized_solenoid() {
+        let mut test_bed = SimulationTestBed::from(
+            SolenoidValve::new(
+                PneumaticValveCharacteristics::new(
+                    Pressure::new::<psi>(0.),
+                    [35., 75.],
+                    [1., 0.],
+                    1.,
+                ),
+                ElectricalBusType::DirectCurrent(2),
+            )
+            .with_failures(PneumaticValveId::HighPressure(1)),
+        );
+        test_bed.fail(FailureType::PneumaticValveStuckClosed(
+            PneumaticValveId::HighPressure(1),
+        ));
+        test_bed.run();
+
+        let mut container_one = quick_container(1., 30., 15.);
+        let mut container_two = quick_container(1., 14.7, 15.);
+        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+
+        test_bed.command_element(|valve| {
+            valve.update_move_fluid(&context, &mut container_one, &mut container_two)
+        });
+
+        assert!(test_bed.query_element(|valve| !valve.is_open()));
+        assert_about_eq!(container_two.pressure().get::<psi>(), 14.7);
+    }
+
     #[test]
     fn exhaust_makes_pressure_go_to_ambient_pressure() {
         let mut container = quick_container(1., 20., 15.);
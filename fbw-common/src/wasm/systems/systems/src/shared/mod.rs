@@ -103,6 +103,11 @@ pub trait CargoDoorLocked {
     fn aft_cargo_door_locked(&self) -> bool;
 }
 
+pub trait SmokeDetection {
+    /// Indicates if smoke is detected in the given zone.
+    fn smoke_detected(&self, zone: FireDetectionZone) -> bool;
+}
+
 pub trait LgciuWeightOnWheels {
     fn right_gear_compressed(&self, treat_ext_pwr_as_ground: bool) -> bool;
     fn right_gear_extended(&self, treat_ext_pwr_as_ground: bool) -> bool;
@@ -201,6 +206,26 @@ pub enum GearActuatorId {
     GearDoorRight,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum HydraulicSectionId {
+    Pump(usize),
+    System,
+    Auxiliary,
+}
+
+/// Identifies a flight control surface panel. Panel numbers start at 1 and follow the
+/// panel ordering of each aircraft.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum FlightControlSurfaceId {
+    LeftAileron(usize),
+    RightAileron(usize),
+    LeftElevator(usize),
+    RightElevator(usize),
+    Rudder(usize),
+    LeftSpoiler(usize),
+    RightSpoiler(usize),
+}
+
 pub trait EngineCorrectedN1 {
     fn corrected_n1(&self) -> Ratio;
 }
@@ -280,6 +305,7 @@ pub trait SectionPressure {
     fn pressure_downstream_leak_valve(&self) -> Pressure;
     fn pressure_downstream_priority_valve(&self) -> Pressure;
     fn is_pressure_switch_pressurised(&self) -> bool;
+    fn fluid_viscosity_flow_ratio(&self) -> Ratio;
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -469,6 +495,10 @@ pub trait PowerConsumptionReport {
 
     /// Returns the total power consumed from the given [PotentialOrigin].
     fn total_consumption_of(&self, potential_origin: PotentialOrigin) -> Power;
+
+    /// Returns the total power consumed directly from the given bus. Power consumed
+    /// from buses or elements to which the bus provides power is not included.
+    fn total_consumption_from_bus(&self, bus_type: ElectricalBusType) -> Power;
 }
 
 /// Trait through which elements can consume power from the aircraft's electrical system.
@@ -1113,6 +1143,11 @@ pub enum FireDetectionZone {
     Engine(usize),
     Apu,
     Mlg,
+    ForwardCargo,
+    AftCargo,
+    BulkCargo,
+    Lavatory,
+    Avionics,
 }
 
 impl Display for FireDetectionZone {
@@ -1121,6 +1156,11 @@ impl Display for FireDetectionZone {
             FireDetectionZone::Apu => write!(f, "APU"),
             FireDetectionZone::Mlg => write!(f, "MLG"),
             FireDetectionZone::Engine(number) => write!(f, "{}", number),
+            FireDetectionZone::ForwardCargo => write!(f, "FWD_CARGO"),
+            FireDetectionZone::AftCargo => write!(f, "AFT_CARGO"),
+            FireDetectionZone::BulkCargo => write!(f, "BULK_CARGO"),
+            FireDetectionZone::Lavatory => write!(f, "LAVATORY"),
+            FireDetectionZone::Avionics => write!(f, "AVIONICS"),
         }
     }
 }
@@ -1131,6 +1171,38 @@ pub enum FireDetectionLoopID {
     B,
 }
 
+/// A section of the hot air ducts monitored by the overheat detection loops of the bleed
+/// leak detection system. Wings and pack bays are numbered from left to right.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BleedLeakZone {
+    Pylon(usize),
+    Wing(usize),
+    ApuDuct,
+    PackBay(usize),
+}
+
+impl Display for BleedLeakZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BleedLeakZone::Pylon(number) => write!(f, "PYLON_{}", number),
+            BleedLeakZone::Wing(number) => write!(f, "WING_{}", number),
+            BleedLeakZone::ApuDuct => write!(f, "APU_DUCT"),
+            BleedLeakZone::PackBay(number) => write!(f, "PACK_BAY_{}", number),
+        }
+    }
+}
+
+/// A bleed air valve which can be failed mechanically. Engines, cross bleed valves and pack flow
+/// control valves are numbered from left to right.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PneumaticValveId {
+    HighPressure(usize),
+    PressureRegulating(usize),
+    CrossBleed(usize),
+    PackFlowControl(usize),
+    ApuBleed,
+}
+
 pub trait Clamp {
     /// Restrict a value to a certain interval unless it is NaN.
     ///
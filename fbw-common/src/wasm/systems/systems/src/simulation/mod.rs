@@ -4,7 +4,7 @@ mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
 use crate::shared::{from_bool, ElectricalBusType};
 use crate::{
-    electrical::Electricity,
+    electrical::{ElectricalNetworkFormat, Electricity},
     failures::FailureType,
     shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus},
     shared::arinc825::{from_arinc825, to_arinc825, Arinc825Word},
@@ -470,6 +470,18 @@ impl<T: Aircraft> Simulation<T> {
             .accept(&mut FailureSimulationElementVisitor::new(active_failures));
     }
 
+    /// Enables or disables recording of the electrical network during each tick.
+    /// See [Electricity::record_network] for details.
+    pub fn record_electrical_network(&mut self, enabled: bool) {
+        self.electricity.record_network(enabled);
+    }
+
+    /// Exports the electrical network as it was during the last tick, or `None`
+    /// when the network isn't being recorded.
+    pub fn export_electrical_network(&self, format: ElectricalNetworkFormat) -> Option<String> {
+        self.electricity.export_network(format)
+    }
+
     fn electricity(&self) -> &Electricity {
         &self.electricity
     }
@@ -11,7 +11,7 @@ use uom::si::{
 };
 
 use crate::{
-    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    electrical::{test::TestElectricitySource, Contactor, ElectricalBus, Electricity},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
         MachNumber, PotentialOrigin, PowerConsumptionReport,
@@ -411,6 +411,7 @@ impl EgpwsElectricalHarness for TestElectricalHarness {
 
 struct TestAircraft {
     electricity_source: TestElectricitySource,
+    ac_1_bus_contactor: Contactor,
     ac_1_bus: ElectricalBus,
     ra: TestRa,
     adiru: TestAdiru,
@@ -421,12 +422,16 @@ struct TestAircraft {
     power_consumption: Power,
 }
 impl TestAircraft {
+    const AC_1_BUS_CONTACTOR_CLOSING_TIME: Duration = Duration::from_millis(50);
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             electricity_source: TestElectricitySource::powered(
                 context,
                 PotentialOrigin::EngineGenerator(1),
             ),
+            ac_1_bus_contactor: Contactor::new(context, "AC_1_BUS")
+                .with_closing_time(Self::AC_1_BUS_CONTACTOR_CLOSING_TIME),
             ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
             ra: TestRa::new(context, Length::new::<foot>(0.0)),
             adiru: TestAdiru::new(context),
@@ -444,20 +449,25 @@ impl TestAircraft {
     fn set_ac_1_power(&mut self, is_powered: bool) {
         self.is_ac_1_powered = is_powered;
     }
+
+    fn is_ac_1_bus_powered(&self, electricity: &Electricity) -> bool {
+        electricity.is_powered(&self.ac_1_bus)
+    }
 }
 impl Aircraft for TestAircraft {
     fn update_before_power_distribution(
         &mut self,
-        _: &UpdateContext,
+        context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
         self.electricity_source
             .power_with_potential(ElectricPotential::new::<volt>(115.));
         electricity.supplied_by(&self.electricity_source);
 
-        if self.is_ac_1_powered {
-            electricity.flow(&self.electricity_source, &self.ac_1_bus);
-        }
+        self.ac_1_bus_contactor
+            .close_after_closing_time_when(context, self.is_ac_1_powered);
+        electricity.flow(&self.electricity_source, &self.ac_1_bus_contactor);
+        electricity.flow(&self.ac_1_bus_contactor, &self.ac_1_bus);
     }
 
     fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -520,6 +530,26 @@ impl EgpwcTestBed {
         self
     }
 
+    /// Opens the AC 1 bus contactor for the given time. Once commanded closed again,
+    /// the bus stays unpowered for the closing time of the contactor, as it does during
+    /// a break power transfer.
+    fn interrupt_ac_1_power(mut self, open_for: Duration) -> Self {
+        self = self.no_power();
+        self.run_with_delta(open_for);
+        assert!(!self.is_ac_1_bus_powered());
+
+        self = self.powered();
+        while !self.is_ac_1_bus_powered() {
+            self.run_with_delta(Duration::from_millis(10));
+        }
+
+        self
+    }
+
+    fn is_ac_1_bus_powered(&self) -> bool {
+        self.query_elec(|a, elec| a.is_ac_1_bus_powered(elec))
+    }
+
     fn on_ground(mut self) -> Self {
         let terr_height = ReadByName::<EgpwcTestBed, Length>::read_by_name(
             &mut self,
@@ -686,6 +716,29 @@ fn self_tests_after_power_loss_on_ground_and_emits_no_warnings() {
     test_bed.assert_no_warning_active();
 }
 
+#[test]
+fn survives_a_break_power_transfer() {
+    let mut test_bed = test_bed_with().on_ground().and().powered();
+    test_bed.run_with_delta(Duration::from_millis(1));
+    assert!(!test_bed.egpws_sys_fault());
+
+    let mut test_bed = test_bed.interrupt_ac_1_power(Duration::from_millis(1));
+    assert!(!test_bed.egpws_sys_fault());
+    test_bed.assert_no_warning_active();
+}
+
+#[test]
+fn self_tests_after_power_interruption_longer_than_holdover() {
+    let mut test_bed = test_bed_with().on_ground().and().powered();
+    test_bed.run_with_delta(Duration::from_millis(1));
+    assert!(!test_bed.egpws_sys_fault());
+
+    let mut test_bed = test_bed.interrupt_ac_1_power(Duration::from_millis(300));
+    assert!(test_bed.egpws_sys_fault());
+    test_bed.run_with_delta(Duration::from_millis(20_000));
+    assert!(!test_bed.egpws_sys_fault());
+}
+
 #[test]
 fn emits_failure_when_ra_is_failed() {
     let mut test_bed = test_bed_with()
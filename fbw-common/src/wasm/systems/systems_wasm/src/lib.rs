@@ -30,6 +30,7 @@ use std::cell::RefCell;
 use std::fmt::{Display, Formatter};
 use std::rc::Rc;
 use std::{error::Error, time::Duration};
+use systems::electrical::ElectricalNetworkFormat;
 use systems::shared::ElectricalBusType;
 use systems::simulation::{InitContext, StartState};
 use systems::{
@@ -188,6 +189,7 @@ pub struct MsfsHandler {
     variables: Option<MsfsVariableRegistry>,
     aspects: Vec<Box<dyn Aspect>>,
     failures: Rc<RefCell<Failures>>,
+    electrical_network_format: Rc<RefCell<Option<ElectricalNetworkFormat>>>,
     _commbus: CommBus<'static>,
     time: Time,
 }
@@ -207,10 +209,26 @@ impl MsfsHandler {
             });
         }
         CommBus::call("FBW_FAILURE_REQUEST", "", CommBusBroadcastFlags::JS);
+
+        // Tooling can request the electrical network to be published after every tick,
+        // by sending "dot" or "json". Any other value stops the publishing.
+        let electrical_network_format = Rc::new(RefCell::new(None));
+        {
+            let electrical_network_format = electrical_network_format.clone();
+            commbus.register("FBW_ELEC_NETWORK_REQUEST", move |data| {
+                *electrical_network_format.borrow_mut() = match data {
+                    "dot" => Some(ElectricalNetworkFormat::Dot),
+                    "json" => Some(ElectricalNetworkFormat::Json),
+                    _ => None,
+                };
+            });
+        }
+
         Ok(Self {
             variables: Some(variables),
             aspects,
             failures,
+            electrical_network_format,
             _commbus: commbus,
             time: Time::new(sim_connect)?,
         })
@@ -229,8 +247,21 @@ impl MsfsHandler {
                     self.pre_tick(sim_connect, delta_time)?;
                     self.read_failures_into_simulation(simulation);
 
+                    let electrical_network_format = *self.electrical_network_format.borrow();
+                    simulation.record_electrical_network(electrical_network_format.is_some());
+
                     simulation.tick(delta_time, self.time.simulation_time(), self);
                     self.post_tick(sim_connect)?;
+
+                    if let Some(network) = electrical_network_format
+                        .and_then(|format| simulation.export_electrical_network(format))
+                    {
+                        CommBus::call(
+                            "FBW_ELEC_NETWORK_UPDATE",
+                            &network,
+                            CommBusBroadcastFlags::JS,
+                        );
+                    }
                 }
             }
             MSFSEvent::SimConnect(message) => match message {
@@ -32,6 +32,8 @@ fn main() {
         // included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .parse_callbacks(Box::new(CustomDeriveCallback::new()))
+        // The ARINC 429 word is provided by the arinc429 module, to allow SSM decoding.
+        .blocklist_type("base_arinc_429")
         // Finish the builder and generate the bindings.
         .generate()
         // Unwrap the Result and panic on failure.
@@ -46,6 +48,8 @@ fn main() {
         // included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .parse_callbacks(Box::new(CustomDeriveCallback::new()))
+        // The ARINC 429 word is provided by the arinc429 module, to allow SSM decoding.
+        .blocklist_type("base_arinc_429")
         // Finish the builder and generate the bindings.
         .generate()
         // Unwrap the Result and panic on failure.
@@ -7,4 +7,6 @@ use serde::Serialize;
 
 use bytemuck::AnyBitPattern;
 
+use crate::arinc429::base_arinc_429;
+
 include!(concat!(env!("OUT_DIR"), "/bindings_380.rs"));
@@ -0,0 +1,293 @@
+use bytemuck::AnyBitPattern;
+use serde::{
+    ser::{SerializeStruct, Serializer},
+    Serialize,
+};
+use systems::shared::arinc429::SignStatus;
+
+// The SSM of an ARINC 429 word is serialized as a newtype variant of this name, carrying the
+// abbreviated sign status as variant and the raw SSM as value. Regular serializers write the
+// raw value, while the SsmDecodingSerializer below writes the abbreviation instead.
+const SIGN_STATUS: &str = "SignStatus";
+
+fn abbreviation(ssm: SignStatus) -> &'static str {
+    match ssm {
+        SignStatus::FailureWarning => "FW",
+        SignStatus::NoComputedData => "NCD",
+        SignStatus::FunctionalTest => "FT",
+        SignStatus::NormalOperation => "NO",
+    }
+}
+
+// Replaces the bindgen generated struct of the same name, which is blocklisted in build.rs,
+// so that the SSM can be decoded while the records are streamed into the CSV writer.
+#[allow(non_camel_case_types, non_snake_case)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, AnyBitPattern)]
+pub struct base_arinc_429 {
+    pub SSM: u32,
+    pub Data: f32,
+}
+
+impl base_arinc_429 {
+    pub fn ssm(&self) -> SignStatus {
+        // Only the two SSM bits are considered, so garbled records cannot abort the conversion.
+        (self.SSM & 0b11).into()
+    }
+}
+
+impl Serialize for base_arinc_429 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("base_arinc_429", 2)?;
+        state.serialize_field("SSM", &Ssm(self))?;
+        state.serialize_field("Data", &self.Data)?;
+        state.end()
+    }
+}
+
+struct Ssm<'a>(&'a base_arinc_429);
+
+impl Serialize for Ssm<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ssm = self.0.ssm();
+        serializer.serialize_newtype_variant(
+            SIGN_STATUS,
+            u64::from(ssm) as u32,
+            abbreviation(ssm),
+            &self.0.SSM,
+        )
+    }
+}
+
+// Wraps a record, so that the SSM of every ARINC 429 word within it is written
+// as its abbreviated sign status (FW/NCD/FT/NO) instead of the raw integer.
+pub struct SsmDecoded<'a, T: ?Sized>(pub &'a T);
+
+impl<T: ?Sized + Serialize> Serialize for SsmDecoded<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(SsmDecodingSerializer(serializer))
+    }
+}
+
+// Forwards everything to the wrapped serializer, except for the SSM of ARINC 429 words.
+// As the FDR records consist of structs and elementary data types only, just struct fields
+// are serialized through this serializer again.
+struct SsmDecodingSerializer<S>(S);
+
+macro_rules! forward_scalar {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<S::Ok, S::Error> {
+                self.0.$method(v)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for SsmDecodingSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    type SerializeSeq = S::SerializeSeq;
+    type SerializeTuple = S::SerializeTuple;
+    type SerializeTupleStruct = S::SerializeTupleStruct;
+    type SerializeTupleVariant = S::SerializeTupleVariant;
+    type SerializeMap = S::SerializeMap;
+    type SerializeStruct = SsmDecodingStruct<S::SerializeStruct>;
+    type SerializeStructVariant = S::SerializeStructVariant;
+
+    forward_scalar!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+    );
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_none()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.0.serialize_some(&SsmDecoded(value))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_newtype_struct(name, &SsmDecoded(value))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        if name == SIGN_STATUS {
+            self.0.serialize_str(variant)
+        } else {
+            self.0
+                .serialize_newtype_variant(name, variant_index, variant, &SsmDecoded(value))
+        }
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<S::SerializeSeq, S::Error> {
+        self.0.serialize_seq(len)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<S::SerializeTuple, S::Error> {
+        self.0.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<S::SerializeTupleStruct, S::Error> {
+        self.0.serialize_tuple_struct(name, len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<S::SerializeTupleVariant, S::Error> {
+        self.0
+            .serialize_tuple_variant(name, variant_index, variant, len)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<S::SerializeMap, S::Error> {
+        self.0.serialize_map(len)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        Ok(SsmDecodingStruct(self.0.serialize_struct(name, len)?))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<S::SerializeStructVariant, S::Error> {
+        self.0
+            .serialize_struct_variant(name, variant_index, variant, len)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+struct SsmDecodingStruct<S>(S);
+
+impl<S: SerializeStruct> SerializeStruct for SsmDecodingStruct<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        self.0.serialize_field(key, &SsmDecoded(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csv::WriterBuilder;
+
+    #[derive(Serialize)]
+    struct Record {
+        altitude: base_arinc_429,
+        speed: base_arinc_429,
+        discrete: u32,
+    }
+
+    fn record() -> Record {
+        Record {
+            altitude: base_arinc_429 {
+                SSM: 0b11,
+                Data: 1500.,
+            },
+            speed: base_arinc_429 {
+                SSM: 0b01,
+                Data: 0.,
+            },
+            discrete: 2,
+        }
+    }
+
+    fn to_csv(record: impl Serialize) -> String {
+        let mut writer = WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(Vec::new());
+        writer.serialize(record).unwrap();
+
+        String::from_utf8(writer.into_inner().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn raw_ssm_is_written_by_default() {
+        assert_eq!(to_csv(record()), "3,1500.0,1,0.0,2\n");
+    }
+
+    #[test]
+    fn ssm_is_written_as_sign_status_when_decoded() {
+        assert_eq!(to_csv(SsmDecoded(&record())), "NO,1500.0,NCD,0.0,2\n");
+    }
+
+    #[test]
+    fn garbled_ssm_bits_are_ignored_when_decoded() {
+        let word = base_arinc_429 {
+            SSM: 0xffff_fff2,
+            Data: 1.,
+        };
+
+        assert_eq!(word.ssm(), SignStatus::FunctionalTest);
+        assert_eq!(to_csv(SsmDecoded(&word)), "FT,1.0\n");
+    }
+}
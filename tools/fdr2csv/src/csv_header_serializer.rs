@@ -163,6 +163,7 @@ impl ser::Serializer for &mut CsvHeaderSerializer {
         Err(Error::Message("Unsupported datatype".to_owned()))
     }
 
+    // Used for the SSM of ARINC 429 words, which only occupies a single column.
     fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
@@ -173,7 +174,7 @@ impl ser::Serializer for &mut CsvHeaderSerializer {
     where
         T: ?Sized + Serialize,
     {
-        Err(Error::Message("Unsupported datatype".to_owned()))
+        self.serialize_scalar()
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
@@ -1,7 +1,8 @@
 use bytemuck::AnyBitPattern;
 use clap::Parser;
-use csv::WriterBuilder;
+use csv::{Writer, WriterBuilder};
 use flate2::bufread::GzDecoder;
+use serde::Serialize;
 use std::{
     fs::{File, OpenOptions},
     io::{prelude::*, BufReader, BufWriter, Error, ErrorKind},
@@ -12,6 +13,7 @@ mod a320;
 mod a320_headers;
 mod a380;
 mod a380_headers;
+mod arinc429;
 mod csv_header_serializer;
 mod error;
 
@@ -48,6 +50,9 @@ struct Args {
     /// Disregard the detected FDR file version, will output garbled data if version is mismatched
     #[arg(long, default_value_t = false)]
     override_interface_version: bool,
+    /// Decode the SSM of ARINC 429 words into FW/NCD/FT/NO instead of printing the raw value
+    #[arg(short = 'a', long, default_value_t = false)]
+    decode_arinc429: bool,
 }
 
 // Read number of bytes specified by the size of T from the binary file
@@ -66,6 +71,19 @@ pub fn read_bytes<T: AnyBitPattern>(reader: &mut impl Read) -> Result<T, Error>
     Ok(*res)
 }
 
+// Serialize a single FDR record into the CSV writer
+fn write_record<W: Write>(
+    writer: &mut Writer<W>,
+    record: &impl Serialize,
+    decode_arinc429: bool,
+) -> csv::Result<()> {
+    if decode_arinc429 {
+        writer.serialize(arinc429::SsmDecoded(record))
+    } else {
+        writer.serialize(record)
+    }
+}
+
 fn main() -> Result<(), std::io::Error> {
     // Parse CLI arguments
     let args = Args::parse();
@@ -158,7 +176,7 @@ fn main() -> Result<(), std::io::Error> {
     match aircraft_type {
         AircraftType::A320 => {
             while let Ok(fdr_data) = a320::read_record(&mut reader) {
-                writer.serialize(&fdr_data)?;
+                write_record(&mut writer, &fdr_data, args.decode_arinc429)?;
 
                 counter += 1;
 
@@ -170,7 +188,7 @@ fn main() -> Result<(), std::io::Error> {
         }
         AircraftType::A380 => {
             while let Ok(fdr_data) = a380::read_record(&mut reader) {
-                writer.serialize(&fdr_data)?;
+                write_record(&mut writer, &fdr_data, args.decode_arinc429)?;
 
                 counter += 1;
 